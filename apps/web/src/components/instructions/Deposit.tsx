@@ -1,12 +1,18 @@
 'use client';
 
 import { useState } from 'react';
-import { generateKeyPairSigner, type Address } from '@solana/kit';
-import { findReceiptPda, getDepositInstructionAsync } from '@solana/escrow-program-client';
+import { type Address, createSolanaRpc } from '@solana/kit';
+import {
+    fetchMaybeReceiptCounter,
+    findReceiptCounterPda,
+    findReceiptPda,
+    getDepositInstructionAsync,
+} from '@solana/escrow-program-client';
 import { useSendTx } from '@/hooks/useSendTx';
 import { useSavedValues } from '@/contexts/SavedValuesContext';
 import { useWallet } from '@/contexts/WalletContext';
 import { useProgramContext } from '@/contexts/ProgramContext';
+import { useRpcContext } from '@/contexts/RpcContext';
 import { TxResult } from '@/components/TxResult';
 import { firstValidationError, validateAddress, validatePositiveInteger } from '@/lib/validation';
 import { FormField, SendButton } from './shared';
@@ -16,10 +22,11 @@ export function Deposit() {
     const { send, sending, signature, error, reset } = useSendTx();
     const { defaultEscrow, defaultMint, rememberEscrow, rememberMint, rememberReceipt } = useSavedValues();
     const { programId } = useProgramContext();
+    const { rpcUrl } = useRpcContext();
     const [escrow, setEscrow] = useState('');
     const [mint, setMint] = useState('');
     const [amount, setAmount] = useState('');
-    const [generatedSeed, setGeneratedSeed] = useState('');
+    const [receiptNonce, setReceiptNonce] = useState('');
     const [generatedReceipt, setGeneratedReceipt] = useState('');
     const [formError, setFormError] = useState<string | null>(null);
 
@@ -40,15 +47,22 @@ export function Deposit() {
             return;
         }
 
-        const receiptSeed = await generateKeyPairSigner();
-        setGeneratedSeed(receiptSeed.address);
+        // The next receipt is derived from the depositor's counter (nonce 0 before the first deposit).
         const signerAddress = signer.address as Address;
+        const [receiptCounter] = await findReceiptCounterPda(
+            { escrow: escrow as Address, depositor: signerAddress, mint: mint as Address },
+            { programAddress: programId as Address },
+        );
+        const rpc = createSolanaRpc(rpcUrl);
+        const counterAccount = await fetchMaybeReceiptCounter(rpc, receiptCounter);
+        const nonce = counterAccount.exists ? counterAccount.data.nextNonce : 0n;
+        setReceiptNonce(nonce.toString());
         const [receipt] = await findReceiptPda(
             {
                 escrow: escrow as Address,
                 depositor: signerAddress,
                 mint: mint as Address,
-                receiptSeed: receiptSeed.address as Address,
+                nonce,
             },
             { programAddress: programId as Address },
         );
@@ -60,7 +74,9 @@ export function Deposit() {
                 escrow: escrow as Address,
                 mint: mint as Address,
                 amount: BigInt(amount),
-                receiptSeed,
+                proof: [],
+                receiptCounter,
+                receipt,
                 payer: signer,
             },
             { programAddress: programId as Address },
@@ -110,13 +126,13 @@ export function Deposit() {
                 hint="Amount in smallest token units (no decimals)"
                 required
             />
-            {generatedSeed && (
+            {receiptNonce && (
                 <FormField
-                    label="Generated Receipt Seed"
-                    value={generatedSeed}
+                    label="Receipt Nonce"
+                    value={receiptNonce}
                     onChange={() => {}}
                     readOnly
-                    hint="Random seed used to derive the receipt PDA"
+                    hint="Next nonce from the depositor's receipt counter, used to derive the receipt PDA"
                 />
             )}
             {generatedReceipt && (
@@ -9,7 +9,12 @@ import { useWallet } from '@/contexts/WalletContext';
 import { useProgramContext } from '@/contexts/ProgramContext';
 import { TxResult } from '@/components/TxResult';
 import { firstValidationError, validateAddress } from '@/lib/validation';
-import { FormField, SendButton } from './shared';
+import { FormField, SelectField, SendButton } from './shared';
+
+const HOOK_FLAG_OPTIONS = [
+    { label: 'Read-only accounts (0)', value: '0' },
+    { label: 'Writable hook-owned accounts (1)', value: '1' },
+];
 
 export function SetHook() {
     const { createSigner } = useWallet();
@@ -18,6 +23,7 @@ export function SetHook() {
     const { programId } = useProgramContext();
     const [escrow, setEscrow] = useState('');
     const [hookProgram, setHookProgram] = useState('');
+    const [flags, setFlags] = useState('0');
     const [formError, setFormError] = useState<string | null>(null);
 
     const handleSubmit = async (e: React.FormEvent) => {
@@ -40,6 +46,7 @@ export function SetHook() {
             {
                 admin: signer,
                 escrow: escrow as Address,
+                flags: Number(flags),
                 hookProgram: hookProgram as Address,
                 payer: signer,
             },
@@ -47,7 +54,7 @@ export function SetHook() {
         );
         const txSignature = await send([ix], {
             action: 'Set Hook',
-            values: { escrow, hookProgram },
+            values: { escrow, flags, hookProgram },
         });
         if (txSignature) {
             rememberEscrow(escrow);
@@ -78,6 +85,13 @@ export function SetHook() {
                 hint="Warning: if this escrow is later set immutable, this hook dependency becomes permanent and hook reverts will block operations."
                 required
             />
+            <SelectField
+                label="Hook Flags"
+                value={flags}
+                onChange={setFlags}
+                options={HOOK_FLAG_OPTIONS}
+                hint="Writable hook-owned accounts lets the hook update its own state accounts passed as remaining accounts."
+            />
             <SendButton sending={sending} />
             <TxResult signature={signature} error={formError ?? error} />
         </form>
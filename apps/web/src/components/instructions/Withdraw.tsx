@@ -56,6 +56,7 @@ export function Withdraw() {
     const [mint, setMint] = useState('');
     const [receipt, setReceipt] = useState('');
     const [rentRecipient, setRentRecipient] = useState('');
+    const [destination, setDestination] = useState('');
     const [formError, setFormError] = useState<string | null>(null);
 
     const handleSubmit = async (e: React.FormEvent) => {
@@ -70,6 +71,7 @@ export function Withdraw() {
             validateAddress(mint, 'Mint address'),
             validateAddress(receipt, 'Receipt address'),
             validateOptionalAddress(rentRecipient, 'Rent recipient'),
+            validateOptionalAddress(destination, 'Destination'),
         );
         if (validationError) {
             setFormError(validationError);
@@ -103,7 +105,9 @@ export function Withdraw() {
 
         const ix = await getWithdrawInstructionAsync(
             {
+                payer: signer,
                 withdrawer: signer,
+                destination: (destination || signer.address) as Address,
                 escrow: escrow as Address,
                 mint: mint as Address,
                 receipt: receipt as Address,
@@ -118,7 +122,13 @@ export function Withdraw() {
 
         const txSignature = await send([finalIx], {
             action: 'Withdraw',
-            values: { escrow, mint, receipt, rentRecipient: rentRecipient || account?.address || '' },
+            values: {
+                escrow,
+                mint,
+                receipt,
+                rentRecipient: rentRecipient || account?.address || '',
+                destination: destination || account?.address || '',
+            },
         });
         if (txSignature) {
             rememberEscrow(escrow);
@@ -169,6 +179,13 @@ export function Withdraw() {
                 placeholder={account?.address ?? 'Defaults to connected wallet'}
                 hint="Address that receives rent from the closed receipt account"
             />
+            <FormField
+                label="Destination"
+                value={destination}
+                onChange={setDestination}
+                placeholder={account?.address ?? 'Defaults to connected wallet'}
+                hint="Owner of the token account receiving the tokens (its ATA is created if missing)"
+            />
             <SendButton sending={sending} />
             <TxResult signature={signature} error={formError ?? error} />
         </form>
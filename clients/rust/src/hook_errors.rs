@@ -0,0 +1,29 @@
+//! Decoding of hook rejection codes.
+//!
+//! Hooks reject an operation by setting [`hook_rejection_data`] as return data,
+//! which the escrow program surfaces as a custom error in a range reserved for
+//! hooks. Codes below [`HOOK_ERROR_CODE_BASE`] are regular `EscrowProgramError` codes.
+
+/// Base of the custom error range reserved for hook rejection codes
+pub const HOOK_ERROR_CODE_BASE: u32 = 0x1_0000;
+
+/// Prefix that marks hook return data as a rejection; other return data is ignored
+pub const HOOK_REJECTION_MAGIC: [u8; 4] = *b"EHRJ";
+
+/// Returns the return data a hook sets to reject with reason `code`
+pub fn hook_rejection_data(code: u16) -> [u8; 6] {
+    let mut data = [0u8; 6];
+    data[..4].copy_from_slice(&HOOK_REJECTION_MAGIC);
+    data[4..].copy_from_slice(&code.to_le_bytes());
+    data
+}
+
+/// Returns the custom error code the escrow program reports for a hook reason code
+pub fn hook_error_code(code: u16) -> u32 {
+    HOOK_ERROR_CODE_BASE + code as u32
+}
+
+/// Extracts the hook reason code from an escrow custom error code, if it is in the hook range
+pub fn decode_hook_error(error_code: u32) -> Option<u16> {
+    error_code.checked_sub(HOOK_ERROR_CODE_BASE).and_then(|code| u16::try_from(code).ok())
+}
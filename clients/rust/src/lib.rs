@@ -5,6 +5,8 @@
 pub mod generated;
 pub use generated::*;
 
+pub mod hook_errors;
+
 // Re-export commonly used items
 pub use generated::accounts::*;
 pub use generated::errors::*;
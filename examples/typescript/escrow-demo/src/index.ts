@@ -52,7 +52,6 @@ const { rpc, rpcSubscriptions, payer } = await createDefaultLocalhostRpcClient()
 // Generate keypairs
 const admin = await generateKeyPairSigner();
 const escrowSeed = await generateKeyPairSigner();
-const receiptSeed = await generateKeyPairSigner();
 const mintKeypair = await generateKeyPairSigner();
 
 logAddress('Payer', payer.address);
@@ -184,12 +183,12 @@ logSuccess('Timelock extension added');
 // ============================================================
 logStep(6, 'Deposit Tokens');
 
-// Find receipt PDA
+// Find receipt PDA (first deposit for this depositor and mint uses nonce 0)
 const [receiptPda] = await findReceiptPda({
     depositor: payer.address,
     escrow: escrowPda,
     mint: mintKeypair.address,
-    receiptSeed: receiptSeed.address,
+    nonce: 0n,
 });
 
 logAddress('Receipt PDA', receiptPda);
@@ -201,7 +200,8 @@ const depositIx = await getDepositInstructionAsync({
     escrow: escrowPda,
     mint: mintKeypair.address,
     payer,
-    receiptSeed,
+    proof: [],
+    receipt: receiptPda,
 });
 
 await buildAndSend({ instructions: [depositIx], payer, rpc, rpcSubscriptions });
@@ -223,6 +223,7 @@ try {
     const earlyWithdrawIx = await getWithdrawInstructionAsync({
         escrow: escrowPda,
         mint: mintKeypair.address,
+        payer,
         receipt: receiptPda,
         rentRecipient: payer.address,
         withdrawer: payer,
@@ -257,6 +258,7 @@ await sleep(CONFIG.TIMELOCK_WAIT_MS);
 const withdrawIx = await getWithdrawInstructionAsync({
     escrow: escrowPda,
     mint: mintKeypair.address,
+    payer,
     receipt: receiptPda,
     rentRecipient: payer.address,
     withdrawer: payer,
@@ -2,10 +2,17 @@ use pinocchio::{account::AccountView, entrypoint, error::ProgramError, Address,
 
 use crate::{
     instructions::{
-        process_add_timelock, process_allow_mint, process_block_mint, process_block_token_extension,
-        process_create_escrow, process_deposit, process_emit_event, process_remove_extension, process_set_arbiter,
-        process_set_hook, process_set_immutable, process_unblock_token_extension, process_update_admin,
-        process_withdraw,
+        process_add_timelock, process_allow_mint, process_approve_withdraw_delegate, process_block_mint,
+        process_block_token_extension, process_cancel_queued_change, process_claim_withdraw, process_create_escrow,
+        process_create_escrow_with_config, process_create_escrow_with_seed, process_deposit, process_deposit_basket,
+        process_emit_event, process_execute_queued_change, process_force_refund, process_lock_extension,
+        process_lock_mint_list, process_queue_change, process_release_matured, process_remove_extension,
+        process_request_hook_bypass, process_request_withdraw, process_revoke_withdraw_delegate, process_set_arbiter,
+        process_set_auto_release, process_set_cooldown, process_set_depositor_allowlist, process_set_governance_delay,
+        process_set_hook, process_set_hook_bypass, process_set_immutable, process_set_metadata,
+        process_set_mint_limits, process_set_mint_override, process_set_refund_authority, process_set_roles,
+        process_sweep_surplus, process_unblock_token_extension, process_update_admin, process_verify_solvency,
+        process_withdraw, process_withdraw_basket, process_withdraw_many,
     },
     traits::EscrowInstructionDiscriminators,
 };
@@ -38,6 +45,77 @@ pub fn process_instruction(program_id: &Address, accounts: &[AccountView], instr
         }
         EscrowInstructionDiscriminators::SetArbiter => process_set_arbiter(program_id, accounts, instruction_data),
         EscrowInstructionDiscriminators::SetImmutable => process_set_immutable(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::SetHookBypass => {
+            process_set_hook_bypass(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::RequestHookBypass => {
+            process_request_hook_bypass(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SetMintLimits => {
+            process_set_mint_limits(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SetDepositorAllowlist => {
+            process_set_depositor_allowlist(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::VerifySolvency => {
+            process_verify_solvency(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SweepSurplus => process_sweep_surplus(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::CreateEscrowWithConfig => {
+            process_create_escrow_with_config(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::CreateEscrowWithSeed => {
+            process_create_escrow_with_seed(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SetRoles => process_set_roles(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::SetGovernanceDelay => {
+            process_set_governance_delay(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::QueueChange => process_queue_change(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::ExecuteQueuedChange => {
+            process_execute_queued_change(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::CancelQueuedChange => {
+            process_cancel_queued_change(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::LockExtension => {
+            process_lock_extension(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::LockMintList => process_lock_mint_list(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::SetMetadata => process_set_metadata(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::SetMintOverride => {
+            process_set_mint_override(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::ApproveWithdrawDelegate => {
+            process_approve_withdraw_delegate(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::RevokeWithdrawDelegate => {
+            process_revoke_withdraw_delegate(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SetAutoRelease => {
+            process_set_auto_release(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::ReleaseMatured => {
+            process_release_matured(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::WithdrawMany => process_withdraw_many(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::DepositBasket => {
+            process_deposit_basket(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::WithdrawBasket => {
+            process_withdraw_basket(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::SetRefundAuthority => {
+            process_set_refund_authority(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::ForceRefund => process_force_refund(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::SetCooldown => process_set_cooldown(program_id, accounts, instruction_data),
+        EscrowInstructionDiscriminators::RequestWithdraw => {
+            process_request_withdraw(program_id, accounts, instruction_data)
+        }
+        EscrowInstructionDiscriminators::ClaimWithdraw => {
+            process_claim_withdraw(program_id, accounts, instruction_data)
+        }
         EscrowInstructionDiscriminators::EmitEvent => process_emit_event(program_id, accounts),
     }
 }
@@ -72,6 +72,162 @@ pub enum EscrowProgramError {
     /// (16) Escrow is immutable and cannot be modified
     #[error("Escrow is immutable and cannot be modified")]
     EscrowImmutable,
+
+    /// (17) Hook bypass is not enabled for this escrow
+    #[error("Hook bypass is not enabled for this escrow")]
+    HookBypassNotEnabled,
+
+    /// (18) Hook bypass grace period has not elapsed yet
+    #[error("Hook bypass grace period has not elapsed yet")]
+    HookBypassGracePeriodActive,
+
+    /// (19) Hook bypass request does not match receipt
+    #[error("Hook bypass request does not match receipt")]
+    InvalidHookBypassRequest,
+
+    /// (20) Too many accounts passed to the hook program
+    #[error("Too many accounts passed to the hook program")]
+    TooManyHookAccounts,
+
+    /// (21) Deposit would exceed the mint's total deposit cap
+    #[error("Deposit would exceed the mint's total deposit cap")]
+    DepositCapExceeded,
+
+    /// (22) Deposit amount is below the mint's minimum deposit
+    #[error("Deposit amount is below the mint's minimum deposit")]
+    DepositBelowMinimum,
+
+    /// (23) Deposit amount is above the mint's maximum deposit
+    #[error("Deposit amount is above the mint's maximum deposit")]
+    DepositAboveMaximum,
+
+    /// (24) Depositor is not in the escrow's allowlist
+    #[error("Depositor is not in the escrow's allowlist")]
+    DepositorNotAllowlisted,
+
+    /// (25) Deposit would exceed the depositor's allowance
+    #[error("Deposit would exceed the depositor's allowance")]
+    DepositorCapExceeded,
+
+    /// (26) Vault balance is below the mint's outstanding deposits
+    #[error("Vault balance is below the mint's outstanding deposits")]
+    VaultInsolvent,
+
+    /// (27) Vault holds no surplus above outstanding deposits
+    #[error("Vault holds no surplus above outstanding deposits")]
+    NoSurplus,
+
+    /// (28) Signer does not hold the role this instruction requires
+    #[error("Signer does not hold the role this instruction requires")]
+    InvalidRoleAuthority,
+
+    /// (29) Extension changes must be queued while a governance delay is set
+    #[error("Extension changes must be queued while a governance delay is set")]
+    GovernanceDelayActive,
+
+    /// (30) Queued change cannot be executed before its governance delay has elapsed
+    #[error("Queued change cannot be executed before its governance delay has elapsed")]
+    GovernanceDelayNotElapsed,
+
+    /// (31) Extension is locked and cannot be changed
+    #[error("Extension is locked and cannot be changed")]
+    ExtensionLocked,
+
+    /// (32) Mint list is locked and mints cannot be allowed or blocked
+    #[error("Mint list is locked and mints cannot be allowed or blocked")]
+    MintListLocked,
+
+    /// (33) Rent recipient does not match the address recorded on the receipt
+    #[error("Rent recipient does not match the address recorded on the receipt")]
+    InvalidRentRecipient,
+
+    /// (34) Withdraw delegate does not belong to this receipt or escrow
+    #[error("Withdraw delegate does not belong to this receipt or escrow")]
+    InvalidWithdrawDelegate,
+
+    /// (35) Withdraw delegate approval has expired
+    #[error("Withdraw delegate approval has expired")]
+    WithdrawDelegateExpired,
+
+    /// (36) Delegated withdrawals must pay out to the depositor
+    #[error("Delegated withdrawals must pay out to the depositor")]
+    InvalidWithdrawDestination,
+
+    /// (37) Auto-release extension not set on this escrow
+    #[error("Auto-release extension not set on this escrow")]
+    AutoReleaseNotEnabled,
+
+    /// (38) Auto-release is unavailable while a hook or arbiter is configured
+    #[error("Auto-release is unavailable while a hook or arbiter is configured")]
+    AutoReleaseBlocked,
+
+    /// (39) Receipt has not matured for auto-release
+    #[error("Receipt has not matured for auto-release")]
+    ReceiptNotMatured,
+
+    /// (40) Receipt passed more than once
+    #[error("Receipt passed more than once")]
+    DuplicateReceipt,
+
+    /// (41) Mint appears more than once in a basket
+    #[error("Mint appears more than once in a basket")]
+    DuplicateBasketMint,
+
+    /// (42) Force-refund extension not set on this escrow
+    #[error("Force-refund extension not set on this escrow")]
+    ForceRefundNotEnabled,
+
+    /// (43) Signer is not allowed to force a refund
+    #[error("Signer is not allowed to force a refund")]
+    InvalidRefundAuthority,
+
+    /// (44) Cooldown extension not set on this escrow
+    #[error("Cooldown extension not set on this escrow")]
+    CooldownNotEnabled,
+
+    /// (45) Withdrawal cooldown has not elapsed yet
+    #[error("Withdrawal cooldown has not elapsed yet")]
+    CooldownNotElapsed,
+
+    /// (46) Withdrawals must be requested and claimed while a cooldown is set
+    #[error("Withdrawals must be requested and claimed while a cooldown is set")]
+    CooldownActive,
+
+    /// (47) Receipt has a pending withdraw request
+    #[error("Receipt has a pending withdraw request")]
+    WithdrawRequestPending,
+
+    /// (48) Withdraw request does not match receipt
+    #[error("Withdraw request does not match receipt")]
+    InvalidWithdrawRequest,
+
+    /// (49) AllowedMint account predates mint limits and has not been grown yet
+    #[error("AllowedMint account predates mint limits and has not been grown yet")]
+    LegacyAllowedMint,
+
+    /// (50) Mint still has outstanding receipts
+    #[error("Mint still has outstanding receipts")]
+    OutstandingReceipts,
+
+    /// (51) Mint still has per-mint overrides
+    #[error("Mint still has per-mint overrides")]
+    MintOverridesPresent,
+
+    /// (52) Basket legs resolve to different arbiters or hooks
+    #[error("Basket legs resolve to different arbiters or hooks")]
+    MixedBasketLegs,
+}
+
+/// Base of the error range reserved for hook rejection codes.
+///
+/// A hook that rejects with reason `code` surfaces as `Custom(HOOK_ERROR_CODE_BASE + code)`,
+/// which never overlaps with `EscrowProgramError` codes.
+pub const HOOK_ERROR_CODE_BASE: u32 = 0x1_0000;
+
+/// Maps a hook-provided rejection code into the reserved hook error range
+#[inline(always)]
+pub fn hook_error(code: u16) -> ProgramError {
+    ProgramError::Custom(HOOK_ERROR_CODE_BASE + code as u32)
 }
 
 impl From<EscrowProgramError> for ProgramError {
@@ -107,5 +263,121 @@ mod tests {
         let error: ProgramError = EscrowProgramError::EscrowImmutable.into();
         assert_eq!(error, ProgramError::Custom(16));
         assert_eq!(error, ProgramError::Custom(16));
+
+        let error: ProgramError = EscrowProgramError::HookBypassNotEnabled.into();
+        assert_eq!(error, ProgramError::Custom(17));
+
+        let error: ProgramError = EscrowProgramError::HookBypassGracePeriodActive.into();
+        assert_eq!(error, ProgramError::Custom(18));
+
+        let error: ProgramError = EscrowProgramError::InvalidHookBypassRequest.into();
+        assert_eq!(error, ProgramError::Custom(19));
+
+        let error: ProgramError = EscrowProgramError::TooManyHookAccounts.into();
+        assert_eq!(error, ProgramError::Custom(20));
+
+        let error: ProgramError = EscrowProgramError::DepositCapExceeded.into();
+        assert_eq!(error, ProgramError::Custom(21));
+
+        let error: ProgramError = EscrowProgramError::DepositBelowMinimum.into();
+        assert_eq!(error, ProgramError::Custom(22));
+
+        let error: ProgramError = EscrowProgramError::DepositAboveMaximum.into();
+        assert_eq!(error, ProgramError::Custom(23));
+
+        let error: ProgramError = EscrowProgramError::DepositorNotAllowlisted.into();
+        assert_eq!(error, ProgramError::Custom(24));
+
+        let error: ProgramError = EscrowProgramError::DepositorCapExceeded.into();
+        assert_eq!(error, ProgramError::Custom(25));
+
+        let error: ProgramError = EscrowProgramError::VaultInsolvent.into();
+        assert_eq!(error, ProgramError::Custom(26));
+
+        let error: ProgramError = EscrowProgramError::NoSurplus.into();
+        assert_eq!(error, ProgramError::Custom(27));
+
+        let error: ProgramError = EscrowProgramError::InvalidRoleAuthority.into();
+        assert_eq!(error, ProgramError::Custom(28));
+
+        let error: ProgramError = EscrowProgramError::GovernanceDelayActive.into();
+        assert_eq!(error, ProgramError::Custom(29));
+
+        let error: ProgramError = EscrowProgramError::GovernanceDelayNotElapsed.into();
+        assert_eq!(error, ProgramError::Custom(30));
+
+        let error: ProgramError = EscrowProgramError::ExtensionLocked.into();
+        assert_eq!(error, ProgramError::Custom(31));
+
+        let error: ProgramError = EscrowProgramError::MintListLocked.into();
+        assert_eq!(error, ProgramError::Custom(32));
+
+        let error: ProgramError = EscrowProgramError::InvalidRentRecipient.into();
+        assert_eq!(error, ProgramError::Custom(33));
+
+        let error: ProgramError = EscrowProgramError::InvalidWithdrawDelegate.into();
+        assert_eq!(error, ProgramError::Custom(34));
+
+        let error: ProgramError = EscrowProgramError::WithdrawDelegateExpired.into();
+        assert_eq!(error, ProgramError::Custom(35));
+
+        let error: ProgramError = EscrowProgramError::InvalidWithdrawDestination.into();
+        assert_eq!(error, ProgramError::Custom(36));
+
+        let error: ProgramError = EscrowProgramError::AutoReleaseNotEnabled.into();
+        assert_eq!(error, ProgramError::Custom(37));
+
+        let error: ProgramError = EscrowProgramError::AutoReleaseBlocked.into();
+        assert_eq!(error, ProgramError::Custom(38));
+
+        let error: ProgramError = EscrowProgramError::ReceiptNotMatured.into();
+        assert_eq!(error, ProgramError::Custom(39));
+
+        let error: ProgramError = EscrowProgramError::DuplicateReceipt.into();
+        assert_eq!(error, ProgramError::Custom(40));
+
+        let error: ProgramError = EscrowProgramError::DuplicateBasketMint.into();
+        assert_eq!(error, ProgramError::Custom(41));
+
+        let error: ProgramError = EscrowProgramError::ForceRefundNotEnabled.into();
+        assert_eq!(error, ProgramError::Custom(42));
+
+        let error: ProgramError = EscrowProgramError::InvalidRefundAuthority.into();
+        assert_eq!(error, ProgramError::Custom(43));
+
+        let error: ProgramError = EscrowProgramError::CooldownNotEnabled.into();
+        assert_eq!(error, ProgramError::Custom(44));
+
+        let error: ProgramError = EscrowProgramError::CooldownNotElapsed.into();
+        assert_eq!(error, ProgramError::Custom(45));
+
+        let error: ProgramError = EscrowProgramError::CooldownActive.into();
+        assert_eq!(error, ProgramError::Custom(46));
+
+        let error: ProgramError = EscrowProgramError::WithdrawRequestPending.into();
+        assert_eq!(error, ProgramError::Custom(47));
+
+        let error: ProgramError = EscrowProgramError::InvalidWithdrawRequest.into();
+        assert_eq!(error, ProgramError::Custom(48));
+
+        let error: ProgramError = EscrowProgramError::LegacyAllowedMint.into();
+        assert_eq!(error, ProgramError::Custom(49));
+
+        let error: ProgramError = EscrowProgramError::OutstandingReceipts.into();
+        assert_eq!(error, ProgramError::Custom(50));
+
+        let error: ProgramError = EscrowProgramError::MintOverridesPresent.into();
+        assert_eq!(error, ProgramError::Custom(51));
+
+        let error: ProgramError = EscrowProgramError::MixedBasketLegs.into();
+        assert_eq!(error, ProgramError::Custom(52));
+    }
+
+    #[test]
+    fn test_hook_error_range() {
+        assert_eq!(hook_error(0), ProgramError::Custom(HOOK_ERROR_CODE_BASE));
+        assert_eq!(hook_error(42), ProgramError::Custom(HOOK_ERROR_CODE_BASE + 42));
+        assert_eq!(hook_error(u16::MAX), ProgramError::Custom(HOOK_ERROR_CODE_BASE + u16::MAX as u32));
+        assert!((EscrowProgramError::MixedBasketLegs as u32) < HOOK_ERROR_CODE_BASE);
     }
 }
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::state::BasketLeg;
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct BasketWithdrawEvent {
+    pub escrow: Address,
+    pub withdrawer: Address,
+    pub basket_id: u64,
+    pub legs: Vec<BasketLeg>,
+}
+
+impl EventDiscriminator for BasketWithdrawEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::BasketWithdraw as u8;
+}
+
+impl EventSerialize for BasketWithdrawEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN + 4 + self.legs.len() * BasketLeg::LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.withdrawer.as_ref());
+        data.extend_from_slice(&self.basket_id.to_le_bytes());
+        data.extend_from_slice(&(self.legs.len() as u32).to_le_bytes());
+        for leg in &self.legs {
+            data.extend_from_slice(leg.mint.as_ref());
+            data.extend_from_slice(&leg.amount.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl BasketWithdrawEvent {
+    /// Fixed part only; the legs follow with a u32 length prefix
+    pub const DATA_LEN: usize = 32 + 32 + 8; // escrow + withdrawer + basket_id
+
+    #[inline(always)]
+    pub fn new(escrow: Address, withdrawer: Address, basket_id: u64, legs: Vec<BasketLeg>) -> Self {
+        Self { escrow, withdrawer, basket_id, legs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+    use alloc::vec;
+
+    fn create_test_event() -> BasketWithdrawEvent {
+        BasketWithdrawEvent::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            7,
+            vec![
+                BasketLeg { mint: Address::new_from_array([3u8; 32]), amount: 1000 },
+                BasketLeg { mint: Address::new_from_array([4u8; 32]), amount: 2000 },
+            ],
+        )
+    }
+
+    #[test]
+    fn test_basket_withdraw_event_new() {
+        let event = create_test_event();
+
+        assert_eq!(event.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(event.withdrawer, Address::new_from_array([2u8; 32]));
+        assert_eq!(event.basket_id, 7);
+        assert_eq!(event.legs.len(), 2);
+    }
+
+    #[test]
+    fn test_basket_withdraw_event_to_bytes_inner() {
+        let event = create_test_event();
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), BasketWithdrawEvent::DATA_LEN + 4 + 2 * BasketLeg::LEN);
+        assert_eq!(&bytes[..32], &[1u8; 32]);
+        assert_eq!(&bytes[32..64], &[2u8; 32]);
+        assert_eq!(&bytes[64..72], &7u64.to_le_bytes());
+        assert_eq!(&bytes[72..76], &2u32.to_le_bytes());
+        assert_eq!(&bytes[76..108], &[3u8; 32]);
+        assert_eq!(&bytes[108..116], &1000u64.to_le_bytes());
+        assert_eq!(&bytes[116..148], &[4u8; 32]);
+        assert_eq!(&bytes[148..156], &2000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_basket_withdraw_event_to_bytes() {
+        let event = create_test_event();
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + BasketWithdrawEvent::DATA_LEN + 4 + 2 * BasketLeg::LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::BasketWithdraw as u8);
+        assert_eq!(&bytes[9..41], &[1u8; 32]);
+    }
+}
@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct ChangeCancelledEvent {
+    pub escrow: Address,
+    pub change_id: u64,
+}
+
+impl EventDiscriminator for ChangeCancelledEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::ChangeCancelled as u8;
+}
+
+impl EventSerialize for ChangeCancelledEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.change_id.to_le_bytes());
+        data
+    }
+}
+
+impl ChangeCancelledEvent {
+    pub const DATA_LEN: usize = 32 + 8; // escrow + change_id
+
+    #[inline(always)]
+    pub fn new(escrow: Address, change_id: u64) -> Self {
+        Self { escrow, change_id }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_change_cancelled_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeCancelledEvent::new(escrow, 7);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.change_id, 7);
+    }
+
+    #[test]
+    fn test_change_cancelled_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeCancelledEvent::new(escrow, 7);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + ChangeCancelledEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::ChangeCancelled as u8);
+        assert_eq!(&bytes[41..49], &7u64.to_le_bytes());
+    }
+}
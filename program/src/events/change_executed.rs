@@ -0,0 +1,70 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct ChangeExecutedEvent {
+    pub escrow: Address,
+    pub change_id: u64,
+    pub action: u8,
+    pub extension_type: u16,
+}
+
+impl EventDiscriminator for ChangeExecutedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::ChangeExecuted as u8;
+}
+
+impl EventSerialize for ChangeExecutedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.change_id.to_le_bytes());
+        data.push(self.action);
+        data.extend_from_slice(&self.extension_type.to_le_bytes());
+        data
+    }
+}
+
+impl ChangeExecutedEvent {
+    pub const DATA_LEN: usize = 32 + 8 + 1 + 2; // escrow + change_id + action + extension_type
+
+    #[inline(always)]
+    pub fn new(escrow: Address, change_id: u64, action: u8, extension_type: u16) -> Self {
+        Self { escrow, change_id, action, extension_type }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_change_executed_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeExecutedEvent::new(escrow, 7, 0, 1);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.change_id, 7);
+        assert_eq!(event.action, 0);
+        assert_eq!(event.extension_type, 1);
+    }
+
+    #[test]
+    fn test_change_executed_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeExecutedEvent::new(escrow, 7, 0, 1);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + ChangeExecutedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::ChangeExecuted as u8);
+        assert_eq!(&bytes[41..49], &7u64.to_le_bytes());
+        assert_eq!(bytes[49], 0);
+        assert_eq!(&bytes[50..52], &1u16.to_le_bytes());
+    }
+}
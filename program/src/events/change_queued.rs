@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct ChangeQueuedEvent {
+    pub escrow: Address,
+    pub change_id: u64,
+    pub action: u8,
+    pub extension_type: u16,
+    pub queued_at: i64,
+}
+
+impl EventDiscriminator for ChangeQueuedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::ChangeQueued as u8;
+}
+
+impl EventSerialize for ChangeQueuedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.change_id.to_le_bytes());
+        data.push(self.action);
+        data.extend_from_slice(&self.extension_type.to_le_bytes());
+        data.extend_from_slice(&self.queued_at.to_le_bytes());
+        data
+    }
+}
+
+impl ChangeQueuedEvent {
+    pub const DATA_LEN: usize = 32 + 8 + 1 + 2 + 8; // escrow + change_id + action + extension_type + queued_at
+
+    #[inline(always)]
+    pub fn new(escrow: Address, change_id: u64, action: u8, extension_type: u16, queued_at: i64) -> Self {
+        Self { escrow, change_id, action, extension_type, queued_at }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_change_queued_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeQueuedEvent::new(escrow, 7, 1, 3, 1_700_000_000);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.change_id, 7);
+        assert_eq!(event.action, 1);
+        assert_eq!(event.extension_type, 3);
+        assert_eq!(event.queued_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_change_queued_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = ChangeQueuedEvent::new(escrow, 7, 1, 3, 1_700_000_000);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + ChangeQueuedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::ChangeQueued as u8);
+        assert_eq!(&bytes[41..49], &7u64.to_le_bytes());
+        assert_eq!(bytes[49], 1);
+        assert_eq!(&bytes[50..52], &3u16.to_le_bytes());
+        assert_eq!(&bytes[52..60], &1_700_000_000i64.to_le_bytes());
+    }
+}
@@ -9,7 +9,7 @@ pub struct DepositEvent {
     pub escrow: Address,
     pub depositor: Address,
     pub mint: Address,
-    pub receipt_seed: Address,
+    pub nonce: u64,
     pub amount: u64,
 }
 
@@ -24,18 +24,18 @@ impl EventSerialize for DepositEvent {
         data.extend_from_slice(self.escrow.as_ref());
         data.extend_from_slice(self.depositor.as_ref());
         data.extend_from_slice(self.mint.as_ref());
-        data.extend_from_slice(self.receipt_seed.as_ref());
+        data.extend_from_slice(&self.nonce.to_le_bytes());
         data.extend_from_slice(&self.amount.to_le_bytes());
         data
     }
 }
 
 impl DepositEvent {
-    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8; // escrow + depositor + mint + receipt_seed + amount
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 8 + 8; // escrow + depositor + mint + nonce + amount
 
     #[inline(always)]
-    pub fn new(escrow: Address, depositor: Address, mint: Address, receipt_seed: Address, amount: u64) -> Self {
-        Self { escrow, depositor, mint, receipt_seed, amount }
+    pub fn new(escrow: Address, depositor: Address, mint: Address, nonce: u64, amount: u64) -> Self {
+        Self { escrow, depositor, mint, nonce, amount }
     }
 }
 
@@ -50,14 +50,13 @@ mod tests {
         let escrow = Address::new_from_array([1u8; 32]);
         let depositor = Address::new_from_array([2u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
 
-        let event = DepositEvent::new(escrow, depositor, mint, receipt_seed, 1000);
+        let event = DepositEvent::new(escrow, depositor, mint, 4, 1000);
 
         assert_eq!(event.escrow, escrow);
         assert_eq!(event.depositor, depositor);
         assert_eq!(event.mint, mint);
-        assert_eq!(event.receipt_seed, receipt_seed);
+        assert_eq!(event.nonce, 4);
         assert_eq!(event.amount, 1000);
     }
 
@@ -66,16 +65,15 @@ mod tests {
         let escrow = Address::new_from_array([1u8; 32]);
         let depositor = Address::new_from_array([2u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
-        let event = DepositEvent::new(escrow, depositor, mint, receipt_seed, 5000);
+        let event = DepositEvent::new(escrow, depositor, mint, 4, 5000);
 
         let bytes = event.to_bytes_inner();
         assert_eq!(bytes.len(), DepositEvent::DATA_LEN);
         assert_eq!(&bytes[..32], escrow.as_ref());
         assert_eq!(&bytes[32..64], depositor.as_ref());
         assert_eq!(&bytes[64..96], mint.as_ref());
-        assert_eq!(&bytes[96..128], receipt_seed.as_ref());
-        assert_eq!(&bytes[128..136], &5000u64.to_le_bytes());
+        assert_eq!(&bytes[96..104], &4u64.to_le_bytes());
+        assert_eq!(&bytes[104..112], &5000u64.to_le_bytes());
     }
 
     #[test]
@@ -83,8 +81,7 @@ mod tests {
         let escrow = Address::new_from_array([1u8; 32]);
         let depositor = Address::new_from_array([2u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
-        let event = DepositEvent::new(escrow, depositor, mint, receipt_seed, 1000);
+        let event = DepositEvent::new(escrow, depositor, mint, 4, 1000);
 
         let bytes = event.to_bytes();
         assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DepositEvent::DATA_LEN);
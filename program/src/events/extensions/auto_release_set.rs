@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct AutoReleaseSetEvent {
+    pub escrow: Address,
+    pub release_delay: u64,
+}
+
+impl EventDiscriminator for AutoReleaseSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::AutoReleaseSet as u8;
+}
+
+impl EventSerialize for AutoReleaseSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.release_delay.to_le_bytes());
+        data
+    }
+}
+
+impl AutoReleaseSetEvent {
+    pub const DATA_LEN: usize = 32 + 8; // escrow + release_delay
+
+    #[inline(always)]
+    pub fn new(escrow: Address, release_delay: u64) -> Self {
+        Self { escrow, release_delay }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_auto_release_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = AutoReleaseSetEvent::new(escrow, 86_400);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.release_delay, 86_400);
+    }
+
+    #[test]
+    fn test_auto_release_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = AutoReleaseSetEvent::new(escrow, 86_400);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + AutoReleaseSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::AutoReleaseSet as u8);
+        assert_eq!(&bytes[41..49], &86_400u64.to_le_bytes());
+    }
+}
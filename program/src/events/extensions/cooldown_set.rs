@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct CooldownSetEvent {
+    pub escrow: Address,
+    pub cooldown_period: u64,
+}
+
+impl EventDiscriminator for CooldownSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::CooldownSet as u8;
+}
+
+impl EventSerialize for CooldownSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.cooldown_period.to_le_bytes());
+        data
+    }
+}
+
+impl CooldownSetEvent {
+    pub const DATA_LEN: usize = 32 + 8; // escrow + cooldown_period
+
+    #[inline(always)]
+    pub fn new(escrow: Address, cooldown_period: u64) -> Self {
+        Self { escrow, cooldown_period }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_cooldown_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = CooldownSetEvent::new(escrow, 86_400);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.cooldown_period, 86_400);
+    }
+
+    #[test]
+    fn test_cooldown_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = CooldownSetEvent::new(escrow, 86_400);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + CooldownSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::CooldownSet as u8);
+        assert_eq!(&bytes[41..49], &86_400u64.to_le_bytes());
+    }
+}
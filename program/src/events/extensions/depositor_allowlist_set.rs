@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct DepositorAllowlistSetEvent {
+    pub escrow: Address,
+    pub merkle_root: [u8; 32],
+    pub per_address_cap: u64,
+}
+
+impl EventDiscriminator for DepositorAllowlistSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::DepositorAllowlistSet as u8;
+}
+
+impl EventSerialize for DepositorAllowlistSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.merkle_root);
+        data.extend_from_slice(&self.per_address_cap.to_le_bytes());
+        data
+    }
+}
+
+impl DepositorAllowlistSetEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 8; // escrow + merkle_root + per_address_cap
+
+    #[inline(always)]
+    pub fn new(escrow: Address, merkle_root: [u8; 32], per_address_cap: u64) -> Self {
+        Self { escrow, merkle_root, per_address_cap }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_depositor_allowlist_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = DepositorAllowlistSetEvent::new(escrow, [2u8; 32], 1_000);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.merkle_root, [2u8; 32]);
+        assert_eq!(event.per_address_cap, 1_000);
+    }
+
+    #[test]
+    fn test_depositor_allowlist_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = DepositorAllowlistSetEvent::new(escrow, [2u8; 32], 1_000);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + DepositorAllowlistSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::DepositorAllowlistSet as u8);
+        assert_eq!(&bytes[41..73], &[2u8; 32]);
+        assert_eq!(&bytes[73..81], &1_000u64.to_le_bytes());
+    }
+}
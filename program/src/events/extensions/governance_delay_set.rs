@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct GovernanceDelaySetEvent {
+    pub escrow: Address,
+    pub delay: u64,
+}
+
+impl EventDiscriminator for GovernanceDelaySetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::GovernanceDelaySet as u8;
+}
+
+impl EventSerialize for GovernanceDelaySetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.delay.to_le_bytes());
+        data
+    }
+}
+
+impl GovernanceDelaySetEvent {
+    pub const DATA_LEN: usize = 32 + 8; // escrow + delay
+
+    #[inline(always)]
+    pub fn new(escrow: Address, delay: u64) -> Self {
+        Self { escrow, delay }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_governance_delay_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = GovernanceDelaySetEvent::new(escrow, 172_800);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.delay, 172_800);
+    }
+
+    #[test]
+    fn test_governance_delay_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = GovernanceDelaySetEvent::new(escrow, 172_800);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + GovernanceDelaySetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::GovernanceDelaySet as u8);
+        assert_eq!(&bytes[41..49], &172_800u64.to_le_bytes());
+    }
+}
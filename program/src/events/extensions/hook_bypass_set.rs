@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct HookBypassSetEvent {
+    pub escrow: Address,
+    pub grace_period: u64,
+}
+
+impl EventDiscriminator for HookBypassSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::HookBypassSet as u8;
+}
+
+impl EventSerialize for HookBypassSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.grace_period.to_le_bytes());
+        data
+    }
+}
+
+impl HookBypassSetEvent {
+    pub const DATA_LEN: usize = 32 + 8; // escrow + grace_period
+
+    #[inline(always)]
+    pub fn new(escrow: Address, grace_period: u64) -> Self {
+        Self { escrow, grace_period }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_hook_bypass_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = HookBypassSetEvent::new(escrow, 86_400);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.grace_period, 86_400);
+    }
+
+    #[test]
+    fn test_hook_bypass_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = HookBypassSetEvent::new(escrow, 86_400);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + HookBypassSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::HookBypassSet as u8);
+        assert_eq!(&bytes[41..49], &86_400u64.to_le_bytes());
+    }
+}
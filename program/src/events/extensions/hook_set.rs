@@ -8,6 +8,7 @@ use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
 pub struct HookSetEvent {
     pub escrow: Address,
     pub hook_program: Address,
+    pub flags: u8,
 }
 
 impl EventDiscriminator for HookSetEvent {
@@ -20,16 +21,17 @@ impl EventSerialize for HookSetEvent {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.extend_from_slice(self.escrow.as_ref());
         data.extend_from_slice(self.hook_program.as_ref());
+        data.push(self.flags);
         data
     }
 }
 
 impl HookSetEvent {
-    pub const DATA_LEN: usize = 32 + 32; // escrow + hook_program
+    pub const DATA_LEN: usize = 32 + 32 + 1; // escrow + hook_program + flags
 
     #[inline(always)]
-    pub fn new(escrow: Address, hook_program: Address) -> Self {
-        Self { escrow, hook_program }
+    pub fn new(escrow: Address, hook_program: Address, flags: u8) -> Self {
+        Self { escrow, hook_program, flags }
     }
 }
 
@@ -43,17 +45,18 @@ mod tests {
     fn test_hook_set_event_new() {
         let escrow = Address::new_from_array([1u8; 32]);
         let hook_program = Address::new_from_array([2u8; 32]);
-        let event = HookSetEvent::new(escrow, hook_program);
+        let event = HookSetEvent::new(escrow, hook_program, 1);
 
         assert_eq!(event.escrow, escrow);
         assert_eq!(event.hook_program, hook_program);
+        assert_eq!(event.flags, 1);
     }
 
     #[test]
     fn test_hook_set_event_to_bytes() {
         let escrow = Address::new_from_array([1u8; 32]);
         let hook_program = Address::new_from_array([0u8; 32]);
-        let event = HookSetEvent::new(escrow, hook_program);
+        let event = HookSetEvent::new(escrow, hook_program, 0);
 
         let bytes = event.to_bytes();
         assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + HookSetEvent::DATA_LEN);
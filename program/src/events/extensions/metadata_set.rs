@@ -0,0 +1,81 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::state::MetadataData;
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct MetadataSetEvent {
+    pub escrow: Address,
+    pub name: String,
+    pub uri: String,
+    pub external_reference: Vec<u8>,
+}
+
+impl EventDiscriminator for MetadataSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::MetadataSet as u8;
+}
+
+impl EventSerialize for MetadataSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let fields = [self.name.as_bytes(), self.uri.as_bytes(), &self.external_reference];
+        let mut data = Vec::with_capacity(Self::DATA_LEN + fields.iter().map(|field| 4 + field.len()).sum::<usize>());
+        data.extend_from_slice(self.escrow.as_ref());
+        for field in fields {
+            data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field);
+        }
+        data
+    }
+}
+
+impl MetadataSetEvent {
+    /// Fixed part only; the metadata fields follow with u32 length prefixes
+    pub const DATA_LEN: usize = 32; // escrow
+
+    #[inline(always)]
+    pub fn new(escrow: Address, metadata: &MetadataData) -> Self {
+        Self {
+            escrow,
+            name: metadata.name.clone(),
+            uri: metadata.uri.clone(),
+            external_reference: metadata.external_reference.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::{ExtensionData, EVENT_DISCRIMINATOR_LEN};
+
+    #[test]
+    fn test_metadata_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let metadata = MetadataData::new("name", "https://example.com", b"deal-1").unwrap();
+        let event = MetadataSetEvent::new(escrow, &metadata);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.name, "name");
+        assert_eq!(event.uri, "https://example.com");
+        assert_eq!(event.external_reference, b"deal-1");
+    }
+
+    #[test]
+    fn test_metadata_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let metadata = MetadataData::new("name", "uri", b"ref").unwrap();
+        let event = MetadataSetEvent::new(escrow, &metadata);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + MetadataSetEvent::DATA_LEN + metadata.byte_len());
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::MetadataSet as u8);
+        assert_eq!(&bytes[9..41], escrow.as_ref());
+        assert_eq!(&bytes[41..], metadata.to_bytes().as_slice());
+    }
+}
@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct MintListLockedEvent {
+    pub escrow: Address,
+}
+
+impl EventDiscriminator for MintListLockedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::MintListLocked as u8;
+}
+
+impl EventSerialize for MintListLockedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        self.escrow.as_ref().to_vec()
+    }
+}
+
+impl MintListLockedEvent {
+    pub const DATA_LEN: usize = 32; // escrow
+
+    #[inline(always)]
+    pub fn new(escrow: Address) -> Self {
+        Self { escrow }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_mint_list_locked_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = MintListLockedEvent::new(escrow);
+
+        assert_eq!(event.escrow, escrow);
+    }
+
+    #[test]
+    fn test_mint_list_locked_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = MintListLockedEvent::new(escrow);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + MintListLockedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::MintListLocked as u8);
+        assert_eq!(&bytes[EVENT_DISCRIMINATOR_LEN..], escrow.as_ref());
+    }
+}
@@ -1,13 +1,33 @@
 pub mod arbiter_set;
+pub mod auto_release_set;
+pub mod cooldown_set;
+pub mod depositor_allowlist_set;
+pub mod extension_locked;
 pub mod extension_removed;
+pub mod governance_delay_set;
+pub mod hook_bypass_set;
 pub mod hook_set;
+pub mod metadata_set;
+pub mod mint_list_locked;
+pub mod refund_authority_set;
+pub mod roles_set;
 pub mod timelock_added;
 pub mod token_extension_blocked;
 pub mod token_extension_unblocked;
 
 pub use arbiter_set::*;
+pub use auto_release_set::*;
+pub use cooldown_set::*;
+pub use depositor_allowlist_set::*;
+pub use extension_locked::*;
 pub use extension_removed::*;
+pub use governance_delay_set::*;
+pub use hook_bypass_set::*;
 pub use hook_set::*;
+pub use metadata_set::*;
+pub use mint_list_locked::*;
+pub use refund_authority_set::*;
+pub use roles_set::*;
 pub use timelock_added::*;
 pub use token_extension_blocked::*;
 pub use token_extension_unblocked::*;
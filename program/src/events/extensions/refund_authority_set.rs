@@ -0,0 +1,62 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct RefundAuthoritySetEvent {
+    pub escrow: Address,
+    pub flags: u8,
+}
+
+impl EventDiscriminator for RefundAuthoritySetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::RefundAuthoritySet as u8;
+}
+
+impl EventSerialize for RefundAuthoritySetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.push(self.flags);
+        data
+    }
+}
+
+impl RefundAuthoritySetEvent {
+    pub const DATA_LEN: usize = 32 + 1; // escrow + flags
+
+    #[inline(always)]
+    pub fn new(escrow: Address, flags: u8) -> Self {
+        Self { escrow, flags }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_refund_authority_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = RefundAuthoritySetEvent::new(escrow, 0b11);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.flags, 0b11);
+    }
+
+    #[test]
+    fn test_refund_authority_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = RefundAuthoritySetEvent::new(escrow, 0b10);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + RefundAuthoritySetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::RefundAuthoritySet as u8);
+        assert_eq!(bytes[41], 0b10);
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct RolesSetEvent {
+    pub escrow: Address,
+    pub mint_manager: Address,
+    pub extension_manager: Address,
+    pub admin_transfer_authority: Address,
+}
+
+impl EventDiscriminator for RolesSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::RolesSet as u8;
+}
+
+impl EventSerialize for RolesSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.mint_manager.as_ref());
+        data.extend_from_slice(self.extension_manager.as_ref());
+        data.extend_from_slice(self.admin_transfer_authority.as_ref());
+        data
+    }
+}
+
+impl RolesSetEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 32; // escrow + mint_manager + extension_manager + admin_transfer_authority
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        mint_manager: Address,
+        extension_manager: Address,
+        admin_transfer_authority: Address,
+    ) -> Self {
+        Self { escrow, mint_manager, extension_manager, admin_transfer_authority }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_roles_set_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint_manager = Address::new_from_array([2u8; 32]);
+        let extension_manager = Address::new_from_array([3u8; 32]);
+        let admin_transfer_authority = Address::new_from_array([4u8; 32]);
+        let event = RolesSetEvent::new(escrow, mint_manager, extension_manager, admin_transfer_authority);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.mint_manager, mint_manager);
+        assert_eq!(event.extension_manager, extension_manager);
+        assert_eq!(event.admin_transfer_authority, admin_transfer_authority);
+    }
+
+    #[test]
+    fn test_roles_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let event = RolesSetEvent::new(escrow, Address::default(), Address::default(), Address::default());
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + RolesSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::RolesSet as u8);
+        assert_eq!(&bytes[9..41], escrow.as_ref());
+    }
+}
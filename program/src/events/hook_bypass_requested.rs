@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct HookBypassRequestedEvent {
+    pub escrow: Address,
+    pub depositor: Address,
+    pub receipt: Address,
+    pub requested_at: i64,
+}
+
+impl EventDiscriminator for HookBypassRequestedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::HookBypassRequested as u8;
+}
+
+impl EventSerialize for HookBypassRequestedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.receipt.as_ref());
+        data.extend_from_slice(&self.requested_at.to_le_bytes());
+        data
+    }
+}
+
+impl HookBypassRequestedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 8; // escrow + depositor + receipt + requested_at
+
+    #[inline(always)]
+    pub fn new(escrow: Address, depositor: Address, receipt: Address, requested_at: i64) -> Self {
+        Self { escrow, depositor, receipt, requested_at }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_hook_bypass_requested_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+
+        let event = HookBypassRequestedEvent::new(escrow, depositor, receipt, 1_700_000_000);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.depositor, depositor);
+        assert_eq!(event.receipt, receipt);
+        assert_eq!(event.requested_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_hook_bypass_requested_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let event = HookBypassRequestedEvent::new(escrow, depositor, receipt, 1_700_000_000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), HookBypassRequestedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], depositor.as_ref());
+        assert_eq!(&bytes[64..96], receipt.as_ref());
+        assert_eq!(&bytes[96..104], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_hook_bypass_requested_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let event = HookBypassRequestedEvent::new(escrow, depositor, receipt, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + HookBypassRequestedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::HookBypassRequested as u8);
+    }
+}
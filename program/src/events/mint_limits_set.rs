@@ -0,0 +1,74 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct MintLimitsSetEvent {
+    pub escrow: Address,
+    pub mint: Address,
+    pub max_total_deposits: u64,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+}
+
+impl EventDiscriminator for MintLimitsSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::MintLimitsSet as u8;
+}
+
+impl EventSerialize for MintLimitsSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.max_total_deposits.to_le_bytes());
+        data.extend_from_slice(&self.min_deposit.to_le_bytes());
+        data.extend_from_slice(&self.max_deposit.to_le_bytes());
+        data
+    }
+}
+
+impl MintLimitsSetEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 8 + 8 + 8; // escrow + mint + max_total_deposits + min_deposit + max_deposit
+
+    #[inline(always)]
+    pub fn new(escrow: Address, mint: Address, max_total_deposits: u64, min_deposit: u64, max_deposit: u64) -> Self {
+        Self { escrow, mint, max_total_deposits, min_deposit, max_deposit }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_mint_limits_set_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = MintLimitsSetEvent::new(escrow, mint, 1_000, 10, 500);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), MintLimitsSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], mint.as_ref());
+        assert_eq!(&bytes[64..72], &1_000u64.to_le_bytes());
+        assert_eq!(&bytes[72..80], &10u64.to_le_bytes());
+        assert_eq!(&bytes[80..88], &500u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_mint_limits_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = MintLimitsSetEvent::new(escrow, mint, 0, 0, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + MintLimitsSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::MintLimitsSet as u8);
+    }
+}
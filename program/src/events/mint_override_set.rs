@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct MintOverrideSetEvent {
+    pub escrow: Address,
+    pub mint: Address,
+    pub action: u8,
+    pub extension_type: u16,
+}
+
+impl EventDiscriminator for MintOverrideSetEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::MintOverrideSet as u8;
+}
+
+impl EventSerialize for MintOverrideSetEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.push(self.action);
+        data.extend_from_slice(&self.extension_type.to_le_bytes());
+        data
+    }
+}
+
+impl MintOverrideSetEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 1 + 2; // escrow + mint + action + extension_type
+
+    #[inline(always)]
+    pub fn new(escrow: Address, mint: Address, action: u8, extension_type: u16) -> Self {
+        Self { escrow, mint, action, extension_type }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_mint_override_set_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = MintOverrideSetEvent::new(escrow, mint, 1, 3);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), MintOverrideSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], mint.as_ref());
+        assert_eq!(bytes[64], 1);
+        assert_eq!(&bytes[65..67], &3u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_mint_override_set_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = MintOverrideSetEvent::new(escrow, mint, 0, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + MintOverrideSetEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::MintOverrideSet as u8);
+    }
+}
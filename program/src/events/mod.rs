@@ -1,19 +1,51 @@
 pub mod admin_update;
 pub mod allow_mint;
+pub mod basket_deposit;
+pub mod basket_withdraw;
 pub mod block_mint;
+pub mod change_cancelled;
+pub mod change_executed;
+pub mod change_queued;
 pub mod create_escrow;
 pub mod deposit;
 pub mod extensions;
+pub mod force_refund;
+pub mod hook_bypass_requested;
+pub mod mint_limits_set;
+pub mod mint_override_set;
+pub mod receipt_released;
 pub mod set_immutable;
 pub mod shared;
+pub mod solvency_verified;
+pub mod surplus_swept;
 pub mod withdraw;
+pub mod withdraw_delegate_approved;
+pub mod withdraw_delegate_revoked;
+pub mod withdraw_many;
+pub mod withdraw_requested;
 
 pub use admin_update::*;
 pub use allow_mint::*;
+pub use basket_deposit::*;
+pub use basket_withdraw::*;
 pub use block_mint::*;
+pub use change_cancelled::*;
+pub use change_executed::*;
+pub use change_queued::*;
 pub use create_escrow::*;
 pub use deposit::*;
 pub use extensions::*;
+pub use force_refund::*;
+pub use hook_bypass_requested::*;
+pub use mint_limits_set::*;
+pub use mint_override_set::*;
+pub use receipt_released::*;
 pub use set_immutable::*;
 pub use shared::*;
+pub use solvency_verified::*;
+pub use surplus_swept::*;
 pub use withdraw::*;
+pub use withdraw_delegate_approved::*;
+pub use withdraw_delegate_revoked::*;
+pub use withdraw_many::*;
+pub use withdraw_requested::*;
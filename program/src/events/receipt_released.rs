@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct ReceiptReleasedEvent {
+    pub escrow: Address,
+    pub depositor: Address,
+    pub released_by: Address,
+    pub mint: Address,
+    pub nonce: u64,
+    pub amount: u64,
+}
+
+impl EventDiscriminator for ReceiptReleasedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::ReceiptReleased as u8;
+}
+
+impl EventSerialize for ReceiptReleasedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.released_by.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.nonce.to_le_bytes());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data
+    }
+}
+
+impl ReceiptReleasedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8 + 8; // escrow + depositor + released_by + mint + nonce + amount
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        depositor: Address,
+        released_by: Address,
+        mint: Address,
+        nonce: u64,
+        amount: u64,
+    ) -> Self {
+        Self { escrow, depositor, released_by, mint, nonce, amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_receipt_released_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let released_by = Address::new_from_array([5u8; 32]);
+        let mint = Address::new_from_array([3u8; 32]);
+
+        let event = ReceiptReleasedEvent::new(escrow, depositor, released_by, mint, 4, 1000);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.depositor, depositor);
+        assert_eq!(event.released_by, released_by);
+        assert_eq!(event.mint, mint);
+        assert_eq!(event.nonce, 4);
+        assert_eq!(event.amount, 1000);
+    }
+
+    #[test]
+    fn test_receipt_released_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let released_by = Address::new_from_array([5u8; 32]);
+        let mint = Address::new_from_array([3u8; 32]);
+        let event = ReceiptReleasedEvent::new(escrow, depositor, released_by, mint, 4, 5000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), ReceiptReleasedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], depositor.as_ref());
+        assert_eq!(&bytes[64..96], released_by.as_ref());
+        assert_eq!(&bytes[96..128], mint.as_ref());
+        assert_eq!(&bytes[128..136], &4u64.to_le_bytes());
+        assert_eq!(&bytes[136..144], &5000u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_receipt_released_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let released_by = Address::new_from_array([5u8; 32]);
+        let mint = Address::new_from_array([3u8; 32]);
+        let event = ReceiptReleasedEvent::new(escrow, depositor, released_by, mint, 4, 1000);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + ReceiptReleasedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::ReceiptReleased as u8);
+        assert_eq!(&bytes[9..41], escrow.as_ref());
+    }
+}
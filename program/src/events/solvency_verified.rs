@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct SolvencyVerifiedEvent {
+    pub escrow: Address,
+    pub mint: Address,
+    pub vault_balance: u64,
+    pub total_deposited: u64,
+    pub outstanding_receipts: u64,
+}
+
+impl EventDiscriminator for SolvencyVerifiedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::SolvencyVerified as u8;
+}
+
+impl EventSerialize for SolvencyVerifiedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.vault_balance.to_le_bytes());
+        data.extend_from_slice(&self.total_deposited.to_le_bytes());
+        data.extend_from_slice(&self.outstanding_receipts.to_le_bytes());
+        data
+    }
+}
+
+impl SolvencyVerifiedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 8 + 8 + 8; // escrow + mint + vault_balance + total_deposited + outstanding_receipts
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        mint: Address,
+        vault_balance: u64,
+        total_deposited: u64,
+        outstanding_receipts: u64,
+    ) -> Self {
+        Self { escrow, mint, vault_balance, total_deposited, outstanding_receipts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_solvency_verified_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = SolvencyVerifiedEvent::new(escrow, mint, 1_500, 1_000, 3);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), SolvencyVerifiedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], mint.as_ref());
+        assert_eq!(&bytes[64..72], &1_500u64.to_le_bytes());
+        assert_eq!(&bytes[72..80], &1_000u64.to_le_bytes());
+        assert_eq!(&bytes[80..88], &3u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_solvency_verified_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let event = SolvencyVerifiedEvent::new(escrow, mint, 0, 0, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + SolvencyVerifiedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::SolvencyVerified as u8);
+    }
+}
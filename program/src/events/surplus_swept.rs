@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct SurplusSweptEvent {
+    pub escrow: Address,
+    pub mint: Address,
+    pub destination: Address,
+    pub amount: u64,
+}
+
+impl EventDiscriminator for SurplusSweptEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::SurplusSwept as u8;
+}
+
+impl EventSerialize for SurplusSweptEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(self.destination.as_ref());
+        data.extend_from_slice(&self.amount.to_le_bytes());
+        data
+    }
+}
+
+impl SurplusSweptEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 8; // escrow + mint + destination + amount
+
+    #[inline(always)]
+    pub fn new(escrow: Address, mint: Address, destination: Address, amount: u64) -> Self {
+        Self { escrow, mint, destination, amount }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_surplus_swept_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let destination = Address::new_from_array([3u8; 32]);
+        let event = SurplusSweptEvent::new(escrow, mint, destination, 500);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), SurplusSweptEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], mint.as_ref());
+        assert_eq!(&bytes[64..96], destination.as_ref());
+        assert_eq!(&bytes[96..104], &500u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_surplus_swept_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let mint = Address::new_from_array([2u8; 32]);
+        let destination = Address::new_from_array([3u8; 32]);
+        let event = SurplusSweptEvent::new(escrow, mint, destination, 500);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + SurplusSweptEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::SurplusSwept as u8);
+    }
+}
@@ -8,8 +8,9 @@ use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
 pub struct WithdrawEvent {
     pub escrow: Address,
     pub withdrawer: Address,
+    pub destination: Address,
     pub mint: Address,
-    pub receipt_seed: Address,
+    pub nonce: u64,
     pub amount: u64,
 }
 
@@ -23,19 +24,27 @@ impl EventSerialize for WithdrawEvent {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.extend_from_slice(self.escrow.as_ref());
         data.extend_from_slice(self.withdrawer.as_ref());
+        data.extend_from_slice(self.destination.as_ref());
         data.extend_from_slice(self.mint.as_ref());
-        data.extend_from_slice(self.receipt_seed.as_ref());
+        data.extend_from_slice(&self.nonce.to_le_bytes());
         data.extend_from_slice(&self.amount.to_le_bytes());
         data
     }
 }
 
 impl WithdrawEvent {
-    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8; // escrow + withdrawer + mint + receipt_seed + amount
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8 + 8; // escrow + withdrawer + destination + mint + nonce + amount
 
     #[inline(always)]
-    pub fn new(escrow: Address, withdrawer: Address, mint: Address, receipt_seed: Address, amount: u64) -> Self {
-        Self { escrow, withdrawer, mint, receipt_seed, amount }
+    pub fn new(
+        escrow: Address,
+        withdrawer: Address,
+        destination: Address,
+        mint: Address,
+        nonce: u64,
+        amount: u64,
+    ) -> Self {
+        Self { escrow, withdrawer, destination, mint, nonce, amount }
     }
 }
 
@@ -49,15 +58,16 @@ mod tests {
     fn test_withdraw_event_new() {
         let escrow = Address::new_from_array([1u8; 32]);
         let withdrawer = Address::new_from_array([2u8; 32]);
+        let destination = Address::new_from_array([5u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
 
-        let event = WithdrawEvent::new(escrow, withdrawer, mint, receipt_seed, 1000);
+        let event = WithdrawEvent::new(escrow, withdrawer, destination, mint, 4, 1000);
 
         assert_eq!(event.escrow, escrow);
         assert_eq!(event.withdrawer, withdrawer);
+        assert_eq!(event.destination, destination);
         assert_eq!(event.mint, mint);
-        assert_eq!(event.receipt_seed, receipt_seed);
+        assert_eq!(event.nonce, 4);
         assert_eq!(event.amount, 1000);
     }
 
@@ -65,26 +75,27 @@ mod tests {
     fn test_withdraw_event_to_bytes_inner() {
         let escrow = Address::new_from_array([1u8; 32]);
         let withdrawer = Address::new_from_array([2u8; 32]);
+        let destination = Address::new_from_array([5u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
-        let event = WithdrawEvent::new(escrow, withdrawer, mint, receipt_seed, 5000);
+        let event = WithdrawEvent::new(escrow, withdrawer, destination, mint, 4, 5000);
 
         let bytes = event.to_bytes_inner();
         assert_eq!(bytes.len(), WithdrawEvent::DATA_LEN);
         assert_eq!(&bytes[..32], escrow.as_ref());
         assert_eq!(&bytes[32..64], withdrawer.as_ref());
-        assert_eq!(&bytes[64..96], mint.as_ref());
-        assert_eq!(&bytes[96..128], receipt_seed.as_ref());
-        assert_eq!(&bytes[128..136], &5000u64.to_le_bytes());
+        assert_eq!(&bytes[64..96], destination.as_ref());
+        assert_eq!(&bytes[96..128], mint.as_ref());
+        assert_eq!(&bytes[128..136], &4u64.to_le_bytes());
+        assert_eq!(&bytes[136..144], &5000u64.to_le_bytes());
     }
 
     #[test]
     fn test_withdraw_event_to_bytes() {
         let escrow = Address::new_from_array([1u8; 32]);
         let withdrawer = Address::new_from_array([2u8; 32]);
+        let destination = Address::new_from_array([5u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
-        let event = WithdrawEvent::new(escrow, withdrawer, mint, receipt_seed, 1000);
+        let event = WithdrawEvent::new(escrow, withdrawer, destination, mint, 4, 1000);
 
         let bytes = event.to_bytes();
         assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + WithdrawEvent::DATA_LEN);
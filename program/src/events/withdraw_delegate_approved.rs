@@ -0,0 +1,94 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct WithdrawDelegateApprovedEvent {
+    pub escrow: Address,
+    pub depositor: Address,
+    pub receipt: Address,
+    pub delegate: Address,
+    pub expires_at: i64,
+}
+
+impl EventDiscriminator for WithdrawDelegateApprovedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::WithdrawDelegateApproved as u8;
+}
+
+impl EventSerialize for WithdrawDelegateApprovedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.receipt.as_ref());
+        data.extend_from_slice(self.delegate.as_ref());
+        data.extend_from_slice(&self.expires_at.to_le_bytes());
+        data
+    }
+}
+
+impl WithdrawDelegateApprovedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8; // escrow + depositor + receipt + delegate + expires_at
+
+    #[inline(always)]
+    pub fn new(escrow: Address, depositor: Address, receipt: Address, delegate: Address, expires_at: i64) -> Self {
+        Self { escrow, depositor, receipt, delegate, expires_at }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_withdraw_delegate_approved_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let delegate = Address::new_from_array([4u8; 32]);
+
+        let event = WithdrawDelegateApprovedEvent::new(escrow, depositor, receipt, delegate, 1_700_000_000);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.depositor, depositor);
+        assert_eq!(event.receipt, receipt);
+        assert_eq!(event.delegate, delegate);
+        assert_eq!(event.expires_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_withdraw_delegate_approved_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let delegate = Address::new_from_array([4u8; 32]);
+        let event = WithdrawDelegateApprovedEvent::new(escrow, depositor, receipt, delegate, 1_700_000_000);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), WithdrawDelegateApprovedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], depositor.as_ref());
+        assert_eq!(&bytes[64..96], receipt.as_ref());
+        assert_eq!(&bytes[96..128], delegate.as_ref());
+        assert_eq!(&bytes[128..136], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_withdraw_delegate_approved_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let delegate = Address::new_from_array([4u8; 32]);
+        let event = WithdrawDelegateApprovedEvent::new(escrow, depositor, receipt, delegate, 0);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + WithdrawDelegateApprovedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::WithdrawDelegateApproved as u8);
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct WithdrawDelegateRevokedEvent {
+    pub escrow: Address,
+    pub depositor: Address,
+    pub receipt: Address,
+}
+
+impl EventDiscriminator for WithdrawDelegateRevokedEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::WithdrawDelegateRevoked as u8;
+}
+
+impl EventSerialize for WithdrawDelegateRevokedEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.receipt.as_ref());
+        data
+    }
+}
+
+impl WithdrawDelegateRevokedEvent {
+    pub const DATA_LEN: usize = 32 + 32 + 32; // escrow + depositor + receipt
+
+    #[inline(always)]
+    pub fn new(escrow: Address, depositor: Address, receipt: Address) -> Self {
+        Self { escrow, depositor, receipt }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+
+    #[test]
+    fn test_withdraw_delegate_revoked_event_new() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+
+        let event = WithdrawDelegateRevokedEvent::new(escrow, depositor, receipt);
+
+        assert_eq!(event.escrow, escrow);
+        assert_eq!(event.depositor, depositor);
+        assert_eq!(event.receipt, receipt);
+    }
+
+    #[test]
+    fn test_withdraw_delegate_revoked_event_to_bytes_inner() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let event = WithdrawDelegateRevokedEvent::new(escrow, depositor, receipt);
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), WithdrawDelegateRevokedEvent::DATA_LEN);
+        assert_eq!(&bytes[..32], escrow.as_ref());
+        assert_eq!(&bytes[32..64], depositor.as_ref());
+        assert_eq!(&bytes[64..96], receipt.as_ref());
+    }
+
+    #[test]
+    fn test_withdraw_delegate_revoked_event_to_bytes() {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let receipt = Address::new_from_array([3u8; 32]);
+        let event = WithdrawDelegateRevokedEvent::new(escrow, depositor, receipt);
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + WithdrawDelegateRevokedEvent::DATA_LEN);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::WithdrawDelegateRevoked as u8);
+    }
+}
@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::Address;
+
+use crate::traits::{EventDiscriminator, EventDiscriminators, EventSerialize};
+
+#[derive(CodamaType)]
+pub struct WithdrawManyEvent {
+    pub escrow: Address,
+    pub withdrawer: Address,
+    pub destination: Address,
+    pub mint: Address,
+    pub total_amount: u64,
+    pub nonces: Vec<u64>,
+}
+
+impl EventDiscriminator for WithdrawManyEvent {
+    const DISCRIMINATOR: u8 = EventDiscriminators::WithdrawMany as u8;
+}
+
+impl EventSerialize for WithdrawManyEvent {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN + 4 + self.nonces.len() * 8);
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.withdrawer.as_ref());
+        data.extend_from_slice(self.destination.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.total_amount.to_le_bytes());
+        data.extend_from_slice(&(self.nonces.len() as u32).to_le_bytes());
+        for nonce in &self.nonces {
+            data.extend_from_slice(&nonce.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl WithdrawManyEvent {
+    /// Fixed part only; the receipt nonces follow with a u32 length prefix
+    pub const DATA_LEN: usize = 32 + 32 + 32 + 32 + 8; // escrow + withdrawer + destination + mint + total_amount
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        withdrawer: Address,
+        destination: Address,
+        mint: Address,
+        total_amount: u64,
+        nonces: Vec<u64>,
+    ) -> Self {
+        Self { escrow, withdrawer, destination, mint, total_amount, nonces }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EVENT_IX_TAG_LE;
+    use crate::traits::EVENT_DISCRIMINATOR_LEN;
+    use alloc::vec;
+
+    fn create_test_event() -> WithdrawManyEvent {
+        WithdrawManyEvent::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([5u8; 32]),
+            Address::new_from_array([3u8; 32]),
+            3000,
+            vec![0, 1, 4],
+        )
+    }
+
+    #[test]
+    fn test_withdraw_many_event_new() {
+        let event = create_test_event();
+
+        assert_eq!(event.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(event.withdrawer, Address::new_from_array([2u8; 32]));
+        assert_eq!(event.destination, Address::new_from_array([5u8; 32]));
+        assert_eq!(event.mint, Address::new_from_array([3u8; 32]));
+        assert_eq!(event.total_amount, 3000);
+        assert_eq!(event.nonces, [0, 1, 4]);
+    }
+
+    #[test]
+    fn test_withdraw_many_event_to_bytes_inner() {
+        let event = create_test_event();
+
+        let bytes = event.to_bytes_inner();
+        assert_eq!(bytes.len(), WithdrawManyEvent::DATA_LEN + 4 + 3 * 8);
+        assert_eq!(&bytes[..32], &[1u8; 32]);
+        assert_eq!(&bytes[32..64], &[2u8; 32]);
+        assert_eq!(&bytes[64..96], &[5u8; 32]);
+        assert_eq!(&bytes[96..128], &[3u8; 32]);
+        assert_eq!(&bytes[128..136], &3000u64.to_le_bytes());
+        assert_eq!(&bytes[136..140], &3u32.to_le_bytes());
+        assert_eq!(&bytes[140..148], &0u64.to_le_bytes());
+        assert_eq!(&bytes[156..164], &4u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_withdraw_many_event_to_bytes() {
+        let event = create_test_event();
+
+        let bytes = event.to_bytes();
+        assert_eq!(bytes.len(), EVENT_DISCRIMINATOR_LEN + WithdrawManyEvent::DATA_LEN + 4 + 3 * 8);
+        assert_eq!(&bytes[..8], EVENT_IX_TAG_LE);
+        assert_eq!(bytes[8], EventDiscriminators::WithdrawMany as u8);
+        assert_eq!(&bytes[9..41], &[1u8; 32]);
+    }
+}
@@ -16,7 +16,7 @@ use crate::{
 ///
 /// # Account Layout
 /// 0. `[signer, writable]` payer - Pays for account creation
-/// 1. `[signer]` admin - Must match escrow.admin
+/// 1. `[signer]` admin - Mint manager, or escrow.admin if the role is unassigned
 /// 2. `[]` escrow - Escrow PDA (validates admin)
 /// 3. `[]` escrow_extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
 /// 4. `[]` mint - Mint account to allow (must be owned by token_program)
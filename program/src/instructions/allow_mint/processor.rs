@@ -5,9 +5,9 @@ use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use crate::{
     events::AllowMintEvent,
     instructions::AllowMint,
-    state::{AllowedMint, AllowedMintPda, Escrow, ExtensionsPda},
+    state::{require_mint_list_unlocked, AllowedMint, AllowedMintPda, Escrow, ExtensionsPda, Role},
     traits::{AccountSerialize, AccountSize, EventSerialize, PdaSeeds},
-    utils::{create_pda_account, emit_event, validate_mint_extensions},
+    utils::{create_pda_account, emit_event, get_token_account_amount, validate_mint_extensions},
 };
 
 /// Processes the AllowMint instruction.
@@ -16,18 +16,22 @@ use crate::{
 pub fn process_allow_mint(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = AllowMint::try_from((instruction_data, accounts))?;
 
-    // Verify escrow exists and validate admin
+    // Verify escrow exists
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
+
+    // Validate escrow_extensions is the correct PDA for this escrow
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda_address(ix.accounts.escrow_extensions, &crate::ID)?;
+
+    // Validate signer holds the mint manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.escrow_extensions, Role::MintManager)?;
+    require_mint_list_unlocked(ix.accounts.escrow_extensions)?;
 
     // Validate AllowedMint PDA using external seeds
     let pda_seeds = AllowedMintPda::new(ix.accounts.escrow.address(), ix.accounts.mint.address());
     pda_seeds.validate_pda(ix.accounts.allowed_mint, program_id, ix.data.bump)?;
 
-    // Validate escrow_extensions is the correct PDA for this escrow
-    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
-    extensions_pda.validate_pda_address(ix.accounts.escrow_extensions, &crate::ID)?;
     validate_mint_extensions(ix.accounts.mint, ix.accounts.escrow_extensions)?;
 
     // Get seeds for AllowedMint account creation
@@ -45,14 +49,6 @@ pub fn process_allow_mint(program_id: &Address, accounts: &[AccountView], instru
         allowed_mint_seeds_array,
     )?;
 
-    // Create AllowedMint state (only stores bump)
-    let allowed_mint = AllowedMint::new(ix.data.bump);
-
-    // Write serialized AllowedMint data to the account
-    let mut allowed_mint_data_slice = ix.accounts.allowed_mint.try_borrow_mut()?;
-    allowed_mint.write_to_slice(&mut allowed_mint_data_slice)?;
-    drop(allowed_mint_data_slice);
-
     // Create vault ATA for the escrow
     CreateIdempotent {
         funding_account: ix.accounts.payer,
@@ -64,6 +60,15 @@ pub fn process_allow_mint(program_id: &Address, accounts: &[AccountView], instru
     }
     .invoke()?;
 
+    // A vault left over from before the mint was blocked may still back old receipts
+    let opening_balance = get_token_account_amount(ix.accounts.vault)?;
+    let allowed_mint = AllowedMint::new(ix.data.bump, opening_balance);
+
+    // Write serialized AllowedMint data to the account
+    let mut allowed_mint_data_slice = ix.accounts.allowed_mint.try_borrow_mut()?;
+    allowed_mint.write_to_slice(&mut allowed_mint_data_slice)?;
+    drop(allowed_mint_data_slice);
+
     // Emit event via CPI
     let event = AllowMintEvent::new(*ix.accounts.escrow.address(), *ix.accounts.mint.address());
     emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
@@ -0,0 +1,90 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for ApproveWithdrawDelegate
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the withdraw delegate PDA
+/// * `delegate` (Address) - Key allowed to withdraw the receipt to the depositor
+/// * `expires_at` (i64) - Unix timestamp the approval lapses at, `0` for no expiry
+pub struct ApproveWithdrawDelegateData {
+    pub bump: u8,
+    pub delegate: Address,
+    pub expires_at: i64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for ApproveWithdrawDelegateData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let delegate = Address::new_from_array(data[1..33].try_into().unwrap());
+        let expires_at = i64::from_le_bytes(data[33..41].try_into().unwrap());
+
+        if delegate == Address::default() || expires_at < 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { bump, delegate, expires_at })
+    }
+}
+
+impl<'a> InstructionData<'a> for ApproveWithdrawDelegateData {
+    const LEN: usize = 1 + 32 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approve_data(delegate: [u8; 32], expires_at: i64) -> [u8; ApproveWithdrawDelegateData::LEN] {
+        let mut data = [0u8; ApproveWithdrawDelegateData::LEN];
+        data[0] = 254;
+        data[1..33].copy_from_slice(&delegate);
+        data[33..41].copy_from_slice(&expires_at.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_approve_withdraw_delegate_data_try_from_valid() {
+        let data = approve_data([7u8; 32], 1_700_000_000);
+
+        let result = ApproveWithdrawDelegateData::try_from(&data[..]).unwrap();
+        assert_eq!(result.bump, 254);
+        assert_eq!(result.delegate, Address::new_from_array([7u8; 32]));
+        assert_eq!(result.expires_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_approve_withdraw_delegate_data_try_from_no_expiry() {
+        let data = approve_data([7u8; 32], 0);
+
+        let result = ApproveWithdrawDelegateData::try_from(&data[..]).unwrap();
+        assert_eq!(result.expires_at, 0);
+    }
+
+    #[test]
+    fn test_approve_withdraw_delegate_data_try_from_default_delegate() {
+        let data = approve_data([0u8; 32], 0);
+        let result = ApproveWithdrawDelegateData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_approve_withdraw_delegate_data_try_from_negative_expiry() {
+        let data = approve_data([7u8; 32], -1);
+        let result = ApproveWithdrawDelegateData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_approve_withdraw_delegate_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = ApproveWithdrawDelegateData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::WithdrawDelegateApprovedEvent,
+    instructions::ApproveWithdrawDelegate,
+    state::{Escrow, Receipt, WithdrawDelegate},
+    traits::{AccountSerialize, AccountSize, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event},
+};
+
+/// Processes the ApproveWithdrawDelegate instruction.
+///
+/// Lets the depositor name a key that may sign Withdraw for one receipt. Delegated
+/// withdrawals always pay out to the depositor, so the delegate can never redirect funds.
+/// Revoke the existing delegate before approving a different one.
+pub fn process_approve_withdraw_delegate(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ApproveWithdrawDelegate::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate receipt
+    {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+
+        // Requested receipts can only be claimed by the depositor
+        receipt.require_no_withdraw_request()?;
+    }
+
+    // The depositor can already withdraw on their own
+    if ix.data.delegate == *ix.accounts.depositor.address() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Create WithdrawDelegate state
+    let withdraw_delegate = WithdrawDelegate::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.receipt.address(),
+        *ix.accounts.depositor.address(),
+        ix.data.delegate,
+        ix.data.expires_at,
+        ix.data.bump,
+    );
+
+    // Reject approvals that would already be expired
+    let clock = Clock::get()?;
+    if withdraw_delegate.is_expired(clock.unix_timestamp) {
+        return Err(EscrowProgramError::WithdrawDelegateExpired.into());
+    }
+
+    // Validate withdraw delegate PDA
+    withdraw_delegate.validate_pda(ix.accounts.withdraw_delegate, program_id, ix.data.bump)?;
+
+    // Get seeds for withdraw delegate account creation
+    let delegate_bump_seed = [ix.data.bump];
+    let delegate_seeds: Vec<Seed> = withdraw_delegate.seeds_with_bump(&delegate_bump_seed);
+    let delegate_seeds_array: [Seed; 3] = delegate_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        WithdrawDelegate::LEN,
+        program_id,
+        ix.accounts.withdraw_delegate,
+        delegate_seeds_array,
+    )?;
+
+    // Write serialized withdraw delegate data to the account
+    let mut delegate_data_slice = ix.accounts.withdraw_delegate.try_borrow_mut()?;
+    withdraw_delegate.write_to_slice(&mut delegate_data_slice)?;
+    drop(delegate_data_slice);
+
+    // Emit event
+    let event = WithdrawDelegateApprovedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.receipt.address(),
+        ix.data.delegate,
+        ix.data.expires_at,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
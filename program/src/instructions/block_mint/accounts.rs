@@ -13,7 +13,7 @@ use crate::{
 /// Closes the AllowedMint PDA, blocking future deposits of that mint.
 ///
 /// # Account Layout
-/// 0. `[signer]` admin - Must match escrow.admin
+/// 0. `[signer]` admin - Mint manager, or escrow.admin if the role is unassigned
 /// 1. `[writable]` rent_recipient - Receives rent refund from closed account
 /// 2. `[]` escrow - Escrow PDA (validates admin)
 /// 3. `[]` mint - Token mint being blocked
@@ -21,6 +21,7 @@ use crate::{
 /// 5. `[]` token_program - Token program (SPL Token or Token-2022)
 /// 6. `[]` event_authority - Event authority PDA
 /// 7. `[]` escrow_program - Current program (for event emission)
+/// 8. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
 pub struct BlockMintAccounts<'a> {
     pub admin: &'a AccountView,
     pub rent_recipient: &'a AccountView,
@@ -30,6 +31,7 @@ pub struct BlockMintAccounts<'a> {
     pub token_program: &'a AccountView,
     pub event_authority: &'a AccountView,
     pub escrow_program: &'a AccountView,
+    pub extensions: &'a AccountView,
 }
 
 impl<'a> TryFrom<&'a [AccountView]> for BlockMintAccounts<'a> {
@@ -37,7 +39,7 @@ impl<'a> TryFrom<&'a [AccountView]> for BlockMintAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [admin, rent_recipient, escrow, mint, allowed_mint, token_program, event_authority, escrow_program] =
+        let [admin, rent_recipient, escrow, mint, allowed_mint, token_program, event_authority, escrow_program, extensions] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -52,6 +54,7 @@ impl<'a> TryFrom<&'a [AccountView]> for BlockMintAccounts<'a> {
 
         // 3. Validate readonly
         verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
         verify_readonly(mint)?;
 
         // 4. Validate program IDs
@@ -66,7 +69,17 @@ impl<'a> TryFrom<&'a [AccountView]> for BlockMintAccounts<'a> {
         // 6. Validate token account ownership
         verify_token_program_account(mint)?;
 
-        Ok(Self { admin, rent_recipient, escrow, mint, allowed_mint, token_program, event_authority, escrow_program })
+        Ok(Self {
+            admin,
+            rent_recipient,
+            escrow,
+            mint,
+            allowed_mint,
+            token_program,
+            event_authority,
+            escrow_program,
+            extensions,
+        })
     }
 }
 
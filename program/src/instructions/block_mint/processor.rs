@@ -1,35 +1,50 @@
 use pinocchio::{account::AccountView, Address, ProgramResult};
 
 use crate::{
+    errors::EscrowProgramError,
     events::BlockMintEvent,
     instructions::BlockMint,
-    state::{AllowedMint, Escrow},
-    traits::EventSerialize,
+    state::{require_mint_list_unlocked, validate_extensions_pda, AllowedMint, Escrow, Role},
+    traits::{AccountSize, EventSerialize},
     utils::{close_pda_account, emit_event},
 };
 
 /// Processes the BlockMint instruction.
 ///
-/// Closes the AllowedMint PDA, blocking future deposits of that mint.
+/// Closes the AllowedMint PDA, blocking future deposits of that mint. Fails while receipts
+/// counted by the AllowedMint are still outstanding or while it holds per-mint overrides.
 pub fn process_block_mint(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = BlockMint::try_from((instruction_data, accounts))?;
 
-    // Verify escrow exists and validate admin
+    // Verify escrow exists and validate the mint manager role
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::MintManager)?;
+    require_mint_list_unlocked(ix.accounts.extensions)?;
 
     // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
     let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
-    let _allowed_mint = AllowedMint::from_account(
+    let allowed_mint = AllowedMint::from_account(
         &allowed_mint_data,
         ix.accounts.allowed_mint,
         program_id,
         ix.accounts.escrow.address(),
         ix.accounts.mint.address(),
     )?;
+    // Payouts after a block fall back to the escrow-level extensions, so overrides must be
+    // removed first rather than vanish with the account
+    let has_overrides = allowed_mint_data.len() > AllowedMint::LEN;
     drop(allowed_mint_data);
 
+    // The running totals must outlive every receipt they count
+    if allowed_mint.outstanding_receipts != 0 {
+        return Err(EscrowProgramError::OutstandingReceipts.into());
+    }
+    if has_overrides {
+        return Err(EscrowProgramError::MintOverridesPresent.into());
+    }
+
     // Close the AllowedMint account and return lamports to rent_recipient
     close_pda_account(ix.accounts.allowed_mint, ix.accounts.rent_recipient)?;
 
@@ -0,0 +1,66 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
+    },
+};
+
+/// Accounts for the CancelQueuedChange instruction
+///
+/// Drops a pending change without applying it and closes the PendingChange account.
+///
+/// # Account Layout
+/// 0. `[signer]` admin - Authority for the changed extension (escrow.admin or the extension manager)
+/// 1. `[writable]` rent_recipient - Receives rent refund from the closed pending change
+/// 2. `[]` escrow - Escrow the change applies to
+/// 3. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
+/// 4. `[writable]` pending_change - PendingChange PDA to close
+/// 5. `[]` event_authority - Event authority PDA
+/// 6. `[]` escrow_program - Current program
+pub struct CancelQueuedChangeAccounts<'a> {
+    pub admin: &'a AccountView,
+    pub rent_recipient: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub pending_change: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CancelQueuedChangeAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [admin, rent_recipient, escrow, extensions, pending_change, event_authority, escrow_program] = accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(pending_change, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(pending_change)?;
+
+        Ok(Self { admin, rent_recipient, escrow, extensions, pending_change, event_authority, escrow_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CancelQueuedChangeAccounts<'a> {}
@@ -0,0 +1,48 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::ChangeCancelledEvent,
+    instructions::CancelQueuedChange,
+    state::{validate_extensions_pda, Escrow, PendingChange},
+    traits::EventSerialize,
+    utils::{close_pda_account, emit_event},
+};
+
+/// Processes the CancelQueuedChange instruction.
+///
+/// Closes a PendingChange account without applying it. Allowed at any time, including
+/// after the governance delay has elapsed or the escrow has become immutable.
+pub fn process_cancel_queued_change(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CancelQueuedChange::try_from((instruction_data, accounts))?;
+
+    // Read escrow
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+
+    // Read pending change and validate it belongs to this escrow
+    let pending_change_data = ix.accounts.pending_change.try_borrow()?;
+    let pending_change = PendingChange::from_account(&pending_change_data, ix.accounts.pending_change, program_id)?;
+    pending_change.validate_escrow(ix.accounts.escrow.address())?;
+    drop(pending_change_data);
+
+    // Validate extensions PDA and the signer's authority over the extension
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_extension_authority(
+        ix.accounts.admin.address(),
+        ix.accounts.extensions,
+        pending_change.extension_type()?,
+    )?;
+
+    // Close the PendingChange account and return lamports to rent_recipient
+    close_pda_account(ix.accounts.pending_change, ix.accounts.rent_recipient)?;
+
+    // Emit event
+    let event = ChangeCancelledEvent::new(*ix.accounts.escrow.address(), pending_change.change_id());
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, validate_associated_token_account_address, verify_associated_token_program,
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_owned_by,
+        verify_readonly, verify_signer, verify_system_program, verify_token_program, verify_writable,
+    },
+};
+
+/// Accounts for the ClaimWithdraw instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for the destination token account if it has to be created
+/// 1. `[writable]` rent_recipient - Receives rent from closed receipt and request, must match receipt.rent_recipient
+/// 2. `[signer]` depositor - Must match receipt.depositor
+/// 3. `[]` escrow - Escrow PDA (signing authority for vault transfer)
+/// 4. `[]` extensions - Extensions PDA (optional, may be system-owned)
+/// 5. `[writable]` receipt - Deposit receipt to verify and close
+/// 6. `[writable]` withdraw_request - Withdraw request PDA for the receipt (closed on success)
+/// 7. `[writable]` vault - Escrow's vault token account (source)
+/// 8. `[]` destination - Owner of the destination token account, chosen by the depositor
+/// 9. `[writable]` destination_token_account - Destination's ATA (created if it does not exist)
+/// 10. `[]` mint - Token mint
+/// 11. `[]` token_program - SPL Token program
+/// 12. `[]` associated_token_program - Associated Token program for destination ATA creation
+/// 13. `[]` system_program - System program
+/// 14. `[]` event_authority - Event authority PDA
+/// 15. `[]` escrow_program - Current program
+/// 16. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (may be closed if the mint was blocked)
+///
+/// # Remaining Accounts
+/// If arbiter extension is set:
+///   0. `[signer]` arbiter - The arbiter who must authorize the withdrawal
+/// If hook extension is set (after arbiter if both):
+///   N. `[]` hook_program - The hook program to invoke (N counts the preceding accounts)
+///   N+1..M. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
+/// If hook bypass extension is set and the grace period after the depositor's request has elapsed,
+/// the hook program may be replaced by:
+///   N. `[writable]` hook_bypass_request - Hook bypass request PDA for this receipt (closed on success)
+pub struct ClaimWithdrawAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub rent_recipient: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub receipt: &'a AccountView,
+    pub withdraw_request: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub destination: &'a AccountView,
+    pub destination_token_account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ClaimWithdrawAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, rent_recipient, depositor, escrow, extensions, receipt, withdraw_request, vault, destination, destination_token_account, mint, token_program, associated_token_program, system_program, event_authority, escrow_program, allowed_mint, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(depositor, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(receipt, true)?;
+        verify_writable(withdraw_request, true)?;
+        verify_writable(vault, true)?;
+        verify_writable(destination_token_account, true)?;
+        verify_writable(allowed_mint, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(destination)?;
+        verify_readonly(mint)?;
+
+        // 4. Validate program IDs
+        verify_token_program(token_program)?;
+        verify_owned_by(mint, token_program.address())?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(receipt)?;
+        verify_current_program_account(withdraw_request)?;
+
+        // 6. Validate ATAs (the destination ATA may not exist yet and is created by the processor)
+        validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
+        validate_associated_token_account_address(
+            destination_token_account,
+            destination.address(),
+            mint,
+            token_program,
+        )?;
+
+        Ok(Self {
+            payer,
+            rent_recipient,
+            depositor,
+            escrow,
+            extensions,
+            receipt,
+            withdraw_request,
+            vault,
+            destination,
+            destination_token_account,
+            mint,
+            token_program,
+            associated_token_program,
+            system_program,
+            event_authority,
+            escrow_program,
+            allowed_mint,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for ClaimWithdrawAccounts<'a> {}
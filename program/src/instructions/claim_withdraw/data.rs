@@ -0,0 +1,40 @@
+use pinocchio::error::ProgramError;
+
+use crate::traits::InstructionData;
+
+/// Instruction data for ClaimWithdraw
+///
+/// All withdrawal information comes from the receipt and withdraw request accounts.
+pub struct ClaimWithdrawData {}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimWithdrawData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(_data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self {})
+    }
+}
+
+impl<'a> InstructionData<'a> for ClaimWithdrawData {
+    const LEN: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_withdraw_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = ClaimWithdrawData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_claim_withdraw_data_try_from_with_extra_bytes() {
+        let data = [1u8, 2, 3, 4, 5];
+        let result = ClaimWithdrawData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,214 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    events::WithdrawEvent,
+    instructions::ClaimWithdraw,
+    state::{
+        get_extensions_from_account, release_from_allowed_mint, take_hook_bypass_request, validate_extensions_pda,
+        AllowedMint, ArbiterData, CooldownData, Escrow, ExtensionType, HookData, HookPoint, Receipt, TimelockData,
+        WithdrawRequest,
+    },
+    traits::{AccountDeserialize, EventSerialize, ExtensionData},
+    utils::{close_pda_account, emit_event, get_mint_decimals, validate_associated_token_account},
+};
+
+/// Processes the ClaimWithdraw instruction.
+///
+/// Pays out a requested receipt once the escrow's cooldown has elapsed since the request,
+/// closing both the receipt and its withdraw request. Timelock, arbiter, hook and hook bypass apply as
+/// in Withdraw.
+pub fn process_claim_withdraw(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ClaimWithdraw::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate receipt
+    let receipt = {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+
+        // Verify the receipt is for this escrow and depositor
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+
+        // Rent goes back to whoever the deposit's payer designated
+        receipt.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+
+        receipt
+    };
+    let (amount, nonce, receipt_mint, deposited_at) =
+        (receipt.amount, receipt.nonce(), receipt.mint, receipt.deposited_at);
+
+    // Read and validate the withdraw request for this receipt
+    let requested_at = {
+        let request_data = ix.accounts.withdraw_request.try_borrow()?;
+        let request = WithdrawRequest::from_account(&request_data, ix.accounts.withdraw_request, program_id)?;
+        request.validate_receipt(ix.accounts.receipt.address())?;
+        request.requested_at
+    };
+
+    // Ensure the mint account matches the receipt's mint to prevent cross-mint withdrawals.
+    if receipt_mint != *ix.accounts.mint.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Get timelock, hook, arbiter, cooldown, and hook bypass extensions in single pass
+    let ext_types = [
+        ExtensionType::Timelock,
+        ExtensionType::Hook,
+        ExtensionType::Arbiter,
+        ExtensionType::Cooldown,
+        ExtensionType::HookBypass,
+    ];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Per-mint overrides take precedence. A blocked mint has no AllowedMint account left and
+    // falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
+
+    // Validate timelock if present
+    if let Some(ref timelock_bytes) = exts[0] {
+        let timelock = TimelockData::from_bytes(timelock_bytes)?;
+        timelock.validate(deposited_at)?;
+    }
+
+    // The cooldown runs from the request. If the extension was removed since, the request is claimable now.
+    if let Some(ref cooldown_bytes) = exts[3] {
+        let cooldown = CooldownData::from_bytes(cooldown_bytes)?;
+        cooldown.validate(requested_at)?;
+    }
+
+    // Parse hook if present
+    let hook_data = exts[1].as_ref().map(|b| HookData::from_bytes(b)).transpose()?;
+
+    // Validate arbiter if present — arbiter is first in remaining_accounts
+    let remaining_accounts = if let Some(ref arbiter_bytes) = exts[2] {
+        let arbiter = ArbiterData::from_bytes(arbiter_bytes)?;
+        arbiter.validate(ix.accounts.remaining_accounts)?;
+        // Skip arbiter account, pass rest to hook
+        ix.accounts.remaining_accounts.get(1..).unwrap_or(&[])
+    } else {
+        ix.accounts.remaining_accounts
+    };
+
+    // A matured hook bypass request may be passed in place of the hook program to skip the hook
+    let hook_bypass_request = take_hook_bypass_request(
+        program_id,
+        hook_data.as_ref(),
+        exts[4].as_deref(),
+        remaining_accounts,
+        ix.accounts.receipt.address(),
+    )?;
+    let hook_data = if hook_bypass_request.is_some() { None } else { hook_data };
+
+    // Invoke pre-withdraw hook if configured
+    if let Some(ref hook) = hook_data {
+        hook.invoke(
+            HookPoint::PreWithdraw,
+            remaining_accounts,
+            &[ix.accounts.escrow, ix.accounts.depositor, ix.accounts.mint, ix.accounts.receipt],
+        )?;
+    }
+
+    // Create the destination ATA if it does not exist yet, otherwise check it is a live token account
+    if ix.accounts.destination_token_account.is_data_empty() {
+        CreateIdempotent {
+            funding_account: ix.accounts.payer,
+            account: ix.accounts.destination_token_account,
+            wallet: ix.accounts.destination,
+            mint: ix.accounts.mint,
+            system_program: ix.accounts.system_program,
+            token_program: ix.accounts.token_program,
+        }
+        .invoke()?;
+    } else {
+        validate_associated_token_account(
+            ix.accounts.destination_token_account,
+            ix.accounts.destination.address(),
+            ix.accounts.mint,
+            ix.accounts.token_program,
+        )?;
+    }
+
+    // Transfer tokens from vault to destination using escrow PDA as signer
+    let decimals = get_mint_decimals(ix.accounts.mint)?;
+
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_bytes(&escrow_data)?;
+        escrow.with_signer(|signers| {
+            TransferChecked {
+                from: ix.accounts.vault,
+                mint: ix.accounts.mint,
+                to: ix.accounts.destination_token_account,
+                authority: ix.accounts.escrow,
+                amount,
+                decimals,
+                token_program: ix.accounts.token_program.address(),
+            }
+            .invoke_signed(signers)
+        })?;
+    }
+
+    // Invoke post-withdraw hook if configured (receipt is still open, pass it for context)
+    if let Some(ref hook) = hook_data {
+        hook.invoke(
+            HookPoint::PostWithdraw,
+            remaining_accounts,
+            &[ix.accounts.escrow, ix.accounts.depositor, ix.accounts.mint, ix.accounts.receipt],
+        )?;
+    }
+
+    // Release the withdrawn amount from the mint's running total
+    release_from_allowed_mint(
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+        receipt.tracked_amount(),
+    )?;
+
+    // Close receipt and the consumed withdraw request, returning lamports to rent_recipient
+    close_pda_account(ix.accounts.receipt, ix.accounts.rent_recipient)?;
+    close_pda_account(ix.accounts.withdraw_request, ix.accounts.rent_recipient)?;
+
+    // Close the consumed hook bypass request alongside the receipt
+    if let Some(request_account) = hook_bypass_request {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Emit event
+    let event = WithdrawEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.destination.address(),
+        receipt_mint,
+        nonce,
+        amount,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
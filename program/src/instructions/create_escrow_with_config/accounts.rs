@@ -0,0 +1,82 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_associated_token_program, verify_current_program, verify_event_authority, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the CreateEscrowWithConfig instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for account creation
+/// 1. `[signer]` admin - Admin of the escrow
+/// 2. `[signer]` escrow_seed - Escrow seed signer for PDA derivation
+/// 3. `[writable]` escrow - Escrow PDA to be created
+/// 4. `[writable]` extensions - Extensions PDA `[b"extensions", escrow]` (created only if extensions are given)
+/// 5. `[]` associated_token_program - Associated Token program for vault creation
+/// 6. `[]` system_program - System program for account creation
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program
+///
+/// # Remaining Accounts
+/// 1. `[signer]` arbiter - Only when the extensions include an arbiter; must match it
+/// 2. For each mint to allow, in `allowed_mint_bumps` order:
+///    `[]` mint, `[writable]` allowed_mint, `[writable]` vault, `[]` token_program
+pub struct CreateEscrowWithConfigAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub admin: &'a AccountView,
+    pub escrow_seed: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateEscrowWithConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, admin, escrow_seed, escrow, extensions, associated_token_program, system_program, event_authority, escrow_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(admin, false)?;
+        verify_signer(escrow_seed, false)?;
+
+        // 2. Validate writable
+        verify_writable(escrow, true)?;
+        verify_writable(extensions, true)?;
+
+        // 3. Validate program IDs
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        Ok(Self {
+            payer,
+            admin,
+            escrow_seed,
+            escrow,
+            extensions,
+            associated_token_program,
+            system_program,
+            event_authority,
+            escrow_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CreateEscrowWithConfigAccounts<'a> {}
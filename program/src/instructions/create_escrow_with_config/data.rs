@@ -0,0 +1,413 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{
+    require_len,
+    state::{
+        ArbiterData, AutoReleaseData, BlockTokenExtensionsData, CooldownData, DepositorAllowlistData, ExtensionType,
+        GovernanceDelayData, HookBypassData, HookData, MetadataData, RefundAuthorityData, RolesData, TimelockData,
+        TLV_HEADER_SIZE,
+    },
+    traits::{ExtensionData, InstructionData},
+};
+
+/// Extensions decoded from the TLV blob of a CreateEscrowWithConfig instruction
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EscrowConfig {
+    pub timelock: Option<TimelockData>,
+    pub hook: Option<HookData>,
+    pub blocked_token_extensions: Option<BlockTokenExtensionsData>,
+    pub arbiter: Option<ArbiterData>,
+    pub hook_bypass: Option<HookBypassData>,
+    pub depositor_allowlist: Option<DepositorAllowlistData>,
+    pub roles: Option<RolesData>,
+    pub governance_delay: Option<GovernanceDelayData>,
+    pub metadata: Option<MetadataData>,
+    pub auto_release: Option<AutoReleaseData>,
+    pub refund_authority: Option<RefundAuthorityData>,
+    pub cooldown: Option<CooldownData>,
+    pub extension_count: u8,
+}
+
+impl EscrowConfig {
+    /// Decodes and validates a TLV blob in the extensions PDA layout
+    ///
+    /// Each entry must be a known extension type, appear once, and hold exactly the bytes its
+    /// setter instruction would write.
+    pub fn parse(tlv: &[u8]) -> Result<Self, ProgramError> {
+        let mut config = Self::default();
+        let mut offset = 0;
+
+        while offset < tlv.len() {
+            require_len!(tlv[offset..], TLV_HEADER_SIZE);
+            let ext_type = u16::from_le_bytes([tlv[offset], tlv[offset + 1]]);
+            let length = u16::from_le_bytes([tlv[offset + 2], tlv[offset + 3]]) as usize;
+            let value = tlv
+                .get(offset + TLV_HEADER_SIZE..offset + TLV_HEADER_SIZE + length)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+
+            let ext_type = ExtensionType::try_from(ext_type).map_err(|_| ProgramError::InvalidInstructionData)?;
+            config.insert(ext_type, value)?;
+
+            config.extension_count += 1;
+            offset += TLV_HEADER_SIZE + length;
+        }
+
+        Ok(config)
+    }
+
+    /// Decodes and validates a single extension value, rejecting a type already present
+    pub fn insert(&mut self, ext_type: ExtensionType, value: &[u8]) -> Result<(), ProgramError> {
+        match ext_type {
+            ExtensionType::Timelock => set_once(&mut self.timelock, parse_exact(value)?),
+            ExtensionType::Hook => {
+                let hook: HookData = parse_exact(value)?;
+                if !hook.is_valid() {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.hook, hook)
+            }
+            ExtensionType::BlockedTokenExtensions => {
+                let blocked: BlockTokenExtensionsData = parse_exact(value)?;
+                // Rebuild through `add_extension` so duplicates are rejected as in BlockTokenExtension
+                let mut deduplicated = BlockTokenExtensionsData::new(&[])?;
+                for &extension in blocked.blocked_extensions() {
+                    deduplicated.add_extension(extension)?;
+                }
+                set_once(&mut self.blocked_token_extensions, deduplicated)
+            }
+            ExtensionType::Arbiter => set_once(&mut self.arbiter, parse_exact(value)?),
+            ExtensionType::HookBypass => {
+                let hook_bypass: HookBypassData = parse_exact(value)?;
+                if !hook_bypass.is_valid() {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.hook_bypass, hook_bypass)
+            }
+            ExtensionType::DepositorAllowlist => set_once(&mut self.depositor_allowlist, parse_exact(value)?),
+            ExtensionType::Roles => set_once(&mut self.roles, parse_exact(value)?),
+            ExtensionType::GovernanceDelay => {
+                let governance_delay: GovernanceDelayData = parse_exact(value)?;
+                if governance_delay.delay > i64::MAX as u64 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.governance_delay, governance_delay)
+            }
+            ExtensionType::Metadata => set_once(&mut self.metadata, parse_exact(value)?),
+            ExtensionType::AutoRelease => {
+                let auto_release: AutoReleaseData = parse_exact(value)?;
+                if auto_release.release_delay > i64::MAX as u64 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.auto_release, auto_release)
+            }
+            ExtensionType::RefundAuthority => {
+                let refund_authority: RefundAuthorityData = parse_exact(value)?;
+                if !refund_authority.is_valid() {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.refund_authority, refund_authority)
+            }
+            ExtensionType::Cooldown => {
+                let cooldown: CooldownData = parse_exact(value)?;
+                if cooldown.cooldown_period > i64::MAX as u64 {
+                    return Err(ProgramError::InvalidInstructionData);
+                }
+                set_once(&mut self.cooldown, cooldown)
+            }
+            // Locks are only added through LockExtension and LockMintList
+            ExtensionType::ExtensionLocks => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Parses an extension value, rejecting trailing or legacy-sized bytes
+fn parse_exact<T: ExtensionData>(value: &[u8]) -> Result<T, ProgramError> {
+    let parsed = T::from_bytes(value)?;
+    if parsed.to_bytes() != value {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(parsed)
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T) -> Result<(), ProgramError> {
+    if slot.is_some() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Instruction data for CreateEscrowWithConfig
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the escrow PDA
+/// * `extensions_bump` (u8) - Bump for the extensions PDA
+/// * `is_immutable` (bool) - Make the escrow immutable once configured
+/// * `extension_data` (u32 length + bytes) - TLV-encoded initial extensions (may be empty)
+/// * `allowed_mint_bumps` (u32 length + u8 each) - AllowedMint PDA bump for each mint to allow
+pub struct CreateEscrowWithConfigData {
+    pub bump: u8,
+    pub extensions_bump: u8,
+    pub is_immutable: bool,
+    pub extension_data: Vec<u8>,
+    pub config: EscrowConfig,
+    pub allowed_mint_bumps: Vec<u8>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateEscrowWithConfigData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let extensions_bump = data[1];
+        let is_immutable = match data[2] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        let (extension_data, rest) = split_vec(&data[3..])?;
+        let (allowed_mint_bumps, _) = split_vec(rest)?;
+
+        let config = EscrowConfig::parse(extension_data)?;
+
+        Ok(Self {
+            bump,
+            extensions_bump,
+            is_immutable,
+            extension_data: extension_data.to_vec(),
+            config,
+            allowed_mint_bumps: allowed_mint_bumps.to_vec(),
+        })
+    }
+}
+
+/// Splits a `u32`-length-prefixed byte vector off the front of `data`
+fn split_vec(data: &[u8]) -> Result<(&[u8], &[u8]), ProgramError> {
+    require_len!(data, 4);
+    let len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    let end = len.checked_add(4).ok_or(ProgramError::InvalidInstructionData)?;
+    require_len!(data, end);
+    Ok(data[4..].split_at(len))
+}
+
+impl<'a> InstructionData<'a> for CreateEscrowWithConfigData {
+    const LEN: usize = 1 + 1 + 1 + 4 + 4; // bump + extensions_bump + is_immutable + extension_data length + allowed_mint_bumps length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::TlvWriter;
+    use alloc::vec;
+    use pinocchio::Address;
+
+    fn build_data(is_immutable: u8, extension_data: &[u8], allowed_mint_bumps: &[u8]) -> Vec<u8> {
+        let mut data = vec![255, 254, is_immutable];
+        data.extend_from_slice(&(extension_data.len() as u32).to_le_bytes());
+        data.extend_from_slice(extension_data);
+        data.extend_from_slice(&(allowed_mint_bumps.len() as u32).to_le_bytes());
+        data.extend_from_slice(allowed_mint_bumps);
+        data
+    }
+
+    fn tlv_entry(ext_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut entry = Vec::new();
+        entry.extend_from_slice(&ext_type.to_le_bytes());
+        entry.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        entry.extend_from_slice(value);
+        entry
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_empty_config() {
+        let data = build_data(0, &[], &[]);
+
+        let parsed = CreateEscrowWithConfigData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.extensions_bump, 254);
+        assert!(!parsed.is_immutable);
+        assert!(parsed.extension_data.is_empty());
+        assert_eq!(parsed.config, EscrowConfig::default());
+        assert!(parsed.allowed_mint_bumps.is_empty());
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_full_config() {
+        let mut writer = TlvWriter::new();
+        writer.write_timelock(&TimelockData::new(3600));
+        writer.write_hook(&HookData::new(Address::new_from_array([1u8; 32]), HookData::FLAG_WRITABLE_HOOK_ACCOUNTS));
+        writer.write_block_token_extensions(&BlockTokenExtensionsData::new(&[1, 2]).unwrap());
+        writer.write_arbiter(&ArbiterData::new(Address::new_from_array([2u8; 32])));
+        writer.write_hook_bypass(&HookBypassData::new(86_400));
+        writer.write_extension(
+            ExtensionType::DepositorAllowlist,
+            &DepositorAllowlistData::new([3u8; 32], 500).to_bytes(),
+        );
+        writer.write_extension(
+            ExtensionType::Roles,
+            &RolesData::new(Address::new_from_array([4u8; 32]), Address::default(), Address::default()).to_bytes(),
+        );
+        writer.write_extension(ExtensionType::GovernanceDelay, &GovernanceDelayData::new(86_400).to_bytes());
+        writer
+            .write_extension(ExtensionType::Metadata, &MetadataData::new("Escrow", "", b"deal-1").unwrap().to_bytes());
+        writer.write_extension(ExtensionType::AutoRelease, &AutoReleaseData::new(3600).to_bytes());
+        writer.write_extension(
+            ExtensionType::RefundAuthority,
+            &RefundAuthorityData::new(RefundAuthorityData::FLAG_ADMIN).to_bytes(),
+        );
+        writer.write_extension(ExtensionType::Cooldown, &CooldownData::new(7200).to_bytes());
+        let tlv = writer.into_bytes();
+        let data = build_data(1, &tlv, &[250, 251]);
+
+        let parsed = CreateEscrowWithConfigData::try_from(&data[..]).unwrap();
+        assert!(parsed.is_immutable);
+        assert_eq!(parsed.extension_data, tlv);
+        assert_eq!(parsed.allowed_mint_bumps, [250, 251]);
+
+        let config = parsed.config;
+        assert_eq!(config.extension_count, 12);
+        assert_eq!(config.timelock.unwrap().lock_duration, 3600);
+        assert_eq!(config.hook.unwrap().flags, HookData::FLAG_WRITABLE_HOOK_ACCOUNTS);
+        assert_eq!(config.blocked_token_extensions.unwrap().blocked_extensions(), [1, 2]);
+        assert_eq!(config.arbiter.unwrap().arbiter, Address::new_from_array([2u8; 32]));
+        assert_eq!(config.hook_bypass.unwrap().grace_period, 86_400);
+        assert_eq!(config.depositor_allowlist.unwrap().per_address_cap, 500);
+        assert_eq!(config.roles.unwrap().mint_manager, Address::new_from_array([4u8; 32]));
+        assert_eq!(config.governance_delay.unwrap().delay, 86_400);
+        assert_eq!(config.metadata.unwrap().external_reference, b"deal-1");
+        assert_eq!(config.auto_release.unwrap().release_delay, 3600);
+        assert_eq!(config.refund_authority.unwrap().flags, RefundAuthorityData::FLAG_ADMIN);
+        assert_eq!(config.cooldown.unwrap().cooldown_period, 7200);
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_invalid_immutable_flag() {
+        let data = build_data(2, &[], &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_unknown_extension_type() {
+        let tlv = tlv_entry(99, &[0u8; 8]);
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_duplicate_extension() {
+        let mut tlv = tlv_entry(ExtensionType::Timelock as u16, &TimelockData::new(1).to_bytes());
+        tlv.extend(tlv_entry(ExtensionType::Timelock as u16, &TimelockData::new(2).to_bytes()));
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_non_canonical_length() {
+        // Legacy hook entry without flags
+        let tlv = tlv_entry(ExtensionType::Hook as u16, &[1u8; HookData::LEGACY_LEN]);
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_unknown_hook_flags() {
+        let tlv =
+            tlv_entry(ExtensionType::Hook as u16, &HookData::new(Address::new_from_array([1u8; 32]), 0b100).to_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_escrow_program_hook() {
+        let tlv = tlv_entry(ExtensionType::Hook as u16, &HookData::new(crate::ID, 0).to_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_short_hook_bypass_grace_period() {
+        let tlv = tlv_entry(ExtensionType::HookBypass as u16, &HookBypassData::new(60).to_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_governance_delay_overflow() {
+        let tlv = tlv_entry(ExtensionType::GovernanceDelay as u16, &u64::MAX.to_le_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_invalid_refund_authority_flags() {
+        for flags in [0, 0b100] {
+            let tlv = tlv_entry(ExtensionType::RefundAuthority as u16, &RefundAuthorityData::new(flags).to_bytes());
+            let data = build_data(0, &tlv, &[]);
+            let result = CreateEscrowWithConfigData::try_from(&data[..]);
+            assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+        }
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_cooldown_overflow() {
+        let tlv = tlv_entry(ExtensionType::Cooldown as u16, &u64::MAX.to_le_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_extension_locks() {
+        let tlv = tlv_entry(ExtensionType::ExtensionLocks as u16, &[1, 0, 0, 0, 0]);
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_duplicate_blocked_extension() {
+        let blocked = BlockTokenExtensionsData { count: 2, blocked_extensions: vec![7, 7] };
+        let tlv = tlv_entry(ExtensionType::BlockedTokenExtensions as u16, &blocked.to_bytes());
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::Custom(_))));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_truncated_tlv() {
+        let mut tlv = tlv_entry(ExtensionType::Timelock as u16, &TimelockData::new(1).to_bytes());
+        tlv.truncate(tlv.len() - 1);
+        let data = build_data(0, &tlv, &[]);
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_length_overflow() {
+        let mut data = vec![255, 254, 0];
+        data.extend_from_slice(&u32::MAX.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_config_data_try_from_too_short() {
+        let data = [0u8; 10];
+        let result = CreateEscrowWithConfigData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,219 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+
+use crate::{
+    events::{
+        AllowMintEvent, ArbiterSetEvent, AutoReleaseSetEvent, CooldownSetEvent, CreatesEscrowEvent,
+        DepositorAllowlistSetEvent, GovernanceDelaySetEvent, HookBypassSetEvent, HookSetEvent, MetadataSetEvent,
+        RefundAuthoritySetEvent, RolesSetEvent, SetImmutableEvent, TimelockAddedEvent, TokenExtensionBlocked,
+    },
+    instructions::CreateEscrowWithConfig,
+    state::{AllowedMint, AllowedMintPda, Escrow, EscrowExtensionsHeader, ExtensionsPda},
+    traits::{AccountSerialize, AccountSize, EventSerialize, PdaSeeds},
+    utils::{
+        create_pda_account, emit_event, get_token_account_amount, validate_associated_token_account_address,
+        validate_mint_extensions, verify_readonly, verify_token_program, verify_token_program_account, verify_writable,
+    },
+};
+
+/// Number of remaining accounts consumed per mint: mint, allowed_mint, vault, token_program
+const ACCOUNTS_PER_MINT: usize = 4;
+
+/// Processes the CreateEscrowWithConfig instruction.
+///
+/// Creates an Escrow PDA together with its extensions and allowed mints in a single
+/// instruction, so the escrow never exists in a partially configured state.
+pub fn process_create_escrow_with_config(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CreateEscrowWithConfig::try_from((instruction_data, accounts))?;
+    let config = &ix.data.config;
+
+    // Split remaining accounts into the optional arbiter signer and the per-mint groups
+    let mut remaining_accounts = ix.accounts.remaining_accounts;
+    if let Some(arbiter) = &config.arbiter {
+        arbiter.validate(remaining_accounts)?;
+        remaining_accounts = &remaining_accounts[1..];
+    }
+    if remaining_accounts.len() != ix.data.allowed_mint_bumps.len() * ACCOUNTS_PER_MINT {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // Create Escrow state
+    let escrow = Escrow::new(
+        ix.data.bump,
+        *ix.accounts.escrow_seed.address(),
+        *ix.accounts.admin.address(),
+        ix.data.is_immutable,
+    );
+
+    // Validate Escrow PDA
+    escrow.validate_pda(ix.accounts.escrow, program_id, ix.data.bump)?;
+
+    // Validate Extensions PDA
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+
+    // Get seeds for Escrow account creation
+    let escrow_bump_seed = [ix.data.bump];
+    let escrow_seeds: Vec<Seed> = escrow.seeds_with_bump(&escrow_bump_seed);
+    let escrow_seeds_array: [Seed; 3] = escrow_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Create the Escrow account
+    create_pda_account(ix.accounts.payer, Escrow::LEN, program_id, ix.accounts.escrow, escrow_seeds_array)?;
+
+    // Write serialized Escrow data to the account
+    let mut escrow_data_slice = ix.accounts.escrow.try_borrow_mut()?;
+    escrow.write_to_slice(&mut escrow_data_slice)?;
+    drop(escrow_data_slice);
+
+    // Write all extensions at once
+    if !ix.data.extension_data.is_empty() {
+        let extensions_bump_seed = [ix.data.extensions_bump];
+        let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+        let extensions_seeds_array: [Seed; 3] =
+            extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+        let required_size = EscrowExtensionsHeader::LEN + ix.data.extension_data.len();
+        create_pda_account(
+            ix.accounts.payer,
+            required_size,
+            program_id,
+            ix.accounts.extensions,
+            extensions_seeds_array,
+        )?;
+
+        let mut data = ix.accounts.extensions.try_borrow_mut()?;
+        let header = EscrowExtensionsHeader::new(ix.data.extensions_bump, config.extension_count);
+        data[..EscrowExtensionsHeader::LEN].copy_from_slice(&header.to_bytes());
+        data[EscrowExtensionsHeader::LEN..required_size].copy_from_slice(&ix.data.extension_data);
+    }
+
+    // Allow each mint against the freshly written extensions
+    for (group, &allowed_mint_bump) in
+        remaining_accounts.chunks_exact(ACCOUNTS_PER_MINT).zip(&ix.data.allowed_mint_bumps)
+    {
+        let [mint, allowed_mint, vault, token_program] = group else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        allow_mint(program_id, &ix, mint, allowed_mint, vault, token_program, allowed_mint_bump)?;
+    }
+
+    // Emit events in the order the equivalent standalone instructions would
+    let escrow_address = *ix.accounts.escrow.address();
+    let emit = |event: &[u8]| emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, event);
+    emit(&CreatesEscrowEvent::new(*ix.accounts.escrow_seed.address(), *ix.accounts.admin.address()).to_bytes())?;
+    if let Some(timelock) = &config.timelock {
+        emit(&TimelockAddedEvent::new(escrow_address, timelock.lock_duration).to_bytes())?;
+    }
+    if let Some(hook) = &config.hook {
+        emit(&HookSetEvent::new(escrow_address, hook.hook_program, hook.flags).to_bytes())?;
+    }
+    if let Some(blocked) = &config.blocked_token_extensions {
+        for &extension in blocked.blocked_extensions() {
+            emit(&TokenExtensionBlocked::new(escrow_address, extension).to_bytes())?;
+        }
+    }
+    if let Some(arbiter) = &config.arbiter {
+        emit(&ArbiterSetEvent::new(escrow_address, arbiter.arbiter).to_bytes())?;
+    }
+    if let Some(hook_bypass) = &config.hook_bypass {
+        emit(&HookBypassSetEvent::new(escrow_address, hook_bypass.grace_period).to_bytes())?;
+    }
+    if let Some(allowlist) = &config.depositor_allowlist {
+        emit(
+            &DepositorAllowlistSetEvent::new(escrow_address, allowlist.merkle_root, allowlist.per_address_cap)
+                .to_bytes(),
+        )?;
+    }
+    if let Some(roles) = &config.roles {
+        emit(
+            &RolesSetEvent::new(
+                escrow_address,
+                roles.mint_manager,
+                roles.extension_manager,
+                roles.admin_transfer_authority,
+            )
+            .to_bytes(),
+        )?;
+    }
+    if let Some(governance_delay) = &config.governance_delay {
+        emit(&GovernanceDelaySetEvent::new(escrow_address, governance_delay.delay).to_bytes())?;
+    }
+    if let Some(metadata) = &config.metadata {
+        emit(&MetadataSetEvent::new(escrow_address, metadata).to_bytes())?;
+    }
+    if let Some(auto_release) = &config.auto_release {
+        emit(&AutoReleaseSetEvent::new(escrow_address, auto_release.release_delay).to_bytes())?;
+    }
+    if let Some(refund_authority) = &config.refund_authority {
+        emit(&RefundAuthoritySetEvent::new(escrow_address, refund_authority.flags).to_bytes())?;
+    }
+    if let Some(cooldown) = &config.cooldown {
+        emit(&CooldownSetEvent::new(escrow_address, cooldown.cooldown_period).to_bytes())?;
+    }
+    for group in remaining_accounts.chunks_exact(ACCOUNTS_PER_MINT) {
+        emit(&AllowMintEvent::new(escrow_address, *group[0].address()).to_bytes())?;
+    }
+    if ix.data.is_immutable {
+        emit(&SetImmutableEvent::new(escrow_address, *ix.accounts.admin.address()).to_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Creates the AllowedMint PDA and vault ATA for one mint, mirroring AllowMint.
+fn allow_mint(
+    program_id: &Address,
+    ix: &CreateEscrowWithConfig,
+    mint: &AccountView,
+    allowed_mint: &AccountView,
+    vault: &AccountView,
+    token_program: &AccountView,
+    bump: u8,
+) -> ProgramResult {
+    verify_readonly(mint)?;
+    verify_writable(allowed_mint, true)?;
+    verify_writable(vault, true)?;
+    verify_token_program(token_program)?;
+    verify_token_program_account(mint)?;
+    validate_associated_token_account_address(vault, ix.accounts.escrow.address(), mint, token_program)?;
+
+    // Validate AllowedMint PDA using external seeds
+    let pda_seeds = AllowedMintPda::new(ix.accounts.escrow.address(), mint.address());
+    pda_seeds.validate_pda(allowed_mint, program_id, bump)?;
+
+    validate_mint_extensions(mint, ix.accounts.extensions)?;
+
+    // Get seeds for AllowedMint account creation
+    let allowed_mint_bump_seed = [bump];
+    let allowed_mint_seeds: Vec<Seed> = pda_seeds.seeds_with_bump(&allowed_mint_bump_seed);
+    let allowed_mint_seeds_array: [Seed; 4] =
+        allowed_mint_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Create the AllowedMint PDA account (fails if the same mint is listed twice)
+    create_pda_account(ix.accounts.payer, AllowedMint::LEN, program_id, allowed_mint, allowed_mint_seeds_array)?;
+
+    // Create vault ATA for the escrow
+    CreateIdempotent {
+        funding_account: ix.accounts.payer,
+        account: vault,
+        wallet: ix.accounts.escrow,
+        mint,
+        system_program: ix.accounts.system_program,
+        token_program,
+    }
+    .invoke()?;
+
+    // Tokens sent to the vault address before the escrow existed are not backed by receipts
+    let opening_balance = get_token_account_amount(vault)?;
+    let allowed_mint_state = AllowedMint::new(bump, opening_balance);
+
+    let mut allowed_mint_data_slice = allowed_mint.try_borrow_mut()?;
+    allowed_mint_state.write_to_slice(&mut allowed_mint_data_slice)?;
+
+    Ok(())
+}
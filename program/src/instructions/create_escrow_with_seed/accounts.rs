@@ -0,0 +1,51 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{verify_current_program, verify_event_authority, verify_signer, verify_system_program, verify_writable},
+};
+
+/// Accounts for the CreateEscrowWithSeed instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for account creation
+/// 1. `[signer]` admin - Admin of the escrow; the escrow address is bound to it
+/// 2. `[writable]` escrow - Escrow PDA to be created
+/// 3. `[]` system_program - System program for account creation
+/// 4. `[]` event_authority - Event authority PDA
+/// 5. `[]` escrow_program - Current program
+pub struct CreateEscrowWithSeedAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub admin: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for CreateEscrowWithSeedAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, admin, escrow, system_program, event_authority, escrow_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(escrow, true)?;
+
+        // 3. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        Ok(Self { payer, admin, escrow, system_program, event_authority, escrow_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for CreateEscrowWithSeedAccounts<'a> {}
@@ -0,0 +1,63 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for CreateEscrowWithSeed
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the escrow PDA
+/// * `seed` ([u8; 32]) - Caller-chosen seed, bound to the admin to derive the escrow seed
+pub struct CreateEscrowWithSeedData {
+    pub bump: u8,
+    pub seed: [u8; 32],
+}
+
+impl<'a> TryFrom<&'a [u8]> for CreateEscrowWithSeedData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let seed = data[1..33].try_into().map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        Ok(Self { bump, seed })
+    }
+}
+
+impl<'a> InstructionData<'a> for CreateEscrowWithSeedData {
+    const LEN: usize = 1 + 32; // bump + seed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_escrow_with_seed_data_try_from_valid() {
+        let mut data = [0u8; 33];
+        data[0] = 255; // bump
+        data[1..33].copy_from_slice(&[7u8; 32]); // seed
+
+        let result = CreateEscrowWithSeedData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.seed, [7u8; 32]);
+    }
+
+    #[test]
+    fn test_create_escrow_with_seed_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = CreateEscrowWithSeedData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_create_escrow_with_seed_data_try_from_too_short() {
+        let data = [0u8; 32];
+        let result = CreateEscrowWithSeedData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
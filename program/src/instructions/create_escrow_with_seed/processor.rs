@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::CreatesEscrowEvent,
+    instructions::CreateEscrowWithSeed,
+    state::{admin_bound_escrow_seed, Escrow},
+    traits::{AccountSerialize, AccountSize, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event},
+};
+
+/// Processes the CreateEscrowWithSeed instruction.
+///
+/// Creates an Escrow PDA whose seed is derived from the admin and a caller-chosen seed,
+/// so callers (including programs via CPI) need no extra keypair to sign.
+pub fn process_create_escrow_with_seed(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = CreateEscrowWithSeed::try_from((instruction_data, accounts))?;
+
+    // Derive the escrow seed, binding the caller seed to the signing admin
+    let escrow_seed = admin_bound_escrow_seed(ix.accounts.admin.address(), &ix.data.seed);
+
+    // Create Escrow state
+    let escrow = Escrow::new(ix.data.bump, escrow_seed, *ix.accounts.admin.address(), false);
+
+    // Validate Escrow PDA
+    escrow.validate_pda(ix.accounts.escrow, program_id, ix.data.bump)?;
+
+    // Get seeds for Escrow account creation
+    let escrow_bump_seed = [ix.data.bump];
+    let escrow_seeds: Vec<Seed> = escrow.seeds_with_bump(&escrow_bump_seed);
+    let escrow_seeds_array: [Seed; 3] = escrow_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    // Create the Escrow account
+    create_pda_account(ix.accounts.payer, Escrow::LEN, program_id, ix.accounts.escrow, escrow_seeds_array)?;
+
+    // Write serialized Escrow data to the account
+    let mut escrow_data_slice = ix.accounts.escrow.try_borrow_mut()?;
+    escrow.write_to_slice(&mut escrow_data_slice)?;
+
+    // Emit event via CPI
+    let event = CreatesEscrowEvent::new(escrow_seed, *ix.accounts.admin.address());
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use alloc::string::String;
+use alloc::vec::Vec;
 use codama::CodamaInstructions;
 use pinocchio::Address;
 
@@ -35,7 +37,7 @@ pub enum EscrowProgramInstruction {
 
     /// Add timelock extension to an escrow.
     #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to add timelock to",))]
     #[codama(account(
         name = "extensions",
@@ -64,7 +66,7 @@ pub enum EscrowProgramInstruction {
 
     /// Set hook program on an escrow.
     #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to set hook on"))]
     #[codama(account(
         name = "extensions",
@@ -89,6 +91,8 @@ pub enum EscrowProgramInstruction {
         extensions_bump: u8,
         /// Hook program address
         hook_program: Address,
+        /// Hook configuration flags (bit 0: writable hook accounts, bit 1: one withdraw hook call per batch)
+        flags: u8,
     } = 2,
 
     /// Deposit tokens into an escrow vault and create a receipt.
@@ -97,21 +101,25 @@ pub enum EscrowProgramInstruction {
     #[codama(account(name = "escrow", docs = "Escrow account to deposit into"))]
     #[codama(account(
         name = "allowed_mint",
-        docs = "Allowed mint PDA proving this mint is permitted",
+        docs = "Allowed mint PDA proving this mint is permitted and tracking its deposit limits",
+        writable,
         default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
     ))]
-    #[codama(account(name = "receipt_seed", docs = "Random keypair seed for receipt PDA derivation", signer))]
     #[codama(account(
-        name = "receipt",
-        docs = "Deposit receipt PDA to be created",
+        name = "receipt_counter",
+        docs = "Receipt counter PDA holding the nonce of the next receipt (created on first deposit)",
         writable,
-        default_value = pda("receipt", [
+        default_value = pda("receiptCounter", [
             seed("escrow", account("escrow")),
             seed("depositor", account("depositor")),
-            seed("mint", account("mint")),
-            seed("receiptSeed", account("receiptSeed"))
+            seed("mint", account("mint"))
         ])
     ))]
+    #[codama(account(
+        name = "receipt",
+        docs = "Deposit receipt PDA to be created, derived from the counter's next nonce",
+        writable
+    ))]
     #[codama(account(
         name = "vault",
         docs = "Escrow vault token account to receive tokens",
@@ -148,12 +156,26 @@ pub enum EscrowProgramInstruction {
         bump: u8,
         /// Amount of tokens to deposit
         amount: u64,
+        /// Address refunded the receipt rent when the receipt is closed (defaults to the payer)
+        #[codama(default_value = account("payer"))]
+        rent_recipient: Address,
+        /// Merkle proof for the depositor allowlist (empty if the escrow has no allowlist)
+        proof: Vec<[u8; 32]>,
     } = 3,
 
     /// Update the admin on an escrow.
-    #[codama(account(name = "admin", docs = "Current admin authority for the escrow", signer))]
+    #[codama(account(
+        name = "admin",
+        docs = "Admin-transfer authority, or the current admin if that role is unassigned",
+        signer
+    ))]
     #[codama(account(name = "new_admin", docs = "New admin authority to transfer ownership to", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to update admin on", writable))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
     #[codama(account(
         name = "event_authority",
         docs = "Event authority PDA for CPI event emission",
@@ -166,9 +188,14 @@ pub enum EscrowProgramInstruction {
     ))]
     UpdateAdmin {} = 4,
 
-    /// Withdraw tokens from an escrow vault back to the original depositor.
-    #[codama(account(name = "rent_recipient", docs = "Receives rent from closed receipt account", writable))]
-    #[codama(account(name = "withdrawer", docs = "Authority withdrawing tokens", signer))]
+    /// Withdraw tokens from an escrow vault to a destination chosen by the original depositor.
+    /// Creates the destination's ATA if it does not exist.
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed receipt account, must be the rent recipient recorded on the receipt",
+        writable
+    ))]
+    #[codama(account(name = "withdrawer", docs = "Depositor, or the receipt's approved withdraw delegate", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to withdraw from"))]
     #[codama(account(
         name = "extensions",
@@ -183,10 +210,10 @@ pub enum EscrowProgramInstruction {
         default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
     ))]
     #[codama(account(
-        name = "withdrawer_token_account",
-        docs = "Withdrawer's token account to receive tokens",
+        name = "destination_token_account",
+        docs = "Destination's token account to receive tokens (created if it does not exist)",
         writable,
-        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("withdrawer")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("destination")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
     ))]
     #[codama(account(name = "mint", docs = "Token mint of the withdrawn tokens"))]
     #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
@@ -201,12 +228,34 @@ pub enum EscrowProgramInstruction {
         docs = "Escrow program for CPI event emission",
         default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
     ))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA whose deposit total is reduced (may be closed if the mint was blocked)",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "payer",
+        docs = "Pays for the destination token account if it has to be created",
+        signer,
+        writable
+    ))]
+    #[codama(account(
+        name = "destination",
+        docs = "Owner of the token account receiving the tokens (defaults to the withdrawer)",
+        default_value = account("withdrawer")
+    ))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for destination account creation",
+        default_value = program("associated-token")
+    ))]
     Withdraw {} = 5,
 
     /// Allow a token mint for deposits into an escrow.
     /// Also creates the vault ATA for the escrow to hold tokens of this mint.
     #[codama(account(name = "payer", docs = "Pays for allowed mint and vault account creation", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Mint manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to allow mint on"))]
     #[codama(account(
         name = "escrow_extensions",
@@ -250,7 +299,7 @@ pub enum EscrowProgramInstruction {
     } = 6,
 
     /// Block a token mint from deposits into an escrow.
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Mint manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "rent_recipient", docs = "Receives rent from closed allowed mint account", writable))]
     #[codama(account(name = "escrow", docs = "Escrow account to block mint on"))]
     #[codama(account(name = "mint", docs = "Token mint to block from deposits"))]
@@ -271,11 +320,16 @@ pub enum EscrowProgramInstruction {
         docs = "Escrow program for CPI event emission",
         default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
     ))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
     BlockMint {} = 7,
 
     /// Block a token extension for an escrow.
     #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to block extension on"))]
     #[codama(account(
         name = "extensions",
@@ -304,7 +358,7 @@ pub enum EscrowProgramInstruction {
 
     /// Set an arbiter on an escrow. The arbiter must sign withdrawal transactions.
     #[codama(account(name = "payer", signer, writable))]
-    #[codama(account(name = "admin", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "arbiter", signer))]
     #[codama(account(name = "escrow"))]
     #[codama(account(
@@ -329,7 +383,7 @@ pub enum EscrowProgramInstruction {
 
     /// Remove an extension from an escrow.
     #[codama(account(name = "payer", docs = "Pays for transaction fees", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to remove extension from"))]
     #[codama(account(
         name = "extensions",
@@ -358,7 +412,7 @@ pub enum EscrowProgramInstruction {
 
     /// Unblock a previously blocked token extension for an escrow.
     #[codama(account(name = "payer", docs = "Pays for transaction fees", signer, writable))]
-    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
     #[codama(account(name = "escrow", docs = "Escrow account to unblock extension on"))]
     #[codama(account(
         name = "extensions",
@@ -400,6 +454,1080 @@ pub enum EscrowProgramInstruction {
     ))]
     SetImmutable {} = 12,
 
+    /// Configure the hook bypass escape hatch on an escrow.
+    /// Depositors may skip the hook on withdraw once the grace period after their bypass request has elapsed.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to configure hook bypass on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store hook bypass config",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetHookBypass {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Seconds a depositor must wait after requesting a bypass, at least one day
+        grace_period: u64,
+    } = 13,
+
+    /// Request to bypass the hook for a receipt or basket receipt when the hook blocks withdrawal.
+    #[codama(account(name = "payer", docs = "Pays for hook bypass request account creation", signer, writable))]
+    #[codama(account(name = "depositor", docs = "Original depositor of the receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the receipt belongs to"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA for escrow configuration",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "receipt", docs = "Deposit receipt or basket receipt the bypass is requested for"))]
+    #[codama(account(
+        name = "hook_bypass_request",
+        docs = "Hook bypass request PDA to be created",
+        writable,
+        default_value = pda("hookBypassRequest", [seed("receipt", account("receipt"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint of the receipt (any leg's mint for a basket receipt)"))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA holding per-mint overrides (may be closed if the mint was blocked)",
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    RequestHookBypass {
+        /// Bump for the hook bypass request PDA
+        #[codama(default_value = account_bump("hookBypassRequest"))]
+        bump: u8,
+    } = 14,
+
+    /// Set deposit limits for an allowed mint. A limit of 0 disables that limit.
+    #[codama(account(name = "admin", docs = "Mint manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the mint is allowed on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint the limits apply to"))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA storing the limits",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetMintLimits {
+        /// Cap on the total amount held for this mint
+        max_total_deposits: u64,
+        /// Minimum amount per deposit
+        min_deposit: u64,
+        /// Maximum amount per deposit
+        max_deposit: u64,
+    } = 15,
+
+    /// Set or rotate the depositor allowlist on an escrow.
+    /// Deposits must then include a Merkle proof of the depositor; a non-zero cap limits each depositor's total.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to configure the depositor allowlist on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the depositor allowlist",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetDepositorAllowlist {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Root of the depositor allowlist Merkle tree
+        merkle_root: [u8; 32],
+        /// Max cumulative deposit per depositor (0 = uncapped)
+        per_address_cap: u64,
+    } = 16,
+
+    /// Check that a mint's vault covers the deposits recorded on its AllowedMint PDA.
+    /// Permissionless; fails if the vault balance is below the outstanding deposits.
+    #[codama(account(name = "escrow", docs = "Escrow account that owns the vault"))]
+    #[codama(account(name = "mint", docs = "Token mint to check"))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA holding the outstanding deposit counters",
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account for the mint",
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    VerifySolvency {} = 17,
+
+    /// Move the vault balance not backing any receipt to a destination token account.
+    /// Only the amount above the mint's outstanding deposits and opening balance can be swept.
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account that owns the vault"))]
+    #[codama(account(name = "mint", docs = "Token mint of the vault"))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA holding the outstanding deposit counters",
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account to sweep from",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "destination", docs = "Token account receiving the surplus", writable))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SweepSurplus {} = 18,
+
+    /// Create an escrow with its extensions, allowed mints, and immutability set in one step.
+    /// Remaining accounts: the arbiter signer (only if an arbiter extension is given), then
+    /// `[mint, allowed_mint, vault, token_program]` for each mint to allow.
+    #[codama(account(
+        name = "payer",
+        docs = "Pays for escrow, extensions, and vault account creation",
+        signer,
+        writable
+    ))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow_seed", docs = "Random keypair seed for escrow PDA derivation", signer))]
+    #[codama(account(
+        name = "escrow",
+        docs = "Escrow PDA account to be created",
+        writable,
+        default_value = pda("escrow", [seed("escrowSeed", account("escrowSeed"))])
+    ))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account, created only if extensions are given",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for vault creation",
+        default_value = program("associated-token")
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    CreateEscrowWithConfig {
+        /// Bump for the escrow PDA
+        #[codama(default_value = account_bump("escrow"))]
+        bump: u8,
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Make the escrow immutable once configured
+        is_immutable: bool,
+        /// TLV-encoded initial extensions, in the extensions PDA layout (empty for none)
+        extension_data: Vec<u8>,
+        /// AllowedMint PDA bump for each mint to allow, in remaining-account order
+        allowed_mint_bumps: Vec<u8>,
+    } = 19,
+
+    /// Create a new escrow whose address is derived from the admin and a caller-chosen seed.
+    /// The escrow seed is `sha256("admin_bound_escrow_seed" || admin || seed)`, so no seed keypair
+    /// has to sign and no one but the admin can claim the address.
+    #[codama(account(name = "payer", docs = "Pays for escrow account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow, bound into its address", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow PDA account to be created", writable))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    CreateEscrowWithSeed {
+        /// Bump for the escrow PDA
+        bump: u8,
+        /// Caller-chosen seed, bound to the admin
+        seed: [u8; 32],
+    } = 20,
+
+    /// Assign delegated admin roles on an escrow. A role set to the default address is unassigned
+    /// and falls back to the admin; an assigned role is held exclusively by its key.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to assign roles on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the roles",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetRoles {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Holder of the mint manager role (AllowMint, BlockMint, SetMintLimits)
+        mint_manager: Address,
+        /// Holder of the extension manager role (extension setters, RemoveExtension)
+        extension_manager: Address,
+        /// Holder of the admin-transfer role (UpdateAdmin)
+        admin_transfer_authority: Address,
+    } = 21,
+
+    /// Set the governance delay on an escrow. While a delay is set, extension changes must be
+    /// queued with QueueChange and can only be executed once the delay has elapsed.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to set the governance delay on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the governance delay",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetGovernanceDelay {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Seconds a queued change must wait before it can be executed
+        delay: u64,
+    } = 22,
+
+    /// Queue an extension change. It can be executed with ExecuteQueuedChange once the escrow's
+    /// governance delay has elapsed. Setting the arbiter requires the new arbiter to sign as the
+    /// first remaining account.
+    #[codama(account(name = "payer", docs = "Pays for pending change account creation", signer, writable))]
+    #[codama(account(
+        name = "admin",
+        docs = "Authority for the changed extension: escrow admin, or the extension manager for extensions other than roles and governance delay",
+        signer
+    ))]
+    #[codama(account(name = "escrow", docs = "Escrow account the change applies to"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles and governance delay (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "pending_change", docs = "Pending change PDA to be created", writable))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    QueueChange {
+        /// Bump for the pending change PDA
+        bump: u8,
+        /// Caller-chosen id, unique among the escrow's pending changes
+        change_id: u64,
+        /// 0 = set the extension to `value`, 1 = remove the extension
+        action: u8,
+        /// Extension type the change applies to
+        extension_type: u16,
+        /// Extension data as its setter instruction would write it (empty for removals)
+        value: Vec<u8>,
+    } = 23,
+
+    /// Apply a queued extension change once its governance delay has elapsed and close the
+    /// pending change account.
+    #[codama(account(name = "payer", docs = "Pays for extensions account growth", signer, writable))]
+    #[codama(account(
+        name = "admin",
+        docs = "Authority for the changed extension: escrow admin, or the extension manager for extensions other than roles and governance delay",
+        signer
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from the closed pending change account",
+        writable
+    ))]
+    #[codama(account(name = "escrow", docs = "Escrow account the change applies to"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to apply the change to",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "pending_change", docs = "Pending change PDA to execute and close", writable))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    ExecuteQueuedChange {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+    } = 24,
+
+    /// Cancel a queued extension change and close the pending change account.
+    #[codama(account(
+        name = "admin",
+        docs = "Authority for the changed extension: escrow admin, or the extension manager for extensions other than roles and governance delay",
+        signer
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from the closed pending change account",
+        writable
+    ))]
+    #[codama(account(name = "escrow", docs = "Escrow account the change applies to"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "pending_change", docs = "Pending change PDA to close", writable))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    CancelQueuedChange {} = 25,
+
+    /// Permanently lock one extension so it can no longer be set, updated or removed.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to lock"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the locks",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    LockExtension {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Extension type discriminator to lock
+        extension_type: u16,
+    } = 26,
+
+    /// Permanently lock the mint list so mints can no longer be allowed or blocked.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Admin authority for the escrow", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to lock"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the locks",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    LockMintList {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+    } = 27,
+
+    /// Set descriptive metadata (name, URI and external reference) on an escrow.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to set metadata on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the metadata",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetMetadata {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Display name (UTF-8, at most 64 bytes)
+        name: String,
+        /// URI of off-chain metadata (UTF-8, at most 200 bytes)
+        uri: String,
+        /// Opaque external reference such as a deal ID (at most 64 bytes)
+        external_reference: Vec<u8>,
+    } = 28,
+
+    /// Set or remove a per-mint override of the timelock, hook, or arbiter extension. Deposits and
+    /// withdrawals of the mint use the override in place of the escrow-level extension. Setting an
+    /// arbiter requires the new arbiter to sign as the first remaining account.
+    #[codama(account(name = "payer", docs = "Pays for allowed mint account growth", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the mint is allowed on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the escrow's roles and locks (may be uninitialized)",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint the override applies to"))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA storing the overrides",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetMintOverride {
+        /// 0 = set the override to `value`, 1 = remove the override
+        action: u8,
+        /// Extension type to override: 0 = timelock, 1 = hook, 3 = arbiter
+        extension_type: u16,
+        /// Extension data as its setter instruction would write it (empty for removals)
+        value: Vec<u8>,
+    } = 29,
+
+    /// Approve a delegate that may withdraw a receipt, paying out only to the depositor.
+    #[codama(account(name = "payer", docs = "Pays for withdraw delegate account creation", signer, writable))]
+    #[codama(account(name = "depositor", docs = "Original depositor of the receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the receipt belongs to"))]
+    #[codama(account(name = "receipt", docs = "Deposit receipt the delegate may withdraw"))]
+    #[codama(account(
+        name = "withdraw_delegate",
+        docs = "Withdraw delegate PDA to be created",
+        writable,
+        default_value = pda("withdrawDelegate", [seed("receipt", account("receipt"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    ApproveWithdrawDelegate {
+        /// Bump for the withdraw delegate PDA
+        #[codama(default_value = account_bump("withdrawDelegate"))]
+        bump: u8,
+        /// Key allowed to sign Withdraw for the receipt
+        delegate: Address,
+        /// Unix timestamp the approval lapses at, 0 for no expiry
+        expires_at: i64,
+    } = 30,
+
+    /// Revoke a receipt's withdraw delegate and close its account.
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed withdraw delegate account",
+        writable
+    ))]
+    #[codama(account(name = "depositor", docs = "Original depositor of the receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the receipt belongs to"))]
+    #[codama(account(name = "withdraw_delegate", docs = "Withdraw delegate PDA to be closed", writable))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    RevokeWithdrawDelegate {} = 31,
+
+    /// Configure the auto-release crank on an escrow.
+    /// Anyone may release a receipt to its depositor once the delay after it unlocks has elapsed.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to configure auto-release on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store auto-release config",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetAutoRelease {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Seconds after a receipt unlocks before anyone may release it
+        release_delay: u64,
+    } = 32,
+
+    /// Permissionlessly release a matured receipt to its depositor.
+    /// Requires the auto-release extension and is unavailable while a hook or arbiter applies to the mint.
+    #[codama(account(
+        name = "payer",
+        docs = "Anyone; pays for the depositor token account if it has to be created",
+        signer,
+        writable
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed receipt account, must be the rent recipient recorded on the receipt",
+        writable
+    ))]
+    #[codama(account(name = "escrow", docs = "Escrow account to release from"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the auto-release config",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "receipt", docs = "Matured deposit receipt to release and close", writable))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account to transfer from",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "depositor", docs = "Depositor recorded on the receipt"))]
+    #[codama(account(
+        name = "depositor_token_account",
+        docs = "Depositor's token account to receive tokens (created if it does not exist)",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("depositor")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint of the released tokens"))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for depositor account creation",
+        default_value = program("associated-token")
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA whose deposit total is reduced (may be closed if the mint was blocked)",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    ReleaseMatured {} = 33,
+
+    /// Withdraw several receipts of one escrow and mint with a single transfer.
+    /// Receipts are passed first in remaining accounts, followed by the arbiter and hook accounts.
+    #[codama(account(
+        name = "payer",
+        docs = "Pays for the destination token account if it has to be created",
+        signer,
+        writable
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed receipt accounts, must be the rent recipient recorded on every receipt",
+        writable
+    ))]
+    #[codama(account(name = "withdrawer", docs = "Depositor of every receipt in the batch", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to withdraw from"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA for escrow configuration",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account to transfer from",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "destination",
+        docs = "Owner of the token account receiving the tokens (defaults to the withdrawer)",
+        default_value = account("withdrawer")
+    ))]
+    #[codama(account(
+        name = "destination_token_account",
+        docs = "Destination's token account to receive tokens (created if it does not exist)",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("destination")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint of every withdrawn receipt"))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for destination account creation",
+        default_value = program("associated-token")
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA whose deposit total is reduced (may be closed if the mint was blocked)",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    WithdrawMany {
+        /// Number of receipts at the start of remaining accounts
+        receipt_count: u8,
+    } = 34,
+
+    /// Deposit several allowed mints into one escrow with a single basket receipt.
+    /// One group of mint, token program, allowed mint, vault and depositor token account is passed per amount
+    /// at the start of remaining accounts, followed by the depositor allowance and hook accounts.
+    #[codama(account(name = "payer", docs = "Pays for the basket receipt account", signer, writable))]
+    #[codama(account(name = "depositor", docs = "Token authority for every deposited mint", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to deposit into"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA for escrow configuration",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "basket_receipt", docs = "Basket receipt PDA to be created", writable))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    DepositBasket {
+        /// Bump for the basket receipt PDA
+        bump: u8,
+        /// Caller-chosen id, unique among the depositor's baskets in the escrow
+        basket_id: u64,
+        /// Address refunded the basket receipt rent when it is closed (defaults to the payer)
+        #[codama(default_value = account("payer"))]
+        rent_recipient: Address,
+        /// Amount to deposit for each mint, in the order of the leg accounts
+        amounts: Vec<u64>,
+        /// Merkle proof for the depositor allowlist (empty if the escrow has no allowlist)
+        proof: Vec<[u8; 32]>,
+    } = 35,
+
+    /// Withdraw every leg of a basket receipt back to the depositor and close it.
+    /// One group of leg accounts is passed per basket leg at the start of remaining accounts,
+    /// followed by each leg's arbiter and hook accounts in leg order.
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from the closed basket receipt, must be the rent recipient recorded on it",
+        writable
+    ))]
+    #[codama(account(name = "withdrawer", docs = "Depositor recorded on the basket receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to withdraw from"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA for escrow configuration",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "basket_receipt", docs = "Basket receipt to withdraw and close", writable))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    WithdrawBasket {
+        /// Number of extra accounts passed to each leg's hook after its hook program, in leg order
+        /// (missing entries count as zero)
+        hook_account_counts: Vec<u8>,
+    } = 36,
+
+    /// Configure which authorities may force refunds on an escrow.
+    /// Flags: 0x01 = escrow admin, 0x02 = arbiter of the receipt's mint. At least one must be set.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to configure force refunds on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the refund authority config",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetRefundAuthority {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Authorities allowed to force a refund
+        flags: u8,
+    } = 37,
+
+    /// Return a receipt's tokens to its depositor and close it, ignoring the timelock.
+    /// Requires the refund authority extension; the hook program and extra accounts follow in remaining accounts.
+    #[codama(account(
+        name = "payer",
+        docs = "Pays for the depositor token account if it has to be created",
+        signer,
+        writable
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed receipt account, must be the rent recipient recorded on the receipt",
+        writable
+    ))]
+    #[codama(account(
+        name = "authority",
+        docs = "Escrow admin or arbiter, as allowed by the refund authority extension",
+        signer
+    ))]
+    #[codama(account(name = "escrow", docs = "Escrow account to refund from"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the refund authority config",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "receipt", docs = "Deposit receipt to refund and close", writable))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account to transfer from",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "depositor", docs = "Depositor recorded on the receipt"))]
+    #[codama(account(
+        name = "depositor_token_account",
+        docs = "Depositor's token account to receive tokens (created if it does not exist)",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("depositor")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint of the refunded tokens"))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for depositor account creation",
+        default_value = program("associated-token")
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA whose deposit total is reduced (may be closed if the mint was blocked)",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    ForceRefund {} = 38,
+
+    /// Configure the withdrawal cooldown on an escrow.
+    /// Receipts must then be requested with RequestWithdraw and claimed once the cooldown has elapsed.
+    #[codama(account(name = "payer", docs = "Pays for extensions account creation", signer, writable))]
+    #[codama(account(name = "admin", docs = "Extension manager, or the admin if that role is unassigned", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to configure the cooldown on"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA account to store the cooldown config",
+        writable,
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    SetCooldown {
+        /// Bump for extensions PDA
+        #[codama(default_value = account_bump("extensions"))]
+        extensions_bump: u8,
+        /// Seconds between a withdraw request and its claim
+        cooldown_period: u64,
+    } = 39,
+
+    /// Request to withdraw a receipt, starting the escrow's cooldown.
+    /// The receipt can then only be paid out through ClaimWithdraw.
+    #[codama(account(name = "payer", docs = "Pays for withdraw request account creation", signer, writable))]
+    #[codama(account(name = "depositor", docs = "Original depositor of the receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account the receipt belongs to"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA holding the cooldown config",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "receipt", docs = "Deposit receipt the withdrawal is requested for", writable))]
+    #[codama(account(
+        name = "withdraw_request",
+        docs = "Withdraw request PDA to be created",
+        writable,
+        default_value = pda("withdrawRequest", [seed("receipt", account("receipt"))])
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    RequestWithdraw {
+        /// Bump for the withdraw request PDA
+        #[codama(default_value = account_bump("withdrawRequest"))]
+        bump: u8,
+    } = 40,
+
+    /// Withdraw a requested receipt once the cooldown has elapsed since the request.
+    /// Closes the receipt and its withdraw request; timelock, arbiter and hook apply as in Withdraw.
+    #[codama(account(
+        name = "payer",
+        docs = "Pays for the destination token account if it has to be created",
+        signer,
+        writable
+    ))]
+    #[codama(account(
+        name = "rent_recipient",
+        docs = "Receives rent from closed receipt and request accounts, must be the rent recipient recorded on the receipt",
+        writable
+    ))]
+    #[codama(account(name = "depositor", docs = "Original depositor of the receipt", signer))]
+    #[codama(account(name = "escrow", docs = "Escrow account to withdraw from"))]
+    #[codama(account(
+        name = "extensions",
+        docs = "Extensions PDA for escrow configuration",
+        default_value = pda("extensions", [seed("escrow", account("escrow"))])
+    ))]
+    #[codama(account(name = "receipt", docs = "Requested deposit receipt to close upon withdrawal", writable))]
+    #[codama(account(
+        name = "withdraw_request",
+        docs = "Withdraw request PDA to close upon withdrawal",
+        writable,
+        default_value = pda("withdrawRequest", [seed("receipt", account("receipt"))])
+    ))]
+    #[codama(account(
+        name = "vault",
+        docs = "Escrow vault token account to transfer from",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("escrow")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(
+        name = "destination",
+        docs = "Owner of the token account receiving the tokens (defaults to the depositor)",
+        default_value = account("depositor")
+    ))]
+    #[codama(account(
+        name = "destination_token_account",
+        docs = "Destination's token account to receive tokens (created if it does not exist)",
+        writable,
+        default_value = pda("associatedToken", program = "associatedToken", [seed("owner", account("destination")), seed("tokenProgram", account("tokenProgram")), seed("mint", account("mint"))])
+    ))]
+    #[codama(account(name = "mint", docs = "Token mint of the withdrawn tokens"))]
+    #[codama(account(name = "token_program", docs = "SPL Token program", default_value = program("token")))]
+    #[codama(account(
+        name = "associated_token_program",
+        docs = "Associated Token program for destination account creation",
+        default_value = program("associated-token")
+    ))]
+    #[codama(account(name = "system_program", docs = "System program", default_value = program("system")))]
+    #[codama(account(
+        name = "event_authority",
+        docs = "Event authority PDA for CPI event emission",
+        default_value = public_key("Eq63FWYo9DXgwoTnpK9gjp7BH4PyhSPo11zEF9FK7f4M")
+    ))]
+    #[codama(account(
+        name = "escrow_program",
+        docs = "Escrow program for CPI event emission",
+        default_value = public_key("Escrowae7RaUfNn4oEZHywMXE5zWzYCXenwrCDaEoifg")
+    ))]
+    #[codama(account(
+        name = "allowed_mint",
+        docs = "Allowed mint PDA whose deposit total is reduced (may be closed if the mint was blocked)",
+        writable,
+        default_value = pda("allowedMint", [seed("escrow", account("escrow")), seed("mint", account("mint"))])
+    ))]
+    ClaimWithdraw {} = 41,
+
     /// Invoked via CPI to emit event data in instruction args (prevents log truncation).
     #[codama(skip)]
     #[codama(account(
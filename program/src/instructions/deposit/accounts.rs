@@ -15,9 +15,9 @@ use crate::{
 /// 0. `[signer, writable]` payer - Pays for account creation
 /// 1. `[signer]` depositor - Token authority for the deposit
 /// 2. `[]` escrow - Escrow account (must exist)
-/// 3. `[]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (validates mint is allowed)
-/// 4. `[signer]` receipt_seed - Receipt seed signer for PDA uniqueness
-/// 5. `[writable]` receipt - Deposit receipt PDA to be created
+/// 3. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (validates mint is allowed, tracks deposit limits)
+/// 4. `[writable]` receipt_counter - ReceiptCounter PDA `[b"receipt_counter", escrow, depositor, mint]` (created if empty)
+/// 5. `[writable]` receipt - Deposit receipt PDA `[b"receipt", escrow, depositor, mint, nonce]` to be created
 /// 6. `[writable]` vault - Escrow's vault token account (destination)
 /// 7. `[writable]` depositor_token_account - Depositor's token account (source)
 /// 8. `[]` mint - Token mint
@@ -27,15 +27,18 @@ use crate::{
 /// 12. `[]` escrow_program - Current program
 /// 13. `[]` extensions - Extensions PDA (may be empty/uninitialized)
 ///
-/// # Remaining Accounts (if hook configured)
-/// 0. `[]` hook_program - The hook program to invoke
-/// 1. ..N. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
+/// # Remaining Accounts
+/// If depositor allowlist extension is set with a per-address cap:
+///   0. `[writable]` depositor_allowance - DepositorAllowance PDA `[b"allowance", escrow, depositor, mint]` (created if empty)
+/// If hook extension is set (after depositor_allowance if both):
+///   N. `[]` hook_program - The hook program to invoke
+///   N+1..M. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
 pub struct DepositAccounts<'a> {
     pub payer: &'a AccountView,
     pub depositor: &'a AccountView,
     pub escrow: &'a AccountView,
     pub allowed_mint: &'a AccountView,
-    pub receipt_seed: &'a AccountView,
+    pub receipt_counter: &'a AccountView,
     pub receipt: &'a AccountView,
     pub vault: &'a AccountView,
     pub depositor_token_account: &'a AccountView,
@@ -53,7 +56,7 @@ impl<'a> TryFrom<&'a [AccountView]> for DepositAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [payer, depositor, escrow, allowed_mint, receipt_seed, receipt, vault, depositor_token_account, mint, token_program, system_program, event_authority, escrow_program, extensions, remaining_accounts @ ..] =
+        let [payer, depositor, escrow, allowed_mint, receipt_counter, receipt, vault, depositor_token_account, mint, token_program, system_program, event_authority, escrow_program, extensions, remaining_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
@@ -62,16 +65,16 @@ impl<'a> TryFrom<&'a [AccountView]> for DepositAccounts<'a> {
         // 1. Validate signers
         verify_signer(payer, true)?;
         verify_signer(depositor, false)?;
-        verify_signer(receipt_seed, false)?;
 
         // 2. Validate writable
+        verify_writable(allowed_mint, true)?;
+        verify_writable(receipt_counter, true)?;
         verify_writable(receipt, true)?;
         verify_writable(vault, true)?;
         verify_writable(depositor_token_account, true)?;
 
         // 3. Validate readonly
         verify_readonly(escrow)?;
-        verify_readonly(allowed_mint)?;
         verify_readonly(mint)?;
         verify_readonly(extensions)?;
 
@@ -95,7 +98,7 @@ impl<'a> TryFrom<&'a [AccountView]> for DepositAccounts<'a> {
             depositor,
             escrow,
             allowed_mint,
-            receipt_seed,
+            receipt_counter,
             receipt,
             vault,
             depositor_token_account,
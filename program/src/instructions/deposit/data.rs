@@ -1,4 +1,5 @@
-use pinocchio::error::ProgramError;
+use alloc::vec::Vec;
+use pinocchio::{error::ProgramError, Address};
 
 use crate::{errors::EscrowProgramError, require_len, traits::InstructionData};
 
@@ -7,9 +8,13 @@ use crate::{errors::EscrowProgramError, require_len, traits::InstructionData};
 /// # Layout
 /// * `bump` (u8) - Bump for the deposit receipt PDA
 /// * `amount` (u64) - Amount of tokens to deposit
+/// * `rent_recipient` (Address) - Address refunded the receipt rent when it is closed
+/// * `proof` (u32 count + [u8; 32] nodes) - Depositor allowlist Merkle proof (empty if no allowlist)
 pub struct DepositData {
     pub bump: u8,
     pub amount: u64,
+    pub rent_recipient: Address,
+    pub proof: Vec<[u8; 32]>,
 }
 
 impl<'a> TryFrom<&'a [u8]> for DepositData {
@@ -26,29 +31,86 @@ impl<'a> TryFrom<&'a [u8]> for DepositData {
             return Err(EscrowProgramError::ZeroDepositAmount.into());
         }
 
-        Ok(Self { bump, amount })
+        // An unset (default) rent recipient would strand the receipt rent, so it is rejected
+        let rent_recipient = Address::new_from_array(data[9..41].try_into().unwrap());
+        if rent_recipient == Address::default() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let proof_len = u32::from_le_bytes(data[41..45].try_into().unwrap()) as usize;
+        let proof_bytes = proof_len
+            .checked_mul(32)
+            .and_then(|len| data.get(Self::LEN..Self::LEN.checked_add(len)?))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let proof = proof_bytes.chunks_exact(32).map(|node| node.try_into().unwrap()).collect();
+
+        Ok(Self { bump, amount, rent_recipient, proof })
     }
 }
 
 impl<'a> InstructionData<'a> for DepositData {
-    const LEN: usize = 1 + 8; // bump + amount
+    const LEN: usize = 1 + 8 + 32 + 4; // bump + amount + rent_recipient + proof length
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn deposit_data(amount: u64, proof_len: u32, extra: usize) -> alloc::vec::Vec<u8> {
+        let mut data = alloc::vec![0u8; DepositData::LEN + extra];
+        data[0] = 255; // bump
+        data[1..9].copy_from_slice(&amount.to_le_bytes()); // amount
+        data[9..41].copy_from_slice(&[7u8; 32]); // rent_recipient
+        data[41..45].copy_from_slice(&proof_len.to_le_bytes()); // proof length
+        data
+    }
+
     #[test]
     fn test_deposit_data_try_from_valid() {
-        let mut data = [0u8; 9];
-        data[0] = 255; // bump
-        data[1..9].copy_from_slice(&1000u64.to_le_bytes()); // amount
+        let data = deposit_data(1000, 0, 0);
 
         let result = DepositData::try_from(&data[..]);
         assert!(result.is_ok());
         let deposit_data = result.unwrap();
         assert_eq!(deposit_data.bump, 255);
         assert_eq!(deposit_data.amount, 1000);
+        assert_eq!(deposit_data.rent_recipient, Address::new_from_array([7u8; 32]));
+        assert!(deposit_data.proof.is_empty());
+    }
+
+    #[test]
+    fn test_deposit_data_try_from_with_proof() {
+        let mut data = deposit_data(1000, 2, 64);
+        data[45..77].copy_from_slice(&[1u8; 32]);
+        data[77..109].copy_from_slice(&[2u8; 32]);
+
+        let deposit_data = DepositData::try_from(&data[..]).unwrap();
+        assert_eq!(deposit_data.proof, [[1u8; 32], [2u8; 32]]);
+    }
+
+    #[test]
+    fn test_deposit_data_try_from_truncated_proof() {
+        let data = deposit_data(1000, 2, 63);
+
+        let result = DepositData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_data_try_from_proof_length_overflow() {
+        let data = deposit_data(1000, u32::MAX, 0);
+
+        let result = DepositData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_data_try_from_default_rent_recipient() {
+        let mut data = deposit_data(1000, 0, 0);
+        data[9..41].copy_from_slice(&[0u8; 32]);
+
+        let result = DepositData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
     }
 
     #[test]
@@ -60,16 +122,14 @@ mod tests {
 
     #[test]
     fn test_deposit_data_try_from_too_short() {
-        let data = [0u8; 5];
+        let data = [0u8; 13];
         let result = DepositData::try_from(&data[..]);
         assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
     }
 
     #[test]
     fn test_deposit_data_try_from_zero_amount() {
-        let mut data = [0u8; 9];
-        data[0] = 255; // bump
-        data[1..9].copy_from_slice(&0u64.to_le_bytes()); // zero amount
+        let data = deposit_data(0, 0, 0);
 
         let result = DepositData::try_from(&data[..]);
         assert!(matches!(
@@ -13,11 +13,14 @@ use crate::{
     events::DepositEvent,
     instructions::Deposit,
     state::{
-        get_extensions_from_account, validate_extensions_pda, AllowedMint, Escrow, ExtensionType, HookData, HookPoint,
-        Receipt,
+        get_extensions_from_account, validate_extensions_pda, AllowedMint, DepositorAllowance, DepositorAllowlistData,
+        Escrow, ExtensionType, HookData, HookPoint, Receipt, ReceiptCounter, ReceiptCounterPda,
     },
     traits::{AccountSerialize, AccountSize, EventSerialize, ExtensionData, PdaSeeds},
-    utils::{create_pda_account, emit_event, get_mint_decimals, validate_mint_extensions},
+    utils::{
+        create_pda_account, emit_event, get_mint_decimals, get_token_account_amount, validate_mint_extensions,
+        verify_current_program_account, verify_writable,
+    },
 };
 
 /// Processes the Deposit instruction.
@@ -32,7 +35,7 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
 
     // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
     let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
-    let _allowed_mint = AllowedMint::from_account(
+    let mut allowed_mint = AllowedMint::from_account(
         &allowed_mint_data,
         ix.accounts.allowed_mint,
         program_id,
@@ -40,11 +43,25 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
         ix.accounts.mint.address(),
     )
     .map_err(|_| EscrowProgramError::MintNotAllowed)?;
+    let legacy_allowed_mint = AllowedMint::is_legacy(&allowed_mint_data);
+    drop(allowed_mint_data);
+
+    // A legacy AllowedMint starts counting here; the vault so far backs receipts it never counted
+    if legacy_allowed_mint {
+        allowed_mint.opening_balance = get_token_account_amount(ix.accounts.vault)?;
+    }
+
+    // Enforce per-mint deposit limits and track the running total
+    allowed_mint.record_deposit(ix.data.amount)?;
+    allowed_mint.write_to_account(ix.accounts.payer, ix.accounts.allowed_mint)?;
 
     // Get current timestamp from Clock sysvar
     let clock = Clock::get()?;
     let deposited_at = clock.unix_timestamp;
 
+    // Take the next receipt nonce for this depositor and mint
+    let nonce = take_receipt_nonce(&ix, program_id)?;
+
     // Create Receipt state
     let receipt = Receipt::new(
         ix.data.amount,
@@ -52,8 +69,9 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
         *ix.accounts.escrow.address(),
         *ix.accounts.depositor.address(),
         *ix.accounts.mint.address(),
-        *ix.accounts.receipt_seed.address(),
+        nonce,
         ix.data.bump,
+        ix.data.rent_recipient,
     );
 
     // Validate deposit receipt PDA
@@ -79,15 +97,43 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
     // This prevents stale AllowedMint entries from bypassing new blocklist rules.
     validate_mint_extensions(ix.accounts.mint, ix.accounts.extensions)?;
 
-    // Get hook extension if present
-    let exts = get_extensions_from_account(ix.accounts.extensions, &[ExtensionType::Hook])?;
+    // Get hook and depositor allowlist extensions in single pass; a per-mint hook override takes precedence
+    let ext_types = [ExtensionType::Hook, ExtensionType::DepositorAllowlist];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+    AllowedMint::apply_overrides(&ix.accounts.allowed_mint.try_borrow()?, &ext_types, &mut exts)?;
     let hook_data = exts[0].as_ref().map(|b| HookData::from_bytes(b)).transpose()?;
 
+    // Validate depositor allowlist if present — a capped allowlist takes the first remaining account
+    let remaining_accounts = if let Some(ref allowlist_bytes) = exts[1] {
+        let allowlist = DepositorAllowlistData::from_bytes(allowlist_bytes)?;
+        allowlist.validate(ix.accounts.depositor.address(), &ix.data.proof)?;
+
+        if allowlist.has_cap() {
+            let allowance_account = ix.accounts.remaining_accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+            consume_depositor_allowance(
+                program_id,
+                ix.accounts.payer,
+                ix.accounts.escrow,
+                ix.accounts.depositor,
+                ix.accounts.mint,
+                allowance_account,
+                ix.data.amount,
+                allowlist.per_address_cap,
+            )?;
+            // Skip depositor allowance account, pass rest to hook
+            ix.accounts.remaining_accounts.get(1..).unwrap_or(&[])
+        } else {
+            ix.accounts.remaining_accounts
+        }
+    } else {
+        ix.accounts.remaining_accounts
+    };
+
     // Invoke pre-deposit hook if configured
     if let Some(ref hook) = hook_data {
         hook.invoke(
             HookPoint::PreDeposit,
-            ix.accounts.remaining_accounts,
+            remaining_accounts,
             &[ix.accounts.escrow, ix.accounts.mint, ix.accounts.receipt],
         )?;
     }
@@ -110,7 +156,7 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
     if let Some(ref hook) = hook_data {
         hook.invoke(
             HookPoint::PostDeposit,
-            ix.accounts.remaining_accounts,
+            remaining_accounts,
             &[ix.accounts.escrow, ix.accounts.mint, ix.accounts.receipt],
         )?;
     }
@@ -120,10 +166,83 @@ pub fn process_deposit(program_id: &Address, accounts: &[AccountView], instructi
         *ix.accounts.escrow.address(),
         *ix.accounts.depositor.address(),
         *ix.accounts.mint.address(),
-        *ix.accounts.receipt_seed.address(),
+        nonce,
         ix.data.amount,
     );
     emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
 
     Ok(())
 }
+
+/// Returns the depositor's next receipt nonce for this mint and advances the counter,
+/// creating the ReceiptCounter PDA on the first deposit.
+fn take_receipt_nonce(ix: &Deposit, program_id: &Address) -> Result<u64, ProgramError> {
+    let counter_account = ix.accounts.receipt_counter;
+    let counter_pda = ReceiptCounterPda::new(
+        ix.accounts.escrow.address(),
+        ix.accounts.depositor.address(),
+        ix.accounts.mint.address(),
+    );
+
+    let mut counter = if counter_account.data_len() == 0 {
+        let bump = counter_pda.validate_pda_address(counter_account, program_id)?;
+
+        let bump_seed = [bump];
+        let counter_seeds: Vec<Seed> = counter_pda.seeds_with_bump(&bump_seed);
+        let counter_seeds_array: [Seed; 5] = counter_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+        create_pda_account(ix.accounts.payer, ReceiptCounter::LEN, program_id, counter_account, counter_seeds_array)?;
+
+        ReceiptCounter::new(bump)
+    } else {
+        verify_current_program_account(counter_account)?;
+        let counter_data = counter_account.try_borrow()?;
+        ReceiptCounter::from_account(
+            &counter_data,
+            counter_account,
+            program_id,
+            counter_pda.escrow,
+            counter_pda.depositor,
+            counter_pda.mint,
+        )?
+    };
+
+    let nonce = counter.take_nonce()?;
+    counter.write_to_slice(&mut counter_account.try_borrow_mut()?)?;
+    Ok(nonce)
+}
+
+/// Adds the deposit to the depositor's consumed allowance for `mint`, creating the allowance PDA on first use.
+#[allow(clippy::too_many_arguments)]
+pub fn consume_depositor_allowance(
+    program_id: &Address,
+    payer: &AccountView,
+    escrow: &AccountView,
+    depositor: &AccountView,
+    mint: &AccountView,
+    allowance_account: &AccountView,
+    amount: u64,
+    cap: u64,
+) -> ProgramResult {
+    verify_writable(allowance_account, true)?;
+
+    let mut allowance = if allowance_account.data_len() == 0 {
+        let mut allowance = DepositorAllowance::new(*escrow.address(), *depositor.address(), *mint.address(), 0);
+        allowance.bump = allowance.validate_pda_address(allowance_account, program_id)?;
+
+        let bump_seed = [allowance.bump];
+        let allowance_seeds: Vec<Seed> = allowance.seeds_with_bump(&bump_seed);
+        let allowance_seeds_array: [Seed; 5] = allowance_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+        create_pda_account(payer, DepositorAllowance::LEN, program_id, allowance_account, allowance_seeds_array)?;
+
+        allowance
+    } else {
+        verify_current_program_account(allowance_account)?;
+        let allowance_data = allowance_account.try_borrow()?;
+        let allowance = DepositorAllowance::from_account(&allowance_data, allowance_account, program_id)?;
+        allowance.validate_owner(escrow.address(), depositor.address(), mint.address())?;
+        allowance
+    };
+
+    allowance.record_deposit(amount, cap)?;
+    allowance.write_to_slice(&mut allowance_account.try_borrow_mut()?)
+}
@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError, Address};
+
+use crate::{
+    errors::EscrowProgramError,
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, verify_current_program, verify_current_program_account,
+        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
+        verify_token_program, verify_writable,
+    },
+};
+
+/// Accounts for the DepositBasket instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for the basket receipt account
+/// 1. `[signer]` depositor - Token authority for every leg
+/// 2. `[]` escrow - Escrow account (must exist)
+/// 3. `[]` extensions - Extensions PDA (may be empty/uninitialized)
+/// 4. `[writable]` basket_receipt - BasketReceipt PDA `[b"basket_receipt", escrow, depositor, basket_id]` to be created
+/// 5. `[]` system_program - System program for account creation
+/// 6. `[]` event_authority - Event authority PDA
+/// 7. `[]` escrow_program - Current program
+///
+/// # Remaining Accounts
+/// 0..5N. One group of `BasketLegAccounts` per amount, in leg order
+/// If depositor allowlist extension is set with a per-address cap:
+///   5N..6N. `[writable]` depositor_allowance - One DepositorAllowance PDA
+///   `[b"allowance", escrow, depositor, mint]` per leg, in leg order (created if empty)
+/// If hook extension is set (after the depositor allowances if both):
+///   M. `[]` hook_program - The hook program to invoke
+///   M+1.. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
+pub struct DepositBasketAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub basket_receipt: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for DepositBasketAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, depositor, escrow, extensions, basket_receipt, system_program, event_authority, escrow_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(depositor, false)?;
+
+        // 2. Validate writable
+        verify_writable(basket_receipt, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+
+        Ok(Self {
+            payer,
+            depositor,
+            escrow,
+            extensions,
+            basket_receipt,
+            system_program,
+            event_authority,
+            escrow_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for DepositBasketAccounts<'a> {}
+
+/// Accounts for one mint of a `DepositBasket` or `WithdrawBasket`
+///
+/// # Account Layout
+/// 0. `[]` mint - Token mint of the leg
+/// 1. `[]` token_program - Token program owning the mint
+/// 2. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]`
+/// 3. `[writable]` vault - Escrow's vault token account for the mint
+/// 4. `[writable]` token_account - Depositor's token account for the mint
+pub struct BasketLegAccounts<'a> {
+    pub mint: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub token_account: &'a AccountView,
+}
+
+impl<'a> BasketLegAccounts<'a> {
+    /// Number of accounts per leg
+    pub const LEN: usize = 5;
+
+    /// Splits `leg_count` leg groups off the front of `accounts` and validates them, returning
+    /// the legs and the accounts that follow. Mints must be distinct.
+    pub fn parse(
+        accounts: &'a [AccountView],
+        leg_count: usize,
+        escrow: &Address,
+        owner: &Address,
+    ) -> Result<(Vec<Self>, &'a [AccountView]), ProgramError> {
+        let legs_len = leg_count * Self::LEN;
+        if accounts.len() < legs_len {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+        let (leg_accounts, rest) = accounts.split_at(legs_len);
+
+        let mut legs = Vec::with_capacity(leg_count);
+        for group in leg_accounts.chunks_exact(Self::LEN) {
+            let [mint, token_program, allowed_mint, vault, token_account] = group else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            if legs.iter().any(|leg: &Self| leg.mint.address() == mint.address()) {
+                return Err(EscrowProgramError::DuplicateBasketMint.into());
+            }
+
+            verify_writable(allowed_mint, true)?;
+            verify_writable(vault, true)?;
+            verify_writable(token_account, true)?;
+            verify_readonly(mint)?;
+
+            verify_token_program(token_program)?;
+            verify_owned_by(mint, token_program.address())?;
+
+            validate_associated_token_account(vault, escrow, mint, token_program)?;
+            validate_associated_token_account(token_account, owner, mint, token_program)?;
+
+            legs.push(Self { mint, token_program, allowed_mint, vault, token_account });
+        }
+
+        Ok((legs, rest))
+    }
+}
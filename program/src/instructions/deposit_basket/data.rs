@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{errors::EscrowProgramError, require_len, state::BasketReceipt, traits::InstructionData};
+
+/// Instruction data for DepositBasket
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the basket receipt PDA
+/// * `basket_id` (u64) - Caller-chosen id, unique among the depositor's baskets in the escrow
+/// * `rent_recipient` (Address) - Address refunded the basket receipt rent when it is closed
+/// * `amounts` (u32 count + u64 amounts) - Amount to deposit for each mint, in leg order
+/// * `proof` (u32 count + [u8; 32] nodes) - Depositor allowlist Merkle proof (empty if no allowlist)
+pub struct DepositBasketData {
+    pub bump: u8,
+    pub basket_id: u64,
+    pub rent_recipient: Address,
+    pub amounts: Vec<u64>,
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for DepositBasketData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let basket_id = u64::from_le_bytes(data[1..9].try_into().unwrap());
+
+        // The default address is never a valid rent recipient
+        let rent_recipient = Address::new_from_array(data[9..41].try_into().unwrap());
+        if rent_recipient == Address::default() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let amounts_len = u32::from_le_bytes(data[41..45].try_into().unwrap()) as usize;
+        if amounts_len == 0 || amounts_len > BasketReceipt::MAX_LEGS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let proof_offset = Self::LEN + amounts_len * 8;
+        let amounts_bytes = data.get(Self::LEN..proof_offset).ok_or(ProgramError::InvalidInstructionData)?;
+        let amounts: Vec<u64> =
+            amounts_bytes.chunks_exact(8).map(|amount| u64::from_le_bytes(amount.try_into().unwrap())).collect();
+
+        if amounts.contains(&0) {
+            return Err(EscrowProgramError::ZeroDepositAmount.into());
+        }
+
+        let proof_len_bytes = data.get(proof_offset..proof_offset + 4).ok_or(ProgramError::InvalidInstructionData)?;
+        let proof_len = u32::from_le_bytes(proof_len_bytes.try_into().unwrap()) as usize;
+        let proof_start = proof_offset + 4;
+        let proof_bytes = proof_len
+            .checked_mul(32)
+            .and_then(|len| data.get(proof_start..proof_start.checked_add(len)?))
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let proof = proof_bytes.chunks_exact(32).map(|node| node.try_into().unwrap()).collect();
+
+        Ok(Self { bump, basket_id, rent_recipient, amounts, proof })
+    }
+}
+
+impl<'a> InstructionData<'a> for DepositBasketData {
+    const LEN: usize = 1 + 8 + 32 + 4; // bump + basket_id + rent_recipient + amounts length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn basket_data(amounts: &[u64], proof: &[[u8; 32]]) -> Vec<u8> {
+        let mut data = alloc::vec![255u8]; // bump
+        data.extend_from_slice(&3u64.to_le_bytes()); // basket_id
+        data.extend_from_slice(&[7u8; 32]); // rent_recipient
+        data.extend_from_slice(&(amounts.len() as u32).to_le_bytes());
+        for amount in amounts {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data.extend_from_slice(&(proof.len() as u32).to_le_bytes());
+        for node in proof {
+            data.extend_from_slice(node);
+        }
+        data
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_valid() {
+        let data = basket_data(&[1000, 2000], &[[1u8; 32]]);
+
+        let parsed = DepositBasketData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 255);
+        assert_eq!(parsed.basket_id, 3);
+        assert_eq!(parsed.rent_recipient, Address::new_from_array([7u8; 32]));
+        assert_eq!(parsed.amounts, [1000, 2000]);
+        assert_eq!(parsed.proof, [[1u8; 32]]);
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_no_legs() {
+        let data = basket_data(&[], &[]);
+
+        let result = DepositBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_too_many_legs() {
+        let data = basket_data(&[1; BasketReceipt::MAX_LEGS + 1], &[]);
+
+        let result = DepositBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_zero_amount() {
+        let data = basket_data(&[1000, 0], &[]);
+
+        let result = DepositBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::Custom(13)))); // ZeroDepositAmount
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_default_rent_recipient() {
+        let mut data = basket_data(&[1000], &[]);
+        data[9..41].copy_from_slice(&[0u8; 32]);
+
+        let result = DepositBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_truncated_amounts() {
+        let data = basket_data(&[1000, 2000], &[]);
+
+        let result = DepositBasketData::try_from(&data[..DepositBasketData::LEN + 12]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_truncated_proof() {
+        let data = basket_data(&[1000], &[[1u8; 32]]);
+
+        let result = DepositBasketData::try_from(&data[..data.len() - 1]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_deposit_basket_data_try_from_too_short() {
+        let data = [0u8; 13];
+        let result = DepositBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
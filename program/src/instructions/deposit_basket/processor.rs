@@ -0,0 +1,212 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    errors::EscrowProgramError,
+    events::BasketDepositEvent,
+    instructions::{consume_depositor_allowance, BasketLegAccounts, DepositBasket},
+    state::{
+        get_extensions_from_account, require_no_cooldown, validate_extensions_pda, AllowedMint, BasketLeg,
+        BasketReceipt, DepositorAllowlistData, Escrow, ExtensionType, HookData, HookPoint,
+    },
+    traits::{AccountSerialize, EventSerialize, ExtensionData, PdaSeeds},
+    utils::{
+        create_pda_account, emit_event, get_mint_decimals, get_token_account_amount, validate_mint_extensions,
+        verify_current_program_account,
+    },
+};
+
+/// Processes the DepositBasket instruction.
+///
+/// Transfers several allowed mints from the depositor into their vaults and records them on a
+/// single basket receipt, which can only be withdrawn as a whole with `WithdrawBasket`. Each leg
+/// is checked like a `Deposit` of its mint, including per-mint limits and hook overrides, and
+/// all legs must resolve to the same arbiter and hook.
+pub fn process_deposit_basket(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = DepositBasket::try_from((instruction_data, accounts))?;
+
+    // Verify escrow exists and is valid
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Leg accounts come first in remaining_accounts, one group per amount
+    let (legs, remaining_accounts) = BasketLegAccounts::parse(
+        ix.accounts.remaining_accounts,
+        ix.data.amounts.len(),
+        ix.accounts.escrow.address(),
+        ix.accounts.depositor.address(),
+    )?;
+
+    // Enforce per-mint deposit limits and track the running totals
+    for (leg, amount) in legs.iter().zip(&ix.data.amounts) {
+        verify_current_program_account(leg.allowed_mint)?;
+        let allowed_mint_data = leg.allowed_mint.try_borrow()?;
+        let mut allowed_mint = AllowedMint::from_account(
+            &allowed_mint_data,
+            leg.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            leg.mint.address(),
+        )
+        .map_err(|_| EscrowProgramError::MintNotAllowed)?;
+        let legacy_allowed_mint = AllowedMint::is_legacy(&allowed_mint_data);
+        drop(allowed_mint_data);
+
+        // A legacy AllowedMint starts counting here; the vault so far backs receipts it never counted
+        if legacy_allowed_mint {
+            allowed_mint.opening_balance = get_token_account_amount(leg.vault)?;
+        }
+
+        allowed_mint.record_deposit(*amount)?;
+        allowed_mint.write_to_account(ix.accounts.payer, leg.allowed_mint)?;
+    }
+
+    // Create the basket receipt
+    let clock = Clock::get()?;
+    let basket = BasketReceipt::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        ix.data.basket_id,
+        clock.unix_timestamp,
+        ix.data.rent_recipient,
+        legs.iter()
+            .zip(&ix.data.amounts)
+            .map(|(leg, amount)| BasketLeg { mint: *leg.mint.address(), amount: *amount })
+            .collect(),
+        ix.data.bump,
+    );
+
+    basket.validate_pda(ix.accounts.basket_receipt, program_id, ix.data.bump)?;
+
+    let basket_bump_seed = [ix.data.bump];
+    let basket_seeds: Vec<Seed> = basket.seeds_with_bump(&basket_bump_seed);
+    let basket_seeds_array: [Seed; 5] = basket_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+    create_pda_account(ix.accounts.payer, basket.space(), program_id, ix.accounts.basket_receipt, basket_seeds_array)?;
+
+    basket.write_to_slice(&mut ix.accounts.basket_receipt.try_borrow_mut()?)?;
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Basket receipts cannot be requested, so they would skip the cooldown on withdraw
+    require_no_cooldown(ix.accounts.extensions)?;
+
+    // Re-check every mint against the current escrow blocklist
+    for leg in &legs {
+        validate_mint_extensions(leg.mint, ix.accounts.extensions)?;
+    }
+
+    // Get hook, depositor allowlist, and arbiter extensions in single pass
+    let ext_types = [ExtensionType::Hook, ExtensionType::DepositorAllowlist, ExtensionType::Arbiter];
+    let exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Validate depositor allowlist if present — a capped allowlist takes one allowance per leg after the legs
+    let remaining_accounts = if let Some(ref allowlist_bytes) = exts[1] {
+        let allowlist = DepositorAllowlistData::from_bytes(allowlist_bytes)?;
+        allowlist.validate(ix.accounts.depositor.address(), &ix.data.proof)?;
+
+        if allowlist.has_cap() {
+            if remaining_accounts.len() < legs.len() {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+            let (allowance_accounts, rest) = remaining_accounts.split_at(legs.len());
+            for ((leg, amount), allowance_account) in legs.iter().zip(&ix.data.amounts).zip(allowance_accounts) {
+                consume_depositor_allowance(
+                    program_id,
+                    ix.accounts.payer,
+                    ix.accounts.escrow,
+                    ix.accounts.depositor,
+                    leg.mint,
+                    allowance_account,
+                    *amount,
+                    allowlist.per_address_cap,
+                )?;
+            }
+            // Skip depositor allowance accounts, pass rest to hook
+            rest
+        } else {
+            remaining_accounts
+        }
+    } else {
+        remaining_accounts
+    };
+
+    // Resolve each leg's hook and arbiter, taking per-mint overrides into account. The hook accounts
+    // are shared by every leg, so every leg must resolve to the same arbiter and hook.
+    let override_types = [ExtensionType::Hook, ExtensionType::Arbiter];
+    let mut leg_exts = Vec::with_capacity(legs.len());
+    for leg in &legs {
+        let mut resolved = [exts[0].clone(), exts[2].clone()];
+        AllowedMint::apply_overrides(&leg.allowed_mint.try_borrow()?, &override_types, &mut resolved)?;
+        leg_exts.push(resolved);
+    }
+    if leg_exts.windows(2).any(|pair| pair[0] != pair[1]) {
+        return Err(EscrowProgramError::MixedBasketLegs.into());
+    }
+    let hook =
+        leg_exts.first().and_then(|resolved| resolved[0].as_ref()).map(|b| HookData::from_bytes(b)).transpose()?;
+
+    // Invokes the hook once per leg, with the basket receipt in the receipt slot
+    let invoke_hooks = |hook_point: HookPoint| -> ProgramResult {
+        if let Some(ref hook) = hook {
+            for leg in &legs {
+                hook.invoke(
+                    hook_point,
+                    remaining_accounts,
+                    &[ix.accounts.escrow, leg.mint, ix.accounts.basket_receipt],
+                )?;
+            }
+        }
+        Ok(())
+    };
+
+    // Invoke pre-deposit hooks if configured
+    if hook.is_some() {
+        invoke_hooks(HookPoint::PreDeposit)?;
+    }
+
+    // Transfer every leg from depositor to vault
+    for (leg, amount) in legs.iter().zip(&ix.data.amounts) {
+        let decimals = get_mint_decimals(leg.mint)?;
+
+        TransferChecked {
+            from: leg.token_account,
+            mint: leg.mint,
+            to: leg.vault,
+            authority: ix.accounts.depositor,
+            amount: *amount,
+            decimals,
+            token_program: leg.token_program.address(),
+        }
+        .invoke()?;
+    }
+
+    // Invoke post-deposit hooks if configured
+    if hook.is_some() {
+        invoke_hooks(HookPoint::PostDeposit)?;
+    }
+
+    // Emit event via CPI
+    let event = BasketDepositEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        ix.data.basket_id,
+        basket.legs,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
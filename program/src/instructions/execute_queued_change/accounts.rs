@@ -0,0 +1,83 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the ExecuteQueuedChange instruction
+///
+/// Applies a pending change to the extensions PDA and closes the PendingChange account.
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for extensions account growth
+/// 1. `[signer]` admin - Authority for the changed extension (escrow.admin or the extension manager)
+/// 2. `[writable]` rent_recipient - Receives rent refund from the closed pending change
+/// 3. `[]` escrow - Escrow the change applies to
+/// 4. `[writable]` extensions - Extensions PDA (created if doesn't exist)
+/// 5. `[writable]` pending_change - PendingChange PDA to execute and close
+/// 6. `[]` system_program - System program for account creation
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program
+pub struct ExecuteQueuedChangeAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub admin: &'a AccountView,
+    pub rent_recipient: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub pending_change: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ExecuteQueuedChangeAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, admin, rent_recipient, escrow, extensions, pending_change, system_program, event_authority, escrow_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(extensions, true)?;
+        verify_writable(pending_change, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(pending_change)?;
+
+        Ok(Self {
+            payer,
+            admin,
+            rent_recipient,
+            escrow,
+            extensions,
+            pending_change,
+            system_program,
+            event_authority,
+            escrow_program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for ExecuteQueuedChangeAccounts<'a> {}
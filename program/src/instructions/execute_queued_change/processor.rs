@@ -0,0 +1,96 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::ChangeExecutedEvent,
+    instructions::ExecuteQueuedChange,
+    state::{
+        get_governance_delay, remove_extension, update_or_append_extension, ChangeAction, Escrow, ExtensionsPda,
+        GovernanceDelayData, PendingChange,
+    },
+    traits::{EventSerialize, PdaSeeds},
+    utils::{close_pda_account, emit_event},
+};
+
+/// Processes the ExecuteQueuedChange instruction.
+///
+/// Applies a queued extension change once the escrow's current governance delay has
+/// elapsed since it was queued, then closes the PendingChange account. The signer's authority is checked again against the
+/// escrow's current admin and roles.
+pub fn process_execute_queued_change(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ExecuteQueuedChange::try_from((instruction_data, accounts))?;
+
+    // Read escrow
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+
+    // Read pending change and validate it belongs to this escrow
+    let pending_change_data = ix.accounts.pending_change.try_borrow()?;
+    let pending_change = PendingChange::from_account(&pending_change_data, ix.accounts.pending_change, program_id)?;
+    pending_change.validate_escrow(ix.accounts.escrow.address())?;
+    drop(pending_change_data);
+
+    let action = pending_change.action()?;
+    let extension_type = pending_change.extension_type()?;
+    if !extension_type.is_mutable_when_immutable() {
+        escrow.require_mutable()?;
+    }
+
+    // Validate extensions PDA and the signer's authority over the extension
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+    escrow.validate_extension_authority(ix.accounts.admin.address(), ix.accounts.extensions, extension_type)?;
+
+    // Enforce the escrow's current governance delay from the time the change was queued
+    let governance_delay = get_governance_delay(ix.accounts.extensions)?.unwrap_or(GovernanceDelayData::new(0));
+    let execute_after = governance_delay.execute_after(pending_change.queued_at)?;
+    if Clock::get()?.unix_timestamp < execute_after {
+        return Err(EscrowProgramError::GovernanceDelayNotElapsed.into());
+    }
+
+    // Apply the change
+    match action {
+        ChangeAction::SetExtension => {
+            let extensions_bump_seed = [ix.data.extensions_bump];
+            let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+            let extensions_seeds_array: [Seed; 3] =
+                extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+            update_or_append_extension(
+                ix.accounts.payer,
+                ix.accounts.extensions,
+                program_id,
+                ix.data.extensions_bump,
+                extension_type,
+                &pending_change.value,
+                extensions_seeds_array,
+            )?;
+        }
+        ChangeAction::RemoveExtension => remove_extension(ix.accounts.extensions, extension_type)?,
+    }
+
+    // Close the PendingChange account and return lamports to rent_recipient
+    close_pda_account(ix.accounts.pending_change, ix.accounts.rent_recipient)?;
+
+    // Emit event
+    let event = ChangeExecutedEvent::new(
+        *ix.accounts.escrow.address(),
+        pending_change.change_id(),
+        pending_change.action,
+        pending_change.extension_type,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
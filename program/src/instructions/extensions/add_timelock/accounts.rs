@@ -12,7 +12,7 @@ use crate::{
 ///
 /// # Account Layout
 /// 0. `[signer, writable]` payer - Pays for account creation/realloc
-/// 1. `[signer]` admin - Must match escrow.admin
+/// 1. `[signer]` admin - Extension manager, or escrow.admin if the role is unassigned
 /// 2. `[]` escrow - Escrow account to add timelock to
 /// 3. `[writable]` extensions - Extensions PDA (created if doesn't exist)
 /// 4. `[]` system_program - System program for account creation
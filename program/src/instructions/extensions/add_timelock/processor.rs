@@ -4,7 +4,10 @@ use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, P
 use crate::{
     events::TimelockAddedEvent,
     instructions::AddTimelock,
-    state::{update_or_append_extension, Escrow, ExtensionType, ExtensionsPda, TimelockData},
+    state::{
+        require_no_governance_delay, update_or_append_extension, Escrow, ExtensionType, ExtensionsPda, Role,
+        TimelockData,
+    },
     traits::{EventSerialize, ExtensionData, PdaSeeds},
     utils::emit_event,
 };
@@ -18,13 +21,18 @@ pub fn process_add_timelock(program_id: &Address, accounts: &[AccountView], inst
     // Read escrow and validate
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
     escrow.require_mutable()?;
 
     // Validate extensions PDA
     let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
     extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
 
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
     // Build extension data
     let timelock = TimelockData::new(ix.data.lock_duration);
     let timelock_bytes = timelock.to_bytes();
@@ -4,7 +4,10 @@ use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, P
 use crate::{
     events::TokenExtensionBlocked,
     instructions::BlockTokenExtension,
-    state::{update_or_append_extension, BlockTokenExtensionsData, Escrow, ExtensionType, ExtensionsPda},
+    state::{
+        require_no_governance_delay, update_or_append_extension, BlockTokenExtensionsData, Escrow, ExtensionType,
+        ExtensionsPda, Role,
+    },
     traits::{EventSerialize, ExtensionData, PdaSeeds},
     utils::{emit_event, TlvReader},
 };
@@ -22,13 +25,18 @@ pub fn process_block_token_extension(
     // Read escrow and validate
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
     escrow.require_mutable()?;
 
     // Validate extensions PDA
     let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
     extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
 
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
     // Get seeds for PDA operations
     let extensions_bump_seed = [ix.data.extensions_bump];
     let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
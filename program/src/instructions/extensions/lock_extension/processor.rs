@@ -0,0 +1,60 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::ExtensionLockedEvent,
+    instructions::LockExtension,
+    state::{get_extension_locks, update_or_append_extension, Escrow, ExtensionType, ExtensionsPda},
+    traits::{EventSerialize, ExtensionData, PdaSeeds},
+    utils::emit_event,
+};
+
+/// Processes the LockExtension instruction.
+///
+/// Permanently freezes one extension: once locked, it can no longer be set, updated or
+/// removed by any instruction. Creates extensions PDA if it doesn't exist. Only the admin
+/// can lock. Allowed on immutable escrows and while a governance delay is set, since a lock
+/// only takes power away.
+pub fn process_lock_extension(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = LockExtension::try_from((instruction_data, accounts))?;
+
+    // Read escrow and validate
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    escrow.validate_admin(ix.accounts.admin.address())?;
+
+    // Validate extensions PDA
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+
+    // Add the lock
+    let extension_type = ExtensionType::try_from(ix.data.extension_type)?;
+    let mut locks = get_extension_locks(ix.accounts.extensions)?;
+    locks.lock(extension_type)?;
+    let locks_bytes = locks.to_bytes();
+
+    // Get seeds and append/update extension
+    let extensions_bump_seed = [ix.data.extensions_bump];
+    let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+    let extensions_seeds_array: [Seed; 3] = extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    update_or_append_extension(
+        ix.accounts.payer,
+        ix.accounts.extensions,
+        program_id,
+        ix.data.extensions_bump,
+        ExtensionType::ExtensionLocks,
+        &locks_bytes,
+        extensions_seeds_array,
+    )?;
+
+    // Emit event
+    let event = ExtensionLockedEvent::new(*ix.accounts.escrow.address(), ix.data.extension_type);
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -1,12 +1,32 @@
 pub mod add_timelock;
 pub mod block_token_extension;
+pub mod lock_extension;
+pub mod lock_mint_list;
 pub mod remove_extension;
 pub mod set_arbiter;
+pub mod set_auto_release;
+pub mod set_cooldown;
+pub mod set_depositor_allowlist;
+pub mod set_governance_delay;
 pub mod set_hook;
+pub mod set_hook_bypass;
+pub mod set_metadata;
+pub mod set_refund_authority;
+pub mod set_roles;
 pub mod unblock_token_extension;
 pub use add_timelock::*;
 pub use block_token_extension::*;
+pub use lock_extension::*;
+pub use lock_mint_list::*;
 pub use remove_extension::*;
 pub use set_arbiter::*;
+pub use set_auto_release::*;
+pub use set_cooldown::*;
+pub use set_depositor_allowlist::*;
+pub use set_governance_delay::*;
 pub use set_hook::*;
+pub use set_hook_bypass::*;
+pub use set_metadata::*;
+pub use set_refund_authority::*;
+pub use set_roles::*;
 pub use unblock_token_extension::*;
@@ -3,14 +3,15 @@ use pinocchio::{account::AccountView, Address, ProgramResult};
 use crate::{
     events::ExtensionRemovedEvent,
     instructions::RemoveExtension,
-    state::{remove_extension, Escrow, ExtensionType, ExtensionsPda},
+    state::{remove_extension, require_no_governance_delay, Escrow, ExtensionType, ExtensionsPda},
     traits::{EventSerialize, PdaSeeds},
     utils::emit_event,
 };
 
 /// Processes the RemoveExtension instruction.
 ///
-/// Removes an existing extension entry from the escrow extensions account.
+/// Removes an existing extension entry from the escrow extensions account. While a governance
+/// delay is set, removals must go through `QueueChange` instead.
 pub fn process_remove_extension(
     program_id: &Address,
     accounts: &[AccountView],
@@ -21,15 +22,18 @@ pub fn process_remove_extension(
     // Read escrow and validate
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
     escrow.require_mutable()?;
 
     // Validate extensions PDA
     let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
     extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
 
-    // Parse extension type and remove matching extension
+    // Parse extension type and validate signer may change it
     let extension_type = ExtensionType::try_from(ix.data.extension_type)?;
+    escrow.validate_extension_authority(ix.accounts.admin.address(), ix.accounts.extensions, extension_type)?;
+    require_no_governance_delay(ix.accounts.extensions)?;
+
+    // Remove matching extension
     remove_extension(ix.accounts.extensions, extension_type)?;
 
     // Emit event
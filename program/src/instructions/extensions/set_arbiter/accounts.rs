@@ -12,7 +12,7 @@ use crate::{
 ///
 /// # Account Layout
 /// 0. `[signer, writable]` payer - Pays for account creation
-/// 1. `[signer]` admin - Must match escrow.admin
+/// 1. `[signer]` admin - Extension manager, or escrow.admin if the role is unassigned
 /// 2. `[signer]` arbiter - Address that must sign future withdrawals
 /// 3. `[]` escrow - Escrow account to set arbiter on
 /// 4. `[writable]` extensions - Extensions PDA (created if doesn't exist)
@@ -0,0 +1,67 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetCooldown
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `cooldown_period` (u64) - Seconds between a withdraw request and its claim
+pub struct SetCooldownData {
+    pub extensions_bump: u8,
+    pub cooldown_period: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetCooldownData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let cooldown_period = u64::from_le_bytes(data[1..9].try_into().unwrap());
+        if cooldown_period > i64::MAX as u64 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { extensions_bump: data[0], cooldown_period })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetCooldownData {
+    const LEN: usize = 1 + 8; // 9 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_cooldown_data_try_from_valid() {
+        let mut data = [0u8; 9];
+        data[0] = 255; // extensions_bump
+        data[1..9].copy_from_slice(&86_400u64.to_le_bytes());
+
+        let result = SetCooldownData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.cooldown_period, 86_400);
+    }
+
+    #[test]
+    fn test_set_cooldown_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetCooldownData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_cooldown_data_try_from_cooldown_period_overflow() {
+        let mut data = [0u8; 9];
+        data[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let result = SetCooldownData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,68 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetDepositorAllowlist
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `merkle_root` ([u8; 32]) - Root of the depositor allowlist Merkle tree
+/// * `per_address_cap` (u64) - Max cumulative deposit per depositor (0 = uncapped)
+pub struct SetDepositorAllowlistData {
+    pub extensions_bump: u8,
+    pub merkle_root: [u8; 32],
+    pub per_address_cap: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetDepositorAllowlistData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self {
+            extensions_bump: data[0],
+            merkle_root: data[1..33].try_into().unwrap(),
+            per_address_cap: u64::from_le_bytes(data[33..41].try_into().unwrap()),
+        })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetDepositorAllowlistData {
+    const LEN: usize = 1 + 32 + 8; // 41 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_depositor_allowlist_data_try_from_valid() {
+        let mut data = [0u8; 41];
+        data[0] = 255; // extensions_bump
+        data[1..33].copy_from_slice(&[7u8; 32]);
+        data[33..41].copy_from_slice(&5_000u64.to_le_bytes());
+
+        let result = SetDepositorAllowlistData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.merkle_root, [7u8; 32]);
+        assert_eq!(parsed.per_address_cap, 5_000);
+    }
+
+    #[test]
+    fn test_set_depositor_allowlist_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetDepositorAllowlistData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_depositor_allowlist_data_try_from_too_short() {
+        let data = [0u8; 40];
+        let result = SetDepositorAllowlistData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
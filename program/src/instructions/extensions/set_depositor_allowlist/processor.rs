@@ -0,0 +1,65 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::DepositorAllowlistSetEvent,
+    instructions::SetDepositorAllowlist,
+    state::{
+        require_no_governance_delay, update_or_append_extension, DepositorAllowlistData, Escrow, ExtensionType,
+        ExtensionsPda, Role,
+    },
+    traits::{EventSerialize, ExtensionData, PdaSeeds},
+    utils::emit_event,
+};
+
+/// Processes the SetDepositorAllowlist instruction.
+///
+/// Sets or rotates the depositor allowlist root and per-address cap. Creates extensions PDA if it doesn't exist.
+pub fn process_set_depositor_allowlist(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetDepositorAllowlist::try_from((instruction_data, accounts))?;
+
+    // Read escrow and validate
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    escrow.require_mutable()?;
+
+    // Validate extensions PDA
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
+    // Build extension data
+    let allowlist = DepositorAllowlistData::new(ix.data.merkle_root, ix.data.per_address_cap);
+    let allowlist_bytes = allowlist.to_bytes();
+
+    // Get seeds and append/update extension
+    let extensions_bump_seed = [ix.data.extensions_bump];
+    let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+    let extensions_seeds_array: [Seed; 3] = extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    update_or_append_extension(
+        ix.accounts.payer,
+        ix.accounts.extensions,
+        program_id,
+        ix.data.extensions_bump,
+        ExtensionType::DepositorAllowlist,
+        &allowlist_bytes,
+        extensions_seeds_array,
+    )?;
+
+    // Emit event
+    let event =
+        DepositorAllowlistSetEvent::new(*ix.accounts.escrow.address(), ix.data.merkle_root, ix.data.per_address_cap);
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
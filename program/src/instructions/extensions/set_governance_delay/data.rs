@@ -0,0 +1,67 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetGovernanceDelay
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `delay` (u64) - Seconds a queued extension change must wait before it can be executed
+pub struct SetGovernanceDelayData {
+    pub extensions_bump: u8,
+    pub delay: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetGovernanceDelayData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let delay = u64::from_le_bytes(data[1..9].try_into().unwrap());
+        if delay > i64::MAX as u64 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { extensions_bump: data[0], delay })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetGovernanceDelayData {
+    const LEN: usize = 1 + 8; // 9 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_governance_delay_data_try_from_valid() {
+        let mut data = [0u8; 9];
+        data[0] = 255; // extensions_bump
+        data[1..9].copy_from_slice(&172_800u64.to_le_bytes());
+
+        let result = SetGovernanceDelayData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.delay, 172_800);
+    }
+
+    #[test]
+    fn test_set_governance_delay_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetGovernanceDelayData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_governance_delay_data_try_from_delay_overflow() {
+        let mut data = [0u8; 9];
+        data[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let result = SetGovernanceDelayData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
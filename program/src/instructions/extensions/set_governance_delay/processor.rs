@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::GovernanceDelaySetEvent,
+    instructions::SetGovernanceDelay,
+    state::{
+        require_no_governance_delay, update_or_append_extension, Escrow, ExtensionType, ExtensionsPda,
+        GovernanceDelayData,
+    },
+    traits::{EventSerialize, ExtensionData, PdaSeeds},
+    utils::emit_event,
+};
+
+/// Processes the SetGovernanceDelay instruction.
+///
+/// Sets the governance delay on an escrow. Creates extensions PDA if it doesn't exist.
+/// Only the admin can set the delay, and only while none is set: once in place, the delay
+/// itself can only be changed or removed through a queued change.
+pub fn process_set_governance_delay(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetGovernanceDelay::try_from((instruction_data, accounts))?;
+
+    // Read escrow and validate
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    escrow.require_mutable()?;
+    escrow.validate_admin(ix.accounts.admin.address())?;
+
+    // Validate extensions PDA
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
+    // Build extension data
+    let governance_delay = GovernanceDelayData::new(ix.data.delay);
+    let governance_delay_bytes = governance_delay.to_bytes();
+
+    // Get seeds and append/update extension
+    let extensions_bump_seed = [ix.data.extensions_bump];
+    let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+    let extensions_seeds_array: [Seed; 3] = extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    update_or_append_extension(
+        ix.accounts.payer,
+        ix.accounts.extensions,
+        program_id,
+        ix.data.extensions_bump,
+        ExtensionType::GovernanceDelay,
+        &governance_delay_bytes,
+        extensions_seeds_array,
+    )?;
+
+    // Emit event
+    let event = GovernanceDelaySetEvent::new(*ix.accounts.escrow.address(), ix.data.delay);
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
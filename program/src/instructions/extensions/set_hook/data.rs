@@ -1,15 +1,17 @@
 use pinocchio::{error::ProgramError, Address};
 
-use crate::{require_len, traits::InstructionData};
+use crate::{require_len, state::HookData, traits::InstructionData};
 
 /// Instruction data for SetHook
 ///
 /// # Layout
 /// * `extensions_bump` (u8) - Bump for extensions PDA
 /// * `hook_program` (Address) - Hook program address (system_program = disabled)
+/// * `flags` (u8) - Hook configuration flags (`HookData::FLAG_*`)
 pub struct SetHookData {
     pub extensions_bump: u8,
     pub hook_program: Address,
+    pub flags: u8,
 }
 
 impl<'a> TryFrom<&'a [u8]> for SetHookData {
@@ -19,12 +21,18 @@ impl<'a> TryFrom<&'a [u8]> for SetHookData {
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
         require_len!(data, Self::LEN);
 
-        Ok(Self { extensions_bump: data[0], hook_program: Address::new_from_array(data[1..33].try_into().unwrap()) })
+        let hook_program = Address::new_from_array(data[1..33].try_into().unwrap());
+        let flags = data[33];
+        if !HookData::new(hook_program, flags).is_valid() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { extensions_bump: data[0], hook_program, flags })
     }
 }
 
 impl<'a> InstructionData<'a> for SetHookData {
-    const LEN: usize = 1 + 32; // 33 bytes
+    const LEN: usize = 1 + 32 + 1; // 34 bytes
 }
 
 #[cfg(test)]
@@ -33,15 +41,42 @@ mod tests {
 
     #[test]
     fn test_set_hook_data_try_from_valid() {
-        let mut data = [0u8; 33];
+        let mut data = [0u8; 34];
         data[0] = 255; // extensions_bump
         data[1..33].copy_from_slice(&[1u8; 32]); // hook_program
+        data[33] = HookData::FLAG_WRITABLE_HOOK_ACCOUNTS; // flags
 
         let result = SetHookData::try_from(&data[..]);
         assert!(result.is_ok());
         let parsed = result.unwrap();
         assert_eq!(parsed.extensions_bump, 255);
         assert_eq!(parsed.hook_program, Address::new_from_array([1u8; 32]));
+        assert_eq!(parsed.flags, HookData::FLAG_WRITABLE_HOOK_ACCOUNTS);
+    }
+
+    #[test]
+    fn test_set_hook_data_try_from_unknown_flags() {
+        let mut data = [0u8; 34];
+        data[33] = 0b100;
+
+        let result = SetHookData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_hook_data_try_from_escrow_program() {
+        let mut data = [0u8; 34];
+        data[1..33].copy_from_slice(crate::ID.as_ref());
+
+        let result = SetHookData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_hook_data_try_from_missing_flags() {
+        let data = [0u8; 33];
+        let result = SetHookData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
     }
 
     #[test]
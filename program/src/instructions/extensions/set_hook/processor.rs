@@ -4,7 +4,9 @@ use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, P
 use crate::{
     events::HookSetEvent,
     instructions::SetHook,
-    state::{update_or_append_extension, Escrow, ExtensionType, ExtensionsPda, HookData},
+    state::{
+        require_no_governance_delay, update_or_append_extension, Escrow, ExtensionType, ExtensionsPda, HookData, Role,
+    },
     traits::{EventSerialize, ExtensionData, PdaSeeds},
     utils::emit_event,
 };
@@ -18,15 +20,20 @@ pub fn process_set_hook(program_id: &Address, accounts: &[AccountView], instruct
     // Read escrow and validate
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
     escrow.require_mutable()?;
 
     // Validate extensions PDA
     let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
     extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
 
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
     // Build extension data
-    let hook = HookData::new(ix.data.hook_program);
+    let hook = HookData::new(ix.data.hook_program, ix.data.flags);
     let hook_bytes = hook.to_bytes();
 
     // Get seeds and append/update extension
@@ -45,7 +52,7 @@ pub fn process_set_hook(program_id: &Address, accounts: &[AccountView], instruct
     )?;
 
     // Emit event
-    let event = HookSetEvent::new(*ix.accounts.escrow.address(), ix.data.hook_program);
+    let event = HookSetEvent::new(*ix.accounts.escrow.address(), ix.data.hook_program, ix.data.flags);
     emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
 
     Ok(())
@@ -0,0 +1,76 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, state::HookBypassData, traits::InstructionData};
+
+/// Instruction data for SetHookBypass
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `grace_period` (u64) - Seconds a depositor must wait after requesting a bypass (at least `HookBypassData::MIN_GRACE_PERIOD`)
+pub struct SetHookBypassData {
+    pub extensions_bump: u8,
+    pub grace_period: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetHookBypassData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let grace_period = u64::from_le_bytes(data[1..9].try_into().unwrap());
+        if !HookBypassData::new(grace_period).is_valid() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { extensions_bump: data[0], grace_period })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetHookBypassData {
+    const LEN: usize = 1 + 8; // 9 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_hook_bypass_data_try_from_valid() {
+        let mut data = [0u8; 9];
+        data[0] = 255; // extensions_bump
+        data[1..9].copy_from_slice(&86_400u64.to_le_bytes());
+
+        let result = SetHookBypassData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.grace_period, 86_400);
+    }
+
+    #[test]
+    fn test_set_hook_bypass_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetHookBypassData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_hook_bypass_data_try_from_grace_period_below_minimum() {
+        let mut data = [0u8; 9];
+        data[1..9].copy_from_slice(&(HookBypassData::MIN_GRACE_PERIOD - 1).to_le_bytes());
+
+        let result = SetHookBypassData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_hook_bypass_data_try_from_grace_period_overflow() {
+        let mut data = [0u8; 9];
+        data[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+
+        let result = SetHookBypassData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
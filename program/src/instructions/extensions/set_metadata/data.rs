@@ -0,0 +1,75 @@
+use pinocchio::error::ProgramError;
+
+use crate::{
+    require_len,
+    state::MetadataData,
+    traits::{ExtensionData, InstructionData},
+};
+
+/// Instruction data for SetMetadata
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `name` (u32 length + UTF-8 bytes) - Display name
+/// * `uri` (u32 length + UTF-8 bytes) - URI of off-chain metadata
+/// * `external_reference` (u32 length + bytes) - Opaque reference such as a deal ID
+pub struct SetMetadataData {
+    pub extensions_bump: u8,
+    pub metadata: MetadataData,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetMetadataData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { extensions_bump: data[0], metadata: MetadataData::from_bytes(&data[1..])? })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetMetadataData {
+    const LEN: usize = 1 + 4 + 4 + 4; // extensions_bump + name, uri and external_reference lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    fn build_data(metadata: &MetadataData) -> Vec<u8> {
+        let mut data = vec![255]; // extensions_bump
+        data.extend_from_slice(&metadata.to_bytes());
+        data
+    }
+
+    #[test]
+    fn test_set_metadata_data_try_from_valid() {
+        let metadata = MetadataData::new("Escrow", "https://example.com/escrow.json", b"deal-7").unwrap();
+        let data = build_data(&metadata);
+
+        let result = SetMetadataData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.metadata, metadata);
+    }
+
+    #[test]
+    fn test_set_metadata_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetMetadataData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_metadata_data_try_from_truncated_field() {
+        let metadata = MetadataData::new("Escrow", "", &[]).unwrap();
+        let data = build_data(&metadata);
+
+        let result = SetMetadataData::try_from(&data[..data.len() - 1]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
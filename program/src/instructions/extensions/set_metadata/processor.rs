@@ -0,0 +1,51 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
+
+use crate::{
+    events::MetadataSetEvent,
+    instructions::SetMetadata,
+    state::{update_or_append_extension, Escrow, ExtensionType, ExtensionsPda, Role},
+    traits::{EventSerialize, ExtensionData, PdaSeeds},
+    utils::emit_event,
+};
+
+/// Processes the SetMetadata instruction.
+///
+/// Sets the descriptive metadata of an escrow, replacing any previous value. Creates extensions
+/// PDA if it doesn't exist. Metadata is never read by the program, so it can be changed on
+/// immutable escrows and without waiting out a governance delay; `LockExtension` freezes it.
+pub fn process_set_metadata(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = SetMetadata::try_from((instruction_data, accounts))?;
+
+    // Read escrow
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+
+    // Validate extensions PDA
+    let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
+    extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
+
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Get seeds and append/update extension
+    let extensions_bump_seed = [ix.data.extensions_bump];
+    let extensions_seeds: Vec<Seed> = extensions_pda.seeds_with_bump(&extensions_bump_seed);
+    let extensions_seeds_array: [Seed; 3] = extensions_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    update_or_append_extension(
+        ix.accounts.payer,
+        ix.accounts.extensions,
+        program_id,
+        ix.data.extensions_bump,
+        ExtensionType::Metadata,
+        &ix.data.metadata.to_bytes(),
+        extensions_seeds_array,
+    )?;
+
+    // Emit event
+    let event = MetadataSetEvent::new(*ix.accounts.escrow.address(), &ix.data.metadata);
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, state::RefundAuthorityData, traits::InstructionData};
+
+/// Instruction data for SetRefundAuthority
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `flags` (u8) - Authorities allowed to force a refund (see `RefundAuthorityData::FLAG_*`)
+pub struct SetRefundAuthorityData {
+    pub extensions_bump: u8,
+    pub flags: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetRefundAuthorityData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let flags = data[1];
+        if !RefundAuthorityData::new(flags).is_valid() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { extensions_bump: data[0], flags })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetRefundAuthorityData {
+    const LEN: usize = 1 + 1; // 2 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_refund_authority_data_try_from_valid() {
+        let data = [255u8, RefundAuthorityData::FLAG_ADMIN | RefundAuthorityData::FLAG_ARBITER];
+
+        let result = SetRefundAuthorityData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.flags, RefundAuthorityData::VALID_FLAGS);
+    }
+
+    #[test]
+    fn test_set_refund_authority_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = SetRefundAuthorityData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_refund_authority_data_try_from_no_authority() {
+        let data = [255u8, 0];
+        let result = SetRefundAuthorityData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_refund_authority_data_try_from_unknown_flags() {
+        let data = [255u8, 0b100];
+        let result = SetRefundAuthorityData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
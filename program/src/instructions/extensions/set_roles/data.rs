@@ -0,0 +1,66 @@
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetRoles
+///
+/// # Layout
+/// * `extensions_bump` (u8) - Bump for extensions PDA
+/// * `mint_manager` (Address) - Holder of the mint manager role (default address = admin)
+/// * `extension_manager` (Address) - Holder of the extension manager role (default address = admin)
+/// * `admin_transfer_authority` (Address) - Holder of the admin-transfer role (default address = admin)
+pub struct SetRolesData {
+    pub extensions_bump: u8,
+    pub mint_manager: Address,
+    pub extension_manager: Address,
+    pub admin_transfer_authority: Address,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetRolesData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self {
+            extensions_bump: data[0],
+            mint_manager: Address::new_from_array(data[1..33].try_into().unwrap()),
+            extension_manager: Address::new_from_array(data[33..65].try_into().unwrap()),
+            admin_transfer_authority: Address::new_from_array(data[65..97].try_into().unwrap()),
+        })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetRolesData {
+    const LEN: usize = 1 + 32 + 32 + 32; // 97 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_roles_data_try_from_valid() {
+        let mut data = [0u8; 97];
+        data[0] = 255; // extensions_bump
+        data[1..33].copy_from_slice(&[1u8; 32]); // mint_manager
+        data[33..65].copy_from_slice(&[2u8; 32]); // extension_manager
+        data[65..97].copy_from_slice(&[3u8; 32]); // admin_transfer_authority
+
+        let result = SetRolesData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.extensions_bump, 255);
+        assert_eq!(parsed.mint_manager, Address::new_from_array([1u8; 32]));
+        assert_eq!(parsed.extension_manager, Address::new_from_array([2u8; 32]));
+        assert_eq!(parsed.admin_transfer_authority, Address::new_from_array([3u8; 32]));
+    }
+
+    #[test]
+    fn test_set_roles_data_try_from_truncated() {
+        let data = [0u8; 96];
+        let result = SetRolesData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
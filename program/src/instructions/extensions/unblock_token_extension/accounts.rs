@@ -12,7 +12,7 @@ use crate::{
 ///
 /// # Account Layout
 /// 0. `[signer, writable]` payer - Included for consistency with extension mutation flows
-/// 1. `[signer]` admin - Must match escrow.admin
+/// 1. `[signer]` admin - Extension manager, or escrow.admin if the role is unassigned
 /// 2. `[]` escrow - Escrow account to unblock extension on
 /// 3. `[writable]` extensions - Extensions PDA
 /// 4. `[]` system_program - System program
@@ -4,7 +4,9 @@ use crate::{
     errors::EscrowProgramError,
     events::TokenExtensionUnblocked,
     instructions::UnblockTokenExtension,
-    state::{remove_extension, update_extension, Escrow, ExtensionType, ExtensionsPda},
+    state::{
+        remove_extension, require_no_governance_delay, update_extension, Escrow, ExtensionType, ExtensionsPda, Role,
+    },
     traits::{EventSerialize, ExtensionData, PdaSeeds},
     utils::{emit_event, TlvReader},
 };
@@ -23,13 +25,18 @@ pub fn process_unblock_token_extension(
     // Read escrow and validate
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
     escrow.require_mutable()?;
 
     // Validate extensions PDA
     let extensions_pda = ExtensionsPda::new(ix.accounts.escrow.address());
     extensions_pda.validate_pda(ix.accounts.extensions, program_id, ix.data.extensions_bump)?;
 
+    // Validate signer holds the extension manager role
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::ExtensionManager)?;
+
+    // Extension changes must be queued while a governance delay is set
+    require_no_governance_delay(ix.accounts.extensions)?;
+
     // Read existing BlockedTokenExtensions data if present
     let mut blocked_token_extensions = {
         if ix.accounts.extensions.data_len() == 0 {
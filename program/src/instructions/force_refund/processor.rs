@@ -0,0 +1,190 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    errors::EscrowProgramError,
+    events::ForceRefundEvent,
+    instructions::ForceRefund,
+    state::{
+        get_extensions_from_account, release_from_allowed_mint, split_hook_bypass_request, validate_extensions_pda,
+        AllowedMint, ArbiterData, Escrow, ExtensionType, HookData, HookPoint, Receipt, RefundAuthorityData,
+    },
+    traits::{AccountDeserialize, EventSerialize, ExtensionData},
+    utils::{close_pda_account, emit_event, get_mint_decimals, validate_associated_token_account},
+};
+
+/// Processes the ForceRefund instruction.
+///
+/// Returns a receipt's tokens to the depositor's ATA and closes it, ignoring the timelock.
+/// Requires the refund authority extension; the signer must be the admin or the arbiter that
+/// applies to the mint, as the extension allows. The hook still sees the refund as a withdrawal.
+pub fn process_force_refund(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = ForceRefund::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    let admin = {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+        escrow.admin
+    };
+
+    // Read and validate receipt
+    let receipt = {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+
+        // Verify the receipt is for this escrow and depositor, so funds only go back to it
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+
+        // Requested receipts can only be paid out by ClaimWithdraw
+        receipt.require_no_withdraw_request()?;
+
+        // Rent goes back to whoever the deposit's payer designated. Legacy receipts have no
+        // recorded recipient, so only the depositor may be refunded.
+        if receipt.is_legacy() {
+            if ix.accounts.rent_recipient.address() != ix.accounts.depositor.address() {
+                return Err(EscrowProgramError::InvalidRentRecipient.into());
+            }
+        } else {
+            receipt.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+        }
+
+        receipt
+    };
+    let (amount, nonce, receipt_mint) = (receipt.amount, receipt.nonce(), receipt.mint);
+
+    // Ensure the mint account matches the receipt's mint to prevent cross-mint refunds.
+    if receipt_mint != *ix.accounts.mint.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The refund does not honour a hook bypass, but closes the receipt's request with it
+    let (hook_bypass_request, hook_accounts) =
+        split_hook_bypass_request(program_id, ix.accounts.remaining_accounts, ix.accounts.receipt.address())?;
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Get hook, arbiter, and refund authority extensions in single pass
+    let ext_types = [ExtensionType::Hook, ExtensionType::Arbiter, ExtensionType::RefundAuthority];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Per-mint overrides take precedence. A blocked mint has no AllowedMint account left and
+    // falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
+
+    let refund_authority_bytes = exts[2].as_ref().ok_or(EscrowProgramError::ForceRefundNotEnabled)?;
+    let refund_authority = RefundAuthorityData::from_bytes(refund_authority_bytes)?;
+
+    // The signer must be an authority the escrow opted into
+    let arbiter = exts[1].as_ref().map(|b| ArbiterData::from_bytes(b)).transpose()?;
+    if !refund_authority.is_authority(
+        ix.accounts.authority.address(),
+        &admin,
+        arbiter.as_ref().map(|arbiter| &arbiter.arbiter),
+    ) {
+        return Err(EscrowProgramError::InvalidRefundAuthority.into());
+    }
+
+    // Parse hook if present
+    let hook_data = exts[0].as_ref().map(|b| HookData::from_bytes(b)).transpose()?;
+
+    // Invoke pre-withdraw hook if configured
+    if let Some(ref hook) = hook_data {
+        hook.invoke(
+            HookPoint::PreWithdraw,
+            hook_accounts,
+            &[ix.accounts.escrow, ix.accounts.authority, ix.accounts.mint, ix.accounts.receipt],
+        )?;
+    }
+
+    // Create the depositor ATA if it does not exist yet, otherwise check it is a live token account
+    if ix.accounts.depositor_token_account.is_data_empty() {
+        CreateIdempotent {
+            funding_account: ix.accounts.payer,
+            account: ix.accounts.depositor_token_account,
+            wallet: ix.accounts.depositor,
+            mint: ix.accounts.mint,
+            system_program: ix.accounts.system_program,
+            token_program: ix.accounts.token_program,
+        }
+        .invoke()?;
+    } else {
+        validate_associated_token_account(
+            ix.accounts.depositor_token_account,
+            ix.accounts.depositor.address(),
+            ix.accounts.mint,
+            ix.accounts.token_program,
+        )?;
+    }
+
+    // Transfer tokens from vault to depositor using escrow PDA as signer
+    let decimals = get_mint_decimals(ix.accounts.mint)?;
+
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_bytes(&escrow_data)?;
+        escrow.with_signer(|signers| {
+            TransferChecked {
+                from: ix.accounts.vault,
+                mint: ix.accounts.mint,
+                to: ix.accounts.depositor_token_account,
+                authority: ix.accounts.escrow,
+                amount,
+                decimals,
+                token_program: ix.accounts.token_program.address(),
+            }
+            .invoke_signed(signers)
+        })?;
+    }
+
+    // Invoke post-withdraw hook if configured (receipt is still open, pass it for context)
+    if let Some(ref hook) = hook_data {
+        hook.invoke(
+            HookPoint::PostWithdraw,
+            hook_accounts,
+            &[ix.accounts.escrow, ix.accounts.authority, ix.accounts.mint, ix.accounts.receipt],
+        )?;
+    }
+
+    // Release the amount from the mint's running total
+    release_from_allowed_mint(
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+        receipt.tracked_amount(),
+    )?;
+
+    // Close receipt account and return lamports to rent_recipient
+    close_pda_account(ix.accounts.receipt, ix.accounts.rent_recipient)?;
+
+    // Close the orphaned hook bypass request alongside the receipt
+    if let Some(request_account) = hook_bypass_request {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Emit event
+    let event = ForceRefundEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.authority.address(),
+        receipt_mint,
+        nonce,
+        amount,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -1,31 +1,89 @@
 use crate::define_instruction;
 
 use super::allow_mint::{AllowMintAccounts, AllowMintData};
+use super::approve_withdraw_delegate::{ApproveWithdrawDelegateAccounts, ApproveWithdrawDelegateData};
 use super::block_mint::{BlockMintAccounts, BlockMintData};
+use super::cancel_queued_change::{CancelQueuedChangeAccounts, CancelQueuedChangeData};
+use super::claim_withdraw::{ClaimWithdrawAccounts, ClaimWithdrawData};
 use super::create_escrow::{CreateEscrowAccounts, CreateEscrowData};
+use super::create_escrow_with_config::{CreateEscrowWithConfigAccounts, CreateEscrowWithConfigData};
+use super::create_escrow_with_seed::{CreateEscrowWithSeedAccounts, CreateEscrowWithSeedData};
 use super::deposit::{DepositAccounts, DepositData};
+use super::deposit_basket::{DepositBasketAccounts, DepositBasketData};
+use super::execute_queued_change::{ExecuteQueuedChangeAccounts, ExecuteQueuedChangeData};
 use super::extensions::{
     add_timelock::{AddTimelockAccounts, AddTimelockData},
     block_token_extension::{BlockTokenExtensionAccounts, BlockTokenExtensionData},
+    lock_extension::{LockExtensionAccounts, LockExtensionData},
+    lock_mint_list::{LockMintListAccounts, LockMintListData},
     remove_extension::{RemoveExtensionAccounts, RemoveExtensionData},
     set_arbiter::{SetArbiterAccounts, SetArbiterData},
+    set_auto_release::{SetAutoReleaseAccounts, SetAutoReleaseData},
+    set_cooldown::{SetCooldownAccounts, SetCooldownData},
+    set_depositor_allowlist::{SetDepositorAllowlistAccounts, SetDepositorAllowlistData},
+    set_governance_delay::{SetGovernanceDelayAccounts, SetGovernanceDelayData},
     set_hook::{SetHookAccounts, SetHookData},
+    set_hook_bypass::{SetHookBypassAccounts, SetHookBypassData},
+    set_metadata::{SetMetadataAccounts, SetMetadataData},
+    set_refund_authority::{SetRefundAuthorityAccounts, SetRefundAuthorityData},
+    set_roles::{SetRolesAccounts, SetRolesData},
     unblock_token_extension::{UnblockTokenExtensionAccounts, UnblockTokenExtensionData},
 };
+use super::force_refund::{ForceRefundAccounts, ForceRefundData};
+use super::queue_change::{QueueChangeAccounts, QueueChangeData};
+use super::release_matured::{ReleaseMaturedAccounts, ReleaseMaturedData};
+use super::request_hook_bypass::{RequestHookBypassAccounts, RequestHookBypassData};
+use super::request_withdraw::{RequestWithdrawAccounts, RequestWithdrawData};
+use super::revoke_withdraw_delegate::{RevokeWithdrawDelegateAccounts, RevokeWithdrawDelegateData};
 use super::set_immutable::{SetImmutableAccounts, SetImmutableData};
+use super::set_mint_limits::{SetMintLimitsAccounts, SetMintLimitsData};
+use super::set_mint_override::{SetMintOverrideAccounts, SetMintOverrideData};
+use super::sweep_surplus::{SweepSurplusAccounts, SweepSurplusData};
 use super::update_admin::{UpdateAdminAccounts, UpdateAdminData};
+use super::verify_solvency::{VerifySolvencyAccounts, VerifySolvencyData};
 use super::withdraw::{WithdrawAccounts, WithdrawData};
+use super::withdraw_basket::{WithdrawBasketAccounts, WithdrawBasketData};
+use super::withdraw_many::{WithdrawManyAccounts, WithdrawManyData};
 
 define_instruction!(AllowMint, AllowMintAccounts, AllowMintData);
+define_instruction!(ApproveWithdrawDelegate, ApproveWithdrawDelegateAccounts, ApproveWithdrawDelegateData);
 define_instruction!(BlockMint, BlockMintAccounts, BlockMintData);
+define_instruction!(CancelQueuedChange, CancelQueuedChangeAccounts, CancelQueuedChangeData);
 define_instruction!(CreateEscrow, CreateEscrowAccounts, CreateEscrowData);
+define_instruction!(CreateEscrowWithConfig, CreateEscrowWithConfigAccounts, CreateEscrowWithConfigData);
+define_instruction!(CreateEscrowWithSeed, CreateEscrowWithSeedAccounts, CreateEscrowWithSeedData);
 define_instruction!(Deposit, DepositAccounts, DepositData);
+define_instruction!(ExecuteQueuedChange, ExecuteQueuedChangeAccounts, ExecuteQueuedChangeData);
 define_instruction!(AddTimelock, AddTimelockAccounts, AddTimelockData);
 define_instruction!(BlockTokenExtension, BlockTokenExtensionAccounts, BlockTokenExtensionData);
+define_instruction!(LockExtension, LockExtensionAccounts, LockExtensionData);
+define_instruction!(LockMintList, LockMintListAccounts, LockMintListData);
 define_instruction!(RemoveExtension, RemoveExtensionAccounts, RemoveExtensionData);
 define_instruction!(SetArbiter, SetArbiterAccounts, SetArbiterData);
+define_instruction!(SetDepositorAllowlist, SetDepositorAllowlistAccounts, SetDepositorAllowlistData);
+define_instruction!(SetGovernanceDelay, SetGovernanceDelayAccounts, SetGovernanceDelayData);
 define_instruction!(SetHook, SetHookAccounts, SetHookData);
+define_instruction!(SetHookBypass, SetHookBypassAccounts, SetHookBypassData);
+define_instruction!(SetMetadata, SetMetadataAccounts, SetMetadataData);
+define_instruction!(SetRoles, SetRolesAccounts, SetRolesData);
 define_instruction!(UnblockTokenExtension, UnblockTokenExtensionAccounts, UnblockTokenExtensionData);
+define_instruction!(QueueChange, QueueChangeAccounts, QueueChangeData);
+define_instruction!(RequestHookBypass, RequestHookBypassAccounts, RequestHookBypassData);
+define_instruction!(RevokeWithdrawDelegate, RevokeWithdrawDelegateAccounts, RevokeWithdrawDelegateData);
+define_instruction!(SetAutoRelease, SetAutoReleaseAccounts, SetAutoReleaseData);
+define_instruction!(ReleaseMatured, ReleaseMaturedAccounts, ReleaseMaturedData);
+define_instruction!(WithdrawMany, WithdrawManyAccounts, WithdrawManyData);
+define_instruction!(DepositBasket, DepositBasketAccounts, DepositBasketData);
+define_instruction!(WithdrawBasket, WithdrawBasketAccounts, WithdrawBasketData);
+define_instruction!(SetRefundAuthority, SetRefundAuthorityAccounts, SetRefundAuthorityData);
+define_instruction!(ForceRefund, ForceRefundAccounts, ForceRefundData);
+define_instruction!(SetCooldown, SetCooldownAccounts, SetCooldownData);
+define_instruction!(RequestWithdraw, RequestWithdrawAccounts, RequestWithdrawData);
+define_instruction!(ClaimWithdraw, ClaimWithdrawAccounts, ClaimWithdrawData);
 define_instruction!(SetImmutable, SetImmutableAccounts, SetImmutableData);
+define_instruction!(SetMintLimits, SetMintLimitsAccounts, SetMintLimitsData);
+define_instruction!(SetMintOverride, SetMintOverrideAccounts, SetMintOverrideData);
+define_instruction!(SweepSurplus, SweepSurplusAccounts, SweepSurplusData);
 define_instruction!(UpdateAdmin, UpdateAdminAccounts, UpdateAdminData);
+define_instruction!(VerifySolvency, VerifySolvencyAccounts, VerifySolvencyData);
 define_instruction!(Withdraw, WithdrawAccounts, WithdrawData);
@@ -1,24 +1,62 @@
 pub mod allow_mint;
+pub mod approve_withdraw_delegate;
 pub mod block_mint;
+pub mod cancel_queued_change;
+pub mod claim_withdraw;
 pub mod create_escrow;
+pub mod create_escrow_with_config;
+pub mod create_escrow_with_seed;
 pub mod definition;
 pub mod deposit;
+pub mod deposit_basket;
 pub mod emit_event;
+pub mod execute_queued_change;
 pub mod extensions;
+pub mod force_refund;
 pub mod impl_instructions;
+pub mod queue_change;
+pub mod release_matured;
+pub mod request_hook_bypass;
+pub mod request_withdraw;
+pub mod revoke_withdraw_delegate;
 pub mod set_immutable;
+pub mod set_mint_limits;
+pub mod set_mint_override;
+pub mod sweep_surplus;
 pub mod update_admin;
+pub mod verify_solvency;
 pub mod withdraw;
+pub mod withdraw_basket;
+pub mod withdraw_many;
 
 pub use allow_mint::*;
+pub use approve_withdraw_delegate::*;
 pub use block_mint::*;
+pub use cancel_queued_change::*;
+pub use claim_withdraw::*;
 pub use create_escrow::*;
+pub use create_escrow_with_config::*;
+pub use create_escrow_with_seed::*;
 #[cfg(feature = "idl")]
 pub use definition::*;
 pub use deposit::*;
+pub use deposit_basket::*;
 pub use emit_event::*;
+pub use execute_queued_change::*;
 pub use extensions::*;
+pub use force_refund::*;
 pub use impl_instructions::*;
+pub use queue_change::*;
+pub use release_matured::*;
+pub use request_hook_bypass::*;
+pub use request_withdraw::*;
+pub use revoke_withdraw_delegate::*;
 pub use set_immutable::*;
+pub use set_mint_limits::*;
+pub use set_mint_override::*;
+pub use sweep_surplus::*;
 pub use update_admin::*;
+pub use verify_solvency::*;
 pub use withdraw::*;
+pub use withdraw_basket::*;
+pub use withdraw_many::*;
@@ -0,0 +1,81 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the QueueChange instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for the pending change account
+/// 1. `[signer]` admin - Authority for the changed extension (escrow.admin or the extension manager)
+/// 2. `[]` escrow - Escrow the change applies to
+/// 3. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
+/// 4. `[writable]` pending_change - PendingChange PDA to create `[b"pending_change", escrow, change_id]`
+/// 5. `[]` system_program - System program for account creation
+/// 6. `[]` event_authority - Event authority PDA
+/// 7. `[]` escrow_program - Current program
+///
+/// # Remaining Accounts
+/// 1. `[signer]` arbiter - Only when setting the arbiter extension; must match the new arbiter
+pub struct QueueChangeAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub admin: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub pending_change: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for QueueChangeAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, admin, escrow, extensions, pending_change, system_program, event_authority, escrow_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(pending_change, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+
+        Ok(Self {
+            payer,
+            admin,
+            escrow,
+            extensions,
+            pending_change,
+            system_program,
+            event_authority,
+            escrow_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for QueueChangeAccounts<'a> {}
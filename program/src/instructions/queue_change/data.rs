@@ -0,0 +1,143 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{
+    instructions::EscrowConfig,
+    require_len,
+    state::{ChangeAction, ExtensionType},
+    traits::InstructionData,
+};
+
+/// Instruction data for QueueChange
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the pending change PDA
+/// * `change_id` (u64) - Caller-chosen id, unique among the escrow's pending changes
+/// * `action` (u8) - `0` sets the extension to `value`, `1` removes it
+/// * `extension_type` (u16) - Extension the change applies to
+/// * `value` (u32 length + bytes) - Extension data as its setter instruction would write it (empty for removals)
+pub struct QueueChangeData {
+    pub bump: u8,
+    pub change_id: u64,
+    pub action: ChangeAction,
+    pub extension_type: ExtensionType,
+    pub value: Vec<u8>,
+    /// `value` decoded and validated; empty for removals
+    pub config: EscrowConfig,
+}
+
+impl<'a> TryFrom<&'a [u8]> for QueueChangeData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let bump = data[0];
+        let change_id = u64::from_le_bytes(data[1..9].try_into().unwrap());
+        let action = ChangeAction::try_from(data[9])?;
+        let extension_type = ExtensionType::try_from(u16::from_le_bytes([data[10], data[11]]))
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        let value_len = u32::from_le_bytes(data[12..16].try_into().unwrap()) as usize;
+        let value = data.get(16..16 + value_len).ok_or(ProgramError::InvalidInstructionData)?;
+
+        let mut config = EscrowConfig::default();
+        match action {
+            ChangeAction::SetExtension => config.insert(extension_type, value)?,
+            ChangeAction::RemoveExtension if !value.is_empty() => return Err(ProgramError::InvalidInstructionData),
+            ChangeAction::RemoveExtension => {}
+        }
+
+        Ok(Self { bump, change_id, action, extension_type, value: value.to_vec(), config })
+    }
+}
+
+impl<'a> InstructionData<'a> for QueueChangeData {
+    const LEN: usize = 1 + 8 + 1 + 2 + 4; // bump + change_id + action + extension_type + value length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{state::TimelockData, traits::ExtensionData};
+    use alloc::vec;
+
+    fn build_data(action: u8, extension_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut data = vec![254];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.push(action);
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value);
+        data
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_set() {
+        let value = TimelockData::new(3600).to_bytes();
+        let data = build_data(0, ExtensionType::Timelock as u16, &value);
+
+        let parsed = QueueChangeData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.bump, 254);
+        assert_eq!(parsed.change_id, 7);
+        assert_eq!(parsed.action, ChangeAction::SetExtension);
+        assert_eq!(parsed.extension_type, ExtensionType::Timelock);
+        assert_eq!(parsed.value, value);
+        assert_eq!(parsed.config.timelock.unwrap().lock_duration, 3600);
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_remove() {
+        let data = build_data(1, ExtensionType::Hook as u16, &[]);
+
+        let parsed = QueueChangeData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.action, ChangeAction::RemoveExtension);
+        assert_eq!(parsed.extension_type, ExtensionType::Hook);
+        assert!(parsed.value.is_empty());
+        assert_eq!(parsed.config, EscrowConfig::default());
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_remove_with_value() {
+        let data = build_data(1, ExtensionType::Hook as u16, &[1]);
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_invalid_action() {
+        let data = build_data(2, ExtensionType::Timelock as u16, &[]);
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_unknown_extension_type() {
+        let data = build_data(1, 99, &[]);
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_invalid_value() {
+        // Timelock value one byte short
+        let data = build_data(0, ExtensionType::Timelock as u16, &[0u8; 7]);
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_truncated_value() {
+        let mut data = build_data(0, ExtensionType::Timelock as u16, &TimelockData::new(1).to_bytes());
+        data.pop();
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_queue_change_data_try_from_too_short() {
+        let data = [0u8; 15];
+        let result = QueueChangeData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
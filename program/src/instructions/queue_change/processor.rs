@@ -0,0 +1,90 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    events::ChangeQueuedEvent,
+    instructions::QueueChange,
+    state::{validate_extensions_pda, Escrow, PendingChange},
+    traits::{AccountSerialize, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event},
+};
+
+/// Processes the QueueChange instruction.
+///
+/// Records an extension change in a PendingChange account that can be executed once the
+/// escrow's governance delay has elapsed. The delay in force at execution applies, so
+/// lengthening it also holds back changes that are already queued.
+pub fn process_queue_change(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = QueueChange::try_from((instruction_data, accounts))?;
+
+    // Read escrow and validate; roles and metadata may change on immutable escrows, as with
+    // SetRoles and SetMetadata
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    if !ix.data.extension_type.is_mutable_when_immutable() {
+        escrow.require_mutable()?;
+    }
+
+    // Validate extensions PDA and the signer's authority over the extension
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_extension_authority(ix.accounts.admin.address(), ix.accounts.extensions, ix.data.extension_type)?;
+
+    // A new arbiter must sign, as in SetArbiter
+    if let Some(arbiter) = &ix.data.config.arbiter {
+        arbiter.validate(ix.accounts.remaining_accounts)?;
+    }
+
+    // Record when the change was queued; the delay is applied at execution
+    let queued_at = Clock::get()?.unix_timestamp;
+
+    // Create PendingChange state
+    let pending_change = PendingChange::new(
+        *ix.accounts.escrow.address(),
+        ix.data.change_id,
+        ix.data.action,
+        ix.data.extension_type,
+        ix.data.value.clone(),
+        queued_at,
+        ix.data.bump,
+    );
+
+    // Validate pending change PDA
+    pending_change.validate_pda(ix.accounts.pending_change, program_id, ix.data.bump)?;
+
+    // Get seeds for pending change account creation
+    let pending_change_bump_seed = [ix.data.bump];
+    let pending_change_seeds: Vec<Seed> = pending_change.seeds_with_bump(&pending_change_bump_seed);
+    let pending_change_seeds_array: [Seed; 4] =
+        pending_change_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        pending_change.space(),
+        program_id,
+        ix.accounts.pending_change,
+        pending_change_seeds_array,
+    )?;
+
+    // Write serialized pending change data to the account
+    let mut pending_change_data_slice = ix.accounts.pending_change.try_borrow_mut()?;
+    pending_change.write_to_slice(&mut pending_change_data_slice)?;
+    drop(pending_change_data_slice);
+
+    // Emit event
+    let event = ChangeQueuedEvent::new(
+        *ix.accounts.escrow.address(),
+        ix.data.change_id,
+        ix.data.action as u8,
+        ix.data.extension_type as u16,
+        queued_at,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, validate_associated_token_account_address, verify_associated_token_program,
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_owned_by,
+        verify_readonly, verify_signer, verify_system_program, verify_token_program, verify_writable,
+    },
+};
+
+/// Accounts for the ReleaseMatured instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Anyone; pays for the depositor token account if it has to be created
+/// 1. `[writable]` rent_recipient - Receives rent from closed receipt, must match receipt.rent_recipient
+///    (or the depositor for receipts without one)
+/// 2. `[]` escrow - Escrow PDA (signing authority for vault transfer)
+/// 3. `[]` extensions - Extensions PDA (must hold the auto-release extension)
+/// 4. `[writable]` receipt - Deposit receipt to release and close
+/// 5. `[writable]` vault - Escrow's vault token account (source)
+/// 6. `[]` depositor - Receipt depositor
+/// 7. `[writable]` depositor_token_account - Depositor's ATA (created if it does not exist)
+/// 8. `[]` mint - Token mint
+/// 9. `[]` token_program - SPL Token program
+/// 10. `[]` associated_token_program - Associated Token program for depositor ATA creation
+/// 11. `[]` system_program - System program
+/// 12. `[]` event_authority - Event authority PDA
+/// 13. `[]` escrow_program - Current program
+/// 14. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (may be closed if the mint was blocked)
+///
+/// # Remaining Accounts
+/// Optional:
+///   0. `[writable]` hook_bypass_request - Hook bypass request PDA for this receipt (closed with it)
+pub struct ReleaseMaturedAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub rent_recipient: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub receipt: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub depositor_token_account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for ReleaseMaturedAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, rent_recipient, escrow, extensions, receipt, vault, depositor, depositor_token_account, mint, token_program, associated_token_program, system_program, event_authority, escrow_program, allowed_mint, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(receipt, true)?;
+        verify_writable(vault, true)?;
+        verify_writable(depositor_token_account, true)?;
+        verify_writable(allowed_mint, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(depositor)?;
+        verify_readonly(mint)?;
+
+        // 4. Validate program IDs
+        verify_token_program(token_program)?;
+        verify_owned_by(mint, token_program.address())?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(receipt)?;
+
+        // 6. Validate ATAs (the depositor ATA may not exist yet and is created by the processor)
+        validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
+        validate_associated_token_account_address(depositor_token_account, depositor.address(), mint, token_program)?;
+
+        Ok(Self {
+            payer,
+            rent_recipient,
+            escrow,
+            extensions,
+            receipt,
+            vault,
+            depositor,
+            depositor_token_account,
+            mint,
+            token_program,
+            associated_token_program,
+            system_program,
+            event_authority,
+            escrow_program,
+            allowed_mint,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for ReleaseMaturedAccounts<'a> {}
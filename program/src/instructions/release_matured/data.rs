@@ -0,0 +1,40 @@
+use pinocchio::error::ProgramError;
+
+use crate::traits::InstructionData;
+
+/// Instruction data for ReleaseMatured
+///
+/// No additional data needed - all information is from accounts
+pub struct ReleaseMaturedData;
+
+impl<'a> TryFrom<&'a [u8]> for ReleaseMaturedData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(_data: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(Self)
+    }
+}
+
+impl<'a> InstructionData<'a> for ReleaseMaturedData {
+    const LEN: usize = 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release_matured_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = ReleaseMaturedData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_release_matured_data_try_from_with_extra_bytes() {
+        let data = [1u8, 2, 3];
+        let result = ReleaseMaturedData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,177 @@
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    errors::EscrowProgramError,
+    events::ReceiptReleasedEvent,
+    instructions::ReleaseMatured,
+    state::{
+        get_extensions_from_account, release_from_allowed_mint, require_no_cooldown, split_hook_bypass_request,
+        validate_extensions_pda, AllowedMint, AutoReleaseData, Escrow, ExtensionType, Receipt, TimelockData,
+    },
+    traits::{AccountDeserialize, EventSerialize, ExtensionData},
+    utils::{close_pda_account, emit_event, get_mint_decimals, validate_associated_token_account},
+};
+
+/// Processes the ReleaseMatured instruction.
+///
+/// Permissionless crank that pays a matured receipt out to the depositor's ATA and closes it.
+/// Requires the auto-release extension, and is refused while a hook or arbiter applies to the mint.
+pub fn process_release_matured(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = ReleaseMatured::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate receipt
+    let receipt = {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+
+        // Verify the receipt is for this escrow and depositor
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+
+        // Requested receipts can only be paid out by ClaimWithdraw
+        receipt.require_no_withdraw_request()?;
+
+        // Rent goes back to whoever the deposit's payer designated. Legacy receipts have no
+        // recorded recipient, so a permissionless caller may only refund the depositor.
+        if receipt.is_legacy() {
+            if ix.accounts.rent_recipient.address() != ix.accounts.depositor.address() {
+                return Err(EscrowProgramError::InvalidRentRecipient.into());
+            }
+        } else {
+            receipt.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+        }
+
+        receipt
+    };
+    let (amount, nonce, receipt_mint, deposited_at) =
+        (receipt.amount, receipt.nonce(), receipt.mint, receipt.deposited_at);
+
+    // Ensure the mint account matches the receipt's mint to prevent cross-mint releases.
+    if receipt_mint != *ix.accounts.mint.address() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // A hook bypass request left over from when a hook applied is closed with the receipt
+    let (hook_bypass_request, _) =
+        split_hook_bypass_request(program_id, ix.accounts.remaining_accounts, ix.accounts.receipt.address())?;
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Get timelock, hook, arbiter, and auto-release extensions in single pass
+    let ext_types = [ExtensionType::Timelock, ExtensionType::Hook, ExtensionType::Arbiter, ExtensionType::AutoRelease];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Per-mint overrides take precedence. A blocked mint has no AllowedMint account left and
+    // falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
+
+    let auto_release_bytes = exts[3].as_ref().ok_or(EscrowProgramError::AutoReleaseNotEnabled)?;
+    let auto_release = AutoReleaseData::from_bytes(auto_release_bytes)?;
+
+    // A hook or arbiter must see every withdrawal, which a permissionless crank cannot provide
+    if exts[1].is_some() || exts[2].is_some() {
+        return Err(EscrowProgramError::AutoReleaseBlocked.into());
+    }
+
+    // Escrows with a cooldown only pay out through RequestWithdraw and ClaimWithdraw
+    require_no_cooldown(ix.accounts.extensions)?;
+
+    // The release delay runs from the end of the timelock, or from the deposit without one
+    let unlocked_at = match exts[0] {
+        Some(ref timelock_bytes) => TimelockData::from_bytes(timelock_bytes)?.unlock_time(deposited_at)?,
+        None => deposited_at,
+    };
+    auto_release.validate(unlocked_at)?;
+
+    // Create the depositor ATA if it does not exist yet, otherwise check it is a live token account
+    if ix.accounts.depositor_token_account.is_data_empty() {
+        CreateIdempotent {
+            funding_account: ix.accounts.payer,
+            account: ix.accounts.depositor_token_account,
+            wallet: ix.accounts.depositor,
+            mint: ix.accounts.mint,
+            system_program: ix.accounts.system_program,
+            token_program: ix.accounts.token_program,
+        }
+        .invoke()?;
+    } else {
+        validate_associated_token_account(
+            ix.accounts.depositor_token_account,
+            ix.accounts.depositor.address(),
+            ix.accounts.mint,
+            ix.accounts.token_program,
+        )?;
+    }
+
+    // Transfer tokens from vault to depositor using escrow PDA as signer
+    let decimals = get_mint_decimals(ix.accounts.mint)?;
+
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_bytes(&escrow_data)?;
+        escrow.with_signer(|signers| {
+            TransferChecked {
+                from: ix.accounts.vault,
+                mint: ix.accounts.mint,
+                to: ix.accounts.depositor_token_account,
+                authority: ix.accounts.escrow,
+                amount,
+                decimals,
+                token_program: ix.accounts.token_program.address(),
+            }
+            .invoke_signed(signers)
+        })?;
+    }
+
+    // Release the amount from the mint's running total
+    release_from_allowed_mint(
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+        receipt.tracked_amount(),
+    )?;
+
+    // Close receipt account and return lamports to rent_recipient
+    close_pda_account(ix.accounts.receipt, ix.accounts.rent_recipient)?;
+
+    // Close the orphaned hook bypass request alongside the receipt
+    if let Some(request_account) = hook_bypass_request {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Emit event
+    let event = ReceiptReleasedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.payer.address(),
+        receipt_mint,
+        nonce,
+        amount,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the RequestHookBypass instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for hook bypass request creation
+/// 1. `[signer]` depositor - Must match receipt.depositor
+/// 2. `[]` escrow - Escrow PDA the receipt belongs to
+/// 3. `[]` extensions - Extensions PDA (must contain hook and hook bypass extensions)
+/// 4. `[]` receipt - Deposit receipt or basket receipt the bypass is requested for
+/// 5. `[writable]` hook_bypass_request - Hook bypass request PDA to create
+/// 6. `[]` system_program - System program for account creation
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program
+/// 9. `[]` mint - Token mint of the receipt (any leg's mint for a basket receipt)
+/// 10. `[]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (may be closed if the mint was blocked)
+pub struct RequestHookBypassAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub receipt: &'a AccountView,
+    pub hook_bypass_request: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for RequestHookBypassAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, depositor, escrow, extensions, receipt, hook_bypass_request, system_program, event_authority, escrow_program, mint, allowed_mint] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(depositor, false)?;
+
+        // 2. Validate writable
+        verify_writable(hook_bypass_request, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(receipt)?;
+        verify_readonly(allowed_mint)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(receipt)?;
+
+        Ok(Self {
+            payer,
+            depositor,
+            escrow,
+            extensions,
+            receipt,
+            hook_bypass_request,
+            system_program,
+            event_authority,
+            escrow_program,
+            mint,
+            allowed_mint,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for RequestHookBypassAccounts<'a> {}
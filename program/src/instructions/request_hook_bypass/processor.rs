@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::HookBypassRequestedEvent,
+    instructions::RequestHookBypass,
+    state::{
+        get_extensions_from_account, validate_extensions_pda, AllowedMint, BasketReceipt, Escrow, ExtensionType,
+        HookBypassRequest, Receipt,
+    },
+    traits::{AccountSerialize, AccountSize, Discriminator, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event},
+};
+
+/// Processes the RequestHookBypass instruction.
+///
+/// Records the time a depositor asked to bypass the hook for a receipt or basket receipt. Once
+/// the escrow's hook bypass grace period has elapsed, Withdraw and WithdrawBasket accept the
+/// request in place of the hook program.
+pub fn process_request_hook_bypass(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = RequestHookBypass::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate the receipt, which may be a basket receipt
+    {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt_mint_matches = if receipt_data.first() == Some(&BasketReceipt::DISCRIMINATOR) {
+            let basket = BasketReceipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+            basket.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+            basket.legs.iter().any(|leg| leg.mint == *ix.accounts.mint.address())
+        } else {
+            let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+            receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+            receipt.mint == *ix.accounts.mint.address()
+        };
+
+        // Ensure the mint account matches the receipt's mint (any leg's mint for a basket) so the
+        // right overrides are read
+        if !receipt_mint_matches {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Bypass only makes sense when a hook is configured and the escrow opted into the escape hatch
+    let ext_types = [ExtensionType::Hook, ExtensionType::HookBypass];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Resolve both through the mint's overrides, as Withdraw does. A blocked mint has no
+    // AllowedMint account left and falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
+    if exts[0].is_none() || exts[1].is_none() {
+        return Err(EscrowProgramError::HookBypassNotEnabled.into());
+    }
+
+    let clock = Clock::get()?;
+    let requested_at = clock.unix_timestamp;
+
+    // Create HookBypassRequest state
+    let request = HookBypassRequest::new(*ix.accounts.receipt.address(), requested_at, ix.data.bump);
+
+    // Validate hook bypass request PDA
+    request.validate_pda(ix.accounts.hook_bypass_request, program_id, ix.data.bump)?;
+
+    // Get seeds for hook bypass request account creation
+    let request_bump_seed = [ix.data.bump];
+    let request_seeds: Vec<Seed> = request.seeds_with_bump(&request_bump_seed);
+    let request_seeds_array: [Seed; 3] = request_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        HookBypassRequest::LEN,
+        program_id,
+        ix.accounts.hook_bypass_request,
+        request_seeds_array,
+    )?;
+
+    // Write serialized request data to the account
+    let mut request_data_slice = ix.accounts.hook_bypass_request.try_borrow_mut()?;
+    request.write_to_slice(&mut request_data_slice)?;
+    drop(request_data_slice);
+
+    // Emit event
+    let event = HookBypassRequestedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.receipt.address(),
+        requested_at,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the RequestWithdraw instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for withdraw request creation
+/// 1. `[signer]` depositor - Must match receipt.depositor
+/// 2. `[]` escrow - Escrow PDA the receipt belongs to
+/// 3. `[]` extensions - Extensions PDA (must contain the cooldown extension)
+/// 4. `[writable]` receipt - Deposit receipt the withdrawal is requested for (marked as requested)
+/// 5. `[writable]` withdraw_request - Withdraw request PDA to create
+/// 6. `[]` system_program - System program for account creation
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program
+pub struct RequestWithdrawAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub receipt: &'a AccountView,
+    pub withdraw_request: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for RequestWithdrawAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, depositor, escrow, extensions, receipt, withdraw_request, system_program, event_authority, escrow_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(depositor, false)?;
+
+        // 2. Validate writable
+        verify_writable(receipt, true)?;
+        verify_writable(withdraw_request, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(receipt)?;
+
+        Ok(Self {
+            payer,
+            depositor,
+            escrow,
+            extensions,
+            receipt,
+            withdraw_request,
+            system_program,
+            event_authority,
+            escrow_program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for RequestWithdrawAccounts<'a> {}
@@ -0,0 +1,47 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for RequestWithdraw
+///
+/// # Layout
+/// * `bump` (u8) - Bump for the withdraw request PDA
+pub struct RequestWithdrawData {
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for RequestWithdrawData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { bump: data[0] })
+    }
+}
+
+impl<'a> InstructionData<'a> for RequestWithdrawData {
+    const LEN: usize = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_withdraw_data_try_from_valid() {
+        let data = [254u8; 1];
+
+        let result = RequestWithdrawData::try_from(&data[..]);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().bump, 254);
+    }
+
+    #[test]
+    fn test_request_withdraw_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = RequestWithdrawData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    cpi::Seed,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::WithdrawRequestedEvent,
+    instructions::RequestWithdraw,
+    state::{get_extensions_from_account, validate_extensions_pda, Escrow, ExtensionType, Receipt, WithdrawRequest},
+    traits::{AccountSerialize, AccountSize, EventSerialize, PdaSeeds},
+    utils::{create_pda_account, emit_event},
+};
+
+/// Processes the RequestWithdraw instruction.
+///
+/// Records the time a depositor asked to withdraw a receipt and marks the receipt as requested.
+/// Once the escrow's cooldown has elapsed, ClaimWithdraw pays the receipt out.
+pub fn process_request_withdraw(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = RequestWithdraw::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate receipt
+    let mut receipt = {
+        let receipt_data = ix.accounts.receipt.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+        receipt.require_no_withdraw_request()?;
+        receipt
+    };
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Requests only exist on escrows that opted into two-phase withdrawals
+    let exts = get_extensions_from_account(ix.accounts.extensions, &[ExtensionType::Cooldown])?;
+    if exts[0].is_none() {
+        return Err(EscrowProgramError::CooldownNotEnabled.into());
+    }
+
+    let clock = Clock::get()?;
+    let requested_at = clock.unix_timestamp;
+
+    // Create WithdrawRequest state
+    let request = WithdrawRequest::new(*ix.accounts.receipt.address(), requested_at, ix.data.bump);
+
+    // Validate withdraw request PDA
+    request.validate_pda(ix.accounts.withdraw_request, program_id, ix.data.bump)?;
+
+    // Get seeds for withdraw request account creation
+    let request_bump_seed = [ix.data.bump];
+    let request_seeds: Vec<Seed> = request.seeds_with_bump(&request_bump_seed);
+    let request_seeds_array: [Seed; 3] = request_seeds.try_into().map_err(|_| ProgramError::InvalidArgument)?;
+
+    create_pda_account(
+        ix.accounts.payer,
+        WithdrawRequest::LEN,
+        program_id,
+        ix.accounts.withdraw_request,
+        request_seeds_array,
+    )?;
+
+    // Write serialized request data to the account
+    let mut request_data_slice = ix.accounts.withdraw_request.try_borrow_mut()?;
+    request.write_to_slice(&mut request_data_slice)?;
+    drop(request_data_slice);
+
+    // Mark the receipt so it can only leave the escrow through ClaimWithdraw
+    receipt.withdraw_requested = true;
+    receipt.write_to_slice(&mut ix.accounts.receipt.try_borrow_mut()?)?;
+
+    // Emit event
+    let event = WithdrawRequestedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        *ix.accounts.receipt.address(),
+        requested_at,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
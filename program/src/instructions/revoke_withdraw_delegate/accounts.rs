@@ -0,0 +1,62 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
+    },
+};
+
+/// Accounts for the RevokeWithdrawDelegate instruction
+///
+/// Removes a receipt's withdraw delegate and closes the WithdrawDelegate account.
+///
+/// # Account Layout
+/// 0. `[writable]` rent_recipient - Receives rent refund from the closed withdraw delegate
+/// 1. `[signer]` depositor - Must match withdraw_delegate.depositor
+/// 2. `[]` escrow - Escrow PDA the receipt belongs to
+/// 3. `[writable]` withdraw_delegate - Withdraw delegate PDA to close
+/// 4. `[]` event_authority - Event authority PDA
+/// 5. `[]` escrow_program - Current program
+pub struct RevokeWithdrawDelegateAccounts<'a> {
+    pub rent_recipient: &'a AccountView,
+    pub depositor: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub withdraw_delegate: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for RevokeWithdrawDelegateAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [rent_recipient, depositor, escrow, withdraw_delegate, event_authority, escrow_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(depositor, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(withdraw_delegate, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+
+        // 4. Validate program IDs
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(withdraw_delegate)?;
+
+        Ok(Self { rent_recipient, depositor, escrow, withdraw_delegate, event_authority, escrow_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for RevokeWithdrawDelegateAccounts<'a> {}
@@ -0,0 +1,46 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    events::WithdrawDelegateRevokedEvent,
+    instructions::RevokeWithdrawDelegate,
+    state::{Escrow, WithdrawDelegate},
+    traits::EventSerialize,
+    utils::{close_pda_account, emit_event},
+};
+
+/// Processes the RevokeWithdrawDelegate instruction.
+///
+/// Closes a receipt's WithdrawDelegate account so the delegate can no longer withdraw.
+/// Works after the receipt itself has been closed, letting depositors reclaim stale approvals.
+pub fn process_revoke_withdraw_delegate(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = RevokeWithdrawDelegate::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read withdraw delegate and validate it belongs to this depositor
+    let delegate_data = ix.accounts.withdraw_delegate.try_borrow()?;
+    let withdraw_delegate = WithdrawDelegate::from_account(&delegate_data, ix.accounts.withdraw_delegate, program_id)?;
+    withdraw_delegate.validate_depositor(ix.accounts.escrow.address(), ix.accounts.depositor.address())?;
+    drop(delegate_data);
+
+    // Close the WithdrawDelegate account and return lamports to rent_recipient
+    close_pda_account(ix.accounts.withdraw_delegate, ix.accounts.rent_recipient)?;
+
+    // Emit event
+    let event = WithdrawDelegateRevokedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.depositor.address(),
+        withdraw_delegate.receipt,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
+    },
+};
+
+/// Accounts for the SetMintLimits instruction
+///
+/// Updates the deposit limits stored on an AllowedMint PDA.
+///
+/// # Account Layout
+/// 0. `[signer]` admin - Mint manager, or escrow.admin if the role is unassigned
+/// 1. `[]` escrow - Escrow PDA (validates admin)
+/// 2. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
+/// 3. `[]` mint - Token mint the limits apply to
+/// 4. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]`
+/// 5. `[]` event_authority - Event authority PDA
+/// 6. `[]` escrow_program - Current program (for event emission)
+pub struct SetMintLimitsAccounts<'a> {
+    pub admin: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SetMintLimitsAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [admin, escrow, extensions, mint, allowed_mint, event_authority, escrow_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(allowed_mint, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(mint)?;
+
+        // 4. Validate program IDs
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(allowed_mint)?;
+
+        Ok(Self { admin, escrow, extensions, mint, allowed_mint, event_authority, escrow_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for SetMintLimitsAccounts<'a> {}
@@ -0,0 +1,86 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for SetMintLimits
+///
+/// A limit of 0 disables that limit.
+///
+/// # Layout
+/// * `max_total_deposits` (u64) - Cap on the total amount held for this mint
+/// * `min_deposit` (u64) - Minimum amount per deposit
+/// * `max_deposit` (u64) - Maximum amount per deposit
+pub struct SetMintLimitsData {
+    pub max_total_deposits: u64,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetMintLimitsData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let max_total_deposits = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        let min_deposit = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let max_deposit = u64::from_le_bytes(data[16..24].try_into().unwrap());
+
+        // A minimum above the per-deposit maximum would block every deposit
+        if max_deposit != 0 && min_deposit > max_deposit {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { max_total_deposits, min_deposit, max_deposit })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetMintLimitsData {
+    const LEN: usize = 8 + 8 + 8; // 24 bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_data(max_total_deposits: u64, min_deposit: u64, max_deposit: u64) -> [u8; 24] {
+        let mut data = [0u8; 24];
+        data[0..8].copy_from_slice(&max_total_deposits.to_le_bytes());
+        data[8..16].copy_from_slice(&min_deposit.to_le_bytes());
+        data[16..24].copy_from_slice(&max_deposit.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_set_mint_limits_data_try_from_valid() {
+        let data = build_data(1_000_000, 100, 10_000);
+        let result = SetMintLimitsData::try_from(&data[..]);
+        assert!(result.is_ok());
+        let parsed = result.unwrap();
+        assert_eq!(parsed.max_total_deposits, 1_000_000);
+        assert_eq!(parsed.min_deposit, 100);
+        assert_eq!(parsed.max_deposit, 10_000);
+    }
+
+    #[test]
+    fn test_set_mint_limits_data_try_from_unlimited() {
+        let data = build_data(0, 500, 0);
+        let result = SetMintLimitsData::try_from(&data[..]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_set_mint_limits_data_try_from_min_above_max() {
+        let data = build_data(0, 101, 100);
+        let result = SetMintLimitsData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_mint_limits_data_try_from_truncated() {
+        let data = [0u8; 23];
+        let result = SetMintLimitsData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
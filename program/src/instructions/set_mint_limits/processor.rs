@@ -0,0 +1,59 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::MintLimitsSetEvent,
+    instructions::SetMintLimits,
+    state::{validate_extensions_pda, AllowedMint, Escrow, Role},
+    traits::{AccountSerialize, EventSerialize},
+    utils::emit_event,
+};
+
+/// Processes the SetMintLimits instruction.
+///
+/// Updates the TVL cap and per-deposit bounds for an allowed mint. Lowering the cap below
+/// the current total only blocks new deposits; existing receipts can still be withdrawn.
+pub fn process_set_mint_limits(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetMintLimits::try_from((instruction_data, accounts))?;
+
+    // Verify escrow exists and validate the mint manager role
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::MintManager)?;
+
+    // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
+    let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+    let mut allowed_mint = AllowedMint::from_account(
+        &allowed_mint_data,
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+    )?;
+
+    // A legacy account has no room for limits and no payer here to grow it
+    if AllowedMint::is_legacy(&allowed_mint_data) {
+        return Err(EscrowProgramError::LegacyAllowedMint.into());
+    }
+    drop(allowed_mint_data);
+
+    allowed_mint.set_limits(ix.data.max_total_deposits, ix.data.min_deposit, ix.data.max_deposit);
+    allowed_mint.write_to_slice(&mut ix.accounts.allowed_mint.try_borrow_mut()?)?;
+
+    // Emit event via CPI
+    let event = MintLimitsSetEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.mint.address(),
+        ix.data.max_total_deposits,
+        ix.data.min_deposit,
+        ix.data.max_deposit,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
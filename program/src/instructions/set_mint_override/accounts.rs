@@ -0,0 +1,88 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_system_program, verify_writable,
+    },
+};
+
+/// Accounts for the SetMintOverride instruction
+///
+/// Sets or removes a per-mint extension override stored on an AllowedMint PDA.
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for AllowedMint account growth
+/// 1. `[signer]` admin - Extension manager, or escrow.admin if the role is unassigned
+/// 2. `[]` escrow - Escrow PDA (validates admin)
+/// 3. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
+/// 4. `[]` mint - Token mint the override applies to
+/// 5. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]`
+/// 6. `[]` system_program - System program for rent top-up
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program
+///
+/// # Remaining Accounts
+/// 1. `[signer]` arbiter - Only when setting an arbiter override; must match the new arbiter
+pub struct SetMintOverrideAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub admin: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SetMintOverrideAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, admin, escrow, extensions, mint, allowed_mint, system_program, event_authority, escrow_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(allowed_mint, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(mint)?;
+
+        // 4. Validate program IDs
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(allowed_mint)?;
+
+        Ok(Self {
+            payer,
+            admin,
+            escrow,
+            extensions,
+            mint,
+            allowed_mint,
+            system_program,
+            event_authority,
+            escrow_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for SetMintOverrideAccounts<'a> {}
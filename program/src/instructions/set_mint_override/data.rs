@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{
+    instructions::EscrowConfig,
+    require_len,
+    state::{ChangeAction, ExtensionType},
+    traits::InstructionData,
+};
+
+/// Instruction data for SetMintOverride
+///
+/// # Layout
+/// * `action` (u8) - `0` sets the override to `value`, `1` removes it
+/// * `extension_type` (u16) - Extension to override: timelock, hook, or arbiter
+/// * `value` (u32 length + bytes) - Extension data as its setter instruction would write it (empty for removals)
+pub struct SetMintOverrideData {
+    pub action: ChangeAction,
+    pub extension_type: ExtensionType,
+    pub value: Vec<u8>,
+    /// `value` decoded and validated; empty for removals
+    pub config: EscrowConfig,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetMintOverrideData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let action = ChangeAction::try_from(data[0])?;
+        let extension_type = ExtensionType::try_from(u16::from_le_bytes([data[1], data[2]]))
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        if !extension_type.is_mint_overridable() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let value_len = u32::from_le_bytes(data[3..7].try_into().unwrap()) as usize;
+        let value = data.get(7..7 + value_len).ok_or(ProgramError::InvalidInstructionData)?;
+
+        let mut config = EscrowConfig::default();
+        match action {
+            ChangeAction::SetExtension => config.insert(extension_type, value)?,
+            ChangeAction::RemoveExtension if !value.is_empty() => return Err(ProgramError::InvalidInstructionData),
+            ChangeAction::RemoveExtension => {}
+        }
+
+        Ok(Self { action, extension_type, value: value.to_vec(), config })
+    }
+}
+
+impl<'a> InstructionData<'a> for SetMintOverrideData {
+    const LEN: usize = 1 + 2 + 4; // action + extension_type + value length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{state::TimelockData, traits::ExtensionData};
+    use alloc::vec;
+
+    fn build_data(action: u8, extension_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut data = vec![action];
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value);
+        data
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_set() {
+        let value = TimelockData::new(3600).to_bytes();
+        let data = build_data(0, ExtensionType::Timelock as u16, &value);
+
+        let parsed = SetMintOverrideData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.action, ChangeAction::SetExtension);
+        assert_eq!(parsed.extension_type, ExtensionType::Timelock);
+        assert_eq!(parsed.value, value);
+        assert_eq!(parsed.config.timelock.unwrap().lock_duration, 3600);
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_remove() {
+        let data = build_data(1, ExtensionType::Arbiter as u16, &[]);
+
+        let parsed = SetMintOverrideData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.action, ChangeAction::RemoveExtension);
+        assert_eq!(parsed.extension_type, ExtensionType::Arbiter);
+        assert!(parsed.value.is_empty());
+        assert_eq!(parsed.config, EscrowConfig::default());
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_remove_with_value() {
+        let data = build_data(1, ExtensionType::Hook as u16, &[1]);
+        let result = SetMintOverrideData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_not_overridable() {
+        let data = build_data(1, ExtensionType::DepositorAllowlist as u16, &[]);
+        let result = SetMintOverrideData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_invalid_value() {
+        // Timelock value one byte short
+        let data = build_data(0, ExtensionType::Timelock as u16, &[0u8; 7]);
+        let result = SetMintOverrideData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_set_mint_override_data_try_from_too_short() {
+        let data = [0u8; 6];
+        let result = SetMintOverrideData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
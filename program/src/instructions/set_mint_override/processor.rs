@@ -0,0 +1,75 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::MintOverrideSetEvent,
+    instructions::SetMintOverride,
+    state::{
+        get_extension_locks, require_no_governance_delay, set_mint_override, validate_extensions_pda, AllowedMint,
+        ChangeAction, Escrow,
+    },
+    traits::EventSerialize,
+    utils::emit_event,
+};
+
+/// Processes the SetMintOverride instruction.
+///
+/// Sets or removes a timelock, hook, or arbiter override on an AllowedMint account. Deposits
+/// and withdrawals of that mint use the override in place of the escrow-level extension.
+pub fn process_set_mint_override(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = SetMintOverride::try_from((instruction_data, accounts))?;
+
+    // Read escrow and validate
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    escrow.require_mutable()?;
+
+    // Validate extensions PDA and the signer's authority over the extension
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_extension_authority(ix.accounts.admin.address(), ix.accounts.extensions, ix.data.extension_type)?;
+
+    // Overrides follow the same rules as the escrow-level extension they replace. They cannot
+    // be queued, so they stay frozen until a queued change removes the governance delay
+    require_no_governance_delay(ix.accounts.extensions)?;
+    if get_extension_locks(ix.accounts.extensions)?.is_locked(ix.data.extension_type) {
+        return Err(EscrowProgramError::ExtensionLocked.into());
+    }
+
+    // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
+    {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+    }
+
+    // A new arbiter must sign, as in SetArbiter
+    if let Some(arbiter) = &ix.data.config.arbiter {
+        arbiter.validate(ix.accounts.remaining_accounts)?;
+    }
+
+    let value = match ix.data.action {
+        ChangeAction::SetExtension => Some(ix.data.value.as_slice()),
+        ChangeAction::RemoveExtension => None,
+    };
+    set_mint_override(ix.accounts.payer, ix.accounts.allowed_mint, ix.data.extension_type, value)?;
+
+    // Emit event
+    let event = MintOverrideSetEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.mint.address(),
+        ix.data.action as u8,
+        ix.data.extension_type as u16,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, verify_current_program, verify_current_program_account,
+        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_token_program, verify_writable,
+    },
+};
+
+/// Accounts for the SweepSurplus instruction
+///
+/// Moves vault tokens not backing any receipt to a destination chosen by the admin.
+///
+/// # Account Layout
+/// 0. `[signer]` admin - Must match escrow.admin
+/// 1. `[]` escrow - Escrow PDA (signing authority for vault transfer)
+/// 2. `[]` mint - Token mint of the vault
+/// 3. `[]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` holding the counters
+/// 4. `[writable]` vault - Escrow's vault token account (source)
+/// 5. `[writable]` destination - Token account receiving the surplus
+/// 6. `[]` token_program - SPL Token program
+/// 7. `[]` event_authority - Event authority PDA
+/// 8. `[]` escrow_program - Current program (for event emission)
+pub struct SweepSurplusAccounts<'a> {
+    pub admin: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub destination: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for SweepSurplusAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [admin, escrow, mint, allowed_mint, vault, destination, token_program, event_authority, escrow_program] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(admin, false)?;
+
+        // 2. Validate writable
+        verify_writable(vault, true)?;
+        verify_writable(destination, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(mint)?;
+        verify_readonly(allowed_mint)?;
+
+        // 4. Validate program IDs
+        verify_token_program(token_program)?;
+        verify_owned_by(mint, token_program.address())?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(allowed_mint)?;
+
+        // 6. Validate ATA
+        validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
+
+        Ok(Self {
+            admin,
+            escrow,
+            mint,
+            allowed_mint,
+            vault,
+            destination,
+            token_program,
+            event_authority,
+            escrow_program,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for SweepSurplusAccounts<'a> {}
@@ -0,0 +1,8 @@
+mod accounts;
+mod data;
+mod processor;
+
+pub use crate::instructions::impl_instructions::SweepSurplus;
+pub use accounts::*;
+pub use data::*;
+pub use processor::*;
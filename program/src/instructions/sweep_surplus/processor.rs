@@ -0,0 +1,66 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    errors::EscrowProgramError,
+    events::SurplusSweptEvent,
+    instructions::SweepSurplus,
+    state::{AllowedMint, Escrow},
+    traits::EventSerialize,
+    utils::{emit_event, get_mint_decimals, get_token_account_amount},
+};
+
+/// Processes the SweepSurplus instruction.
+///
+/// Transfers the vault balance above the mint's outstanding deposits and opening balance to the
+/// destination. Funds backing receipts are never moved.
+pub fn process_sweep_surplus(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = SweepSurplus::try_from((instruction_data, accounts))?;
+
+    // Verify escrow exists and validate admin
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    escrow.validate_admin(ix.accounts.admin.address())?;
+
+    // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
+    let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+    let allowed_mint = AllowedMint::from_account(
+        &allowed_mint_data,
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+    )?;
+    drop(allowed_mint_data);
+
+    let amount = allowed_mint.surplus(get_token_account_amount(ix.accounts.vault)?);
+    if amount == 0 {
+        return Err(EscrowProgramError::NoSurplus.into());
+    }
+
+    // Transfer the surplus from vault to destination using escrow PDA as signer
+    let decimals = get_mint_decimals(ix.accounts.mint)?;
+    escrow.with_signer(|signers| {
+        TransferChecked {
+            from: ix.accounts.vault,
+            mint: ix.accounts.mint,
+            to: ix.accounts.destination,
+            authority: ix.accounts.escrow,
+            amount,
+            decimals,
+            token_program: ix.accounts.token_program.address(),
+        }
+        .invoke_signed(signers)
+    })?;
+
+    // Emit event via CPI
+    let event = SurplusSweptEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.mint.address(),
+        *ix.accounts.destination.address(),
+        amount,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -3,22 +3,25 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        verify_current_program, verify_current_program_account, verify_event_authority, verify_signer, verify_writable,
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
     },
 };
 
 /// Accounts for the UpdateAdmin instruction
 ///
 /// # Account Layout
-/// 0. `[signer]` admin - Current admin, must match escrow.admin
+/// 0. `[signer]` admin - Admin-transfer authority, or escrow.admin if the role is unassigned
 /// 1. `[signer]` new_admin - New admin pubkey
 /// 2. `[writable]` escrow - Escrow account to update
-/// 3. `[]` event_authority - Event authority PDA
-/// 4. `[]` escrow_program - Current program
+/// 3. `[]` extensions - Extensions PDA `[b"extensions", escrow]` (may be empty/uninitialized)
+/// 4. `[]` event_authority - Event authority PDA
+/// 5. `[]` escrow_program - Current program
 pub struct UpdateAdminAccounts<'a> {
     pub admin: &'a AccountView,
     pub new_admin: &'a AccountView,
     pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
     pub event_authority: &'a AccountView,
     pub escrow_program: &'a AccountView,
 }
@@ -28,7 +31,7 @@ impl<'a> TryFrom<&'a [AccountView]> for UpdateAdminAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [admin, new_admin, escrow, event_authority, escrow_program] = accounts else {
+        let [admin, new_admin, escrow, extensions, event_authority, escrow_program] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -39,14 +42,17 @@ impl<'a> TryFrom<&'a [AccountView]> for UpdateAdminAccounts<'a> {
         // 2. Validate writable
         verify_writable(escrow, true)?;
 
-        // 3. Validate program IDs
+        // 3. Validate readonly
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
         verify_current_program(escrow_program)?;
         verify_event_authority(event_authority)?;
 
-        // 4. Validate accounts owned by current program
+        // 5. Validate accounts owned by current program
         verify_current_program_account(escrow)?;
 
-        Ok(Self { admin, new_admin, escrow, event_authority, escrow_program })
+        Ok(Self { admin, new_admin, escrow, extensions, event_authority, escrow_program })
     }
 }
 
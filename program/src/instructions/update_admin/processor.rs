@@ -3,21 +3,23 @@ use pinocchio::{account::AccountView, Address, ProgramResult};
 use crate::{
     events::AdminUpdateEvent,
     instructions::UpdateAdmin,
-    state::Escrow,
+    state::{validate_extensions_pda, Escrow, Role},
     traits::{AccountSerialize, EventSerialize},
     utils::emit_event,
 };
 
 /// Processes the UpdateAdmin instruction.
 ///
-/// Updates the admin on an escrow. Only the current admin can update to a new admin.
+/// Updates the admin on an escrow. Only the admin-transfer authority, or the current admin
+/// if that role is unassigned, can update to a new admin.
 pub fn process_update_admin(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = UpdateAdmin::try_from((instruction_data, accounts))?;
 
     // Read and validate escrow
     let escrow_data = ix.accounts.escrow.try_borrow()?;
     let escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
-    escrow.validate_admin(ix.accounts.admin.address())?;
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+    escrow.validate_role(ix.accounts.admin.address(), ix.accounts.extensions, Role::AdminTransferAuthority)?;
 
     // Copy values we need for the update
     let old_admin = escrow.admin;
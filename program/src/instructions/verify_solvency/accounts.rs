@@ -0,0 +1,65 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, verify_current_program, verify_current_program_account,
+        verify_event_authority, verify_owned_by, verify_readonly, verify_token_program,
+    },
+};
+
+/// Accounts for the VerifySolvency instruction
+///
+/// Permissionless: anyone may check that a vault covers the deposits recorded for its mint.
+///
+/// # Account Layout
+/// 0. `[]` escrow - Escrow PDA (vault owner)
+/// 1. `[]` mint - Token mint to check
+/// 2. `[]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` holding the counters
+/// 3. `[]` vault - Escrow's vault token account for the mint
+/// 4. `[]` token_program - SPL Token program
+/// 5. `[]` event_authority - Event authority PDA
+/// 6. `[]` escrow_program - Current program (for event emission)
+pub struct VerifySolvencyAccounts<'a> {
+    pub escrow: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for VerifySolvencyAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [escrow, mint, allowed_mint, vault, token_program, event_authority, escrow_program] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(mint)?;
+        verify_readonly(allowed_mint)?;
+        verify_readonly(vault)?;
+
+        // 2. Validate program IDs
+        verify_token_program(token_program)?;
+        verify_owned_by(mint, token_program.address())?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 3. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(allowed_mint)?;
+
+        // 4. Validate ATA
+        validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
+
+        Ok(Self { escrow, mint, allowed_mint, vault, token_program, event_authority, escrow_program })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for VerifySolvencyAccounts<'a> {}
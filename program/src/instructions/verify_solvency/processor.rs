@@ -0,0 +1,53 @@
+use pinocchio::{account::AccountView, Address, ProgramResult};
+
+use crate::{
+    errors::EscrowProgramError,
+    events::SolvencyVerifiedEvent,
+    instructions::VerifySolvency,
+    state::{AllowedMint, Escrow},
+    traits::EventSerialize,
+    utils::{emit_event, get_token_account_amount},
+};
+
+/// Processes the VerifySolvency instruction.
+///
+/// Compares the vault balance with the outstanding deposits recorded on the AllowedMint PDA
+/// and fails if the vault cannot cover them. Tokens sent to the vault directly only add surplus.
+pub fn process_verify_solvency(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = VerifySolvency::try_from((instruction_data, accounts))?;
+
+    // Verify escrow exists
+    let escrow_data = ix.accounts.escrow.try_borrow()?;
+    Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+
+    // Verify allowed_mint account exists and self-validates against escrow + mint PDA derivation
+    let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+    let allowed_mint = AllowedMint::from_account(
+        &allowed_mint_data,
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+    )?;
+
+    let vault_balance = get_token_account_amount(ix.accounts.vault)?;
+    if vault_balance < allowed_mint.total_deposited {
+        return Err(EscrowProgramError::VaultInsolvent.into());
+    }
+
+    // Emit event via CPI
+    let event = SolvencyVerifiedEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.mint.address(),
+        vault_balance,
+        allowed_mint.total_deposited,
+        allowed_mint.outstanding_receipts,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -3,34 +3,43 @@ use pinocchio::{account::AccountView, error::ProgramError};
 use crate::{
     traits::InstructionAccounts,
     utils::{
-        validate_associated_token_account, verify_current_program, verify_current_program_account,
-        verify_event_authority, verify_owned_by, verify_readonly, verify_signer, verify_system_program,
-        verify_token_program, verify_writable,
+        validate_associated_token_account, validate_associated_token_account_address, verify_associated_token_program,
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_owned_by,
+        verify_readonly, verify_signer, verify_system_program, verify_token_program, verify_writable,
     },
 };
 
 /// Accounts for the Withdraw instruction
 ///
 /// # Account Layout
-/// 0. `[writable]` rent_recipient - Receives rent from closed receipt
-/// 1. `[signer]` withdrawer - Must match receipt.depositor
+/// 0. `[writable]` rent_recipient - Receives rent from closed receipt, must match receipt.rent_recipient
+/// 1. `[signer]` withdrawer - Must match receipt.depositor, or the receipt's approved withdraw delegate
 /// 2. `[]` escrow - Escrow PDA (signing authority for vault transfer)
 /// 3. `[]` extensions - Extensions PDA (optional, may be system-owned)
 /// 4. `[writable]` receipt - Deposit receipt to verify and close
 /// 5. `[writable]` vault - Escrow's vault token account (source)
-/// 6. `[writable]` withdrawer_token_account - Withdrawer's token account (destination)
+/// 6. `[writable]` destination_token_account - Destination's ATA (created if it does not exist)
 /// 7. `[]` mint - Token mint
 /// 8. `[]` token_program - SPL Token program
 /// 9. `[]` system_program - System program
 /// 10. `[]` event_authority - Event authority PDA
 /// 11. `[]` escrow_program - Current program
+/// 12. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (may be closed if the mint was blocked)
+/// 13. `[signer, writable]` payer - Pays for the destination token account if it has to be created
+/// 14. `[]` destination - Owner of the destination token account, chosen by the withdrawer
+/// 15. `[]` associated_token_program - Associated Token program for destination ATA creation
 ///
 /// # Remaining Accounts
-/// If arbiter extension is set:
-///   0. `[signer]` arbiter - The arbiter who must authorize the withdrawal
-/// If hook extension is set (after arbiter if both):
-///   N. `[]` hook_program - The hook program to invoke (N=0 if no arbiter, N=1 if arbiter present)
+/// If withdrawer is not the depositor:
+///   0. `[writable]` withdraw_delegate - WithdrawDelegate PDA naming the withdrawer (closed on success)
+/// If arbiter extension is set (after withdraw_delegate if both):
+///   N. `[signer]` arbiter - The arbiter who must authorize the withdrawal
+/// If hook extension is set (after withdraw_delegate and arbiter):
+///   N. `[]` hook_program - The hook program to invoke (N counts the preceding accounts)
 ///   N+1..M. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
+/// If hook bypass extension is set and the grace period after the depositor's request has elapsed,
+/// the hook program may be replaced by:
+///   N. `[writable]` hook_bypass_request - Hook bypass request PDA for this receipt (closed on success)
 pub struct WithdrawAccounts<'a> {
     pub rent_recipient: &'a AccountView,
     pub withdrawer: &'a AccountView,
@@ -38,12 +47,16 @@ pub struct WithdrawAccounts<'a> {
     pub extensions: &'a AccountView,
     pub receipt: &'a AccountView,
     pub vault: &'a AccountView,
-    pub withdrawer_token_account: &'a AccountView,
+    pub destination_token_account: &'a AccountView,
     pub mint: &'a AccountView,
     pub token_program: &'a AccountView,
     pub system_program: &'a AccountView,
     pub event_authority: &'a AccountView,
     pub escrow_program: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub payer: &'a AccountView,
+    pub destination: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
     pub remaining_accounts: &'a [AccountView],
 }
 
@@ -52,29 +65,33 @@ impl<'a> TryFrom<&'a [AccountView]> for WithdrawAccounts<'a> {
 
     #[inline(always)]
     fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
-        let [rent_recipient, withdrawer, escrow, extensions, receipt, vault, withdrawer_token_account, mint, token_program, system_program, event_authority, escrow_program, remaining_accounts @ ..] =
+        let [rent_recipient, withdrawer, escrow, extensions, receipt, vault, destination_token_account, mint, token_program, system_program, event_authority, escrow_program, allowed_mint, payer, destination, associated_token_program, remaining_accounts @ ..] =
             accounts
         else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         // 1. Validate signers
+        verify_signer(payer, true)?;
         verify_signer(withdrawer, false)?;
 
         // 2. Validate writable
         verify_writable(rent_recipient, true)?;
         verify_writable(receipt, true)?;
         verify_writable(vault, true)?;
-        verify_writable(withdrawer_token_account, true)?;
+        verify_writable(destination_token_account, true)?;
+        verify_writable(allowed_mint, true)?;
 
         // 3. Validate readonly
         verify_readonly(escrow)?;
         verify_readonly(extensions)?;
+        verify_readonly(destination)?;
         verify_readonly(mint)?;
 
         // 4. Validate program IDs
         verify_token_program(token_program)?;
         verify_owned_by(mint, token_program.address())?;
+        verify_associated_token_program(associated_token_program)?;
         verify_system_program(system_program)?;
         verify_current_program(escrow_program)?;
         verify_event_authority(event_authority)?;
@@ -83,9 +100,14 @@ impl<'a> TryFrom<&'a [AccountView]> for WithdrawAccounts<'a> {
         verify_current_program_account(escrow)?;
         verify_current_program_account(receipt)?;
 
-        // 6. Validate ATA
+        // 6. Validate ATAs (the destination ATA may not exist yet and is created by the processor)
         validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
-        validate_associated_token_account(withdrawer_token_account, withdrawer.address(), mint, token_program)?;
+        validate_associated_token_account_address(
+            destination_token_account,
+            destination.address(),
+            mint,
+            token_program,
+        )?;
 
         Ok(Self {
             rent_recipient,
@@ -94,12 +116,16 @@ impl<'a> TryFrom<&'a [AccountView]> for WithdrawAccounts<'a> {
             extensions,
             receipt,
             vault,
-            withdrawer_token_account,
+            destination_token_account,
             mint,
             token_program,
             system_program,
             event_authority,
             escrow_program,
+            allowed_mint,
+            payer,
+            destination,
+            associated_token_program,
             remaining_accounts,
         })
     }
@@ -1,20 +1,29 @@
-use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use pinocchio_token_2022::instructions::TransferChecked;
 
 use crate::{
+    errors::EscrowProgramError,
     events::WithdrawEvent,
     instructions::Withdraw,
     state::{
-        get_extensions_from_account, validate_extensions_pda, ArbiterData, Escrow, ExtensionType, HookData, HookPoint,
-        Receipt, TimelockData,
+        get_extensions_from_account, release_from_allowed_mint, require_no_cooldown, take_hook_bypass_request,
+        validate_extensions_pda, AllowedMint, ArbiterData, Escrow, ExtensionType, HookData, HookPoint, Receipt,
+        TimelockData, WithdrawDelegate,
     },
     traits::{AccountDeserialize, EventSerialize, ExtensionData},
-    utils::{close_pda_account, emit_event, get_mint_decimals},
+    utils::{close_pda_account, emit_event, get_mint_decimals, validate_associated_token_account, verify_writable},
 };
 
 /// Processes the Withdraw instruction.
 ///
-/// Transfers tokens from escrow vault back to the withdrawer and closes the receipt PDA.
+/// Transfers tokens from escrow vault to the destination chosen by the withdrawer and closes the receipt PDA.
+/// An approved withdraw delegate may sign in place of the depositor, paying out only to the depositor.
 pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     let ix = Withdraw::try_from((instruction_data, accounts))?;
 
@@ -25,14 +34,49 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
     }
 
     // Read and validate receipt
-    let (amount, receipt_seed, receipt_mint, deposited_at) = {
+    let receipt = {
         let receipt_data = ix.accounts.receipt.try_borrow()?;
         let receipt = Receipt::from_account(&receipt_data, ix.accounts.receipt, program_id)?;
 
-        // Verify withdrawer matches the original depositor and the receipt is for this escrow
-        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.withdrawer.address())?;
+        // Verify the receipt is for this escrow
+        receipt.validate_escrow(ix.accounts.escrow.address())?;
+
+        // Requested receipts can only be paid out by ClaimWithdraw
+        receipt.require_no_withdraw_request()?;
+
+        // Rent goes back to whoever the deposit's payer designated
+        receipt.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+
+        receipt
+    };
+    let (amount, nonce, receipt_mint, deposited_at) =
+        (receipt.amount, receipt.nonce(), receipt.mint, receipt.deposited_at);
+
+    // A withdrawer other than the depositor must pass the receipt's WithdrawDelegate first in
+    // remaining_accounts, and delegated withdrawals can only pay out to the depositor
+    let (withdraw_delegate, remaining_accounts) = if *ix.accounts.withdrawer.address() == receipt.depositor {
+        (None, ix.accounts.remaining_accounts)
+    } else {
+        let delegate_account = ix.accounts.remaining_accounts.first().ok_or(EscrowProgramError::InvalidWithdrawer)?;
+        if !delegate_account.owned_by(program_id) {
+            return Err(EscrowProgramError::InvalidWithdrawer.into());
+        }
+        verify_writable(delegate_account, true)?;
+
+        let delegate_data = delegate_account.try_borrow()?;
+        let withdraw_delegate = WithdrawDelegate::from_account(&delegate_data, delegate_account, program_id)?;
+        withdraw_delegate.validate(
+            ix.accounts.receipt.address(),
+            ix.accounts.withdrawer.address(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        if *ix.accounts.destination.address() != receipt.depositor {
+            return Err(EscrowProgramError::InvalidWithdrawDestination.into());
+        }
 
-        (receipt.amount, receipt.receipt_seed, receipt.mint, receipt.deposited_at)
+        // Skip withdraw delegate account, pass rest to arbiter and hook
+        (Some(delegate_account), ix.accounts.remaining_accounts.get(1..).unwrap_or(&[]))
     };
 
     // Ensure the mint account matches the receipt's mint to prevent cross-mint withdrawals.
@@ -43,11 +87,26 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
     // Validate extensions PDA
     validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
 
-    // Get timelock, hook, and arbiter extensions in single pass
-    let exts = get_extensions_from_account(
-        ix.accounts.extensions,
-        &[ExtensionType::Timelock, ExtensionType::Hook, ExtensionType::Arbiter],
-    )?;
+    // Escrows with a cooldown only pay out through RequestWithdraw and ClaimWithdraw
+    require_no_cooldown(ix.accounts.extensions)?;
+
+    // Get timelock, hook, arbiter, and hook bypass extensions in single pass
+    let ext_types = [ExtensionType::Timelock, ExtensionType::Hook, ExtensionType::Arbiter, ExtensionType::HookBypass];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Per-mint overrides take precedence. A blocked mint has no AllowedMint account left and
+    // falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
 
     // Validate timelock if present
     if let Some(ref timelock_bytes) = exts[0] {
@@ -61,13 +120,23 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
     // Validate arbiter if present — arbiter is first in remaining_accounts
     let remaining_accounts = if let Some(ref arbiter_bytes) = exts[2] {
         let arbiter = ArbiterData::from_bytes(arbiter_bytes)?;
-        arbiter.validate(ix.accounts.remaining_accounts)?;
+        arbiter.validate(remaining_accounts)?;
         // Skip arbiter account, pass rest to hook
-        ix.accounts.remaining_accounts.get(1..).unwrap_or(&[])
+        remaining_accounts.get(1..).unwrap_or(&[])
     } else {
-        ix.accounts.remaining_accounts
+        remaining_accounts
     };
 
+    // A matured hook bypass request may be passed in place of the hook program to skip the hook
+    let hook_bypass_request = take_hook_bypass_request(
+        program_id,
+        hook_data.as_ref(),
+        exts[3].as_deref(),
+        remaining_accounts,
+        ix.accounts.receipt.address(),
+    )?;
+    let hook_data = if hook_bypass_request.is_some() { None } else { hook_data };
+
     // Invoke pre-withdraw hook if configured
     if let Some(ref hook) = hook_data {
         hook.invoke(
@@ -77,7 +146,27 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
         )?;
     }
 
-    // Transfer tokens from vault to withdrawer using escrow PDA as signer
+    // Create the destination ATA if it does not exist yet, otherwise check it is a live token account
+    if ix.accounts.destination_token_account.is_data_empty() {
+        CreateIdempotent {
+            funding_account: ix.accounts.payer,
+            account: ix.accounts.destination_token_account,
+            wallet: ix.accounts.destination,
+            mint: ix.accounts.mint,
+            system_program: ix.accounts.system_program,
+            token_program: ix.accounts.token_program,
+        }
+        .invoke()?;
+    } else {
+        validate_associated_token_account(
+            ix.accounts.destination_token_account,
+            ix.accounts.destination.address(),
+            ix.accounts.mint,
+            ix.accounts.token_program,
+        )?;
+    }
+
+    // Transfer tokens from vault to destination using escrow PDA as signer
     let decimals = get_mint_decimals(ix.accounts.mint)?;
 
     {
@@ -87,7 +176,7 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
             TransferChecked {
                 from: ix.accounts.vault,
                 mint: ix.accounts.mint,
-                to: ix.accounts.withdrawer_token_account,
+                to: ix.accounts.destination_token_account,
                 authority: ix.accounts.escrow,
                 amount,
                 decimals,
@@ -106,15 +195,35 @@ pub fn process_withdraw(program_id: &Address, accounts: &[AccountView], instruct
         )?;
     }
 
+    // Release the withdrawn amount from the mint's running total
+    release_from_allowed_mint(
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+        receipt.tracked_amount(),
+    )?;
+
     // Close receipt account and return lamports to rent_recipient
     close_pda_account(ix.accounts.receipt, ix.accounts.rent_recipient)?;
 
+    // Close the consumed hook bypass request alongside the receipt
+    if let Some(request_account) = hook_bypass_request {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Close the spent withdraw delegate alongside the receipt
+    if let Some(delegate_account) = withdraw_delegate {
+        close_pda_account(delegate_account, ix.accounts.rent_recipient)?;
+    }
+
     // Emit event
     let event = WithdrawEvent::new(
         *ix.accounts.escrow.address(),
         *ix.accounts.withdrawer.address(),
+        *ix.accounts.destination.address(),
         receipt_mint,
-        receipt_seed,
+        nonce,
         amount,
     );
     emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
@@ -0,0 +1,84 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_readonly, verify_signer,
+        verify_writable,
+    },
+};
+
+/// Accounts for the WithdrawBasket instruction
+///
+/// # Account Layout
+/// 0. `[writable]` rent_recipient - Receives rent from the closed basket receipt, must match basket_receipt.rent_recipient
+/// 1. `[signer]` withdrawer - Must match basket_receipt.depositor
+/// 2. `[]` escrow - Escrow PDA (signing authority for vault transfers)
+/// 3. `[]` extensions - Extensions PDA (optional, may be system-owned)
+/// 4. `[writable]` basket_receipt - BasketReceipt PDA to verify and close
+/// 5. `[]` event_authority - Event authority PDA
+/// 6. `[]` escrow_program - Current program
+///
+/// # Remaining Accounts
+/// 0..5N. One group of `BasketLegAccounts` per leg of the basket receipt, in the same order;
+///   the AllowedMint PDA may be closed if the mint was blocked
+/// Then, for each leg in order:
+///   `[signer]` arbiter - If an arbiter applies to the leg, the arbiter who must authorize the withdrawal
+///   `[]` hook_program - If a hook applies to the leg, the hook program to invoke, or a matured
+///     `[writable]` HookBypassRequest for the basket receipt to skip it
+///   `[]` extra accounts - `hook_account_counts[leg]` additional accounts to pass to the leg's hook (all read-only)
+pub struct WithdrawBasketAccounts<'a> {
+    pub rent_recipient: &'a AccountView,
+    pub withdrawer: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub basket_receipt: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for WithdrawBasketAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [rent_recipient, withdrawer, escrow, extensions, basket_receipt, event_authority, escrow_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(withdrawer, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(basket_receipt, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+
+        // 4. Validate program IDs
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+        verify_current_program_account(basket_receipt)?;
+
+        Ok(Self {
+            rent_recipient,
+            withdrawer,
+            escrow,
+            extensions,
+            basket_receipt,
+            event_authority,
+            escrow_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for WithdrawBasketAccounts<'a> {}
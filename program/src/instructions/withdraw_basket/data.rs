@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, state::BasketReceipt, traits::InstructionData};
+
+/// Instruction data for WithdrawBasket
+///
+/// # Layout
+/// * `hook_account_counts` (u32 count + u8 counts) - Number of extra accounts passed to each
+///   leg's hook after its hook program, in leg order (missing entries count as zero)
+pub struct WithdrawBasketData {
+    pub hook_account_counts: Vec<u8>,
+}
+
+impl WithdrawBasketData {
+    /// Number of extra hook accounts for the leg at `index`
+    #[inline(always)]
+    pub fn hook_account_count(&self, index: usize) -> usize {
+        self.hook_account_counts.get(index).copied().unwrap_or(0) as usize
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for WithdrawBasketData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let counts_len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+        if counts_len > BasketReceipt::MAX_LEGS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let hook_account_counts =
+            data.get(Self::LEN..Self::LEN + counts_len).ok_or(ProgramError::InvalidInstructionData)?.to_vec();
+
+        Ok(Self { hook_account_counts })
+    }
+}
+
+impl<'a> InstructionData<'a> for WithdrawBasketData {
+    const LEN: usize = 4; // hook_account_counts length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn withdraw_basket_data(counts: &[u8]) -> Vec<u8> {
+        let mut data = (counts.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(counts);
+        data
+    }
+
+    #[test]
+    fn test_withdraw_basket_data_try_from_valid() {
+        let data = withdraw_basket_data(&[0, 2]);
+        let result = WithdrawBasketData::try_from(&data[..]).unwrap();
+        assert_eq!(result.hook_account_counts, [0, 2]);
+        assert_eq!(result.hook_account_count(1), 2);
+        assert_eq!(result.hook_account_count(5), 0);
+    }
+
+    #[test]
+    fn test_withdraw_basket_data_try_from_no_counts() {
+        let data = withdraw_basket_data(&[]);
+        let result = WithdrawBasketData::try_from(&data[..]).unwrap();
+        assert!(result.hook_account_counts.is_empty());
+        assert_eq!(result.hook_account_count(0), 0);
+    }
+
+    #[test]
+    fn test_withdraw_basket_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = WithdrawBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_withdraw_basket_data_try_from_truncated_counts() {
+        let mut data = withdraw_basket_data(&[1, 2]);
+        data.pop();
+        let result = WithdrawBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_withdraw_basket_data_try_from_too_many_counts() {
+        let data = withdraw_basket_data(&[0; BasketReceipt::MAX_LEGS + 1]);
+        let result = WithdrawBasketData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
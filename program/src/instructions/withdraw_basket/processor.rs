@@ -0,0 +1,208 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    events::BasketWithdrawEvent,
+    instructions::{BasketLegAccounts, WithdrawBasket},
+    state::{
+        get_extensions_from_account, release_from_allowed_mint, require_no_cooldown, take_hook_bypass_request,
+        validate_extensions_pda, AllowedMint, AllowedMintPda, ArbiterData, BasketReceipt, Escrow, ExtensionType,
+        HookData, HookPoint, TimelockData,
+    },
+    traits::{AccountDeserialize, EventSerialize, ExtensionData, PdaSeeds},
+    utils::{close_pda_account, emit_event, get_mint_decimals},
+};
+
+/// Processes the WithdrawBasket instruction.
+///
+/// Withdraws every leg of a basket receipt back to the depositor and closes it. Each leg is
+/// checked against its mint's timelock, arbiter and hook, with per-mint overrides applied.
+/// Overrides may change after the deposit, so every leg takes its own arbiter and hook
+/// accounts, and a matured hook bypass request for the basket skips a leg's hook.
+pub fn process_withdraw_basket(
+    program_id: &Address,
+    accounts: &[AccountView],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ix = WithdrawBasket::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Read and validate the basket receipt
+    let basket = {
+        let basket_data = ix.accounts.basket_receipt.try_borrow()?;
+        BasketReceipt::from_account(&basket_data, ix.accounts.basket_receipt, program_id)?
+    };
+    basket.validate_depositor(ix.accounts.escrow.address(), ix.accounts.withdrawer.address())?;
+
+    // Rent goes back to whoever the deposit's payer designated
+    basket.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+
+    // Leg accounts come first in remaining_accounts, in the basket's leg order
+    let (legs, remaining_accounts) = BasketLegAccounts::parse(
+        ix.accounts.remaining_accounts,
+        basket.legs.len(),
+        ix.accounts.escrow.address(),
+        ix.accounts.withdrawer.address(),
+    )?;
+    for (leg, recorded) in legs.iter().zip(&basket.legs) {
+        if *leg.mint.address() != recorded.mint {
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Escrows with a cooldown only pay out through RequestWithdraw and ClaimWithdraw, which
+    // baskets do not support
+    require_no_cooldown(ix.accounts.extensions)?;
+
+    // Get timelock, hook, arbiter, and hook bypass extensions in single pass, then resolve them
+    // per leg. A blocked mint has no AllowedMint account left and falls back to the escrow-level
+    // extensions.
+    let ext_types = [ExtensionType::Timelock, ExtensionType::Hook, ExtensionType::Arbiter, ExtensionType::HookBypass];
+    let exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    let mut leg_exts = Vec::with_capacity(legs.len());
+    for leg in &legs {
+        let mut resolved = exts.clone();
+        if leg.allowed_mint.owned_by(program_id) {
+            let allowed_mint_data = leg.allowed_mint.try_borrow()?;
+            AllowedMint::from_account(
+                &allowed_mint_data,
+                leg.allowed_mint,
+                program_id,
+                ix.accounts.escrow.address(),
+                leg.mint.address(),
+            )?;
+            AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut resolved)?;
+        } else {
+            AllowedMintPda::new(ix.accounts.escrow.address(), leg.mint.address())
+                .validate_pda_address(leg.allowed_mint, program_id)?;
+        }
+        leg_exts.push(resolved);
+    }
+
+    // Validate each leg's timelock
+    for resolved in &leg_exts {
+        if let Some(ref timelock_bytes) = resolved[0] {
+            TimelockData::from_bytes(timelock_bytes)?.validate(basket.deposited_at)?;
+        }
+    }
+
+    // Each leg's arbiter and then its hook accounts follow the leg accounts, leg by leg
+    let mut leg_accounts = remaining_accounts;
+    let mut leg_hooks = Vec::with_capacity(legs.len());
+    let mut hook_bypass_request = None;
+    for (index, resolved) in leg_exts.iter().enumerate() {
+        if let Some(ref arbiter_bytes) = resolved[2] {
+            ArbiterData::from_bytes(arbiter_bytes)?.validate(leg_accounts)?;
+            leg_accounts = leg_accounts.get(1..).unwrap_or(&[]);
+        }
+
+        let Some(hook) = resolved[1].as_ref().map(|b| HookData::from_bytes(b)).transpose()? else {
+            leg_hooks.push(None);
+            continue;
+        };
+        let hook_accounts_len = 1 + ix.data.hook_account_count(index);
+        let hook_accounts = leg_accounts.get(..hook_accounts_len).ok_or(ProgramError::NotEnoughAccountKeys)?;
+        leg_accounts = &leg_accounts[hook_accounts_len..];
+
+        // A matured hook bypass request may be passed in place of the leg's hook program
+        let request = take_hook_bypass_request(
+            program_id,
+            Some(&hook),
+            resolved[3].as_deref(),
+            hook_accounts,
+            ix.accounts.basket_receipt.address(),
+        )?;
+        if request.is_some() {
+            hook_bypass_request = request;
+            leg_hooks.push(None);
+        } else {
+            leg_hooks.push(Some((hook, hook_accounts)));
+        }
+    }
+    let has_hook = leg_hooks.iter().any(Option::is_some);
+
+    // Invokes the hook of every leg that has one, with the basket receipt in the receipt slot
+    let invoke_hooks = |hook_point: HookPoint| -> ProgramResult {
+        for (leg, leg_hook) in legs.iter().zip(&leg_hooks) {
+            if let Some((hook, hook_accounts)) = leg_hook {
+                hook.invoke(
+                    hook_point,
+                    hook_accounts,
+                    &[ix.accounts.escrow, ix.accounts.withdrawer, leg.mint, ix.accounts.basket_receipt],
+                )?;
+            }
+        }
+        Ok(())
+    };
+
+    // Invoke pre-withdraw hooks if configured
+    if has_hook {
+        invoke_hooks(HookPoint::PreWithdraw)?;
+    }
+
+    // Transfer every leg from its vault back to the depositor using escrow PDA as signer
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_bytes(&escrow_data)?;
+        for (leg, recorded) in legs.iter().zip(&basket.legs) {
+            let decimals = get_mint_decimals(leg.mint)?;
+            escrow.with_signer(|signers| {
+                TransferChecked {
+                    from: leg.vault,
+                    mint: leg.mint,
+                    to: leg.token_account,
+                    authority: ix.accounts.escrow,
+                    amount: recorded.amount,
+                    decimals,
+                    token_program: leg.token_program.address(),
+                }
+                .invoke_signed(signers)
+            })?;
+        }
+    }
+
+    // Invoke post-withdraw hooks if configured (basket receipt is still open, pass it for context)
+    if has_hook {
+        invoke_hooks(HookPoint::PostWithdraw)?;
+    }
+
+    // Release the legs from their mints' running totals
+    for (leg, recorded) in legs.iter().zip(&basket.legs) {
+        release_from_allowed_mint(
+            leg.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            leg.mint.address(),
+            Some(recorded.amount),
+        )?;
+    }
+
+    // Close basket receipt and return lamports to rent_recipient
+    close_pda_account(ix.accounts.basket_receipt, ix.accounts.rent_recipient)?;
+
+    // Close the consumed hook bypass request alongside the basket receipt
+    if let Some(request_account) = hook_bypass_request {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Emit event via CPI
+    let event = BasketWithdrawEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.withdrawer.address(),
+        basket.basket_id(),
+        basket.legs,
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use pinocchio::{account::AccountView, error::ProgramError};
+
+use crate::{
+    traits::InstructionAccounts,
+    utils::{
+        validate_associated_token_account, validate_associated_token_account_address, verify_associated_token_program,
+        verify_current_program, verify_current_program_account, verify_event_authority, verify_owned_by,
+        verify_readonly, verify_signer, verify_system_program, verify_token_program, verify_writable,
+    },
+};
+
+/// Accounts for the WithdrawMany instruction
+///
+/// # Account Layout
+/// 0. `[signer, writable]` payer - Pays for the destination token account if it has to be created
+/// 1. `[writable]` rent_recipient - Receives rent from closed receipts, must match every receipt.rent_recipient
+/// 2. `[signer]` withdrawer - Must match receipt.depositor of every receipt
+/// 3. `[]` escrow - Escrow PDA (signing authority for vault transfer)
+/// 4. `[]` extensions - Extensions PDA (optional, may be system-owned)
+/// 5. `[writable]` vault - Escrow's vault token account (source)
+/// 6. `[]` destination - Owner of the destination token account, chosen by the withdrawer
+/// 7. `[writable]` destination_token_account - Destination's ATA (created if it does not exist)
+/// 8. `[]` mint - Token mint shared by every receipt
+/// 9. `[]` token_program - SPL Token program
+/// 10. `[]` associated_token_program - Associated Token program for destination ATA creation
+/// 11. `[]` system_program - System program
+/// 12. `[]` event_authority - Event authority PDA
+/// 13. `[]` escrow_program - Current program
+/// 14. `[writable]` allowed_mint - AllowedMint PDA `[b"allowed_mint", escrow, mint]` (may be closed if the mint was blocked)
+///
+/// # Remaining Accounts
+/// 0..N. `[writable]` receipts - `receipt_count` deposit receipts to verify and close
+/// If arbiter extension is set:
+///   N. `[signer]` arbiter - The arbiter who must authorize the batch
+/// Optional (after arbiter if set):
+///   N.. `[writable]` hook_bypass_requests - Hook bypass request PDAs for receipts of the batch, closed
+///   with them; with the hook bypass extension set, each must have matured and takes its receipt out of the hook
+/// If hook extension is set (after arbiter and hook bypass requests):
+///   N. `[]` hook_program - The hook program to invoke (N counts the preceding accounts)
+///   N+1..M. `[]` extra accounts - Additional accounts to pass to the hook (all read-only)
+pub struct WithdrawManyAccounts<'a> {
+    pub payer: &'a AccountView,
+    pub rent_recipient: &'a AccountView,
+    pub withdrawer: &'a AccountView,
+    pub escrow: &'a AccountView,
+    pub extensions: &'a AccountView,
+    pub vault: &'a AccountView,
+    pub destination: &'a AccountView,
+    pub destination_token_account: &'a AccountView,
+    pub mint: &'a AccountView,
+    pub token_program: &'a AccountView,
+    pub associated_token_program: &'a AccountView,
+    pub system_program: &'a AccountView,
+    pub event_authority: &'a AccountView,
+    pub escrow_program: &'a AccountView,
+    pub allowed_mint: &'a AccountView,
+    pub remaining_accounts: &'a [AccountView],
+}
+
+impl<'a> TryFrom<&'a [AccountView]> for WithdrawManyAccounts<'a> {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(accounts: &'a [AccountView]) -> Result<Self, Self::Error> {
+        let [payer, rent_recipient, withdrawer, escrow, extensions, vault, destination, destination_token_account, mint, token_program, associated_token_program, system_program, event_authority, escrow_program, allowed_mint, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // 1. Validate signers
+        verify_signer(payer, true)?;
+        verify_signer(withdrawer, false)?;
+
+        // 2. Validate writable
+        verify_writable(rent_recipient, true)?;
+        verify_writable(vault, true)?;
+        verify_writable(destination_token_account, true)?;
+        verify_writable(allowed_mint, true)?;
+
+        // 3. Validate readonly
+        verify_readonly(escrow)?;
+        verify_readonly(extensions)?;
+        verify_readonly(destination)?;
+        verify_readonly(mint)?;
+
+        // 4. Validate program IDs
+        verify_token_program(token_program)?;
+        verify_owned_by(mint, token_program.address())?;
+        verify_associated_token_program(associated_token_program)?;
+        verify_system_program(system_program)?;
+        verify_current_program(escrow_program)?;
+        verify_event_authority(event_authority)?;
+
+        // 5. Validate accounts owned by current program
+        verify_current_program_account(escrow)?;
+
+        // 6. Validate ATAs (the destination ATA may not exist yet and is created by the processor)
+        validate_associated_token_account(vault, escrow.address(), mint, token_program)?;
+        validate_associated_token_account_address(
+            destination_token_account,
+            destination.address(),
+            mint,
+            token_program,
+        )?;
+
+        Ok(Self {
+            payer,
+            rent_recipient,
+            withdrawer,
+            escrow,
+            extensions,
+            vault,
+            destination,
+            destination_token_account,
+            mint,
+            token_program,
+            associated_token_program,
+            system_program,
+            event_authority,
+            escrow_program,
+            allowed_mint,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> InstructionAccounts<'a> for WithdrawManyAccounts<'a> {}
@@ -0,0 +1,57 @@
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::InstructionData};
+
+/// Instruction data for WithdrawMany
+///
+/// # Layout
+/// * `receipt_count` (u8) - Number of receipts at the start of remaining_accounts, at least one
+pub struct WithdrawManyData {
+    pub receipt_count: u8,
+}
+
+impl<'a> TryFrom<&'a [u8]> for WithdrawManyData {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        require_len!(data, Self::LEN);
+
+        let receipt_count = data[0];
+        if receipt_count == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { receipt_count })
+    }
+}
+
+impl<'a> InstructionData<'a> for WithdrawManyData {
+    const LEN: usize = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdraw_many_data_try_from_valid() {
+        let data = [20u8];
+        let parsed = WithdrawManyData::try_from(&data[..]).unwrap();
+        assert_eq!(parsed.receipt_count, 20);
+    }
+
+    #[test]
+    fn test_withdraw_many_data_try_from_empty() {
+        let data: [u8; 0] = [];
+        let result = WithdrawManyData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+
+    #[test]
+    fn test_withdraw_many_data_try_from_zero_receipts() {
+        let data = [0u8];
+        let result = WithdrawManyData::try_from(&data[..]);
+        assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
+    }
+}
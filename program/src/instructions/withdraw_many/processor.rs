@@ -0,0 +1,258 @@
+use alloc::{vec, vec::Vec};
+use pinocchio::{account::AccountView, error::ProgramError, Address, ProgramResult};
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
+use pinocchio_token_2022::instructions::TransferChecked;
+
+use crate::{
+    errors::EscrowProgramError,
+    events::WithdrawManyEvent,
+    instructions::WithdrawMany,
+    state::{
+        get_extensions_from_account, release_from_allowed_mint, require_no_cooldown, validate_extensions_pda,
+        AllowedMint, ArbiterData, Escrow, ExtensionType, HookBypassData, HookBypassRequest, HookData, HookPoint,
+        Receipt, TimelockData,
+    },
+    traits::{AccountDeserialize, EventSerialize, ExtensionData},
+    utils::{
+        close_pda_account, emit_event, get_mint_decimals, validate_associated_token_account,
+        verify_current_program_account, verify_writable,
+    },
+};
+
+/// Processes the WithdrawMany instruction.
+///
+/// Withdraws several of the depositor's receipts for one escrow and mint with a single vault transfer
+/// and closes them. Extensions are checked once, the timelock against every receipt. The hook runs once
+/// per receipt, or once for the batch at the batch hook points if its `FLAG_BATCH_WITHDRAW_HOOK` flag is set.
+/// Receipts whose matured hook bypass request is passed are left out of the hook, as in `Withdraw`.
+pub fn process_withdraw_many(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    let ix = WithdrawMany::try_from((instruction_data, accounts))?;
+
+    // Validate escrow PDA
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let _escrow = Escrow::from_account(&escrow_data, ix.accounts.escrow, program_id)?;
+    }
+
+    // Receipts come first in remaining_accounts, followed by the arbiter and hook accounts
+    let receipt_count = ix.data.receipt_count as usize;
+    if ix.accounts.remaining_accounts.len() < receipt_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (receipt_accounts, remaining_accounts) = ix.accounts.remaining_accounts.split_at(receipt_count);
+
+    // Read and validate every receipt
+    let mut receipts = Vec::with_capacity(receipt_count);
+    let mut total_amount: u64 = 0;
+    for (index, receipt_account) in receipt_accounts.iter().enumerate() {
+        // A repeated receipt would be paid out twice by the aggregated transfer
+        if receipt_accounts[..index].iter().any(|other| other.address() == receipt_account.address()) {
+            return Err(EscrowProgramError::DuplicateReceipt.into());
+        }
+        verify_current_program_account(receipt_account)?;
+        verify_writable(receipt_account, true)?;
+
+        let receipt_data = receipt_account.try_borrow()?;
+        let receipt = Receipt::from_account(&receipt_data, receipt_account, program_id)?;
+
+        // Verify the receipt is for this escrow and belongs to the withdrawer
+        receipt.validate_depositor(ix.accounts.escrow.address(), ix.accounts.withdrawer.address())?;
+
+        // Requested receipts can only be paid out by ClaimWithdraw
+        receipt.require_no_withdraw_request()?;
+
+        // Rent goes back to whoever the deposit's payer designated
+        receipt.validate_rent_recipient(ix.accounts.rent_recipient.address())?;
+
+        // Ensure every receipt is for the mint being transferred to prevent cross-mint withdrawals.
+        if receipt.mint != *ix.accounts.mint.address() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        total_amount = total_amount.checked_add(receipt.amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        receipts.push(receipt);
+    }
+
+    // Validate extensions PDA
+    validate_extensions_pda(ix.accounts.escrow, ix.accounts.extensions, program_id)?;
+
+    // Escrows with a cooldown only pay out through RequestWithdraw and ClaimWithdraw
+    require_no_cooldown(ix.accounts.extensions)?;
+
+    // Get timelock, hook, arbiter, and hook bypass extensions in single pass
+    let ext_types = [ExtensionType::Timelock, ExtensionType::Hook, ExtensionType::Arbiter, ExtensionType::HookBypass];
+    let mut exts = get_extensions_from_account(ix.accounts.extensions, &ext_types)?;
+
+    // Per-mint overrides take precedence. A blocked mint has no AllowedMint account left and
+    // falls back to the escrow-level extensions.
+    if ix.accounts.allowed_mint.owned_by(program_id) {
+        let allowed_mint_data = ix.accounts.allowed_mint.try_borrow()?;
+        AllowedMint::from_account(
+            &allowed_mint_data,
+            ix.accounts.allowed_mint,
+            program_id,
+            ix.accounts.escrow.address(),
+            ix.accounts.mint.address(),
+        )?;
+        AllowedMint::apply_overrides(&allowed_mint_data, &ext_types, &mut exts)?;
+    }
+
+    // Validate timelock against every receipt if present
+    if let Some(ref timelock_bytes) = exts[0] {
+        let timelock = TimelockData::from_bytes(timelock_bytes)?;
+        for receipt in &receipts {
+            timelock.validate(receipt.deposited_at)?;
+        }
+    }
+
+    // Parse hook if present
+    let hook_data = exts[1].as_ref().map(|b| HookData::from_bytes(b)).transpose()?;
+
+    // Validate arbiter if present — arbiter is first after the receipts
+    let remaining_accounts = if let Some(ref arbiter_bytes) = exts[2] {
+        let arbiter = ArbiterData::from_bytes(arbiter_bytes)?;
+        arbiter.validate(remaining_accounts)?;
+        // Skip arbiter account, pass rest to hook
+        remaining_accounts.get(1..).unwrap_or(&[])
+    } else {
+        remaining_accounts
+    };
+
+    // Hook bypass requests come next, ahead of the hook program. Hook programs are never owned by
+    // this program, so the requests are the leading accounts it owns. Each is closed with its receipt.
+    let request_count = remaining_accounts.iter().take_while(|account| account.owned_by(program_id)).count();
+    let (hook_bypass_requests, remaining_accounts) = remaining_accounts.split_at(request_count);
+    let hook_bypass = exts[3].as_ref().map(|b| HookBypassData::from_bytes(b)).transpose()?;
+    let hook_bypass = hook_bypass.filter(|_| hook_data.is_some());
+    let mut requested = vec![false; receipt_count];
+    for request_account in hook_bypass_requests {
+        verify_writable(request_account, true)?;
+        let request_data = request_account.try_borrow()?;
+        let request = HookBypassRequest::from_account(&request_data, request_account, program_id)?;
+
+        // Each request must belong to a distinct receipt of the batch
+        let index = receipt_accounts
+            .iter()
+            .position(|receipt_account| receipt_account.address() == &request.receipt)
+            .ok_or(EscrowProgramError::InvalidHookBypassRequest)?;
+        if requested[index] {
+            return Err(EscrowProgramError::InvalidHookBypassRequest.into());
+        }
+
+        // With a hook and the hook bypass extension, the matured request takes the receipt out of the hook
+        if let Some(ref hook_bypass) = hook_bypass {
+            hook_bypass.validate(request.requested_at)?;
+        }
+        requested[index] = true;
+    }
+    let hooked_receipts: Vec<&AccountView> = receipt_accounts
+        .iter()
+        .zip(&requested)
+        .filter(|(_, requested)| hook_bypass.is_none() || !**requested)
+        .map(|(receipt_account, _)| receipt_account)
+        .collect();
+
+    // Invokes the hook for the whole batch, or once per receipt, leaving out bypassed receipts
+    let invoke_hook = |hook: &HookData, hook_point: HookPoint, batch_hook_point: HookPoint| -> ProgramResult {
+        if hooked_receipts.is_empty() {
+            return Ok(());
+        }
+        let core_accounts = [ix.accounts.escrow, ix.accounts.withdrawer, ix.accounts.mint];
+        if hook.batch_withdraw_hook() {
+            let batch_accounts: Vec<&AccountView> =
+                core_accounts.iter().copied().chain(hooked_receipts.iter().copied()).collect();
+            hook.invoke_batch(batch_hook_point, remaining_accounts, &batch_accounts, hooked_receipts.len() as u8)
+        } else {
+            for receipt_account in &hooked_receipts {
+                hook.invoke(
+                    hook_point,
+                    remaining_accounts,
+                    &[ix.accounts.escrow, ix.accounts.withdrawer, ix.accounts.mint, receipt_account],
+                )?;
+            }
+            Ok(())
+        }
+    };
+
+    // Invoke pre-withdraw hook if configured
+    if let Some(ref hook) = hook_data {
+        invoke_hook(hook, HookPoint::PreWithdraw, HookPoint::PreWithdrawBatch)?;
+    }
+
+    // Create the destination ATA if it does not exist yet, otherwise check it is a live token account
+    if ix.accounts.destination_token_account.is_data_empty() {
+        CreateIdempotent {
+            funding_account: ix.accounts.payer,
+            account: ix.accounts.destination_token_account,
+            wallet: ix.accounts.destination,
+            mint: ix.accounts.mint,
+            system_program: ix.accounts.system_program,
+            token_program: ix.accounts.token_program,
+        }
+        .invoke()?;
+    } else {
+        validate_associated_token_account(
+            ix.accounts.destination_token_account,
+            ix.accounts.destination.address(),
+            ix.accounts.mint,
+            ix.accounts.token_program,
+        )?;
+    }
+
+    // Transfer the combined amount from vault to destination using escrow PDA as signer
+    let decimals = get_mint_decimals(ix.accounts.mint)?;
+
+    {
+        let escrow_data = ix.accounts.escrow.try_borrow()?;
+        let escrow = Escrow::from_bytes(&escrow_data)?;
+        escrow.with_signer(|signers| {
+            TransferChecked {
+                from: ix.accounts.vault,
+                mint: ix.accounts.mint,
+                to: ix.accounts.destination_token_account,
+                authority: ix.accounts.escrow,
+                amount: total_amount,
+                decimals,
+                token_program: ix.accounts.token_program.address(),
+            }
+            .invoke_signed(signers)
+        })?;
+    }
+
+    // Invoke post-withdraw hook if configured (receipts are still open, pass them for context)
+    if let Some(ref hook) = hook_data {
+        invoke_hook(hook, HookPoint::PostWithdraw, HookPoint::PostWithdrawBatch)?;
+    }
+
+    // Release the withdrawn receipts from the mint's running totals
+    release_from_allowed_mint(
+        ix.accounts.allowed_mint,
+        program_id,
+        ix.accounts.escrow.address(),
+        ix.accounts.mint.address(),
+        receipts.iter().filter_map(Receipt::tracked_amount),
+    )?;
+
+    // Close receipt accounts and return lamports to rent_recipient
+    for receipt_account in receipt_accounts {
+        close_pda_account(receipt_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Close the hook bypass requests alongside their receipts
+    for request_account in hook_bypass_requests {
+        close_pda_account(request_account, ix.accounts.rent_recipient)?;
+    }
+
+    // Emit a single summary event
+    let event = WithdrawManyEvent::new(
+        *ix.accounts.escrow.address(),
+        *ix.accounts.withdrawer.address(),
+        *ix.accounts.destination.address(),
+        *ix.accounts.mint.address(),
+        total_amount,
+        receipts.iter().map(|receipt| receipt.nonce()).collect(),
+    );
+    emit_event(program_id, ix.accounts.event_authority, ix.accounts.escrow_program, &event.to_bytes())?;
+
+    Ok(())
+}
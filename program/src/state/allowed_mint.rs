@@ -1,12 +1,15 @@
 use alloc::vec;
 use alloc::vec::Vec;
 use codama::CodamaAccount;
-use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
 
-use crate::assert_no_padding;
+use crate::errors::EscrowProgramError::{DepositAboveMaximum, DepositBelowMinimum, DepositCapExceeded};
+use crate::state::{ExtensionType, TLV_HEADER_SIZE};
 use crate::traits::{
-    AccountDeserialize, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaSeeds, Versioned,
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaSeeds, Versioned,
 };
+use crate::utils::{resize_pda_account, TlvReader};
+use crate::{assert_no_padding, require_account_len, validate_discriminator};
 
 /// AllowedMint account state
 ///
@@ -14,6 +17,21 @@ use crate::traits::{
 /// The existence of this PDA indicates the mint is allowed.
 /// The PDA seeds themselves validate the escrow and mint relationship.
 ///
+/// Deposit limits are set by the admin via `SetMintLimits`; a limit of 0 means unlimited.
+/// `total_deposited` tracks the amount currently owed to depositors for this mint and
+/// `outstanding_receipts` the number of receipts still open; `VerifySolvency` checks both
+/// against the vault. `opening_balance` is the vault balance when the mint was allowed; after a
+/// block and re-allow it may back receipts the counters no longer see, so it is never swept.
+///
+/// The fixed fields may be followed by a TLV area of per-mint overrides, set via `SetMintOverride`.
+/// Each entry uses the extensions PDA's TLV format and replaces the escrow-level timelock, hook, or
+/// arbiter for deposits and withdrawals of this mint.
+///
+/// Version 1 accounts only hold the bump. They parse with no limits and zero counters, and with
+/// `LEGACY_OPENING_BALANCE` since the receipts they back were never counted. The first Deposit,
+/// DepositBasket or SetMintOverride grows them to the current layout; a deposit records the
+/// vault balance at that point as the opening balance.
+///
 /// # PDA Seeds
 /// `[b"allowed_mint", escrow.as_ref(), mint.as_ref()]`
 #[derive(Clone, Debug, PartialEq, CodamaAccount)]
@@ -25,46 +43,156 @@ use crate::traits::{
 #[repr(C)]
 pub struct AllowedMint {
     pub bump: u8,
+    _padding: [u8; 7],
+
+    pub max_total_deposits: u64,
+    pub min_deposit: u64,
+    pub max_deposit: u64,
+    pub total_deposited: u64,
+    pub outstanding_receipts: u64,
+    pub opening_balance: u64,
 }
 
-assert_no_padding!(AllowedMint, 1);
+assert_no_padding!(AllowedMint, 1 + 7 + 8 + 8 + 8 + 8 + 8 + 8);
 
 impl Discriminator for AllowedMint {
     const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::AllowedMintDiscriminator as u8;
 }
 
 impl Versioned for AllowedMint {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 2;
 }
 
 impl AccountSize for AllowedMint {
-    const DATA_LEN: usize = 1; // bump only
+    const DATA_LEN: usize = 1 + 7 + 8 + 8 + 8 + 8 + 8 + 8; // bump + padding + max_total_deposits + min_deposit + max_deposit + total_deposited + outstanding_receipts + opening_balance
 }
 
-impl AccountDeserialize for AllowedMint {}
+impl AccountParse for AllowedMint {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEGACY_LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        match data[1] {
+            Self::LEGACY_VERSION => return Ok(Self::new(data[2], Self::LEGACY_OPENING_BALANCE)),
+            Self::VERSION => require_account_len!(data, Self::LEN),
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        // Skip padding bytes [1..8]
+        Ok(Self {
+            bump: data[0],
+            _padding: [0u8; 7],
+            max_total_deposits: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            min_deposit: u64::from_le_bytes(data[16..24].try_into().unwrap()),
+            max_deposit: u64::from_le_bytes(data[24..32].try_into().unwrap()),
+            total_deposited: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+            outstanding_receipts: u64::from_le_bytes(data[40..48].try_into().unwrap()),
+            opening_balance: u64::from_le_bytes(data[48..56].try_into().unwrap()),
+        })
+    }
+}
 
 impl AccountSerialize for AllowedMint {
     #[inline(always)]
     fn to_bytes_inner(&self) -> Vec<u8> {
-        vec![self.bump]
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(&self.max_total_deposits.to_le_bytes());
+        data.extend_from_slice(&self.min_deposit.to_le_bytes());
+        data.extend_from_slice(&self.max_deposit.to_le_bytes());
+        data.extend_from_slice(&self.total_deposited.to_le_bytes());
+        data.extend_from_slice(&self.outstanding_receipts.to_le_bytes());
+        data.extend_from_slice(&self.opening_balance.to_le_bytes());
+        data
     }
 }
 
 impl AllowedMint {
+    /// Version of accounts written before limits and counters existed
+    pub const LEGACY_VERSION: u8 = 1;
+
+    /// Total size of a legacy account (bump only)
+    pub const LEGACY_LEN: usize = 1 + 1 + 1; // discriminator + version + bump
+
+    /// Opening balance of a legacy account until a deposit records the vault balance, so that
+    /// no part of the vault is treated as surplus
+    pub const LEGACY_OPENING_BALANCE: u64 = u64::MAX;
+
+    #[inline(always)]
+    pub fn new(bump: u8, opening_balance: u64) -> Self {
+        Self {
+            bump,
+            _padding: [0u8; 7],
+            max_total_deposits: 0,
+            min_deposit: 0,
+            max_deposit: 0,
+            total_deposited: 0,
+            outstanding_receipts: 0,
+            opening_balance,
+        }
+    }
+
+    #[inline(always)]
+    pub fn set_limits(&mut self, max_total_deposits: u64, min_deposit: u64, max_deposit: u64) {
+        self.max_total_deposits = max_total_deposits;
+        self.min_deposit = min_deposit;
+        self.max_deposit = max_deposit;
+    }
+
+    /// Checks `amount` against the per-deposit and total limits and records a new receipt for it
+    #[inline(always)]
+    pub fn record_deposit(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if amount < self.min_deposit {
+            return Err(DepositBelowMinimum.into());
+        }
+        if self.max_deposit != 0 && amount > self.max_deposit {
+            return Err(DepositAboveMaximum.into());
+        }
+
+        let total_deposited = self.total_deposited.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        if self.max_total_deposits != 0 && total_deposited > self.max_total_deposits {
+            return Err(DepositCapExceeded.into());
+        }
+        let outstanding_receipts = self.outstanding_receipts.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.total_deposited = total_deposited;
+        self.outstanding_receipts = outstanding_receipts;
+        Ok(())
+    }
+
+    /// Removes a withdrawn `amount` and its receipt from the running totals
+    ///
+    /// Fails if the totals do not cover the receipt, which means it was never counted here.
+    #[inline(always)]
+    pub fn record_withdraw(&mut self, amount: u64) -> Result<(), ProgramError> {
+        let total_deposited = self.total_deposited.checked_sub(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        let outstanding_receipts = self.outstanding_receipts.checked_sub(1).ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.total_deposited = total_deposited;
+        self.outstanding_receipts = outstanding_receipts;
+        Ok(())
+    }
+
+    /// Returns the part of `vault_balance` not needed to back receipts
+    ///
+    /// Both the tracked deposits and the opening balance are held back.
     #[inline(always)]
-    pub fn new(bump: u8) -> Self {
-        Self { bump }
+    pub fn surplus(&self, vault_balance: u64) -> u64 {
+        vault_balance.saturating_sub(self.total_deposited).saturating_sub(self.opening_balance)
     }
 
     #[inline(always)]
-    pub fn from_account<'a>(
-        data: &'a [u8],
+    pub fn from_account(
+        data: &[u8],
         account: &AccountView,
         program_id: &Address,
         escrow: &Address,
         mint: &Address,
-    ) -> Result<&'a Self, ProgramError> {
-        let state = Self::from_bytes(data)?;
+    ) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
         let derived = Address::derive_address(
             &[AllowedMintPda::PREFIX, escrow.as_ref(), mint.as_ref()],
             Some(state.bump),
@@ -75,11 +203,140 @@ impl AllowedMint {
         }
         Ok(state)
     }
+
+    /// Returns true for version 1 account data, which only holds the bump
+    #[inline(always)]
+    pub fn is_legacy(data: &[u8]) -> bool {
+        data.get(1) == Some(&Self::LEGACY_VERSION)
+    }
+
+    /// Writes the state to `allowed_mint`, first growing a legacy account to the current layout
+    /// with `payer` topping up rent
+    pub fn write_to_account(&self, payer: &AccountView, allowed_mint: &AccountView) -> ProgramResult {
+        if Self::is_legacy(&allowed_mint.try_borrow()?) {
+            resize_pda_account(payer, allowed_mint, Self::LEN)?;
+        }
+        self.write_to_slice(&mut allowed_mint.try_borrow_mut()?)
+    }
+
+    /// Returns the raw data of the per-mint override for `ext_type`, if any
+    #[inline(always)]
+    pub fn find_override(data: &[u8], ext_type: ExtensionType) -> Result<Option<&[u8]>, ProgramError> {
+        TlvReader::with_offset(data, Self::LEN).find_extension(ext_type)
+    }
+
+    /// Replaces entries of `exts` (read for the types in `to_get`) with this mint's overrides
+    pub fn apply_overrides(
+        data: &[u8],
+        to_get: &[ExtensionType],
+        exts: &mut [Option<Vec<u8>>],
+    ) -> Result<(), ProgramError> {
+        for (ext_type, ext) in to_get.iter().zip(exts.iter_mut()) {
+            if let Some(value) = Self::find_override(data, *ext_type)? {
+                *ext = Some(value.to_vec());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sets or removes the per-mint override for `ext_type` on an AllowedMint account.
+///
+/// `value` is the raw extension data, or `None` to remove the override. The account is resized
+/// to fit the remaining overrides, with `payer` topping up rent on growth.
+/// Returns error if removing an override that doesn't exist.
+pub fn set_mint_override(
+    payer: &AccountView,
+    allowed_mint: &AccountView,
+    ext_type: ExtensionType,
+    value: Option<&[u8]>,
+) -> ProgramResult {
+    // Grow a legacy account first so the overrides follow the current fixed fields
+    if AllowedMint::is_legacy(&allowed_mint.try_borrow()?) {
+        let state = AllowedMint::parse_from_bytes(&allowed_mint.try_borrow()?)?;
+        state.write_to_account(payer, allowed_mint)?;
+    }
+
+    let data = allowed_mint.try_borrow()?;
+    require_account_len!(data, AllowedMint::LEN);
+
+    // Keep every other override entry
+    let mut overrides = Vec::new();
+    let mut found = false;
+    let mut offset = AllowedMint::LEN;
+
+    while offset + TLV_HEADER_SIZE <= data.len() {
+        let type_bytes = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+
+        let entry_end = offset + TLV_HEADER_SIZE + length;
+        if entry_end > data.len() {
+            break;
+        }
+
+        if type_bytes == ext_type as u16 {
+            found = true;
+        } else {
+            overrides.extend_from_slice(&data[offset..entry_end]);
+        }
+
+        offset = entry_end;
+    }
+
+    drop(data);
+
+    match value {
+        Some(value) => {
+            overrides.extend_from_slice(&(ext_type as u16).to_le_bytes());
+            overrides.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            overrides.extend_from_slice(value);
+        }
+        None if !found => return Err(ProgramError::UninitializedAccount),
+        None => {}
+    }
+
+    let required_size = AllowedMint::LEN + overrides.len();
+    resize_pda_account(payer, allowed_mint, required_size)?;
+
+    let mut data = allowed_mint.try_borrow_mut()?;
+    data[AllowedMint::LEN..required_size].copy_from_slice(&overrides);
+
+    Ok(())
+}
+
+/// Releases paid-out receipts from a mint's running totals on its AllowedMint account.
+///
+/// `amounts` holds the amount of each counted receipt paid out; legacy receipts predate the
+/// counters and are left out. A blocked mint has no AllowedMint account left to update, but the
+/// address must still be the mint's PDA. A legacy AllowedMint counted no receipts and is left as is.
+pub fn release_from_allowed_mint(
+    allowed_mint: &AccountView,
+    program_id: &Address,
+    escrow: &Address,
+    mint: &Address,
+    amounts: impl IntoIterator<Item = u64>,
+) -> ProgramResult {
+    if !allowed_mint.owned_by(program_id) {
+        AllowedMintPda::new(escrow, mint).validate_pda_address(allowed_mint, program_id)?;
+        return Ok(());
+    }
+
+    let data = allowed_mint.try_borrow()?;
+    let mut state = AllowedMint::from_account(&data, allowed_mint, program_id, escrow, mint)?;
+    if AllowedMint::is_legacy(&data) {
+        return Ok(());
+    }
+    drop(data);
+
+    for amount in amounts {
+        state.record_withdraw(amount)?;
+    }
+    state.write_to_slice(&mut allowed_mint.try_borrow_mut()?)
 }
 
 /// PDA context for AllowedMint - holds escrow and mint addresses for seed derivation
 ///
-/// Since the AllowedMint account only stores the bump, we use this helper
+/// Since the AllowedMint account does not store its seeds, we use this helper
 /// to derive and validate PDAs using external escrow and mint addresses.
 ///
 /// Implements `PdaSeeds` trait for consistent PDA handling across codebase.
@@ -119,7 +376,7 @@ mod tests {
     use super::*;
 
     fn create_test_allowed_mint() -> AllowedMint {
-        AllowedMint::new(255)
+        AllowedMint::new(255, 0)
     }
 
     fn create_test_seeds() -> (Address, Address) {
@@ -130,17 +387,34 @@ mod tests {
 
     #[test]
     fn test_allowed_mint_new() {
-        let allowed_mint = AllowedMint::new(200);
+        let allowed_mint = AllowedMint::new(200, 7);
         assert_eq!(allowed_mint.bump, 200);
+        assert_eq!(allowed_mint.opening_balance, 7);
+        assert_eq!(allowed_mint.max_total_deposits, 0);
+        assert_eq!(allowed_mint.min_deposit, 0);
+        assert_eq!(allowed_mint.max_deposit, 0);
+        assert_eq!(allowed_mint.total_deposited, 0);
+        assert_eq!(allowed_mint.outstanding_receipts, 0);
     }
 
     #[test]
     fn test_allowed_mint_to_bytes_inner() {
-        let allowed_mint = create_test_allowed_mint();
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.set_limits(1_000, 10, 500);
+        allowed_mint.total_deposited = 42;
+        allowed_mint.outstanding_receipts = 3;
+        allowed_mint.opening_balance = 9;
         let bytes = allowed_mint.to_bytes_inner();
 
         assert_eq!(bytes.len(), AllowedMint::DATA_LEN);
         assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(u64::from_le_bytes(bytes[8..16].try_into().unwrap()), 1_000);
+        assert_eq!(u64::from_le_bytes(bytes[16..24].try_into().unwrap()), 10);
+        assert_eq!(u64::from_le_bytes(bytes[24..32].try_into().unwrap()), 500);
+        assert_eq!(u64::from_le_bytes(bytes[32..40].try_into().unwrap()), 42);
+        assert_eq!(u64::from_le_bytes(bytes[40..48].try_into().unwrap()), 3);
+        assert_eq!(u64::from_le_bytes(bytes[48..56].try_into().unwrap()), 9);
     }
 
     #[test]
@@ -155,30 +429,170 @@ mod tests {
     }
 
     #[test]
-    fn test_allowed_mint_from_bytes() {
-        let allowed_mint = create_test_allowed_mint();
+    fn test_allowed_mint_parse_from_bytes() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.set_limits(1_000, 10, 500);
+        allowed_mint.total_deposited = 42;
+        allowed_mint.outstanding_receipts = 3;
+        allowed_mint.opening_balance = 9;
         let bytes = allowed_mint.to_bytes();
 
-        let deserialized = AllowedMint::from_bytes(&bytes).unwrap();
+        let deserialized = AllowedMint::parse_from_bytes(&bytes).unwrap();
+
+        assert_eq!(deserialized, allowed_mint);
+    }
+
+    #[test]
+    fn test_allowed_mint_parse_from_bytes_too_short() {
+        let data = [AllowedMint::DISCRIMINATOR, AllowedMint::VERSION, 255]; // No limits
+        let result = AllowedMint::parse_from_bytes(&data);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_allowed_mint_parse_legacy() {
+        let data = [AllowedMint::DISCRIMINATOR, AllowedMint::LEGACY_VERSION, 254];
+        assert_eq!(data.len(), AllowedMint::LEGACY_LEN);
+        assert!(AllowedMint::is_legacy(&data));
+
+        let parsed = AllowedMint::parse_from_bytes(&data).unwrap();
+        assert_eq!(parsed, AllowedMint::new(254, AllowedMint::LEGACY_OPENING_BALANCE));
+        assert_eq!(parsed.surplus(u64::MAX), 0, "A legacy account has no surplus");
+        assert_eq!(AllowedMint::find_override(&data, ExtensionType::Hook).unwrap(), None);
+    }
 
-        assert_eq!(deserialized.bump, allowed_mint.bump);
+    #[test]
+    fn test_allowed_mint_parse_wrong_version() {
+        let mut bytes = create_test_allowed_mint().to_bytes();
+        bytes[1] = AllowedMint::VERSION + 1;
+        assert_eq!(AllowedMint::parse_from_bytes(&bytes), Err(ProgramError::InvalidAccountData));
+        assert!(!AllowedMint::is_legacy(&bytes));
     }
 
     #[test]
-    fn test_allowed_mint_from_bytes_too_short() {
-        let data = [0u8; 2]; // Only discriminator + version, no bump
-        let result = AllowedMint::from_bytes(&data);
-        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    fn test_allowed_mint_legacy_grows_to_current_layout() {
+        let mut allowed_mint =
+            AllowedMint::parse_from_bytes(&[AllowedMint::DISCRIMINATOR, AllowedMint::LEGACY_VERSION, 254]).unwrap();
+        allowed_mint.opening_balance = 500;
+        allowed_mint.record_deposit(100).unwrap();
+
+        let bytes = allowed_mint.to_bytes();
+        assert_eq!(bytes.len(), AllowedMint::LEN);
+        assert_eq!(bytes[1], AllowedMint::VERSION);
+        assert!(!AllowedMint::is_legacy(&bytes));
+        assert_eq!(AllowedMint::parse_from_bytes(&bytes).unwrap(), allowed_mint);
     }
 
     #[test]
-    fn test_allowed_mint_from_bytes_wrong_discriminator() {
-        let mut bytes = [0u8; 3];
+    fn test_allowed_mint_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; AllowedMint::LEN];
         bytes[0] = 99; // wrong discriminator
-        let result = AllowedMint::from_bytes(&bytes);
+        let result = AllowedMint::parse_from_bytes(&bytes);
         assert_eq!(result, Err(ProgramError::InvalidAccountData));
     }
 
+    #[test]
+    fn test_allowed_mint_record_deposit_unlimited() {
+        let mut allowed_mint = create_test_allowed_mint();
+        assert!(allowed_mint.record_deposit(u64::MAX).is_ok());
+        assert_eq!(allowed_mint.total_deposited, u64::MAX);
+        assert_eq!(allowed_mint.record_deposit(1), Err(ProgramError::ArithmeticOverflow));
+    }
+
+    #[test]
+    fn test_allowed_mint_record_deposit_below_minimum() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.set_limits(0, 10, 0);
+        assert_eq!(allowed_mint.record_deposit(9), Err(DepositBelowMinimum.into()));
+        assert!(allowed_mint.record_deposit(10).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_mint_record_deposit_above_maximum() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.set_limits(0, 0, 100);
+        assert_eq!(allowed_mint.record_deposit(101), Err(DepositAboveMaximum.into()));
+        assert!(allowed_mint.record_deposit(100).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_mint_record_deposit_cap_exceeded() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.set_limits(150, 0, 0);
+        assert!(allowed_mint.record_deposit(100).is_ok());
+        assert_eq!(allowed_mint.record_deposit(51), Err(DepositCapExceeded.into()));
+        assert_eq!(allowed_mint.total_deposited, 100, "Failed deposit should not change the total");
+        assert_eq!(allowed_mint.outstanding_receipts, 1, "Failed deposit should not count a receipt");
+        assert!(allowed_mint.record_deposit(50).is_ok());
+        assert_eq!(allowed_mint.total_deposited, 150);
+        assert_eq!(allowed_mint.outstanding_receipts, 2);
+    }
+
+    #[test]
+    fn test_allowed_mint_record_withdraw() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.total_deposited = 100;
+        allowed_mint.outstanding_receipts = 2;
+        allowed_mint.record_withdraw(40).unwrap();
+        assert_eq!(allowed_mint.total_deposited, 60);
+        assert_eq!(allowed_mint.outstanding_receipts, 1);
+        allowed_mint.record_withdraw(60).unwrap();
+        assert_eq!(allowed_mint.total_deposited, 0);
+        assert_eq!(allowed_mint.outstanding_receipts, 0);
+    }
+
+    #[test]
+    fn test_allowed_mint_record_withdraw_underflow() {
+        let mut allowed_mint = create_test_allowed_mint();
+        allowed_mint.total_deposited = 100;
+        allowed_mint.outstanding_receipts = 1;
+        assert_eq!(allowed_mint.record_withdraw(101), Err(ProgramError::ArithmeticOverflow));
+
+        allowed_mint.outstanding_receipts = 0;
+        assert_eq!(allowed_mint.record_withdraw(50), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(allowed_mint.total_deposited, 100, "A failed withdraw should not change the total");
+    }
+
+    #[test]
+    fn test_allowed_mint_surplus() {
+        let mut allowed_mint = AllowedMint::new(255, 50);
+        allowed_mint.total_deposited = 100;
+        assert_eq!(allowed_mint.surplus(175), 25);
+        assert_eq!(allowed_mint.surplus(150), 0);
+        assert_eq!(allowed_mint.surplus(120), 0);
+        assert_eq!(allowed_mint.surplus(0), 0);
+    }
+
+    #[test]
+    fn test_allowed_mint_find_override() {
+        let mut data = create_test_allowed_mint().to_bytes();
+        assert_eq!(AllowedMint::find_override(&data, ExtensionType::Timelock).unwrap(), None);
+
+        data.extend_from_slice(&(ExtensionType::Timelock as u16).to_le_bytes());
+        data.extend_from_slice(&8u16.to_le_bytes());
+        data.extend_from_slice(&60u64.to_le_bytes());
+
+        let value = AllowedMint::find_override(&data, ExtensionType::Timelock).unwrap();
+        assert_eq!(value, Some(&60u64.to_le_bytes()[..]));
+        assert_eq!(AllowedMint::find_override(&data, ExtensionType::Hook).unwrap(), None);
+        assert_eq!(AllowedMint::parse_from_bytes(&data).unwrap(), create_test_allowed_mint());
+    }
+
+    #[test]
+    fn test_allowed_mint_apply_overrides() {
+        let mut data = create_test_allowed_mint().to_bytes();
+        data.extend_from_slice(&(ExtensionType::Arbiter as u16).to_le_bytes());
+        data.extend_from_slice(&32u16.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+
+        let to_get = [ExtensionType::Timelock, ExtensionType::Arbiter];
+        let mut exts = vec![Some(vec![1u8; 8]), Some(vec![9u8; 32])];
+        AllowedMint::apply_overrides(&data, &to_get, &mut exts).unwrap();
+
+        assert_eq!(exts[0], Some(vec![1u8; 8]), "Types without an override keep the escrow-level value");
+        assert_eq!(exts[1], Some(vec![7u8; 32]));
+    }
+
     #[test]
     fn test_allowed_mint_pda_seeds() {
         let (escrow, mint) = create_test_seeds();
@@ -215,7 +629,7 @@ mod tests {
     #[test]
     fn test_allowed_mint_write_to_slice_too_small() {
         let allowed_mint = create_test_allowed_mint();
-        let mut dest = [0u8; 2]; // Only room for discriminator + version
+        let mut dest = [0u8; 3]; // Only room for discriminator + version + bump
 
         let result = allowed_mint.write_to_slice(&mut dest);
         assert_eq!(result, Err(ProgramError::AccountDataTooSmall));
@@ -0,0 +1,348 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::{CodamaAccount, CodamaType};
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::EscrowProgramError::{InvalidReceiptEscrow, InvalidRentRecipient, InvalidWithdrawer};
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
+    Versioned,
+};
+use crate::{require_account_len, validate_discriminator};
+
+/// One mint deposited into a basket and the amount held for it
+#[derive(Clone, Debug, PartialEq, CodamaType)]
+pub struct BasketLeg {
+    pub mint: Address,
+    pub amount: u64,
+}
+
+impl BasketLeg {
+    pub const LEN: usize = 32 + 8; // mint + amount
+}
+
+/// BasketReceipt account state
+///
+/// A single receipt for a `DepositBasket` of several mints. The legs can only be withdrawn
+/// together with `WithdrawBasket`, which closes the account. `rent_recipient` behaves as on
+/// `Receipt`.
+///
+/// The account is variable-sized: `legs` holds one entry per mint in deposit order.
+///
+/// # PDA Seeds
+/// `[b"basket_receipt", escrow.as_ref(), depositor.as_ref(), basket_id.to_le_bytes()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 10))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "basket_receipt"))]
+#[codama(seed(name = "escrow", type = public_key))]
+#[codama(seed(name = "depositor", type = public_key))]
+#[codama(seed(name = "basket_id", type = number(u64)))]
+pub struct BasketReceipt {
+    pub bump: u8,
+    _padding: [u8; 7],
+
+    pub escrow: Address,
+    pub depositor: Address,
+
+    /// Little-endian bytes of the basket id, kept as bytes so they can be borrowed as a PDA seed
+    #[codama(type = number(u64))]
+    basket_id: [u8; 8],
+    pub deposited_at: i64,
+
+    pub rent_recipient: Address,
+
+    pub legs: Vec<BasketLeg>,
+}
+
+impl Discriminator for BasketReceipt {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::BasketReceiptDiscriminator as u8;
+}
+
+impl Versioned for BasketReceipt {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for BasketReceipt {
+    /// Fixed part only; the account is `LEN + legs.len() * BasketLeg::LEN` bytes
+    const DATA_LEN: usize = 1 + 7 + 32 + 32 + 8 + 8 + 32 + 4; // bump + padding + escrow + depositor + basket_id + deposited_at + rent_recipient + legs length
+}
+
+impl AccountParse for BasketReceipt {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..8]
+        let escrow = Address::new_from_array(data[8..40].try_into().unwrap());
+        let depositor = Address::new_from_array(data[40..72].try_into().unwrap());
+        let basket_id = u64::from_le_bytes(data[72..80].try_into().unwrap());
+        let deposited_at = i64::from_le_bytes(data[80..88].try_into().unwrap());
+        let rent_recipient = Address::new_from_array(data[88..120].try_into().unwrap());
+        let legs_len = u32::from_le_bytes(data[120..124].try_into().unwrap()) as usize;
+        let legs_bytes = legs_len
+            .checked_mul(BasketLeg::LEN)
+            .and_then(|len| data.get(124..124usize.checked_add(len)?))
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let legs = legs_bytes
+            .chunks_exact(BasketLeg::LEN)
+            .map(|leg| BasketLeg {
+                mint: Address::new_from_array(leg[0..32].try_into().unwrap()),
+                amount: u64::from_le_bytes(leg[32..40].try_into().unwrap()),
+            })
+            .collect();
+
+        Ok(Self::new(escrow, depositor, basket_id, deposited_at, rent_recipient, legs, bump))
+    }
+}
+
+impl AccountSerialize for BasketReceipt {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN + self.legs.len() * BasketLeg::LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(&self.basket_id);
+        data.extend_from_slice(&self.deposited_at.to_le_bytes());
+        data.extend_from_slice(self.rent_recipient.as_ref());
+        data.extend_from_slice(&(self.legs.len() as u32).to_le_bytes());
+        for leg in &self.legs {
+            data.extend_from_slice(leg.mint.as_ref());
+            data.extend_from_slice(&leg.amount.to_le_bytes());
+        }
+        data
+    }
+}
+
+impl PdaSeeds for BasketReceipt {
+    const PREFIX: &'static [u8] = b"basket_receipt";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), &self.basket_id]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.escrow.as_ref()),
+            Seed::from(self.depositor.as_ref()),
+            Seed::from(&self.basket_id),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for BasketReceipt {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
+        let derived = Address::derive_address(
+            &[Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), &self.basket_id],
+            Some(self.bump),
+            program_id,
+        );
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+}
+
+impl BasketReceipt {
+    /// Maximum number of mints in one basket
+    pub const MAX_LEGS: usize = 8;
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        depositor: Address,
+        basket_id: u64,
+        deposited_at: i64,
+        rent_recipient: Address,
+        legs: Vec<BasketLeg>,
+        bump: u8,
+    ) -> Self {
+        Self {
+            bump,
+            _padding: [0u8; 7],
+            escrow,
+            depositor,
+            basket_id: basket_id.to_le_bytes(),
+            deposited_at,
+            rent_recipient,
+            legs,
+        }
+    }
+
+    /// Account size needed to store this basket receipt
+    #[inline(always)]
+    pub fn space(&self) -> usize {
+        Self::LEN + self.legs.len() * BasketLeg::LEN
+    }
+
+    #[inline(always)]
+    pub fn basket_id(&self) -> u64 {
+        u64::from_le_bytes(self.basket_id)
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    #[inline(always)]
+    pub fn validate_depositor(&self, escrow: &Address, depositor: &Address) -> Result<(), ProgramError> {
+        if self.escrow != *escrow {
+            return Err(InvalidReceiptEscrow.into());
+        }
+
+        if self.depositor != *depositor {
+            return Err(InvalidWithdrawer.into());
+        }
+
+        Ok(())
+    }
+
+    /// Rejects closing the basket receipt to anyone but its recorded rent recipient
+    #[inline(always)]
+    pub fn validate_rent_recipient(&self, rent_recipient: &Address) -> Result<(), ProgramError> {
+        if self.rent_recipient != *rent_recipient {
+            return Err(InvalidRentRecipient.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_basket() -> BasketReceipt {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([2u8; 32]);
+        let rent_recipient = Address::new_from_array([5u8; 32]);
+        let legs = vec![
+            BasketLeg { mint: Address::new_from_array([3u8; 32]), amount: 1000 },
+            BasketLeg { mint: Address::new_from_array([4u8; 32]), amount: 2000 },
+        ];
+        BasketReceipt::new(escrow, depositor, 7, 1234567890, rent_recipient, legs, 255)
+    }
+
+    #[test]
+    fn test_basket_receipt_new() {
+        let basket = create_test_basket();
+
+        assert_eq!(basket.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(basket.depositor, Address::new_from_array([2u8; 32]));
+        assert_eq!(basket.basket_id(), 7);
+        assert_eq!(basket.deposited_at, 1234567890);
+        assert_eq!(basket.rent_recipient, Address::new_from_array([5u8; 32]));
+        assert_eq!(basket.legs.len(), 2);
+        assert_eq!(basket.bump, 255);
+    }
+
+    #[test]
+    fn test_basket_receipt_to_bytes_inner() {
+        let basket = create_test_basket();
+        let bytes = basket.to_bytes_inner();
+
+        assert_eq!(bytes.len(), BasketReceipt::DATA_LEN + 2 * BasketLeg::LEN);
+        assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(&bytes[8..40], &[1u8; 32]); // escrow
+        assert_eq!(&bytes[40..72], &[2u8; 32]); // depositor
+        assert_eq!(&bytes[72..80], &7u64.to_le_bytes()); // basket_id
+        assert_eq!(&bytes[80..88], &1234567890i64.to_le_bytes()); // deposited_at
+        assert_eq!(&bytes[88..120], &[5u8; 32]); // rent_recipient
+        assert_eq!(&bytes[120..124], &2u32.to_le_bytes()); // legs length
+        assert_eq!(&bytes[124..156], &[3u8; 32]); // first leg mint
+        assert_eq!(&bytes[156..164], &1000u64.to_le_bytes()); // first leg amount
+    }
+
+    #[test]
+    fn test_basket_receipt_space() {
+        let basket = create_test_basket();
+        assert_eq!(basket.space(), basket.to_bytes().len());
+    }
+
+    #[test]
+    fn test_basket_receipt_seeds() {
+        let basket = create_test_basket();
+        let seeds = basket.seeds();
+
+        assert_eq!(seeds.len(), 4);
+        assert_eq!(seeds[0], BasketReceipt::PREFIX);
+        assert_eq!(seeds[1], basket.escrow.as_ref());
+        assert_eq!(seeds[2], basket.depositor.as_ref());
+        assert_eq!(seeds[3], &7u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_basket_receipt_parse_roundtrip() {
+        let basket = create_test_basket();
+        let bytes = basket.to_bytes();
+
+        let parsed = BasketReceipt::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, basket);
+    }
+
+    #[test]
+    fn test_basket_receipt_parse_truncated_legs() {
+        let basket = create_test_basket();
+        let bytes = basket.to_bytes();
+
+        let result = BasketReceipt::parse_from_bytes(&bytes[..bytes.len() - 1]);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_basket_receipt_parse_wrong_discriminator() {
+        let mut bytes = create_test_basket().to_bytes();
+        bytes[0] = 99;
+        assert!(BasketReceipt::parse_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_basket_receipt_validate_depositor() {
+        let basket = create_test_basket();
+        let escrow = Address::new_from_array([1u8; 32]);
+
+        assert!(basket.validate_depositor(&escrow, &Address::new_from_array([2u8; 32])).is_ok());
+        assert_eq!(
+            basket.validate_depositor(&escrow, &Address::new_from_array([9u8; 32])),
+            Err(InvalidWithdrawer.into())
+        );
+        assert_eq!(
+            basket.validate_depositor(&Address::new_from_array([9u8; 32]), &basket.depositor),
+            Err(InvalidReceiptEscrow.into())
+        );
+    }
+
+    #[test]
+    fn test_basket_receipt_validate_rent_recipient() {
+        let basket = create_test_basket();
+
+        assert!(basket.validate_rent_recipient(&Address::new_from_array([5u8; 32])).is_ok());
+        assert_eq!(
+            basket.validate_rent_recipient(&Address::new_from_array([6u8; 32])),
+            Err(InvalidRentRecipient.into())
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::EscrowProgramError::DepositorCapExceeded;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
+    Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator};
+
+/// DepositorAllowance account state
+///
+/// Tracks how much of a mint a depositor has deposited into an escrow whose depositor
+/// allowlist has a per-address cap. The cap is a raw token amount, so each mint has its own
+/// allowance. Created on the depositor's first capped deposit of the mint. The consumed
+/// amount is cumulative and is not released by withdrawals or root rotations.
+///
+/// # PDA Seeds
+/// `[b"allowance", escrow.as_ref(), depositor.as_ref(), mint.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 6))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "allowance"))]
+#[codama(seed(name = "escrow", type = public_key))]
+#[codama(seed(name = "depositor", type = public_key))]
+#[codama(seed(name = "mint", type = public_key))]
+#[repr(C)]
+pub struct DepositorAllowance {
+    pub bump: u8,
+    _padding: [u8; 7],
+
+    pub escrow: Address,
+    pub depositor: Address,
+    pub mint: Address,
+
+    pub deposited: u64,
+}
+
+assert_no_padding!(DepositorAllowance, 1 + 7 + 32 + 32 + 32 + 8);
+
+impl Discriminator for DepositorAllowance {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::DepositorAllowanceDiscriminator as u8;
+}
+
+impl Versioned for DepositorAllowance {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for DepositorAllowance {
+    const DATA_LEN: usize = 1 + 7 + 32 + 32 + 32 + 8; // bump + padding + escrow + depositor + mint + deposited
+}
+
+impl AccountParse for DepositorAllowance {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..8]
+        let escrow = Address::new_from_array(data[8..40].try_into().unwrap());
+        let depositor = Address::new_from_array(data[40..72].try_into().unwrap());
+        let mint = Address::new_from_array(data[72..104].try_into().unwrap());
+        let deposited = u64::from_le_bytes(data[104..112].try_into().unwrap());
+
+        Ok(Self { bump, _padding: [0u8; 7], escrow, depositor, mint, deposited })
+    }
+}
+
+impl AccountSerialize for DepositorAllowance {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.mint.as_ref());
+        data.extend_from_slice(&self.deposited.to_le_bytes());
+        data
+    }
+}
+
+impl PdaSeeds for DepositorAllowance {
+    const PREFIX: &'static [u8] = b"allowance";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), self.mint.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.escrow.as_ref()),
+            Seed::from(self.depositor.as_ref()),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for DepositorAllowance {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
+        let derived = Address::derive_address(
+            &[Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), self.mint.as_ref()],
+            Some(self.bump),
+            program_id,
+        );
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+}
+
+impl DepositorAllowance {
+    #[inline(always)]
+    pub fn new(escrow: Address, depositor: Address, mint: Address, bump: u8) -> Self {
+        Self { bump, _padding: [0u8; 7], escrow, depositor, mint, deposited: 0 }
+    }
+
+    /// Validate the allowance belongs to `depositor`'s deposits of `mint` into `escrow`
+    #[inline(always)]
+    pub fn validate_owner(&self, escrow: &Address, depositor: &Address, mint: &Address) -> Result<(), ProgramError> {
+        if self.escrow != *escrow || self.depositor != *depositor || self.mint != *mint {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Adds `amount` to the consumed allowance, failing if it would exceed `cap`
+    #[inline(always)]
+    pub fn record_deposit(&mut self, amount: u64, cap: u64) -> Result<(), ProgramError> {
+        let deposited = self.deposited.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+        if deposited > cap {
+            return Err(DepositorCapExceeded.into());
+        }
+        self.deposited = deposited;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_allowance() -> DepositorAllowance {
+        DepositorAllowance::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+            254,
+        )
+    }
+
+    #[test]
+    fn test_depositor_allowance_new() {
+        let allowance = create_test_allowance();
+
+        assert_eq!(allowance.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(allowance.depositor, Address::new_from_array([2u8; 32]));
+        assert_eq!(allowance.mint, Address::new_from_array([3u8; 32]));
+        assert_eq!(allowance.bump, 254);
+        assert_eq!(allowance.deposited, 0);
+    }
+
+    #[test]
+    fn test_depositor_allowance_to_bytes_inner() {
+        let mut allowance = create_test_allowance();
+        allowance.deposited = 500;
+        let bytes = allowance.to_bytes_inner();
+
+        assert_eq!(bytes.len(), DepositorAllowance::DATA_LEN);
+        assert_eq!(bytes[0], 254); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(&bytes[8..40], &[1u8; 32]); // escrow
+        assert_eq!(&bytes[40..72], &[2u8; 32]); // depositor
+        assert_eq!(&bytes[72..104], &[3u8; 32]); // mint
+        assert_eq!(&bytes[104..112], &500u64.to_le_bytes()); // deposited
+    }
+
+    #[test]
+    fn test_depositor_allowance_to_bytes() {
+        let allowance = create_test_allowance();
+        let bytes = allowance.to_bytes();
+
+        assert_eq!(bytes.len(), DepositorAllowance::LEN);
+        assert_eq!(bytes[0], DepositorAllowance::DISCRIMINATOR);
+        assert_eq!(bytes[1], DepositorAllowance::VERSION); // version auto-prepended
+        assert_eq!(bytes[2], 254); // bump
+    }
+
+    #[test]
+    fn test_depositor_allowance_seeds() {
+        let allowance = create_test_allowance();
+        let seeds = allowance.seeds();
+
+        assert_eq!(seeds.len(), 4);
+        assert_eq!(seeds[0], DepositorAllowance::PREFIX);
+        assert_eq!(seeds[1], allowance.escrow.as_ref());
+        assert_eq!(seeds[2], allowance.depositor.as_ref());
+        assert_eq!(seeds[3], allowance.mint.as_ref());
+    }
+
+    #[test]
+    fn test_depositor_allowance_validate_owner() {
+        let allowance = create_test_allowance();
+        let (escrow, depositor, mint) = (
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+        );
+        assert!(allowance.validate_owner(&escrow, &depositor, &mint).is_ok());
+
+        let other_mint = Address::new_from_array([9u8; 32]);
+        assert_eq!(allowance.validate_owner(&escrow, &depositor, &other_mint), Err(ProgramError::InvalidSeeds));
+        assert_eq!(allowance.validate_owner(&escrow, &other_mint, &mint), Err(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    fn test_depositor_allowance_parse_from_bytes() {
+        let mut allowance = create_test_allowance();
+        allowance.deposited = 42;
+        let bytes = allowance.to_bytes();
+
+        let parsed = DepositorAllowance::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, allowance);
+    }
+
+    #[test]
+    fn test_depositor_allowance_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; DepositorAllowance::LEN];
+        bytes[0] = 99; // wrong discriminator
+        let result = DepositorAllowance::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_depositor_allowance_record_deposit() {
+        let mut allowance = create_test_allowance();
+
+        assert!(allowance.record_deposit(600, 1_000).is_ok());
+        assert!(allowance.record_deposit(400, 1_000).is_ok());
+        assert_eq!(allowance.deposited, 1_000);
+
+        assert_eq!(allowance.record_deposit(1, 1_000), Err(DepositorCapExceeded.into()));
+        assert_eq!(allowance.deposited, 1_000);
+    }
+
+    #[test]
+    fn test_depositor_allowance_record_deposit_overflow() {
+        let mut allowance = create_test_allowance();
+        allowance.deposited = u64::MAX;
+
+        assert_eq!(allowance.record_deposit(1, u64::MAX), Err(ProgramError::ArithmeticOverflow));
+    }
+}
@@ -7,18 +7,27 @@ use pinocchio::{
     error::ProgramError,
     Address,
 };
+use solana_sha256_hasher::hashv;
 
 use crate::assert_no_padding;
 use crate::errors::EscrowProgramError;
+use crate::state::{get_extensions_from_account, ExtensionType, Role, RolesData};
 use crate::traits::{
-    AccountDeserialize, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount,
-    PdaSeeds, Versioned,
+    AccountDeserialize, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, ExtensionData,
+    PdaAccount, PdaSeeds, Versioned,
 };
 
+/// Domain separation prefix for admin-bound escrow seeds
+pub const ADMIN_BOUND_SEED_PREFIX: &[u8] = b"admin_bound_escrow_seed";
+
 /// Escrow account state
 ///
 /// # PDA Seeds
 /// `[b"escrow", escrow_seed.as_ref()]`
+///
+/// `escrow_seed` is either the address of a keypair that signed `CreatesEscrow`, or
+/// `sha256(ADMIN_BOUND_SEED_PREFIX || admin || seed)` for escrows created with
+/// `CreateEscrowWithSeed` (see [`admin_bound_escrow_seed`]). Both share the same PDA form.
 #[derive(Clone, Debug, PartialEq, CodamaAccount)]
 #[codama(field("discriminator", number(u8), default_value = 1))]
 #[codama(discriminator(field = "discriminator"))]
@@ -90,6 +99,15 @@ impl PdaAccount for Escrow {
     }
 }
 
+/// Derive the escrow seed for a caller-supplied seed bound to the creating admin
+///
+/// Binding the admin means only that admin can claim the resulting escrow address, and a
+/// hash output has no known keypair, so it cannot be claimed through `CreatesEscrow` either.
+#[inline(always)]
+pub fn admin_bound_escrow_seed(admin: &Address, seed: &[u8; 32]) -> Address {
+    Address::new_from_array(hashv(&[ADMIN_BOUND_SEED_PREFIX, admin.as_ref(), seed]).to_bytes())
+}
+
 impl Escrow {
     #[inline(always)]
     pub fn new(bump: u8, escrow_seed: Address, admin: Address, is_immutable: bool) -> Self {
@@ -115,6 +133,51 @@ impl Escrow {
         Ok(())
     }
 
+    /// Validate the signer of an admin instruction against the role it requires
+    ///
+    /// An assigned role is held exclusively by its key; an unassigned role, or an escrow
+    /// without the roles extension, falls back to `validate_admin`. Callers must validate
+    /// the extensions PDA first.
+    #[inline(always)]
+    pub fn validate_role(&self, authority: &Address, extensions: &AccountView, role: Role) -> Result<(), ProgramError> {
+        let [roles] = get_extensions_from_account(extensions, &[ExtensionType::Roles])?
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let roles = roles.map(|bytes| RolesData::from_bytes(&bytes)).transpose()?;
+        self.validate_role_holder(authority, roles.as_ref(), role)
+    }
+
+    #[inline(always)]
+    pub fn validate_role_holder(
+        &self,
+        authority: &Address,
+        roles: Option<&RolesData>,
+        role: Role,
+    ) -> Result<(), ProgramError> {
+        match roles.and_then(|roles| roles.holder(role)) {
+            Some(holder) if holder == authority => Ok(()),
+            Some(_) => Err(EscrowProgramError::InvalidRoleAuthority.into()),
+            None => self.validate_admin(authority),
+        }
+    }
+
+    /// Validate the signer of a change to an extension entry
+    ///
+    /// Changes to the roles and governance delay extensions are reserved to the admin;
+    /// every other extension requires the extension manager role.
+    #[inline(always)]
+    pub fn validate_extension_authority(
+        &self,
+        authority: &Address,
+        extensions: &AccountView,
+        extension_type: ExtensionType,
+    ) -> Result<(), ProgramError> {
+        match extension_type {
+            ExtensionType::Roles | ExtensionType::GovernanceDelay => self.validate_admin(authority),
+            _ => self.validate_role(authority, extensions, Role::ExtensionManager),
+        }
+    }
+
     #[inline(always)]
     pub fn require_mutable(&self) -> Result<(), ProgramError> {
         if self.is_immutable {
@@ -181,6 +244,39 @@ mod tests {
         assert_eq!(result, Err(EscrowProgramError::InvalidAdmin.into()));
     }
 
+    #[test]
+    fn test_escrow_validate_role_holder_unassigned_falls_back_to_admin() {
+        let escrow = create_test_escrow();
+        let admin = Address::new_from_array([2u8; 32]);
+        let other = Address::new_from_array([99u8; 32]);
+        let roles = RolesData::new(Address::default(), Address::default(), Address::default());
+
+        assert!(escrow.validate_role_holder(&admin, None, Role::MintManager).is_ok());
+        assert!(escrow.validate_role_holder(&admin, Some(&roles), Role::MintManager).is_ok());
+        assert_eq!(
+            escrow.validate_role_holder(&other, Some(&roles), Role::MintManager),
+            Err(EscrowProgramError::InvalidAdmin.into())
+        );
+    }
+
+    #[test]
+    fn test_escrow_validate_role_holder_assigned_excludes_admin() {
+        let escrow = create_test_escrow();
+        let admin = Address::new_from_array([2u8; 32]);
+        let mint_manager = Address::new_from_array([3u8; 32]);
+        let roles = RolesData::new(mint_manager, Address::default(), Address::default());
+
+        assert!(escrow.validate_role_holder(&mint_manager, Some(&roles), Role::MintManager).is_ok());
+        assert_eq!(
+            escrow.validate_role_holder(&admin, Some(&roles), Role::MintManager),
+            Err(EscrowProgramError::InvalidRoleAuthority.into())
+        );
+        assert_eq!(
+            escrow.validate_role_holder(&mint_manager, Some(&roles), Role::ExtensionManager),
+            Err(EscrowProgramError::InvalidAdmin.into())
+        );
+    }
+
     #[test]
     fn test_escrow_to_bytes_inner() {
         let escrow = create_test_escrow();
@@ -280,6 +376,19 @@ mod tests {
         assert_eq!(result, Err(EscrowProgramError::EscrowImmutable.into()));
     }
 
+    #[test]
+    fn test_admin_bound_escrow_seed_binds_admin_and_seed() {
+        let admin = Address::new_from_array([2u8; 32]);
+        let other_admin = Address::new_from_array([3u8; 32]);
+        let seed = [7u8; 32];
+
+        let escrow_seed = admin_bound_escrow_seed(&admin, &seed);
+        assert_eq!(escrow_seed, admin_bound_escrow_seed(&admin, &seed));
+        assert_ne!(escrow_seed, admin_bound_escrow_seed(&other_admin, &seed));
+        assert_ne!(escrow_seed, admin_bound_escrow_seed(&admin, &[8u8; 32]));
+        assert_ne!(escrow_seed, Address::new_from_array(seed));
+    }
+
     #[test]
     fn test_escrow_write_to_slice_too_small() {
         let escrow = create_test_escrow();
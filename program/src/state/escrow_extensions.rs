@@ -3,8 +3,9 @@ use alloc::vec::Vec;
 use codama::CodamaAccount;
 use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address, ProgramResult};
 
-use crate::state::extensions::TimelockData;
-use crate::traits::{AccountSerialize, Discriminator, EscrowAccountDiscriminators, PdaSeeds, Versioned};
+use crate::errors::EscrowProgramError;
+use crate::state::extensions::{ExtensionLocksData, TimelockData};
+use crate::traits::{AccountSerialize, Discriminator, EscrowAccountDiscriminators, ExtensionData, PdaSeeds, Versioned};
 use crate::utils::{create_pda_account_idempotent, resize_pda_account, TlvReader};
 use crate::{assert_no_padding, require_len, validate_discriminator};
 
@@ -16,6 +17,15 @@ pub enum ExtensionType {
     Hook = 1,
     BlockedTokenExtensions = 2,
     Arbiter = 3,
+    HookBypass = 4,
+    DepositorAllowlist = 5,
+    Roles = 6,
+    GovernanceDelay = 7,
+    ExtensionLocks = 8,
+    Metadata = 9,
+    AutoRelease = 10,
+    RefundAuthority = 11,
+    Cooldown = 12,
 }
 
 impl TryFrom<u16> for ExtensionType {
@@ -27,11 +37,39 @@ impl TryFrom<u16> for ExtensionType {
             1 => Ok(Self::Hook),
             2 => Ok(Self::BlockedTokenExtensions),
             3 => Ok(Self::Arbiter),
+            4 => Ok(Self::HookBypass),
+            5 => Ok(Self::DepositorAllowlist),
+            6 => Ok(Self::Roles),
+            7 => Ok(Self::GovernanceDelay),
+            8 => Ok(Self::ExtensionLocks),
+            9 => Ok(Self::Metadata),
+            10 => Ok(Self::AutoRelease),
+            11 => Ok(Self::RefundAuthority),
+            12 => Ok(Self::Cooldown),
             _ => Err(ProgramError::InvalidAccountData),
         }
     }
 }
 
+impl ExtensionType {
+    /// Whether the extension may still change once the escrow is immutable
+    ///
+    /// Roles and metadata only decide who signs admin instructions and how the escrow is
+    /// labelled, not how it handles funds.
+    #[inline(always)]
+    pub fn is_mutable_when_immutable(self) -> bool {
+        matches!(self, Self::Roles | Self::Metadata)
+    }
+
+    /// Whether an AllowedMint account may override the extension for its mint
+    ///
+    /// Only the withdrawal policy extensions can differ per mint.
+    #[inline(always)]
+    pub fn is_mint_overridable(self) -> bool {
+        matches!(self, Self::Timelock | Self::Hook | Self::Arbiter)
+    }
+}
+
 /// TLV header size: type (u16) + length (u16)
 pub const TLV_HEADER_SIZE: usize = 4;
 
@@ -157,6 +195,7 @@ pub fn append_extension<const N: usize>(
         if reader.find_extension(ext_type)?.is_some() {
             return Err(ProgramError::AccountAlreadyInitialized);
         }
+        require_extension_unlocked(&data, ext_type, new_tlv.get(TLV_HEADER_SIZE..))?;
 
         let tlv_slice = data[EscrowExtensionsHeader::LEN..].to_vec();
         (header.extension_count, tlv_slice)
@@ -201,6 +240,7 @@ pub fn update_extension(
 
     let data = extensions.try_borrow()?;
     let header = EscrowExtensionsHeader::from_bytes(&data)?;
+    require_extension_unlocked(&data, ext_type, Some(new_tlv))?;
 
     // Find the extension entry
     let mut offset = EscrowExtensionsHeader::LEN;
@@ -272,6 +312,7 @@ pub fn remove_extension(extensions: &AccountView, ext_type: ExtensionType) -> Pr
     if header.extension_count == 0 {
         return Err(ProgramError::UninitializedAccount);
     }
+    require_extension_unlocked(&data, ext_type, None)?;
 
     // Find the extension entry.
     let mut offset = EscrowExtensionsHeader::LEN;
@@ -325,6 +366,28 @@ pub fn remove_extension(extensions: &AccountView, ext_type: ExtensionType) -> Pr
     Ok(())
 }
 
+/// Rejects writing or removing `ext_type` if the escrow's extension locks forbid it.
+///
+/// `new_data` is the value about to be written, or `None` for a removal. The locks extension
+/// itself can never be removed, and can only be rewritten with a superset of its locks.
+fn require_extension_unlocked(data: &[u8], ext_type: ExtensionType, new_data: Option<&[u8]>) -> ProgramResult {
+    let reader = TlvReader::new(data);
+    let Some(locks) = reader.find_extension(ExtensionType::ExtensionLocks)? else {
+        return Ok(());
+    };
+    let locks = ExtensionLocksData::from_bytes(locks)?;
+
+    let unlocked = match (ext_type, new_data) {
+        (ExtensionType::ExtensionLocks, Some(new_data)) => ExtensionLocksData::from_bytes(new_data)?.contains(&locks),
+        (ExtensionType::ExtensionLocks, None) => false,
+        _ => !locks.is_locked(ext_type),
+    };
+    if !unlocked {
+        return Err(EscrowProgramError::ExtensionLocked.into());
+    }
+    Ok(())
+}
+
 /// Updates or appends a TLV extension to the extensions PDA.
 ///
 /// Simplifies the common pattern of checking if extension exists and either updating or appending:
@@ -451,6 +514,15 @@ mod tests {
         assert_eq!(ExtensionType::try_from(1u16).unwrap(), ExtensionType::Hook);
         assert_eq!(ExtensionType::try_from(2u16).unwrap(), ExtensionType::BlockedTokenExtensions);
         assert_eq!(ExtensionType::try_from(3u16).unwrap(), ExtensionType::Arbiter);
+        assert_eq!(ExtensionType::try_from(4u16).unwrap(), ExtensionType::HookBypass);
+        assert_eq!(ExtensionType::try_from(5u16).unwrap(), ExtensionType::DepositorAllowlist);
+        assert_eq!(ExtensionType::try_from(6u16).unwrap(), ExtensionType::Roles);
+        assert_eq!(ExtensionType::try_from(7u16).unwrap(), ExtensionType::GovernanceDelay);
+        assert_eq!(ExtensionType::try_from(8u16).unwrap(), ExtensionType::ExtensionLocks);
+        assert_eq!(ExtensionType::try_from(9u16).unwrap(), ExtensionType::Metadata);
+        assert_eq!(ExtensionType::try_from(10u16).unwrap(), ExtensionType::AutoRelease);
+        assert_eq!(ExtensionType::try_from(11u16).unwrap(), ExtensionType::RefundAuthority);
+        assert_eq!(ExtensionType::try_from(12u16).unwrap(), ExtensionType::Cooldown);
         assert!(ExtensionType::try_from(999u16).is_err());
     }
 
@@ -503,6 +575,59 @@ mod tests {
         assert_eq!(with_timelock, EscrowExtensionsHeader::LEN + TLV_HEADER_SIZE + TimelockData::LEN);
     }
 
+    fn extensions_data_with_locks(locks: &ExtensionLocksData) -> Vec<u8> {
+        let mut data = EscrowExtensionsHeader::new(255, 1).to_bytes();
+        let value = locks.to_bytes();
+        data.extend_from_slice(&(ExtensionType::ExtensionLocks as u16).to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(&value);
+        data
+    }
+
+    #[test]
+    fn test_require_extension_unlocked_without_locks() {
+        let data = EscrowExtensionsHeader::new(255, 0).to_bytes();
+        assert!(require_extension_unlocked(&data, ExtensionType::Hook, None).is_ok());
+    }
+
+    #[test]
+    fn test_require_extension_unlocked_locked_extension() {
+        let mut locks = ExtensionLocksData::default();
+        locks.lock(ExtensionType::Hook).unwrap();
+        let data = extensions_data_with_locks(&locks);
+
+        assert_eq!(
+            require_extension_unlocked(&data, ExtensionType::Hook, Some(&[0u8; 33])),
+            Err(EscrowProgramError::ExtensionLocked.into())
+        );
+        assert_eq!(
+            require_extension_unlocked(&data, ExtensionType::Hook, None),
+            Err(EscrowProgramError::ExtensionLocked.into())
+        );
+        assert!(require_extension_unlocked(&data, ExtensionType::Arbiter, None).is_ok());
+    }
+
+    #[test]
+    fn test_require_extension_unlocked_locks_only_grow() {
+        let mut locks = ExtensionLocksData::default();
+        locks.lock(ExtensionType::Hook).unwrap();
+        let data = extensions_data_with_locks(&locks);
+
+        let mut more_locks = locks;
+        more_locks.lock_mint_list().unwrap();
+        assert!(require_extension_unlocked(&data, ExtensionType::ExtensionLocks, Some(&more_locks.to_bytes())).is_ok());
+
+        let fewer_locks = ExtensionLocksData::default().to_bytes();
+        assert_eq!(
+            require_extension_unlocked(&data, ExtensionType::ExtensionLocks, Some(&fewer_locks)),
+            Err(EscrowProgramError::ExtensionLocked.into())
+        );
+        assert_eq!(
+            require_extension_unlocked(&data, ExtensionType::ExtensionLocks, None),
+            Err(EscrowProgramError::ExtensionLocked.into())
+        );
+    }
+
     #[test]
     fn test_extensions_pda_seeds() {
         let escrow = Address::new_from_array([1u8; 32]);
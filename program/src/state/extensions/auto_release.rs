@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{assert_no_padding, errors::EscrowProgramError, require_len, traits::ExtensionData};
+
+/// Auto-release extension data (stored in TLV format)
+///
+/// Opts an escrow into the permissionless `ReleaseMatured` crank. Once a receipt has
+/// unlocked and a further `release_delay` seconds have passed, anyone may pay it out to
+/// the depositor. Not available while a hook or arbiter applies to the receipt's mint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct AutoReleaseData {
+    pub release_delay: u64,
+}
+
+assert_no_padding!(AutoReleaseData, 8);
+
+impl AutoReleaseData {
+    pub const LEN: usize = 8;
+
+    pub fn new(release_delay: u64) -> Self {
+        Self { release_delay }
+    }
+
+    /// Validate that the release delay has elapsed since the receipt unlocked
+    pub fn validate(&self, unlocked_at: i64) -> ProgramResult {
+        let release_delay_i64 = i64::try_from(self.release_delay).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        let release_time = unlocked_at.checked_add(release_delay_i64).ok_or(ProgramError::ArithmeticOverflow)?;
+        let clock = Clock::get()?;
+        if clock.unix_timestamp < release_time {
+            return Err(EscrowProgramError::ReceiptNotMatured.into());
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionData for AutoReleaseData {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.release_delay.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { release_delay: u64::from_le_bytes(data[0..8].try_into().unwrap()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_release_data_new() {
+        let auto_release = AutoReleaseData::new(86_400);
+        assert_eq!(auto_release.release_delay, 86_400);
+    }
+
+    #[test]
+    fn test_auto_release_data_roundtrip() {
+        let auto_release = AutoReleaseData::new(604_800);
+        let bytes = auto_release.to_bytes();
+        let parsed = AutoReleaseData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, auto_release);
+    }
+
+    #[test]
+    fn test_auto_release_data_from_bytes_too_short() {
+        let result = AutoReleaseData::from_bytes(&[0u8; 4]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+}
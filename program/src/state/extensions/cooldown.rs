@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    assert_no_padding,
+    errors::EscrowProgramError,
+    require_len,
+    state::{get_extensions_from_account, ExtensionType},
+    traits::ExtensionData,
+};
+
+/// Cooldown extension data (stored in TLV format)
+///
+/// Two-phase withdrawals for staking-style escrows. Depositors record a `WithdrawRequest`
+/// for their receipt and may claim it once `cooldown_period` seconds have elapsed since the
+/// request. Direct withdrawals are rejected while the extension is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct CooldownData {
+    pub cooldown_period: u64,
+}
+
+assert_no_padding!(CooldownData, 8);
+
+impl CooldownData {
+    pub const LEN: usize = 8;
+
+    pub fn new(cooldown_period: u64) -> Self {
+        Self { cooldown_period }
+    }
+
+    /// Validate that the cooldown period has elapsed since the withdraw was requested
+    pub fn validate(&self, requested_at: i64) -> ProgramResult {
+        let cooldown_period_i64 = i64::try_from(self.cooldown_period).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        let claim_time = requested_at.checked_add(cooldown_period_i64).ok_or(ProgramError::ArithmeticOverflow)?;
+        let clock = Clock::get()?;
+        if clock.unix_timestamp < claim_time {
+            return Err(EscrowProgramError::CooldownNotElapsed.into());
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionData for CooldownData {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.cooldown_period.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { cooldown_period: u64::from_le_bytes(data[0..8].try_into().unwrap()) })
+    }
+}
+
+/// Rejects payouts that skip the request and claim flow while a cooldown is set
+pub fn require_no_cooldown(extensions: &AccountView) -> ProgramResult {
+    let [cooldown] = get_extensions_from_account(extensions, &[ExtensionType::Cooldown])?
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    if cooldown.is_some() {
+        return Err(EscrowProgramError::CooldownActive.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooldown_data_new() {
+        let cooldown = CooldownData::new(86_400);
+        assert_eq!(cooldown.cooldown_period, 86_400);
+    }
+
+    #[test]
+    fn test_cooldown_data_roundtrip() {
+        let cooldown = CooldownData::new(604_800);
+        let bytes = cooldown.to_bytes();
+        let parsed = CooldownData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, cooldown);
+    }
+
+    #[test]
+    fn test_cooldown_data_from_bytes_too_short() {
+        let result = CooldownData::from_bytes(&[0u8; 4]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+}
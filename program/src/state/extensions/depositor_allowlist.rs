@@ -0,0 +1,154 @@
+use alloc::vec::Vec;
+use pinocchio::{error::ProgramError, Address, ProgramResult};
+use solana_sha256_hasher::hashv;
+
+use crate::{assert_no_padding, errors::EscrowProgramError, require_len, traits::ExtensionData};
+
+/// Domain separation prefix for Merkle leaves
+pub const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+
+/// Domain separation prefix for internal Merkle nodes
+pub const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+/// Depositor allowlist extension data (stored in TLV format)
+///
+/// Restricts deposits to addresses in a Merkle tree. Leaves are `sha256(0x00 || depositor)`
+/// and internal nodes are `sha256(0x01 || min(a, b) || max(a, b))`, so proofs carry no
+/// left/right flags. A non-zero `per_address_cap` limits the cumulative amount of each mint
+/// a depositor may deposit, tracked in their `DepositorAllowance` PDA for that mint.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct DepositorAllowlistData {
+    pub merkle_root: [u8; 32],
+    pub per_address_cap: u64,
+}
+
+assert_no_padding!(DepositorAllowlistData, 32 + 8);
+
+impl DepositorAllowlistData {
+    pub const LEN: usize = 32 + 8;
+
+    pub fn new(merkle_root: [u8; 32], per_address_cap: u64) -> Self {
+        Self { merkle_root, per_address_cap }
+    }
+
+    #[inline(always)]
+    pub fn has_cap(&self) -> bool {
+        self.per_address_cap != 0
+    }
+
+    /// Validate that `depositor` is a leaf of the allowlist tree
+    pub fn validate(&self, depositor: &Address, proof: &[[u8; 32]]) -> ProgramResult {
+        let computed = proof.iter().fold(merkle_leaf(depositor), |node, sibling| merkle_parent(&node, sibling));
+        if computed != self.merkle_root {
+            return Err(EscrowProgramError::DepositorNotAllowlisted.into());
+        }
+        Ok(())
+    }
+}
+
+/// Hash a depositor address into an allowlist leaf
+#[inline(always)]
+pub fn merkle_leaf(depositor: &Address) -> [u8; 32] {
+    hashv(&[MERKLE_LEAF_PREFIX, depositor.as_ref()]).to_bytes()
+}
+
+/// Hash two sibling nodes into their parent (order independent)
+#[inline(always)]
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[MERKLE_NODE_PREFIX, left, right]).to_bytes()
+}
+
+impl ExtensionData for DepositorAllowlistData {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.merkle_root);
+        data.extend_from_slice(&self.per_address_cap.to_le_bytes());
+        data
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self {
+            merkle_root: data[0..32].try_into().unwrap(),
+            per_address_cap: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address(byte: u8) -> Address {
+        Address::new_from_array([byte; 32])
+    }
+
+    #[test]
+    fn test_depositor_allowlist_data_roundtrip() {
+        let allowlist = DepositorAllowlistData::new([7u8; 32], 1_000);
+        let bytes = allowlist.to_bytes();
+        assert_eq!(bytes.len(), DepositorAllowlistData::LEN);
+
+        let parsed = DepositorAllowlistData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, allowlist);
+    }
+
+    #[test]
+    fn test_depositor_allowlist_data_from_bytes_too_short() {
+        let result = DepositorAllowlistData::from_bytes(&[0u8; 32]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_depositor_allowlist_has_cap() {
+        assert!(!DepositorAllowlistData::new([0u8; 32], 0).has_cap());
+        assert!(DepositorAllowlistData::new([0u8; 32], 1).has_cap());
+    }
+
+    #[test]
+    fn test_merkle_parent_is_order_independent() {
+        let a = merkle_leaf(&address(1));
+        let b = merkle_leaf(&address(2));
+        assert_eq!(merkle_parent(&a, &b), merkle_parent(&b, &a));
+    }
+
+    #[test]
+    fn test_validate_single_leaf_tree() {
+        let depositor = address(1);
+        let allowlist = DepositorAllowlistData::new(merkle_leaf(&depositor), 0);
+
+        assert!(allowlist.validate(&depositor, &[]).is_ok());
+        assert_eq!(allowlist.validate(&address(2), &[]), Err(EscrowProgramError::DepositorNotAllowlisted.into()));
+    }
+
+    #[test]
+    fn test_validate_multi_level_tree() {
+        let leaves: Vec<[u8; 32]> = (1..=4).map(|i| merkle_leaf(&address(i))).collect();
+        let left = merkle_parent(&leaves[0], &leaves[1]);
+        let right = merkle_parent(&leaves[2], &leaves[3]);
+        let root = merkle_parent(&left, &right);
+        let allowlist = DepositorAllowlistData::new(root, 0);
+
+        assert!(allowlist.validate(&address(1), &[leaves[1], right]).is_ok());
+        assert!(allowlist.validate(&address(4), &[leaves[2], left]).is_ok());
+
+        // Valid proof for another depositor
+        assert!(allowlist.validate(&address(2), &[leaves[1], right]).is_err());
+        // Truncated proof
+        assert!(allowlist.validate(&address(1), &[leaves[1]]).is_err());
+        // Not in tree
+        assert!(allowlist.validate(&address(5), &[leaves[1], right]).is_err());
+    }
+
+    #[test]
+    fn test_leaf_is_not_a_valid_internal_node() {
+        // A leaf hash and an internal node hash over the same bytes must differ
+        let depositor = address(3);
+        let leaf = merkle_leaf(&depositor);
+        let node = hashv(&[MERKLE_NODE_PREFIX, depositor.as_ref()]).to_bytes();
+        assert_ne!(leaf, node);
+    }
+}
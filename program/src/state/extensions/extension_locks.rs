@@ -0,0 +1,158 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::{
+    errors::EscrowProgramError,
+    require_len,
+    state::{get_extensions_from_account, ExtensionType},
+    traits::ExtensionData,
+};
+
+/// Extension locks data (stored in TLV format)
+///
+/// Freezes individual extensions, and optionally the mint list, without making the whole
+/// escrow immutable. A locked extension keeps its current value (or stays absent) for good:
+/// the extension storage helpers reject any write or removal of it, whichever instruction
+/// asks. Locks can only be added, never lifted.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ExtensionLocksData {
+    /// Bit `n` set means the extension with type `n` is locked
+    pub locked_extensions: u32,
+    /// Whether `AllowMint` and `BlockMint` are locked
+    pub mint_list_locked: bool,
+}
+
+impl ExtensionLocksData {
+    pub const LEN: usize = 4 + 1;
+
+    #[inline(always)]
+    pub fn is_locked(&self, ext_type: ExtensionType) -> bool {
+        self.locked_extensions & Self::bit(ext_type) != 0
+    }
+
+    /// Locks `ext_type`, rejecting the locks extension itself and already locked types
+    pub fn lock(&mut self, ext_type: ExtensionType) -> Result<(), ProgramError> {
+        if ext_type == ExtensionType::ExtensionLocks {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if self.is_locked(ext_type) {
+            return Err(EscrowProgramError::ExtensionLocked.into());
+        }
+        self.locked_extensions |= Self::bit(ext_type);
+        Ok(())
+    }
+
+    /// Locks the mint list, rejecting an already locked mint list
+    pub fn lock_mint_list(&mut self) -> Result<(), ProgramError> {
+        if self.mint_list_locked {
+            return Err(EscrowProgramError::MintListLocked.into());
+        }
+        self.mint_list_locked = true;
+        Ok(())
+    }
+
+    /// Whether every lock in `other` is also held by `self`
+    #[inline(always)]
+    pub fn contains(&self, other: &Self) -> bool {
+        self.locked_extensions & other.locked_extensions == other.locked_extensions
+            && (self.mint_list_locked || !other.mint_list_locked)
+    }
+
+    #[inline(always)]
+    fn bit(ext_type: ExtensionType) -> u32 {
+        1u32 << (ext_type as u16)
+    }
+}
+
+impl ExtensionData for ExtensionLocksData {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(&self.locked_extensions.to_le_bytes());
+        data.push(self.mint_list_locked as u8);
+        data
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        let mint_list_locked = match data[4] {
+            0 => false,
+            1 => true,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        Ok(Self { locked_extensions: u32::from_le_bytes(data[0..4].try_into().unwrap()), mint_list_locked })
+    }
+}
+
+/// Reads the extension locks of an escrow, returning no locks if none are set
+pub fn get_extension_locks(extensions: &AccountView) -> Result<ExtensionLocksData, ProgramError> {
+    let [locks] = get_extensions_from_account(extensions, &[ExtensionType::ExtensionLocks])?
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    Ok(locks.map(|bytes| ExtensionLocksData::from_bytes(&bytes)).transpose()?.unwrap_or_default())
+}
+
+/// Rejects `AllowMint` and `BlockMint` once the mint list is locked
+pub fn require_mint_list_unlocked(extensions: &AccountView) -> ProgramResult {
+    if get_extension_locks(extensions)?.mint_list_locked {
+        return Err(EscrowProgramError::MintListLocked.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_locks_data_roundtrip() {
+        let data = ExtensionLocksData { locked_extensions: 0b1011, mint_list_locked: true };
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), ExtensionLocksData::LEN);
+
+        let parsed = ExtensionLocksData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, data);
+    }
+
+    #[test]
+    fn test_extension_locks_data_invalid_mint_list_flag() {
+        let result = ExtensionLocksData::from_bytes(&[0, 0, 0, 0, 2]);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_extension_locks_lock() {
+        let mut data = ExtensionLocksData::default();
+        data.lock(ExtensionType::Hook).unwrap();
+
+        assert!(data.is_locked(ExtensionType::Hook));
+        assert!(!data.is_locked(ExtensionType::Timelock));
+        assert_eq!(data.lock(ExtensionType::Hook), Err(EscrowProgramError::ExtensionLocked.into()));
+        assert_eq!(data.lock(ExtensionType::ExtensionLocks), Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_extension_locks_lock_mint_list() {
+        let mut data = ExtensionLocksData::default();
+        data.lock_mint_list().unwrap();
+
+        assert!(data.mint_list_locked);
+        assert_eq!(data.lock_mint_list(), Err(EscrowProgramError::MintListLocked.into()));
+    }
+
+    #[test]
+    fn test_extension_locks_contains() {
+        let mut held = ExtensionLocksData::default();
+        held.lock(ExtensionType::Hook).unwrap();
+        held.lock(ExtensionType::Timelock).unwrap();
+
+        let mut subset = ExtensionLocksData::default();
+        subset.lock(ExtensionType::Hook).unwrap();
+        assert!(held.contains(&subset));
+        assert!(!subset.contains(&held));
+
+        subset.lock_mint_list().unwrap();
+        assert!(!held.contains(&subset));
+    }
+}
@@ -0,0 +1,95 @@
+use alloc::vec::Vec;
+use pinocchio::{account::AccountView, error::ProgramError, ProgramResult};
+
+use crate::{
+    assert_no_padding,
+    errors::EscrowProgramError,
+    require_len,
+    state::{get_extensions_from_account, ExtensionType},
+    traits::ExtensionData,
+};
+
+/// Governance delay extension data (stored in TLV format)
+///
+/// While set, extension changes cannot be applied directly. They are queued as a
+/// `PendingChange` and can only be executed `delay` seconds later, giving depositors
+/// a window to exit before the new configuration goes live.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct GovernanceDelayData {
+    pub delay: u64,
+}
+
+assert_no_padding!(GovernanceDelayData, 8);
+
+impl GovernanceDelayData {
+    pub const LEN: usize = 8;
+
+    pub fn new(delay: u64) -> Self {
+        Self { delay }
+    }
+
+    /// Timestamp from which a change queued at `queued_at` may be executed
+    pub fn execute_after(&self, queued_at: i64) -> Result<i64, ProgramError> {
+        let delay_i64 = i64::try_from(self.delay).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        queued_at.checked_add(delay_i64).ok_or(ProgramError::ArithmeticOverflow)
+    }
+}
+
+impl ExtensionData for GovernanceDelayData {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.delay.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { delay: u64::from_le_bytes(data[0..8].try_into().unwrap()) })
+    }
+}
+
+/// Reads the governance delay of an escrow, if one is set
+pub fn get_governance_delay(extensions: &AccountView) -> Result<Option<GovernanceDelayData>, ProgramError> {
+    let [delay] = get_extensions_from_account(extensions, &[ExtensionType::GovernanceDelay])?
+        .try_into()
+        .map_err(|_| ProgramError::InvalidAccountData)?;
+    delay.map(|bytes| GovernanceDelayData::from_bytes(&bytes)).transpose()
+}
+
+/// Rejects direct extension changes while a governance delay is set
+pub fn require_no_governance_delay(extensions: &AccountView) -> ProgramResult {
+    if get_governance_delay(extensions)?.is_some() {
+        return Err(EscrowProgramError::GovernanceDelayActive.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_governance_delay_data_roundtrip() {
+        let data = GovernanceDelayData::new(172_800);
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), GovernanceDelayData::LEN);
+
+        let parsed = GovernanceDelayData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, data);
+    }
+
+    #[test]
+    fn test_governance_delay_execute_after() {
+        let data = GovernanceDelayData::new(3600);
+        assert_eq!(data.execute_after(1_000), Ok(4_600));
+    }
+
+    #[test]
+    fn test_governance_delay_execute_after_overflow() {
+        let data = GovernanceDelayData::new(u64::MAX);
+        assert_eq!(data.execute_after(0), Err(ProgramError::ArithmeticOverflow));
+
+        let data = GovernanceDelayData::new(1);
+        assert_eq!(data.execute_after(i64::MAX), Err(ProgramError::ArithmeticOverflow));
+    }
+}
@@ -1,13 +1,18 @@
 use alloc::vec::Vec;
 use pinocchio::{
     account::AccountView,
-    cpi::invoke_with_bounds,
+    cpi::{get_return_data, invoke_with_slice},
     error::ProgramError,
     instruction::{InstructionAccount, InstructionView},
     Address, ProgramResult,
 };
 
-use crate::{assert_no_padding, errors::EscrowProgramError, require_len, traits::ExtensionData};
+use crate::{
+    assert_no_padding,
+    errors::{hook_error, EscrowProgramError},
+    require_len,
+    traits::ExtensionData,
+};
 
 /// Hook points for escrow operations
 #[repr(u8)]
@@ -17,24 +22,79 @@ pub enum HookPoint {
     PostDeposit = 1,
     PreWithdraw = 2,
     PostWithdraw = 3,
+    // Batch points are only used by `WithdrawMany` with `FLAG_BATCH_WITHDRAW_HOOK`
+    PreWithdrawBatch = 4,
+    PostWithdrawBatch = 5,
 }
 
 /// Hook extension data (stored in TLV format)
 ///
-/// Stores the hook program address that will be invoked during escrow operations.
+/// Stores the hook program address that will be invoked during escrow operations,
+/// plus configuration flags (see `HookData::FLAG_*`).
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(C)]
 pub struct HookData {
     pub hook_program: Address,
+    pub flags: u8,
 }
 
-assert_no_padding!(HookData, 32);
+assert_no_padding!(HookData, 32 + 1);
 
 impl HookData {
-    pub const LEN: usize = 32;
+    pub const LEN: usize = 32 + 1;
+
+    /// Length of hook entries written before flags existed (hook_program only)
+    pub const LEGACY_LEN: usize = 32;
+
+    /// Pass extra accounts owned by the hook program to the hook as writable
+    /// (if they are writable in the escrow instruction).
+    pub const FLAG_WRITABLE_HOOK_ACCOUNTS: u8 = 1 << 0;
+
+    /// Invoke the withdraw hooks once per `WithdrawMany` batch, passing every receipt,
+    /// instead of once per receipt (see `HookData::invoke_batch`).
+    pub const FLAG_BATCH_WITHDRAW_HOOK: u8 = 1 << 1;
+
+    /// All flags currently understood by the program
+    pub const VALID_FLAGS: u8 = Self::FLAG_WRITABLE_HOOK_ACCOUNTS | Self::FLAG_BATCH_WITHDRAW_HOOK;
+
+    /// Maximum number of accounts (core + extra) passed to the hook CPI
+    pub const MAX_CPI_ACCOUNTS: usize = 64;
+
+    /// Prefix that marks hook return data as a rejection
+    pub const REJECTION_MAGIC: [u8; 4] = *b"EHRJ";
+
+    /// Length of the return data a hook sets to reject: magic followed by a `u16` LE reason code
+    pub const REJECTION_LEN: usize = 4 + 2;
+
+    pub fn new(hook_program: Address, flags: u8) -> Self {
+        Self { hook_program, flags }
+    }
+
+    /// Programs that may never be configured as a hook: the escrow itself, the token
+    /// programs that move vault funds, and the system program
+    pub const FORBIDDEN_PROGRAMS: [Address; 4] =
+        [crate::ID, pinocchio_token::ID, pinocchio_token_2022::ID, pinocchio_system::ID];
 
-    pub fn new(hook_program: Address) -> Self {
-        Self { hook_program }
+    /// No unknown flags set, and the hook is not one of `FORBIDDEN_PROGRAMS`
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.flags & !Self::VALID_FLAGS == 0 && self.has_allowed_program()
+    }
+
+    /// The hook program is not one of `FORBIDDEN_PROGRAMS`
+    #[inline(always)]
+    pub fn has_allowed_program(&self) -> bool {
+        !Self::FORBIDDEN_PROGRAMS.contains(&self.hook_program)
+    }
+
+    #[inline(always)]
+    pub fn writable_hook_accounts(&self) -> bool {
+        self.flags & Self::FLAG_WRITABLE_HOOK_ACCOUNTS != 0
+    }
+
+    #[inline(always)]
+    pub fn batch_withdraw_hook(&self) -> bool {
+        self.flags & Self::FLAG_BATCH_WITHDRAW_HOOK != 0
     }
 
     /// Validates that the hook program account matches the stored address
@@ -52,6 +112,13 @@ impl HookData {
     /// parent escrow instruction. If an escrow is set immutable with a hook
     /// configured, this external dependency is permanently embedded.
     ///
+    /// A failing CPI aborts the transaction with the hook's raw error code, which
+    /// may collide with escrow error codes. Hooks that want an attributable reason
+    /// should instead return success with `REJECTION_MAGIC` and a `u16` reason code
+    /// as return data; the escrow then fails with `hook_error(code)` from the
+    /// reserved hook error range. Return data without the magic prefix is ignored,
+    /// so hooks remain free to set return data for their own purposes.
+    ///
     /// # Arguments
     /// * `hook_point` - The hook point discriminator
     /// * `remaining_accounts` - Remaining accounts slice: [hook_program, extra_accounts...]
@@ -59,46 +126,111 @@ impl HookData {
     ///
     /// # Returns
     /// * `Ok(())` if hook succeeds
-    /// * `Err(HookRejected)` if hook returns error or remaining_accounts is invalid
+    /// * `Err(hook_error(code))` if hook rejects via return data
+    /// * `Err(TooManyHookAccounts)` if more than `MAX_CPI_ACCOUNTS` accounts would be passed
+    /// * `Err(HookRejected)` if hook sets a malformed rejection, or the stored hook program is
+    ///   forbidden (an entry written before `is_valid` rejected it)
+    ///
+    /// A hook that fails never returns here: the runtime aborts with the hook's own error.
     pub fn invoke(
         &self,
         hook_point: HookPoint,
         remaining_accounts: &[AccountView],
         core_accounts: &[&AccountView],
     ) -> ProgramResult {
+        self.invoke_with_data(&[hook_point as u8], remaining_accounts, core_accounts)
+    }
+
+    /// Validates and invokes the hook program once for a `WithdrawMany` batch.
+    ///
+    /// Core accounts are escrow, withdrawer, mint and then every receipt. The
+    /// instruction data is the hook point followed by the receipt count, so the
+    /// hook can tell where the core accounts end and its extra accounts begin.
+    pub fn invoke_batch(
+        &self,
+        hook_point: HookPoint,
+        remaining_accounts: &[AccountView],
+        core_accounts: &[&AccountView],
+        receipt_count: u8,
+    ) -> ProgramResult {
+        self.invoke_with_data(&[hook_point as u8, receipt_count], remaining_accounts, core_accounts)
+    }
+
+    fn invoke_with_data(
+        &self,
+        instruction_data: &[u8],
+        remaining_accounts: &[AccountView],
+        core_accounts: &[&AccountView],
+    ) -> ProgramResult {
+        // Entries stored before `is_valid` covered every forbidden program are never invoked
+        if !self.has_allowed_program() {
+            return Err(EscrowProgramError::HookRejected.into());
+        }
         self.validate(remaining_accounts)?;
 
         let extra_accounts = remaining_accounts.get(1..).unwrap_or(&[]);
+        if core_accounts.len() + extra_accounts.len() > Self::MAX_CPI_ACCOUNTS {
+            return Err(EscrowProgramError::TooManyHookAccounts.into());
+        }
         let all_accounts: Vec<&AccountView> = core_accounts.iter().copied().chain(extra_accounts.iter()).collect();
 
-        // Build instruction accounts with least privilege for hook CPI
-        // (read-only + non-signer regardless of caller flags).
-        let instruction_accounts: Vec<InstructionAccount> =
-            all_accounts.iter().map(|acc| InstructionAccount::new(acc.address(), false, false)).collect();
+        // Build instruction accounts with least privilege for hook CPI: never signers,
+        // and read-only except for the hook program's own accounts when opted in.
+        // Core accounts (escrow, receipt, vault, ...) are never owned by the hook, and
+        // escrow-owned accounts stay read-only even if a stored hook predates `is_valid`.
+        let writable_hook_accounts = self.writable_hook_accounts();
+        let instruction_accounts: Vec<InstructionAccount> = core_accounts
+            .iter()
+            .map(|acc| InstructionAccount::new(acc.address(), false, false))
+            .chain(extra_accounts.iter().map(|acc| {
+                let is_writable = writable_hook_accounts
+                    && acc.is_writable()
+                    && acc.owned_by(&self.hook_program)
+                    && !acc.owned_by(&crate::ID);
+                InstructionAccount::new(acc.address(), is_writable, false)
+            }))
+            .collect();
 
-        // Instruction data is just the 1-byte hook point discriminator
-        let instruction_data = [hook_point as u8];
+        let instruction =
+            InstructionView { program_id: &self.hook_program, accounts: &instruction_accounts, data: instruction_data };
 
-        let instruction = InstructionView {
-            program_id: &self.hook_program,
-            accounts: &instruction_accounts,
-            data: &instruction_data,
-        };
+        invoke_with_slice(&instruction, &all_accounts)?;
 
-        // Preserve a stable escrow error surface for all hook CPI failures.
-        invoke_with_bounds::<16>(&instruction, &all_accounts).map_err(|_| EscrowProgramError::HookRejected.into())
+        // Return data is cleared before every CPI, so anything attributed to the
+        // hook program here was set during this invocation.
+        match get_return_data() {
+            Some(return_data) if return_data.program_id() == &self.hook_program => {
+                Self::check_rejection(return_data.as_slice())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Decodes hook return data into a rejection error, ignoring data without the magic prefix
+    fn check_rejection(return_data: &[u8]) -> ProgramResult {
+        let Some(code) = return_data.strip_prefix(&Self::REJECTION_MAGIC) else {
+            return Ok(());
+        };
+        let code: [u8; 2] = code.try_into().map_err(|_| ProgramError::from(EscrowProgramError::HookRejected))?;
+        Err(hook_error(u16::from_le_bytes(code)))
     }
 }
 
 impl ExtensionData for HookData {
     fn to_bytes(&self) -> Vec<u8> {
-        self.hook_program.as_array().to_vec()
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(self.hook_program.as_ref());
+        data.push(self.flags);
+        data
     }
 
     fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_len!(data, Self::LEN);
+        require_len!(data, Self::LEGACY_LEN);
 
-        Ok(Self { hook_program: Address::new_from_array(data[0..32].try_into().unwrap()) })
+        // Legacy entries have no flags byte
+        let flags = data.get(32).copied().unwrap_or(0);
+
+        Ok(Self { hook_program: Address::new_from_array(data[0..32].try_into().unwrap()), flags })
     }
 }
 
@@ -109,16 +241,90 @@ mod tests {
     #[test]
     fn test_hook_data_new() {
         let program = Address::new_from_array([1u8; 32]);
-        let hook = HookData::new(program);
+        let hook = HookData::new(program, HookData::FLAG_WRITABLE_HOOK_ACCOUNTS);
         assert_eq!(hook.hook_program, program);
+        assert!(hook.writable_hook_accounts());
+        assert!(!hook.batch_withdraw_hook());
+    }
+
+    #[test]
+    fn test_hook_data_batch_withdraw_hook_flag() {
+        let hook = HookData::new(Address::new_from_array([1u8; 32]), HookData::FLAG_BATCH_WITHDRAW_HOOK);
+        assert!(hook.batch_withdraw_hook());
+        assert!(!hook.writable_hook_accounts());
+    }
+
+    #[test]
+    fn test_hook_data_is_valid() {
+        let program = Address::new_from_array([1u8; 32]);
+        assert!(HookData::new(program, 0).is_valid());
+        assert!(HookData::new(program, HookData::VALID_FLAGS).is_valid());
+        assert!(!HookData::new(program, 1 << 7).is_valid());
+        assert!(!HookData::new(crate::ID, 0).is_valid());
+        assert!(!HookData::new(pinocchio_token::ID, 0).is_valid());
+        assert!(!HookData::new(pinocchio_token_2022::ID, 0).is_valid());
+        assert!(!HookData::new(pinocchio_system::ID, 0).is_valid());
     }
 
     #[test]
     fn test_hook_data_roundtrip() {
         let program = Address::new_from_array([2u8; 32]);
-        let hook = HookData::new(program);
+        let hook = HookData::new(program, HookData::FLAG_WRITABLE_HOOK_ACCOUNTS);
         let bytes = hook.to_bytes();
+        assert_eq!(bytes.len(), HookData::LEN);
         let parsed = HookData::from_bytes(&bytes).unwrap();
         assert_eq!(parsed, hook);
     }
+
+    #[test]
+    fn test_hook_data_from_legacy_bytes() {
+        let parsed = HookData::from_bytes(&[3u8; HookData::LEGACY_LEN]).unwrap();
+        assert_eq!(parsed.hook_program, Address::new_from_array([3u8; 32]));
+        assert_eq!(parsed.flags, 0);
+        assert!(!parsed.writable_hook_accounts());
+    }
+
+    #[test]
+    fn test_hook_data_from_bytes_too_short() {
+        let result = HookData::from_bytes(&[0u8; 16]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_hook_invoke_rejects_stored_forbidden_program() {
+        for program in HookData::FORBIDDEN_PROGRAMS {
+            let result = HookData::new(program, 0).invoke(HookPoint::PreDeposit, &[], &[]);
+            assert_eq!(result, Err(EscrowProgramError::HookRejected.into()));
+        }
+    }
+
+    fn rejection(code: &[u8]) -> Vec<u8> {
+        let mut data = HookData::REJECTION_MAGIC.to_vec();
+        data.extend_from_slice(code);
+        data
+    }
+
+    #[test]
+    fn test_hook_check_rejection_decodes_code() {
+        let data = rejection(&7u16.to_le_bytes());
+        assert_eq!(data.len(), HookData::REJECTION_LEN);
+        assert_eq!(HookData::check_rejection(&data), Err(hook_error(7)));
+    }
+
+    #[test]
+    fn test_hook_check_rejection_malformed_rejection() {
+        let result = HookData::check_rejection(&rejection(&[1, 2, 3]));
+        assert_eq!(result, Err(EscrowProgramError::HookRejected.into()));
+
+        let result = HookData::check_rejection(&rejection(&[]));
+        assert_eq!(result, Err(EscrowProgramError::HookRejected.into()));
+    }
+
+    #[test]
+    fn test_hook_check_rejection_ignores_untagged_return_data() {
+        assert_eq!(HookData::check_rejection(&7u16.to_le_bytes()), Ok(()));
+        assert_eq!(HookData::check_rejection(&[1, 2, 3]), Ok(()));
+        assert_eq!(HookData::check_rejection(&[]), Ok(()));
+        assert_eq!(HookData::check_rejection(&HookData::REJECTION_MAGIC[..3]), Ok(()));
+    }
 }
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+use pinocchio::{
+    account::AccountView,
+    error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    Address, ProgramResult,
+};
+
+use crate::{
+    assert_no_padding,
+    errors::EscrowProgramError,
+    require_len,
+    state::{HookBypassRequest, HookData},
+    traits::ExtensionData,
+    utils::{verify_current_program_account, verify_writable},
+};
+
+/// Hook bypass extension data (stored in TLV format)
+///
+/// Escape hatch for escrows with a hook configured. A depositor who cannot withdraw
+/// because of the hook may record a `HookBypassRequest` for their receipt. Once
+/// `grace_period` seconds have elapsed since the request, the withdraw skips the hook.
+///
+/// Any depositor may request a bypass, so with this extension set the hook is advisory
+/// on withdraw: it can delay a withdraw by at most `grace_period`, not prevent it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct HookBypassData {
+    pub grace_period: u64,
+}
+
+assert_no_padding!(HookBypassData, 8);
+
+impl HookBypassData {
+    pub const LEN: usize = 8;
+
+    /// Shortest grace period accepted, so admins and arbiters have time to react to requests
+    pub const MIN_GRACE_PERIOD: u64 = 86_400;
+
+    pub fn new(grace_period: u64) -> Self {
+        Self { grace_period }
+    }
+
+    /// Grace period is at least `MIN_GRACE_PERIOD` and fits a timestamp offset
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        (Self::MIN_GRACE_PERIOD..=i64::MAX as u64).contains(&self.grace_period)
+    }
+
+    /// Validate that the grace period has elapsed since the bypass was requested
+    pub fn validate(&self, requested_at: i64) -> ProgramResult {
+        let grace_period_i64 = i64::try_from(self.grace_period).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        let bypass_time = requested_at.checked_add(grace_period_i64).ok_or(ProgramError::ArithmeticOverflow)?;
+        let clock = Clock::get()?;
+        if clock.unix_timestamp < bypass_time {
+            return Err(EscrowProgramError::HookBypassGracePeriodActive.into());
+        }
+        Ok(())
+    }
+}
+
+impl ExtensionData for HookBypassData {
+    fn to_bytes(&self) -> Vec<u8> {
+        self.grace_period.to_le_bytes().to_vec()
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { grace_period: u64::from_le_bytes(data[0..8].try_into().unwrap()) })
+    }
+}
+
+/// Returns the matured hook bypass request passed in place of the hook program, if any.
+///
+/// `hook_accounts` starts where the hook program would be. Without a hook or a hook bypass
+/// extension, or when the hook program itself is passed, the hook runs as usual.
+pub fn take_hook_bypass_request<'a>(
+    program_id: &Address,
+    hook: Option<&HookData>,
+    hook_bypass_bytes: Option<&[u8]>,
+    hook_accounts: &'a [AccountView],
+    receipt: &Address,
+) -> Result<Option<&'a AccountView>, ProgramError> {
+    let (Some(hook), Some(hook_bypass_bytes), Some(request_account)) = (hook, hook_bypass_bytes, hook_accounts.first())
+    else {
+        return Ok(None);
+    };
+    if request_account.address() == &hook.hook_program {
+        return Ok(None);
+    }
+
+    let hook_bypass = HookBypassData::from_bytes(hook_bypass_bytes)?;
+
+    verify_current_program_account(request_account)?;
+    verify_writable(request_account, true)?;
+
+    let request_data = request_account.try_borrow()?;
+    let request = HookBypassRequest::from_account(&request_data, request_account, program_id)?;
+    request.validate_receipt(receipt)?;
+    hook_bypass.validate(request.requested_at)?;
+
+    Ok(Some(request_account))
+}
+
+/// Splits a hook bypass request for `receipt` passed ahead of the hook accounts off `accounts`.
+///
+/// Instructions that close a receipt without honouring the bypass take the request this way so it is
+/// closed with the receipt instead of being orphaned. Hook programs are never owned by this program,
+/// so an account owned by it in the first slot is the request.
+pub fn split_hook_bypass_request<'a>(
+    program_id: &Address,
+    accounts: &'a [AccountView],
+    receipt: &Address,
+) -> Result<(Option<&'a AccountView>, &'a [AccountView]), ProgramError> {
+    let Some((request_account, rest)) = accounts.split_first() else {
+        return Ok((None, accounts));
+    };
+    if !request_account.owned_by(program_id) {
+        return Ok((None, accounts));
+    }
+
+    verify_writable(request_account, true)?;
+
+    let request_data = request_account.try_borrow()?;
+    let request = HookBypassRequest::from_account(&request_data, request_account, program_id)?;
+    request.validate_receipt(receipt)?;
+
+    Ok((Some(request_account), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_bypass_data_new() {
+        let hook_bypass = HookBypassData::new(86_400);
+        assert_eq!(hook_bypass.grace_period, 86_400);
+    }
+
+    #[test]
+    fn test_hook_bypass_data_is_valid() {
+        assert!(HookBypassData::new(HookBypassData::MIN_GRACE_PERIOD).is_valid());
+        assert!(HookBypassData::new(i64::MAX as u64).is_valid());
+        assert!(!HookBypassData::new(0).is_valid());
+        assert!(!HookBypassData::new(HookBypassData::MIN_GRACE_PERIOD - 1).is_valid());
+        assert!(!HookBypassData::new(i64::MAX as u64 + 1).is_valid());
+    }
+
+    #[test]
+    fn test_hook_bypass_data_roundtrip() {
+        let hook_bypass = HookBypassData::new(604_800);
+        let bytes = hook_bypass.to_bytes();
+        let parsed = HookBypassData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, hook_bypass);
+    }
+
+    #[test]
+    fn test_hook_bypass_data_from_bytes_too_short() {
+        let result = HookBypassData::from_bytes(&[0u8; 4]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+}
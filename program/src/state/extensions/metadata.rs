@@ -0,0 +1,138 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use codama::CodamaType;
+use pinocchio::error::ProgramError;
+
+use crate::{require_len, traits::ExtensionData};
+
+/// Metadata extension data (stored in TLV format)
+///
+/// Descriptive labels for off-chain indexers and dashboards. The program never reads them.
+///
+/// Uses dynamic sizing - serialized format is:
+/// - 4 bytes + n: `name` (UTF-8, at most `MAX_NAME_LEN` bytes)
+/// - 4 bytes + n: `uri` (UTF-8, at most `MAX_URI_LEN` bytes)
+/// - 4 bytes + n: `external_reference` (opaque, at most `MAX_EXTERNAL_REFERENCE_LEN` bytes)
+#[derive(Clone, Debug, Default, PartialEq, CodamaType)]
+#[codama(name = "escrowMetadata")]
+pub struct MetadataData {
+    pub name: String,
+    pub uri: String,
+    pub external_reference: Vec<u8>,
+}
+
+impl MetadataData {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_EXTERNAL_REFERENCE_LEN: usize = 64;
+
+    pub fn new(name: &str, uri: &str, external_reference: &[u8]) -> Result<Self, ProgramError> {
+        if name.len() > Self::MAX_NAME_LEN
+            || uri.len() > Self::MAX_URI_LEN
+            || external_reference.len() > Self::MAX_EXTERNAL_REFERENCE_LEN
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { name: String::from(name), uri: String::from(uri), external_reference: external_reference.to_vec() })
+    }
+
+    /// Returns the serialized byte length: three u32 length prefixes plus the field bytes
+    pub fn byte_len(&self) -> usize {
+        4 + self.name.len() + 4 + self.uri.len() + 4 + self.external_reference.len()
+    }
+}
+
+/// Splits a u32 length-prefixed field off the front of `data`
+fn split_field(data: &[u8], max_len: usize) -> Result<(&[u8], &[u8]), ProgramError> {
+    require_len!(data, 4);
+    let len = u32::from_le_bytes(data[0..4].try_into().unwrap()) as usize;
+    if len > max_len {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    require_len!(data, 4 + len);
+    Ok(data[4..].split_at(len))
+}
+
+fn parse_utf8(bytes: &[u8]) -> Result<String, ProgramError> {
+    core::str::from_utf8(bytes).map(String::from).map_err(|_| ProgramError::InvalidInstructionData)
+}
+
+impl ExtensionData for MetadataData {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.byte_len());
+        for field in [self.name.as_bytes(), self.uri.as_bytes(), &self.external_reference] {
+            bytes.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(field);
+        }
+        bytes
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let (name, rest) = split_field(data, Self::MAX_NAME_LEN)?;
+        let (uri, rest) = split_field(rest, Self::MAX_URI_LEN)?;
+        let (external_reference, _) = split_field(rest, Self::MAX_EXTERNAL_REFERENCE_LEN)?;
+
+        Ok(Self { name: parse_utf8(name)?, uri: parse_utf8(uri)?, external_reference: external_reference.to_vec() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_data_roundtrip() {
+        let data = MetadataData::new("Payroll escrow", "https://example.com/escrow.json", b"deal-42").unwrap();
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), data.byte_len());
+        assert_eq!(&bytes[0..4], &14u32.to_le_bytes());
+
+        let parsed = MetadataData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, data);
+    }
+
+    #[test]
+    fn test_metadata_data_empty_fields() {
+        let data = MetadataData::default();
+        let bytes = data.to_bytes();
+        assert_eq!(bytes, vec![0u8; 12]);
+        assert_eq!(MetadataData::from_bytes(&bytes).unwrap(), data);
+    }
+
+    #[test]
+    fn test_metadata_data_new_rejects_oversized_fields() {
+        let long_name = "a".repeat(MetadataData::MAX_NAME_LEN + 1);
+        assert!(MetadataData::new(&long_name, "", &[]).is_err());
+
+        let long_uri = "a".repeat(MetadataData::MAX_URI_LEN + 1);
+        assert!(MetadataData::new("", &long_uri, &[]).is_err());
+
+        let long_reference = vec![0u8; MetadataData::MAX_EXTERNAL_REFERENCE_LEN + 1];
+        assert!(MetadataData::new("", "", &long_reference).is_err());
+    }
+
+    #[test]
+    fn test_metadata_data_from_bytes_rejects_oversized_field() {
+        let mut bytes = ((MetadataData::MAX_NAME_LEN + 1) as u32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[b'a'; MetadataData::MAX_NAME_LEN + 1]);
+        bytes.extend_from_slice(&[0u8; 8]);
+        assert_eq!(MetadataData::from_bytes(&bytes), Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_metadata_data_from_bytes_rejects_invalid_utf8() {
+        let mut bytes = 1u32.to_le_bytes().to_vec();
+        bytes.push(0xFF);
+        bytes.extend_from_slice(&[0u8; 8]);
+        assert_eq!(MetadataData::from_bytes(&bytes), Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_metadata_data_from_bytes_truncated() {
+        let bytes = MetadataData::new("name", "uri", b"ref").unwrap().to_bytes();
+        assert_eq!(MetadataData::from_bytes(&bytes[..bytes.len() - 1]), Err(ProgramError::InvalidInstructionData));
+    }
+}
@@ -1,9 +1,27 @@
 pub mod arbiter;
+pub mod auto_release;
 pub mod block_token_extension;
+pub mod cooldown;
+pub mod depositor_allowlist;
+pub mod extension_locks;
+pub mod governance_delay;
 pub mod hook;
+pub mod hook_bypass;
+pub mod metadata;
+pub mod refund_authority;
+pub mod roles;
 pub mod timelock;
 
 pub use arbiter::*;
+pub use auto_release::*;
 pub use block_token_extension::*;
+pub use cooldown::*;
+pub use depositor_allowlist::*;
+pub use extension_locks::*;
+pub use governance_delay::*;
 pub use hook::*;
+pub use hook_bypass::*;
+pub use metadata::*;
+pub use refund_authority::*;
+pub use roles::*;
 pub use timelock::*;
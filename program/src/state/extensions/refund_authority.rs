@@ -0,0 +1,108 @@
+use alloc::vec::Vec;
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{assert_no_padding, require_len, traits::ExtensionData};
+
+/// Refund authority extension data (stored in TLV format)
+///
+/// Opts an escrow into `ForceRefund`, which lets the authorities selected by `flags` return a
+/// receipt's tokens to its depositor before the timelock expires. Escrows without it cannot
+/// be force-refunded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct RefundAuthorityData {
+    pub flags: u8,
+}
+
+assert_no_padding!(RefundAuthorityData, 1);
+
+impl RefundAuthorityData {
+    pub const LEN: usize = 1;
+
+    /// The escrow admin may force a refund
+    pub const FLAG_ADMIN: u8 = 1 << 0;
+
+    /// The arbiter that applies to the receipt's mint may force a refund
+    pub const FLAG_ARBITER: u8 = 1 << 1;
+
+    /// All flags currently understood by the program
+    pub const VALID_FLAGS: u8 = Self::FLAG_ADMIN | Self::FLAG_ARBITER;
+
+    pub fn new(flags: u8) -> Self {
+        Self { flags }
+    }
+
+    /// At least one authority must be selected, and no unknown flags set
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool {
+        self.flags != 0 && self.flags & !Self::VALID_FLAGS == 0
+    }
+
+    /// Whether `authority` may force a refund, given the escrow admin and the arbiter that
+    /// applies to the receipt's mint
+    pub fn is_authority(&self, authority: &Address, admin: &Address, arbiter: Option<&Address>) -> bool {
+        (self.flags & Self::FLAG_ADMIN != 0 && authority == admin)
+            || (self.flags & Self::FLAG_ARBITER != 0 && arbiter == Some(authority))
+    }
+}
+
+impl ExtensionData for RefundAuthorityData {
+    fn to_bytes(&self) -> Vec<u8> {
+        alloc::vec![self.flags]
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self { flags: data[0] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refund_authority_data_roundtrip() {
+        let refund_authority =
+            RefundAuthorityData::new(RefundAuthorityData::FLAG_ADMIN | RefundAuthorityData::FLAG_ARBITER);
+        let bytes = refund_authority.to_bytes();
+        let parsed = RefundAuthorityData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, refund_authority);
+    }
+
+    #[test]
+    fn test_refund_authority_data_from_bytes_too_short() {
+        let result = RefundAuthorityData::from_bytes(&[]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_refund_authority_data_is_valid() {
+        assert!(RefundAuthorityData::new(RefundAuthorityData::FLAG_ADMIN).is_valid());
+        assert!(RefundAuthorityData::new(RefundAuthorityData::VALID_FLAGS).is_valid());
+        assert!(!RefundAuthorityData::new(0).is_valid());
+        assert!(!RefundAuthorityData::new(0b100).is_valid());
+    }
+
+    #[test]
+    fn test_refund_authority_data_is_authority() {
+        let admin = Address::new_from_array([1u8; 32]);
+        let arbiter = Address::new_from_array([2u8; 32]);
+        let other = Address::new_from_array([3u8; 32]);
+
+        let admin_only = RefundAuthorityData::new(RefundAuthorityData::FLAG_ADMIN);
+        assert!(admin_only.is_authority(&admin, &admin, Some(&arbiter)));
+        assert!(!admin_only.is_authority(&arbiter, &admin, Some(&arbiter)));
+
+        let arbiter_only = RefundAuthorityData::new(RefundAuthorityData::FLAG_ARBITER);
+        assert!(arbiter_only.is_authority(&arbiter, &admin, Some(&arbiter)));
+        assert!(!arbiter_only.is_authority(&admin, &admin, Some(&arbiter)));
+        assert!(!arbiter_only.is_authority(&arbiter, &admin, None));
+
+        let both = RefundAuthorityData::new(RefundAuthorityData::VALID_FLAGS);
+        assert!(both.is_authority(&admin, &admin, Some(&arbiter)));
+        assert!(both.is_authority(&arbiter, &admin, Some(&arbiter)));
+        assert!(!both.is_authority(&other, &admin, Some(&arbiter)));
+    }
+}
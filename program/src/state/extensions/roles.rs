@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+use pinocchio::{error::ProgramError, Address};
+
+use crate::{assert_no_padding, require_len, traits::ExtensionData};
+
+/// Delegable authorities over admin instructions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    /// `AllowMint`, `BlockMint` and `SetMintLimits`
+    MintManager,
+    /// Extension setters and `RemoveExtension` (except removing the roles extension)
+    ExtensionManager,
+    /// `UpdateAdmin`
+    AdminTransferAuthority,
+}
+
+/// Roles extension data (stored in TLV format)
+///
+/// Assigns admin instructions to distinct keys. A role set to the default (all-zero) address
+/// is unassigned and falls back to `Escrow.admin`; an assigned role is held exclusively by its
+/// key. Only the admin can set roles, so ownership stays with the admin.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct RolesData {
+    pub mint_manager: Address,
+    pub extension_manager: Address,
+    pub admin_transfer_authority: Address,
+}
+
+assert_no_padding!(RolesData, 32 + 32 + 32);
+
+impl RolesData {
+    pub const LEN: usize = 32 + 32 + 32;
+
+    pub fn new(mint_manager: Address, extension_manager: Address, admin_transfer_authority: Address) -> Self {
+        Self { mint_manager, extension_manager, admin_transfer_authority }
+    }
+
+    /// Key holding `role`, or `None` if the role is unassigned
+    #[inline(always)]
+    pub fn holder(&self, role: Role) -> Option<&Address> {
+        let holder = match role {
+            Role::MintManager => &self.mint_manager,
+            Role::ExtensionManager => &self.extension_manager,
+            Role::AdminTransferAuthority => &self.admin_transfer_authority,
+        };
+        (*holder != Address::default()).then_some(holder)
+    }
+}
+
+impl ExtensionData for RolesData {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::LEN);
+        data.extend_from_slice(self.mint_manager.as_ref());
+        data.extend_from_slice(self.extension_manager.as_ref());
+        data.extend_from_slice(self.admin_transfer_authority.as_ref());
+        data
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_len!(data, Self::LEN);
+
+        Ok(Self {
+            mint_manager: Address::new_from_array(data[0..32].try_into().unwrap()),
+            extension_manager: Address::new_from_array(data[32..64].try_into().unwrap()),
+            admin_transfer_authority: Address::new_from_array(data[64..96].try_into().unwrap()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roles_data_roundtrip() {
+        let data = RolesData::new(
+            Address::new_from_array([1u8; 32]),
+            Address::new_from_array([2u8; 32]),
+            Address::new_from_array([3u8; 32]),
+        );
+        let bytes = data.to_bytes();
+        assert_eq!(bytes.len(), RolesData::LEN);
+
+        let parsed = RolesData::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, data);
+    }
+
+    #[test]
+    fn test_roles_data_holder() {
+        let mint_manager = Address::new_from_array([1u8; 32]);
+        let data = RolesData::new(mint_manager, Address::default(), Address::default());
+
+        assert_eq!(data.holder(Role::MintManager), Some(&mint_manager));
+        assert_eq!(data.holder(Role::ExtensionManager), None);
+        assert_eq!(data.holder(Role::AdminTransferAuthority), None);
+    }
+
+    #[test]
+    fn test_roles_data_from_bytes_wrong_length() {
+        let result = RolesData::from_bytes(&[0u8; RolesData::LEN - 1]);
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+}
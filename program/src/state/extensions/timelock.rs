@@ -31,14 +31,19 @@ impl TimelockData {
         self.lock_duration != 0
     }
 
+    /// Time a receipt deposited at `deposited_at` unlocks
+    pub fn unlock_time(&self, deposited_at: i64) -> Result<i64, ProgramError> {
+        let lock_duration_i64 = i64::try_from(self.lock_duration).map_err(|_| ProgramError::ArithmeticOverflow)?;
+        deposited_at.checked_add(lock_duration_i64).ok_or(ProgramError::ArithmeticOverflow)
+    }
+
     /// Validate timelock constraint against the deposit timestamp
     pub fn validate(&self, deposited_at: i64) -> ProgramResult {
         if !self.is_enabled() {
             return Ok(());
         }
 
-        let lock_duration_i64 = i64::try_from(self.lock_duration).map_err(|_| ProgramError::ArithmeticOverflow)?;
-        let unlock_time = deposited_at.checked_add(lock_duration_i64).ok_or(ProgramError::ArithmeticOverflow)?;
+        let unlock_time = self.unlock_time(deposited_at)?;
         let clock = Clock::get()?;
         if clock.unix_timestamp < unlock_time {
             return Err(EscrowProgramError::TimelockNotExpired.into());
@@ -85,4 +90,11 @@ mod tests {
         let with_duration = TimelockData::new(3600);
         assert!(with_duration.is_enabled());
     }
+
+    #[test]
+    fn test_timelock_unlock_time() {
+        assert_eq!(TimelockData::new(0).unlock_time(1_000).unwrap(), 1_000);
+        assert_eq!(TimelockData::new(3600).unlock_time(1_000).unwrap(), 4_600);
+        assert_eq!(TimelockData::new(u64::MAX).unlock_time(0), Err(ProgramError::ArithmeticOverflow));
+    }
 }
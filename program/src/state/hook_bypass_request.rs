@@ -0,0 +1,211 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::EscrowProgramError::InvalidHookBypassRequest;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
+    Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator};
+
+/// HookBypassRequest account state
+///
+/// Records when a depositor requested to bypass the hook for a receipt.
+/// Closed together with the receipt on withdraw, or when passed to an instruction
+/// that closes the receipt without the bypass (see `split_hook_bypass_request`).
+///
+/// # PDA Seeds
+/// `[b"hook_bypass", receipt.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 5))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "hook_bypass"))]
+#[codama(seed(name = "receipt", type = public_key))]
+#[repr(C)]
+pub struct HookBypassRequest {
+    pub bump: u8,
+    _padding: [u8; 7],
+
+    pub receipt: Address,
+
+    pub requested_at: i64,
+}
+
+assert_no_padding!(HookBypassRequest, 1 + 7 + 32 + 8);
+
+impl Discriminator for HookBypassRequest {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::HookBypassRequestDiscriminator as u8;
+}
+
+impl Versioned for HookBypassRequest {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for HookBypassRequest {
+    const DATA_LEN: usize = 1 + 7 + 32 + 8; // bump + padding + receipt + requested_at
+}
+
+impl AccountParse for HookBypassRequest {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..8]
+        let receipt = Address::new_from_array(data[8..40].try_into().unwrap());
+        let requested_at = i64::from_le_bytes(data[40..48].try_into().unwrap());
+
+        Ok(Self::new(receipt, requested_at, bump))
+    }
+}
+
+impl AccountSerialize for HookBypassRequest {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(self.receipt.as_ref());
+        data.extend_from_slice(&self.requested_at.to_le_bytes());
+        data
+    }
+}
+
+impl PdaSeeds for HookBypassRequest {
+    const PREFIX: &'static [u8] = b"hook_bypass";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.receipt.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![Seed::from(Self::PREFIX), Seed::from(self.receipt.as_ref()), Seed::from(bump.as_slice())]
+    }
+}
+
+impl PdaAccount for HookBypassRequest {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
+        let derived = Address::derive_address(&[Self::PREFIX, self.receipt.as_ref()], Some(self.bump), program_id);
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+}
+
+impl HookBypassRequest {
+    #[inline(always)]
+    pub fn new(receipt: Address, requested_at: i64, bump: u8) -> Self {
+        Self { bump, _padding: [0u8; 7], receipt, requested_at }
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    #[inline(always)]
+    pub fn validate_receipt(&self, receipt: &Address) -> Result<(), ProgramError> {
+        if self.receipt != *receipt {
+            return Err(InvalidHookBypassRequest.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_request() -> HookBypassRequest {
+        let receipt = Address::new_from_array([1u8; 32]);
+        HookBypassRequest::new(receipt, 1234567890, 255)
+    }
+
+    #[test]
+    fn test_hook_bypass_request_new() {
+        let receipt = Address::new_from_array([2u8; 32]);
+        let request = HookBypassRequest::new(receipt, 42, 200);
+
+        assert_eq!(request.receipt, receipt);
+        assert_eq!(request.requested_at, 42);
+        assert_eq!(request.bump, 200);
+    }
+
+    #[test]
+    fn test_hook_bypass_request_to_bytes_inner() {
+        let request = create_test_request();
+        let bytes = request.to_bytes_inner();
+
+        assert_eq!(bytes.len(), HookBypassRequest::DATA_LEN);
+        assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(&bytes[8..40], &[1u8; 32]); // receipt
+        assert_eq!(&bytes[40..48], &1234567890i64.to_le_bytes()); // requested_at
+    }
+
+    #[test]
+    fn test_hook_bypass_request_to_bytes() {
+        let request = create_test_request();
+        let bytes = request.to_bytes();
+
+        assert_eq!(bytes.len(), HookBypassRequest::LEN);
+        assert_eq!(bytes[0], HookBypassRequest::DISCRIMINATOR);
+        assert_eq!(bytes[1], HookBypassRequest::VERSION); // version auto-prepended
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_hook_bypass_request_seeds() {
+        let request = create_test_request();
+        let seeds = request.seeds();
+
+        assert_eq!(seeds.len(), 2);
+        assert_eq!(seeds[0], HookBypassRequest::PREFIX);
+        assert_eq!(seeds[1], request.receipt.as_ref());
+    }
+
+    #[test]
+    fn test_hook_bypass_request_parse_from_bytes() {
+        let request = create_test_request();
+        let bytes = request.to_bytes();
+
+        let parsed = HookBypassRequest::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, request);
+    }
+
+    #[test]
+    fn test_hook_bypass_request_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; HookBypassRequest::LEN];
+        bytes[0] = 99; // wrong discriminator
+        let result = HookBypassRequest::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_hook_bypass_request_validate_receipt() {
+        let request = create_test_request();
+        assert!(request.validate_receipt(&Address::new_from_array([1u8; 32])).is_ok());
+
+        let result = request.validate_receipt(&Address::new_from_array([9u8; 32]));
+        assert_eq!(result, Err(InvalidHookBypassRequest.into()));
+    }
+}
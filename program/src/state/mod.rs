@@ -1,11 +1,25 @@
 pub mod allowed_mint;
+pub mod basket_receipt;
+pub mod depositor_allowance;
 pub mod escrow;
 pub mod escrow_extensions;
 pub mod extensions;
+pub mod hook_bypass_request;
+pub mod pending_change;
 pub mod receipt;
+pub mod receipt_counter;
+pub mod withdraw_delegate;
+pub mod withdraw_request;
 
 pub use allowed_mint::*;
+pub use basket_receipt::*;
+pub use depositor_allowance::*;
 pub use escrow::*;
 pub use escrow_extensions::*;
 pub use extensions::*;
+pub use hook_bypass_request::*;
+pub use pending_change::*;
 pub use receipt::*;
+pub use receipt_counter::*;
+pub use withdraw_delegate::*;
+pub use withdraw_request::*;
@@ -0,0 +1,343 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::state::ExtensionType;
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
+    Versioned,
+};
+use crate::{require_account_len, validate_discriminator};
+
+/// Kind of extension change held by a `PendingChange`
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChangeAction {
+    /// Write `value` as the extension entry, replacing any existing entry
+    SetExtension = 0,
+    /// Remove the extension entry; `value` is empty
+    RemoveExtension = 1,
+}
+
+impl TryFrom<u8> for ChangeAction {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::SetExtension),
+            1 => Ok(Self::RemoveExtension),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// PendingChange account state
+///
+/// An extension change queued while the escrow has a governance delay. It can be applied
+/// with `ExecuteQueuedChange` once the escrow's current governance delay has elapsed since
+/// `queued_at`, or dropped with `CancelQueuedChange`. Either closes the account.
+///
+/// The account is variable-sized: `value` holds the extension entry's data exactly as it
+/// will be written to the extensions PDA.
+///
+/// # PDA Seeds
+/// `[b"pending_change", escrow.as_ref(), change_id.to_le_bytes()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 8))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "pending_change"))]
+#[codama(seed(name = "escrow", type = public_key))]
+#[codama(seed(name = "change_id", type = number(u64)))]
+pub struct PendingChange {
+    pub bump: u8,
+    pub action: u8,
+    pub extension_type: u16,
+    _padding: [u8; 4],
+
+    pub escrow: Address,
+
+    /// Little-endian bytes of the change id, kept as bytes so they can be borrowed as a PDA seed
+    #[codama(type = number(u64))]
+    change_id: [u8; 8],
+    pub queued_at: i64,
+
+    pub value: Vec<u8>,
+}
+
+impl Discriminator for PendingChange {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::PendingChangeDiscriminator as u8;
+}
+
+impl Versioned for PendingChange {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for PendingChange {
+    /// Fixed part only; the account is `LEN + value.len()` bytes
+    const DATA_LEN: usize = 1 + 1 + 2 + 4 + 32 + 8 + 8 + 4; // bump + action + extension_type + padding + escrow + change_id + queued_at + value length
+}
+
+impl AccountParse for PendingChange {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        let action = data[1];
+        ChangeAction::try_from(action).map_err(|_| ProgramError::InvalidAccountData)?;
+        let extension_type = u16::from_le_bytes([data[2], data[3]]);
+        // Skip padding bytes [4..8]
+        let escrow = Address::new_from_array(data[8..40].try_into().unwrap());
+        let change_id = data[40..48].try_into().unwrap();
+        let queued_at = i64::from_le_bytes(data[48..56].try_into().unwrap());
+        let value_len = u32::from_le_bytes(data[56..60].try_into().unwrap()) as usize;
+        let value = data.get(60..60 + value_len).ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(Self {
+            bump,
+            action,
+            extension_type,
+            _padding: [0u8; 4],
+            escrow,
+            change_id,
+            queued_at,
+            value: value.to_vec(),
+        })
+    }
+}
+
+impl AccountSerialize for PendingChange {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN + self.value.len());
+        data.push(self.bump);
+        data.push(self.action);
+        data.extend_from_slice(&self.extension_type.to_le_bytes());
+        data.extend_from_slice(&[0u8; 4]); // padding
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(&self.change_id);
+        data.extend_from_slice(&self.queued_at.to_le_bytes());
+        data.extend_from_slice(&(self.value.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.value);
+        data
+    }
+}
+
+impl PdaSeeds for PendingChange {
+    const PREFIX: &'static [u8] = b"pending_change";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.escrow.as_ref(), &self.change_id]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.escrow.as_ref()),
+            Seed::from(&self.change_id),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+impl PdaAccount for PendingChange {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
+        let derived = Address::derive_address(
+            &[Self::PREFIX, self.escrow.as_ref(), &self.change_id],
+            Some(self.bump),
+            program_id,
+        );
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+}
+
+impl PendingChange {
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        change_id: u64,
+        action: ChangeAction,
+        extension_type: ExtensionType,
+        value: Vec<u8>,
+        queued_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            bump,
+            action: action as u8,
+            extension_type: extension_type as u16,
+            _padding: [0u8; 4],
+            escrow,
+            change_id: change_id.to_le_bytes(),
+            queued_at,
+            value,
+        }
+    }
+
+    /// Account size needed to store this change
+    #[inline(always)]
+    pub fn space(&self) -> usize {
+        Self::LEN + self.value.len()
+    }
+
+    #[inline(always)]
+    pub fn change_id(&self) -> u64 {
+        u64::from_le_bytes(self.change_id)
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Validate the change was queued for `escrow`
+    #[inline(always)]
+    pub fn validate_escrow(&self, escrow: &Address) -> Result<(), ProgramError> {
+        if self.escrow != *escrow {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn action(&self) -> Result<ChangeAction, ProgramError> {
+        ChangeAction::try_from(self.action).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[inline(always)]
+    pub fn extension_type(&self) -> Result<ExtensionType, ProgramError> {
+        ExtensionType::try_from(self.extension_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_change() -> PendingChange {
+        let escrow = Address::new_from_array([1u8; 32]);
+        PendingChange::new(escrow, 7, ChangeAction::SetExtension, ExtensionType::Timelock, vec![9u8; 8], 1_000, 254)
+    }
+
+    #[test]
+    fn test_pending_change_new() {
+        let change = create_test_change();
+
+        assert_eq!(change.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(change.change_id(), 7);
+        assert_eq!(change.action(), Ok(ChangeAction::SetExtension));
+        assert_eq!(change.extension_type(), Ok(ExtensionType::Timelock));
+        assert_eq!(change.queued_at, 1_000);
+        assert_eq!(change.bump, 254);
+    }
+
+    #[test]
+    fn test_pending_change_to_bytes_inner() {
+        let change = create_test_change();
+        let bytes = change.to_bytes_inner();
+
+        assert_eq!(bytes.len(), PendingChange::DATA_LEN + 8);
+        assert_eq!(bytes[0], 254); // bump
+        assert_eq!(bytes[1], ChangeAction::SetExtension as u8); // action
+        assert_eq!(&bytes[2..4], &(ExtensionType::Timelock as u16).to_le_bytes()); // extension_type
+        assert_eq!(&bytes[4..8], &[0u8; 4]); // padding
+        assert_eq!(&bytes[8..40], &[1u8; 32]); // escrow
+        assert_eq!(&bytes[40..48], &7u64.to_le_bytes()); // change_id
+        assert_eq!(&bytes[48..56], &1_000i64.to_le_bytes()); // queued_at
+        assert_eq!(&bytes[56..60], &8u32.to_le_bytes()); // value length
+        assert_eq!(&bytes[60..68], &[9u8; 8]); // value
+    }
+
+    #[test]
+    fn test_pending_change_to_bytes() {
+        let change = create_test_change();
+        let bytes = change.to_bytes();
+
+        assert_eq!(bytes.len(), change.space());
+        assert_eq!(bytes[0], PendingChange::DISCRIMINATOR);
+        assert_eq!(bytes[1], PendingChange::VERSION); // version auto-prepended
+        assert_eq!(bytes[2], 254); // bump
+    }
+
+    #[test]
+    fn test_pending_change_parse_from_bytes() {
+        let change = create_test_change();
+        let bytes = change.to_bytes();
+
+        let parsed = PendingChange::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, change);
+    }
+
+    #[test]
+    fn test_pending_change_parse_from_bytes_truncated_value() {
+        let change = create_test_change();
+        let bytes = change.to_bytes();
+
+        let result = PendingChange::parse_from_bytes(&bytes[..bytes.len() - 1]);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_pending_change_parse_from_bytes_invalid_action() {
+        let mut bytes = create_test_change().to_bytes();
+        bytes[3] = 2; // action
+
+        let result = PendingChange::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_pending_change_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; PendingChange::LEN];
+        bytes[0] = 99; // wrong discriminator
+        let result = PendingChange::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_pending_change_seeds() {
+        let change = create_test_change();
+        let seeds = change.seeds();
+
+        assert_eq!(seeds.len(), 3);
+        assert_eq!(seeds[0], PendingChange::PREFIX);
+        assert_eq!(seeds[1], change.escrow.as_ref());
+        assert_eq!(seeds[2], &7u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_pending_change_validate_escrow() {
+        let change = create_test_change();
+        assert!(change.validate_escrow(&Address::new_from_array([1u8; 32])).is_ok());
+
+        let result = change.validate_escrow(&Address::new_from_array([9u8; 32]));
+        assert_eq!(result, Err(ProgramError::InvalidSeeds));
+    }
+
+    #[test]
+    fn test_change_action_try_from() {
+        assert_eq!(ChangeAction::try_from(0u8), Ok(ChangeAction::SetExtension));
+        assert_eq!(ChangeAction::try_from(1u8), Ok(ChangeAction::RemoveExtension));
+        assert_eq!(ChangeAction::try_from(2u8), Err(ProgramError::InvalidInstructionData));
+    }
+}
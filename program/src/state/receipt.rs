@@ -3,17 +3,44 @@ use alloc::vec::Vec;
 use codama::CodamaAccount;
 use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
 
-use crate::errors::EscrowProgramError::{InvalidReceiptEscrow, InvalidWithdrawer};
+use crate::errors::EscrowProgramError::{
+    InvalidReceiptEscrow, InvalidRentRecipient, InvalidWithdrawer, WithdrawRequestPending,
+};
 use crate::traits::{
     AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
     Versioned,
 };
-use crate::{assert_no_padding, require_account_len, validate_discriminator};
+use crate::{require_account_len, validate_discriminator};
+
+/// Last PDA seed of a receipt
+///
+/// Version 2 receipts are derived from a `nonce` taken from the depositor's `ReceiptCounter`,
+/// held as its little-endian bytes. Version 1 receipts were derived from a caller-chosen
+/// `receipt_seed` address instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReceiptSeed {
+    Nonce([u8; 8]),
+    Legacy(Address),
+}
 
 /// Receipt account state
 ///
+/// `withdraw_requested` is set by RequestWithdraw. The receipt can then only leave the escrow
+/// through ClaimWithdraw once the cooldown recorded on its `WithdrawRequest` has elapsed.
+///
+/// `seed` is the `nonce` taken from the depositor's `ReceiptCounter` for this escrow and mint.
+///
+/// `rent_recipient` is the address the receipt's rent is refunded to when it is closed,
+/// designated by the payer at deposit.
+///
+/// Version 1 receipts predate both the nonce and `rent_recipient`: they store a `receipt_seed`
+/// address in place of the nonce, keep their layout when rewritten, and may refund rent to any
+/// recipient.
+///
 /// # PDA Seeds
-/// `[b"receipt", escrow.as_ref(), depositor.as_ref(), mint.as_ref(), receipt_seed.as_ref()]`
+/// `[b"receipt", escrow.as_ref(), depositor.as_ref(), mint.as_ref(), nonce.to_le_bytes()]`
+///
+/// Version 1: `[b"receipt", escrow.as_ref(), depositor.as_ref(), mint.as_ref(), receipt_seed.as_ref()]`
 #[derive(Clone, Debug, PartialEq, CodamaAccount)]
 #[codama(field("discriminator", number(u8), default_value = 3))]
 #[codama(discriminator(field = "discriminator"))]
@@ -21,58 +48,91 @@ use crate::{assert_no_padding, require_account_len, validate_discriminator};
 #[codama(seed(name = "escrow", type = public_key))]
 #[codama(seed(name = "depositor", type = public_key))]
 #[codama(seed(name = "mint", type = public_key))]
-#[codama(seed(name = "receiptSeed", type = public_key))]
-#[repr(C)]
+#[codama(seed(name = "nonce", type = number(u64)))]
 pub struct Receipt {
     pub bump: u8,
-    _padding: [u8; 7],
+    pub withdraw_requested: bool,
+    _padding: [u8; 6],
 
     pub escrow: Address,
     pub depositor: Address,
     pub mint: Address,
 
-    pub receipt_seed: Address,
-
+    #[codama(name = "nonce")]
+    #[codama(type = number(u64))]
+    pub seed: ReceiptSeed,
     pub amount: u64,
 
     pub deposited_at: i64,
-}
 
-assert_no_padding!(Receipt, 1 + 7 + 32 + 32 + 32 + 32 + 8 + 8);
+    pub rent_recipient: Address,
+}
 
 impl Discriminator for Receipt {
     const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::ReceiptDiscriminator as u8;
 }
 
 impl Versioned for Receipt {
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 2;
 }
 
 impl AccountSize for Receipt {
-    const DATA_LEN: usize = 1 + 7 + 32 + 32 + 32 + 32 + 8 + 8; // bump + padding + escrow + depositor + mint + receipt_seed + amount + deposited_at
+    const DATA_LEN: usize = 1 + 1 + 6 + 32 + 32 + 32 + 8 + 8 + 8 + 32; // bump + withdraw_requested + padding + escrow + depositor + mint + nonce + amount + deposited_at + rent_recipient
 }
 
 impl AccountParse for Receipt {
     fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        require_account_len!(data, Self::LEN);
+        require_account_len!(data, Self::LEGACY_LEN);
         validate_discriminator!(data, Self::DISCRIMINATOR);
-        if data[1] != Self::VERSION {
-            return Err(ProgramError::InvalidAccountData);
-        }
+        let legacy = match data[1] {
+            Self::LEGACY_VERSION => true,
+            Self::VERSION => {
+                require_account_len!(data, Self::LEN);
+                false
+            }
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
 
         // Skip discriminator (byte 0) and version (byte 1)
         let data = &data[2..];
 
         let bump = data[0];
-        // Skip padding bytes [1..8]
+        // The flag sits in a byte that was padding in version 1
+        let withdraw_requested = data[1] != 0;
+        // Skip padding bytes [2..8]
         let escrow = Address::new_from_array(data[8..40].try_into().unwrap());
         let depositor = Address::new_from_array(data[40..72].try_into().unwrap());
         let mint = Address::new_from_array(data[72..104].try_into().unwrap());
-        let receipt_seed = Address::new_from_array(data[104..136].try_into().unwrap());
-        let amount = u64::from_le_bytes(data[136..144].try_into().unwrap());
-        let deposited_at = i64::from_le_bytes(data[144..152].try_into().unwrap());
 
-        Ok(Self::new(amount, deposited_at, escrow, depositor, mint, receipt_seed, bump))
+        let (seed, amount, deposited_at, rent_recipient) = if legacy {
+            (
+                ReceiptSeed::Legacy(Address::new_from_array(data[104..136].try_into().unwrap())),
+                u64::from_le_bytes(data[136..144].try_into().unwrap()),
+                i64::from_le_bytes(data[144..152].try_into().unwrap()),
+                // Legacy receipts have no rent recipient
+                Address::default(),
+            )
+        } else {
+            (
+                ReceiptSeed::Nonce(data[104..112].try_into().unwrap()),
+                u64::from_le_bytes(data[112..120].try_into().unwrap()),
+                i64::from_le_bytes(data[120..128].try_into().unwrap()),
+                Address::new_from_array(data[128..160].try_into().unwrap()),
+            )
+        };
+
+        Ok(Self {
+            bump,
+            withdraw_requested,
+            _padding: [0u8; 6],
+            escrow,
+            depositor,
+            mint,
+            seed,
+            amount,
+            deposited_at,
+            rent_recipient,
+        })
     }
 }
 
@@ -81,13 +141,28 @@ impl AccountSerialize for Receipt {
     fn to_bytes_inner(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(Self::DATA_LEN);
         data.push(self.bump);
-        data.extend_from_slice(&[0u8; 7]); // padding
+        data.push(self.withdraw_requested as u8);
+        data.extend_from_slice(&[0u8; 6]); // padding
         data.extend_from_slice(self.escrow.as_ref());
         data.extend_from_slice(self.depositor.as_ref());
         data.extend_from_slice(self.mint.as_ref());
-        data.extend_from_slice(self.receipt_seed.as_ref());
+        data.extend_from_slice(self.seed_bytes());
         data.extend_from_slice(&self.amount.to_le_bytes());
         data.extend_from_slice(&self.deposited_at.to_le_bytes());
+        if !self.is_legacy() {
+            data.extend_from_slice(self.rent_recipient.as_ref());
+        }
+        data
+    }
+
+    /// Legacy receipts are rewritten with their version 1 prefix and layout
+    #[inline(always)]
+    fn to_bytes(&self) -> Vec<u8> {
+        let inner = self.to_bytes_inner();
+        let mut data = Vec::with_capacity(1 + 1 + inner.len());
+        data.push(Self::DISCRIMINATOR);
+        data.push(if self.is_legacy() { Self::LEGACY_VERSION } else { Self::VERSION });
+        data.extend_from_slice(&inner);
         data
     }
 }
@@ -97,13 +172,7 @@ impl PdaSeeds for Receipt {
 
     #[inline(always)]
     fn seeds(&self) -> Vec<&[u8]> {
-        vec![
-            Self::PREFIX,
-            self.escrow.as_ref(),
-            self.depositor.as_ref(),
-            self.mint.as_ref(),
-            self.receipt_seed.as_ref(),
-        ]
+        vec![Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), self.mint.as_ref(), self.seed_bytes()]
     }
 
     #[inline(always)]
@@ -113,7 +182,7 @@ impl PdaSeeds for Receipt {
             Seed::from(self.escrow.as_ref()),
             Seed::from(self.depositor.as_ref()),
             Seed::from(self.mint.as_ref()),
-            Seed::from(self.receipt_seed.as_ref()),
+            Seed::from(self.seed_bytes()),
             Seed::from(bump.as_slice()),
         ]
     }
@@ -128,13 +197,7 @@ impl PdaAccount for Receipt {
     #[inline(always)]
     fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
         let derived = Address::derive_address(
-            &[
-                Self::PREFIX,
-                self.escrow.as_ref(),
-                self.depositor.as_ref(),
-                self.mint.as_ref(),
-                self.receipt_seed.as_ref(),
-            ],
+            &[Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), self.mint.as_ref(), self.seed_bytes()],
             Some(self.bump),
             program_id,
         );
@@ -146,17 +209,71 @@ impl PdaAccount for Receipt {
 }
 
 impl Receipt {
+    /// Version of receipts written before the nonce and `rent_recipient` existed
+    pub const LEGACY_VERSION: u8 = 1;
+
+    /// Total size of a legacy receipt account (32-byte `receipt_seed`, no `rent_recipient`)
+    pub const LEGACY_LEN: usize = 1 + 1 + 1 + 7 + 32 + 32 + 32 + 32 + 8 + 8; // discriminator + version + bump + padding + escrow + depositor + mint + receipt_seed + amount + deposited_at
+
+    /// Nonce reported in events for legacy receipts, which have none
+    pub const LEGACY_NONCE: u64 = u64::MAX;
+
     #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         amount: u64,
         deposited_at: i64,
         escrow: Address,
         depositor: Address,
         mint: Address,
-        receipt_seed: Address,
+        nonce: u64,
         bump: u8,
+        rent_recipient: Address,
     ) -> Self {
-        Self { amount, deposited_at, escrow, depositor, mint, receipt_seed, bump, _padding: [0u8; 7] }
+        Self {
+            amount,
+            deposited_at,
+            escrow,
+            depositor,
+            mint,
+            seed: ReceiptSeed::Nonce(nonce.to_le_bytes()),
+            bump,
+            rent_recipient,
+            withdraw_requested: false,
+            _padding: [0u8; 6],
+        }
+    }
+
+    /// Returns true for version 1 receipts, which are derived from a `receipt_seed` and did not
+    /// record a rent recipient
+    #[inline(always)]
+    pub fn is_legacy(&self) -> bool {
+        matches!(self.seed, ReceiptSeed::Legacy(_))
+    }
+
+    /// Returns the receipt's nonce, or `LEGACY_NONCE` for legacy receipts
+    #[inline(always)]
+    pub fn nonce(&self) -> u64 {
+        match self.seed {
+            ReceiptSeed::Nonce(nonce) => u64::from_le_bytes(nonce),
+            ReceiptSeed::Legacy(_) => Self::LEGACY_NONCE,
+        }
+    }
+
+    /// Returns the amount counted in the mint's running totals, or `None` for legacy receipts,
+    /// which predate them
+    #[inline(always)]
+    pub fn tracked_amount(&self) -> Option<u64> {
+        (!self.is_legacy()).then_some(self.amount)
+    }
+
+    /// Bytes of the last PDA seed: the nonce in little-endian, or the legacy `receipt_seed`
+    #[inline(always)]
+    fn seed_bytes(&self) -> &[u8] {
+        match &self.seed {
+            ReceiptSeed::Nonce(nonce) => nonce,
+            ReceiptSeed::Legacy(receipt_seed) => receipt_seed.as_ref(),
+        }
     }
 
     #[inline(always)]
@@ -168,14 +285,40 @@ impl Receipt {
 
     #[inline(always)]
     pub fn validate_depositor(&self, escrow: &Address, depositor: &Address) -> Result<(), ProgramError> {
+        self.validate_escrow(escrow)?;
+
+        if self.depositor != *depositor {
+            return Err(InvalidWithdrawer.into());
+        }
+
+        Ok(())
+    }
+
+    /// Rejects receipts issued by another escrow
+    #[inline(always)]
+    pub fn validate_escrow(&self, escrow: &Address) -> Result<(), ProgramError> {
         if self.escrow != *escrow {
             return Err(InvalidReceiptEscrow.into());
         }
 
-        if self.depositor != *depositor {
-            return Err(InvalidWithdrawer.into());
+        Ok(())
+    }
+
+    /// Rejects closing the receipt to anyone but its recorded rent recipient (legacy receipts accept any)
+    #[inline(always)]
+    pub fn validate_rent_recipient(&self, rent_recipient: &Address) -> Result<(), ProgramError> {
+        if !self.is_legacy() && self.rent_recipient != *rent_recipient {
+            return Err(InvalidRentRecipient.into());
         }
+        Ok(())
+    }
 
+    /// Rejects receipts waiting out a withdraw request, which may only be claimed
+    #[inline(always)]
+    pub fn require_no_withdraw_request(&self) -> Result<(), ProgramError> {
+        if self.withdraw_requested {
+            return Err(WithdrawRequestPending.into());
+        }
         Ok(())
     }
 }
@@ -188,8 +331,8 @@ mod tests {
         let escrow = Address::new_from_array([1u8; 32]);
         let depositor = Address::new_from_array([2u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
-        Receipt::new(1000, 1234567890, escrow, depositor, mint, receipt_seed, 255)
+        let rent_recipient = Address::new_from_array([5u8; 32]);
+        Receipt::new(1000, 1234567890, escrow, depositor, mint, 4, 255, rent_recipient)
     }
 
     #[test]
@@ -197,17 +340,21 @@ mod tests {
         let escrow = Address::new_from_array([1u8; 32]);
         let depositor = Address::new_from_array([2u8; 32]);
         let mint = Address::new_from_array([3u8; 32]);
-        let receipt_seed = Address::new_from_array([4u8; 32]);
 
-        let receipt = Receipt::new(5000, 9999, escrow, depositor, mint, receipt_seed, 200);
+        let rent_recipient = Address::new_from_array([5u8; 32]);
+
+        let receipt = Receipt::new(5000, 9999, escrow, depositor, mint, 4, 200, rent_recipient);
 
         assert_eq!(receipt.amount, 5000);
         assert_eq!(receipt.deposited_at, 9999);
         assert_eq!(receipt.escrow, escrow);
         assert_eq!(receipt.depositor, depositor);
         assert_eq!(receipt.mint, mint);
-        assert_eq!(receipt.receipt_seed, receipt_seed);
+        assert_eq!(receipt.seed, ReceiptSeed::Nonce(4u64.to_le_bytes()));
+        assert_eq!(receipt.nonce(), 4);
         assert_eq!(receipt.bump, 200);
+        assert_eq!(receipt.rent_recipient, rent_recipient);
+        assert!(!receipt.is_legacy());
     }
 
     #[test]
@@ -217,13 +364,15 @@ mod tests {
 
         assert_eq!(bytes.len(), Receipt::DATA_LEN);
         assert_eq!(bytes[0], 255); // bump
-        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(bytes[1], 0); // withdraw_requested
+        assert_eq!(&bytes[2..8], &[0u8; 6]); // padding
         assert_eq!(&bytes[8..40], &[1u8; 32]); // escrow
         assert_eq!(&bytes[40..72], &[2u8; 32]); // depositor
         assert_eq!(&bytes[72..104], &[3u8; 32]); // mint
-        assert_eq!(&bytes[104..136], &[4u8; 32]); // receipt_seed
-        assert_eq!(&bytes[136..144], &1000u64.to_le_bytes()); // amount
-        assert_eq!(&bytes[144..152], &1234567890i64.to_le_bytes()); // deposited_at
+        assert_eq!(&bytes[104..112], &4u64.to_le_bytes()); // nonce
+        assert_eq!(&bytes[112..120], &1000u64.to_le_bytes()); // amount
+        assert_eq!(&bytes[120..128], &1234567890i64.to_le_bytes()); // deposited_at
+        assert_eq!(&bytes[128..160], &[5u8; 32]); // rent_recipient
     }
 
     #[test]
@@ -247,7 +396,7 @@ mod tests {
         assert_eq!(seeds[1], receipt.escrow.as_ref());
         assert_eq!(seeds[2], receipt.depositor.as_ref());
         assert_eq!(seeds[3], receipt.mint.as_ref());
-        assert_eq!(seeds[4], receipt.receipt_seed.as_ref());
+        assert_eq!(seeds[4], &4u64.to_le_bytes());
     }
 
     #[test]
@@ -271,8 +420,9 @@ mod tests {
         assert_eq!(parsed.escrow, receipt.escrow);
         assert_eq!(parsed.depositor, receipt.depositor);
         assert_eq!(parsed.mint, receipt.mint);
-        assert_eq!(parsed.receipt_seed, receipt.receipt_seed);
+        assert_eq!(parsed.seed, receipt.seed);
         assert_eq!(parsed.bump, receipt.bump);
+        assert_eq!(parsed.rent_recipient, receipt.rent_recipient);
     }
 
     #[test]
@@ -299,4 +449,122 @@ mod tests {
         let result = Receipt::parse_from_bytes(&bytes);
         assert_eq!(result, Err(ProgramError::InvalidAccountData));
     }
+
+    #[test]
+    fn test_receipt_withdraw_requested_roundtrip() {
+        let mut receipt = create_test_receipt();
+        receipt.withdraw_requested = true;
+
+        let bytes = receipt.to_bytes();
+        assert_eq!(bytes[3], 1); // withdraw_requested after discriminator, version, bump
+
+        let parsed = Receipt::parse_from_bytes(&bytes).unwrap();
+        assert!(parsed.withdraw_requested);
+        assert_eq!(parsed, receipt);
+    }
+
+    #[test]
+    fn test_receipt_require_no_withdraw_request() {
+        let mut receipt = create_test_receipt();
+        assert!(receipt.require_no_withdraw_request().is_ok());
+
+        receipt.withdraw_requested = true;
+        assert_eq!(receipt.require_no_withdraw_request(), Err(WithdrawRequestPending.into()));
+    }
+
+    /// Version 1 receipt bytes as written before the nonce and rent recipient were added
+    fn create_legacy_receipt_bytes() -> Vec<u8> {
+        let mut data = vec![Receipt::DISCRIMINATOR, Receipt::LEGACY_VERSION, 254];
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(&[1u8; 32]); // escrow
+        data.extend_from_slice(&[2u8; 32]); // depositor
+        data.extend_from_slice(&[3u8; 32]); // mint
+        data.extend_from_slice(&[4u8; 32]); // receipt_seed
+        data.extend_from_slice(&1000u64.to_le_bytes()); // amount
+        data.extend_from_slice(&1234567890i64.to_le_bytes()); // deposited_at
+        data
+    }
+
+    #[test]
+    fn test_receipt_parse_legacy() {
+        let bytes = create_legacy_receipt_bytes();
+        assert_eq!(bytes.len(), Receipt::LEGACY_LEN);
+
+        let parsed = Receipt::parse_from_bytes(&bytes).unwrap();
+        assert!(parsed.is_legacy());
+        assert_eq!(parsed.bump, 254);
+        assert_eq!(parsed.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(parsed.depositor, Address::new_from_array([2u8; 32]));
+        assert_eq!(parsed.mint, Address::new_from_array([3u8; 32]));
+        assert_eq!(parsed.seed, ReceiptSeed::Legacy(Address::new_from_array([4u8; 32])));
+        assert_eq!(parsed.nonce(), Receipt::LEGACY_NONCE);
+        assert_eq!(parsed.amount, 1000);
+        assert_eq!(parsed.deposited_at, 1234567890);
+        assert!(!parsed.withdraw_requested);
+    }
+
+    #[test]
+    fn test_receipt_legacy_roundtrip() {
+        let bytes = create_legacy_receipt_bytes();
+        let mut receipt = Receipt::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(receipt.to_bytes(), bytes, "Legacy receipts keep their version 1 layout");
+
+        receipt.withdraw_requested = true;
+        let rewritten = receipt.to_bytes();
+        assert_eq!(rewritten.len(), Receipt::LEGACY_LEN);
+        assert_eq!(rewritten[1], Receipt::LEGACY_VERSION);
+        assert_eq!(Receipt::parse_from_bytes(&rewritten).unwrap(), receipt);
+    }
+
+    #[test]
+    fn test_receipt_legacy_seeds() {
+        let receipt = Receipt::parse_from_bytes(&create_legacy_receipt_bytes()).unwrap();
+        let seeds = receipt.seeds();
+
+        assert_eq!(seeds.len(), 5);
+        assert_eq!(seeds[4], &[4u8; 32]);
+    }
+
+    #[test]
+    fn test_receipt_parse_legacy_too_short() {
+        let bytes = create_legacy_receipt_bytes();
+        let result = Receipt::parse_from_bytes(&bytes[..Receipt::LEGACY_LEN - 1]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_receipt_parse_from_bytes_truncated_current_version() {
+        let receipt = create_test_receipt();
+        let bytes = receipt.to_bytes();
+
+        let result = Receipt::parse_from_bytes(&bytes[..Receipt::LEGACY_LEN]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_receipt_is_legacy_follows_version() {
+        let mut receipt = create_test_receipt();
+        receipt.rent_recipient = Address::default();
+        let parsed = Receipt::parse_from_bytes(&receipt.to_bytes()).unwrap();
+        assert!(!parsed.is_legacy(), "A version 2 receipt is never legacy, whatever its rent recipient");
+        assert_eq!(
+            parsed.validate_rent_recipient(&Address::new_from_array([6u8; 32])),
+            Err(InvalidRentRecipient.into())
+        );
+
+        let legacy = Receipt::parse_from_bytes(&create_legacy_receipt_bytes()).unwrap();
+        assert!(legacy.is_legacy());
+    }
+
+    #[test]
+    fn test_receipt_validate_rent_recipient() {
+        let mut receipt = create_test_receipt();
+        let other = Address::new_from_array([6u8; 32]);
+
+        assert!(receipt.validate_rent_recipient(&Address::new_from_array([5u8; 32])).is_ok());
+        assert_eq!(receipt.validate_rent_recipient(&other), Err(InvalidRentRecipient.into()));
+
+        receipt.seed = ReceiptSeed::Legacy(Address::new_from_array([4u8; 32]));
+        assert!(receipt.validate_rent_recipient(&other).is_ok());
+    }
 }
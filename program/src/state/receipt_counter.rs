@@ -0,0 +1,247 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaSeeds, Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator};
+
+/// ReceiptCounter account state
+///
+/// Holds the nonce of the next receipt for a depositor and mint in an escrow. `Deposit`
+/// derives the receipt PDA from `next_nonce` and then increments it, so receipt addresses
+/// are predictable without a signing seed. Created on the depositor's first deposit of the mint.
+///
+/// # PDA Seeds
+/// `[b"receipt_counter", escrow.as_ref(), depositor.as_ref(), mint.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 7))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "receipt_counter"))]
+#[codama(seed(name = "escrow", type = public_key))]
+#[codama(seed(name = "depositor", type = public_key))]
+#[codama(seed(name = "mint", type = public_key))]
+#[repr(C)]
+pub struct ReceiptCounter {
+    pub bump: u8,
+    _padding: [u8; 7],
+
+    pub next_nonce: u64,
+}
+
+assert_no_padding!(ReceiptCounter, 1 + 7 + 8);
+
+impl Discriminator for ReceiptCounter {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::ReceiptCounterDiscriminator as u8;
+}
+
+impl Versioned for ReceiptCounter {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for ReceiptCounter {
+    const DATA_LEN: usize = 1 + 7 + 8; // bump + padding + next_nonce
+}
+
+impl AccountParse for ReceiptCounter {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..8]
+        let next_nonce = u64::from_le_bytes(data[8..16].try_into().unwrap());
+
+        Ok(Self { bump, _padding: [0u8; 7], next_nonce })
+    }
+}
+
+impl AccountSerialize for ReceiptCounter {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(&self.next_nonce.to_le_bytes());
+        data
+    }
+}
+
+impl ReceiptCounter {
+    #[inline(always)]
+    pub fn new(bump: u8) -> Self {
+        Self { bump, _padding: [0u8; 7], next_nonce: 0 }
+    }
+
+    #[inline(always)]
+    pub fn from_account(
+        data: &[u8],
+        account: &AccountView,
+        program_id: &Address,
+        escrow: &Address,
+        depositor: &Address,
+        mint: &Address,
+    ) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        let derived = Address::derive_address(
+            &[ReceiptCounterPda::PREFIX, escrow.as_ref(), depositor.as_ref(), mint.as_ref()],
+            Some(state.bump),
+            program_id,
+        );
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(state)
+    }
+
+    /// Returns the nonce for the next receipt and advances the counter
+    #[inline(always)]
+    pub fn take_nonce(&mut self) -> Result<u64, ProgramError> {
+        let nonce = self.next_nonce;
+        self.next_nonce = nonce.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(nonce)
+    }
+}
+
+/// PDA context for ReceiptCounter - holds escrow, depositor and mint addresses for seed derivation
+///
+/// The ReceiptCounter account does not store its seeds, so this helper derives and
+/// validates the PDA from the deposit's accounts.
+pub struct ReceiptCounterPda<'a> {
+    pub escrow: &'a Address,
+    pub depositor: &'a Address,
+    pub mint: &'a Address,
+}
+
+impl<'a> ReceiptCounterPda<'a> {
+    #[inline(always)]
+    pub fn new(escrow: &'a Address, depositor: &'a Address, mint: &'a Address) -> Self {
+        Self { escrow, depositor, mint }
+    }
+}
+
+impl PdaSeeds for ReceiptCounterPda<'_> {
+    const PREFIX: &'static [u8] = b"receipt_counter";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.escrow.as_ref(), self.depositor.as_ref(), self.mint.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![
+            Seed::from(Self::PREFIX),
+            Seed::from(self.escrow.as_ref()),
+            Seed::from(self.depositor.as_ref()),
+            Seed::from(self.mint.as_ref()),
+            Seed::from(bump.as_slice()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_addresses() -> (Address, Address, Address) {
+        (Address::new_from_array([1u8; 32]), Address::new_from_array([2u8; 32]), Address::new_from_array([3u8; 32]))
+    }
+
+    #[test]
+    fn test_receipt_counter_new() {
+        let counter = ReceiptCounter::new(254);
+        assert_eq!(counter.bump, 254);
+        assert_eq!(counter.next_nonce, 0);
+    }
+
+    #[test]
+    fn test_receipt_counter_to_bytes_inner() {
+        let mut counter = ReceiptCounter::new(254);
+        counter.next_nonce = 7;
+        let bytes = counter.to_bytes_inner();
+
+        assert_eq!(bytes.len(), ReceiptCounter::DATA_LEN);
+        assert_eq!(bytes[0], 254); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(&bytes[8..16], &7u64.to_le_bytes()); // next_nonce
+    }
+
+    #[test]
+    fn test_receipt_counter_to_bytes() {
+        let counter = ReceiptCounter::new(254);
+        let bytes = counter.to_bytes();
+
+        assert_eq!(bytes.len(), ReceiptCounter::LEN);
+        assert_eq!(bytes[0], ReceiptCounter::DISCRIMINATOR);
+        assert_eq!(bytes[1], ReceiptCounter::VERSION); // version auto-prepended
+        assert_eq!(bytes[2], 254); // bump
+    }
+
+    #[test]
+    fn test_receipt_counter_parse_from_bytes() {
+        let mut counter = ReceiptCounter::new(254);
+        counter.next_nonce = 42;
+        let bytes = counter.to_bytes();
+
+        let parsed = ReceiptCounter::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, counter);
+    }
+
+    #[test]
+    fn test_receipt_counter_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; ReceiptCounter::LEN];
+        bytes[0] = 99; // wrong discriminator
+        let result = ReceiptCounter::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_receipt_counter_take_nonce() {
+        let mut counter = ReceiptCounter::new(254);
+
+        assert_eq!(counter.take_nonce(), Ok(0));
+        assert_eq!(counter.take_nonce(), Ok(1));
+        assert_eq!(counter.next_nonce, 2);
+    }
+
+    #[test]
+    fn test_receipt_counter_take_nonce_overflow() {
+        let mut counter = ReceiptCounter::new(254);
+        counter.next_nonce = u64::MAX;
+
+        assert_eq!(counter.take_nonce(), Err(ProgramError::ArithmeticOverflow));
+        assert_eq!(counter.next_nonce, u64::MAX);
+    }
+
+    #[test]
+    fn test_receipt_counter_pda_seeds() {
+        let (escrow, depositor, mint) = create_test_addresses();
+        let pda = ReceiptCounterPda::new(&escrow, &depositor, &mint);
+        let seeds = pda.seeds();
+
+        assert_eq!(seeds.len(), 4);
+        assert_eq!(seeds[0], ReceiptCounterPda::PREFIX);
+        assert_eq!(seeds[1], escrow.as_ref());
+        assert_eq!(seeds[2], depositor.as_ref());
+        assert_eq!(seeds[3], mint.as_ref());
+    }
+
+    #[test]
+    fn test_receipt_counter_pda_seeds_with_bump() {
+        let (escrow, depositor, mint) = create_test_addresses();
+        let pda = ReceiptCounterPda::new(&escrow, &depositor, &mint);
+        let bump = [254u8];
+        let seeds = pda.seeds_with_bump(&bump);
+
+        assert_eq!(seeds.len(), 5);
+    }
+}
@@ -0,0 +1,303 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use codama::CodamaAccount;
+use pinocchio::{account::AccountView, cpi::Seed, error::ProgramError, Address};
+
+use crate::errors::EscrowProgramError::{InvalidWithdrawDelegate, InvalidWithdrawer, WithdrawDelegateExpired};
+use crate::traits::{
+    AccountParse, AccountSerialize, AccountSize, Discriminator, EscrowAccountDiscriminators, PdaAccount, PdaSeeds,
+    Versioned,
+};
+use crate::{assert_no_padding, require_account_len, validate_discriminator};
+
+/// WithdrawDelegate account state
+///
+/// Records a key the depositor approved to withdraw a receipt on their behalf. Delegated
+/// withdrawals always pay out to the depositor. `expires_at` of `0` means the approval
+/// never expires. Closed together with the receipt on a delegated withdraw, or by the
+/// depositor with `RevokeWithdrawDelegate`.
+///
+/// # PDA Seeds
+/// `[b"withdraw_delegate", receipt.as_ref()]`
+#[derive(Clone, Debug, PartialEq, CodamaAccount)]
+#[codama(field("discriminator", number(u8), default_value = 9))]
+#[codama(discriminator(field = "discriminator"))]
+#[codama(seed(type = string(utf8), value = "withdraw_delegate"))]
+#[codama(seed(name = "receipt", type = public_key))]
+#[repr(C)]
+pub struct WithdrawDelegate {
+    pub bump: u8,
+    _padding: [u8; 7],
+
+    pub escrow: Address,
+    pub receipt: Address,
+    pub depositor: Address,
+    pub delegate: Address,
+
+    pub expires_at: i64,
+}
+
+assert_no_padding!(WithdrawDelegate, 1 + 7 + 32 + 32 + 32 + 32 + 8);
+
+impl Discriminator for WithdrawDelegate {
+    const DISCRIMINATOR: u8 = EscrowAccountDiscriminators::WithdrawDelegateDiscriminator as u8;
+}
+
+impl Versioned for WithdrawDelegate {
+    const VERSION: u8 = 1;
+}
+
+impl AccountSize for WithdrawDelegate {
+    const DATA_LEN: usize = 1 + 7 + 32 + 32 + 32 + 32 + 8; // bump + padding + escrow + receipt + depositor + delegate + expires_at
+}
+
+impl AccountParse for WithdrawDelegate {
+    fn parse_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        require_account_len!(data, Self::LEN);
+        validate_discriminator!(data, Self::DISCRIMINATOR);
+        if data[1] != Self::VERSION {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Skip discriminator (byte 0) and version (byte 1)
+        let data = &data[2..];
+
+        let bump = data[0];
+        // Skip padding bytes [1..8]
+        let escrow = Address::new_from_array(data[8..40].try_into().unwrap());
+        let receipt = Address::new_from_array(data[40..72].try_into().unwrap());
+        let depositor = Address::new_from_array(data[72..104].try_into().unwrap());
+        let delegate = Address::new_from_array(data[104..136].try_into().unwrap());
+        let expires_at = i64::from_le_bytes(data[136..144].try_into().unwrap());
+
+        Ok(Self::new(escrow, receipt, depositor, delegate, expires_at, bump))
+    }
+}
+
+impl AccountSerialize for WithdrawDelegate {
+    #[inline(always)]
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::DATA_LEN);
+        data.push(self.bump);
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(self.escrow.as_ref());
+        data.extend_from_slice(self.receipt.as_ref());
+        data.extend_from_slice(self.depositor.as_ref());
+        data.extend_from_slice(self.delegate.as_ref());
+        data.extend_from_slice(&self.expires_at.to_le_bytes());
+        data
+    }
+}
+
+impl PdaSeeds for WithdrawDelegate {
+    const PREFIX: &'static [u8] = b"withdraw_delegate";
+
+    #[inline(always)]
+    fn seeds(&self) -> Vec<&[u8]> {
+        vec![Self::PREFIX, self.receipt.as_ref()]
+    }
+
+    #[inline(always)]
+    fn seeds_with_bump<'a>(&'a self, bump: &'a [u8; 1]) -> Vec<Seed<'a>> {
+        vec![Seed::from(Self::PREFIX), Seed::from(self.receipt.as_ref()), Seed::from(bump.as_slice())]
+    }
+}
+
+impl PdaAccount for WithdrawDelegate {
+    #[inline(always)]
+    fn bump(&self) -> u8 {
+        self.bump
+    }
+
+    #[inline(always)]
+    fn validate_self(&self, account: &AccountView, program_id: &Address) -> Result<(), ProgramError> {
+        let derived = Address::derive_address(&[Self::PREFIX, self.receipt.as_ref()], Some(self.bump), program_id);
+        if account.address() != &derived {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        Ok(())
+    }
+}
+
+impl WithdrawDelegate {
+    /// `expires_at` value for approvals that never expire
+    pub const NO_EXPIRY: i64 = 0;
+
+    #[inline(always)]
+    pub fn new(
+        escrow: Address,
+        receipt: Address,
+        depositor: Address,
+        delegate: Address,
+        expires_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self { bump, _padding: [0u8; 7], escrow, receipt, depositor, delegate, expires_at }
+    }
+
+    #[inline(always)]
+    pub fn from_account(data: &[u8], account: &AccountView, program_id: &Address) -> Result<Self, ProgramError> {
+        let state = Self::parse_from_bytes(data)?;
+        state.validate_self(account, program_id)?;
+        Ok(state)
+    }
+
+    /// Returns true once `now` has reached a set expiry
+    #[inline(always)]
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != Self::NO_EXPIRY && now >= self.expires_at
+    }
+
+    /// Rejects approvals for another receipt, another signer, or that have expired
+    #[inline(always)]
+    pub fn validate(&self, receipt: &Address, delegate: &Address, now: i64) -> Result<(), ProgramError> {
+        if self.receipt != *receipt {
+            return Err(InvalidWithdrawDelegate.into());
+        }
+
+        if self.delegate != *delegate {
+            return Err(InvalidWithdrawer.into());
+        }
+
+        if self.is_expired(now) {
+            return Err(WithdrawDelegateExpired.into());
+        }
+
+        Ok(())
+    }
+
+    /// Rejects revocations by anyone but the depositor of this escrow's receipt
+    #[inline(always)]
+    pub fn validate_depositor(&self, escrow: &Address, depositor: &Address) -> Result<(), ProgramError> {
+        if self.escrow != *escrow {
+            return Err(InvalidWithdrawDelegate.into());
+        }
+
+        if self.depositor != *depositor {
+            return Err(InvalidWithdrawer.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_delegate() -> WithdrawDelegate {
+        let escrow = Address::new_from_array([1u8; 32]);
+        let receipt = Address::new_from_array([2u8; 32]);
+        let depositor = Address::new_from_array([3u8; 32]);
+        let delegate = Address::new_from_array([4u8; 32]);
+        WithdrawDelegate::new(escrow, receipt, depositor, delegate, 1000, 255)
+    }
+
+    #[test]
+    fn test_withdraw_delegate_new() {
+        let delegate = create_test_delegate();
+
+        assert_eq!(delegate.escrow, Address::new_from_array([1u8; 32]));
+        assert_eq!(delegate.receipt, Address::new_from_array([2u8; 32]));
+        assert_eq!(delegate.depositor, Address::new_from_array([3u8; 32]));
+        assert_eq!(delegate.delegate, Address::new_from_array([4u8; 32]));
+        assert_eq!(delegate.expires_at, 1000);
+        assert_eq!(delegate.bump, 255);
+    }
+
+    #[test]
+    fn test_withdraw_delegate_to_bytes_inner() {
+        let delegate = create_test_delegate();
+        let bytes = delegate.to_bytes_inner();
+
+        assert_eq!(bytes.len(), WithdrawDelegate::DATA_LEN);
+        assert_eq!(bytes[0], 255); // bump
+        assert_eq!(&bytes[1..8], &[0u8; 7]); // padding
+        assert_eq!(&bytes[8..40], &[1u8; 32]); // escrow
+        assert_eq!(&bytes[40..72], &[2u8; 32]); // receipt
+        assert_eq!(&bytes[72..104], &[3u8; 32]); // depositor
+        assert_eq!(&bytes[104..136], &[4u8; 32]); // delegate
+        assert_eq!(&bytes[136..144], &1000i64.to_le_bytes()); // expires_at
+    }
+
+    #[test]
+    fn test_withdraw_delegate_to_bytes() {
+        let delegate = create_test_delegate();
+        let bytes = delegate.to_bytes();
+
+        assert_eq!(bytes.len(), WithdrawDelegate::LEN);
+        assert_eq!(bytes[0], WithdrawDelegate::DISCRIMINATOR);
+        assert_eq!(bytes[1], WithdrawDelegate::VERSION); // version auto-prepended
+        assert_eq!(bytes[2], 255); // bump
+    }
+
+    #[test]
+    fn test_withdraw_delegate_seeds() {
+        let delegate = create_test_delegate();
+        let seeds = delegate.seeds();
+
+        assert_eq!(seeds.len(), 2);
+        assert_eq!(seeds[0], WithdrawDelegate::PREFIX);
+        assert_eq!(seeds[1], delegate.receipt.as_ref());
+    }
+
+    #[test]
+    fn test_withdraw_delegate_parse_from_bytes() {
+        let delegate = create_test_delegate();
+        let bytes = delegate.to_bytes();
+
+        let parsed = WithdrawDelegate::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(parsed, delegate);
+    }
+
+    #[test]
+    fn test_withdraw_delegate_parse_from_bytes_wrong_discriminator() {
+        let mut bytes = [0u8; WithdrawDelegate::LEN];
+        bytes[0] = 99; // wrong discriminator
+        let result = WithdrawDelegate::parse_from_bytes(&bytes);
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_withdraw_delegate_validate() {
+        let delegate = create_test_delegate();
+        let receipt = Address::new_from_array([2u8; 32]);
+        let signer = Address::new_from_array([4u8; 32]);
+
+        assert!(delegate.validate(&receipt, &signer, 999).is_ok());
+        assert_eq!(
+            delegate.validate(&Address::new_from_array([9u8; 32]), &signer, 999),
+            Err(InvalidWithdrawDelegate.into())
+        );
+        assert_eq!(
+            delegate.validate(&receipt, &Address::new_from_array([9u8; 32]), 999),
+            Err(InvalidWithdrawer.into())
+        );
+        assert_eq!(delegate.validate(&receipt, &signer, 1000), Err(WithdrawDelegateExpired.into()));
+    }
+
+    #[test]
+    fn test_withdraw_delegate_no_expiry() {
+        let mut delegate = create_test_delegate();
+        delegate.expires_at = WithdrawDelegate::NO_EXPIRY;
+
+        assert!(!delegate.is_expired(i64::MAX));
+    }
+
+    #[test]
+    fn test_withdraw_delegate_validate_depositor() {
+        let delegate = create_test_delegate();
+        let escrow = Address::new_from_array([1u8; 32]);
+        let depositor = Address::new_from_array([3u8; 32]);
+
+        assert!(delegate.validate_depositor(&escrow, &depositor).is_ok());
+        assert_eq!(
+            delegate.validate_depositor(&Address::new_from_array([9u8; 32]), &depositor),
+            Err(InvalidWithdrawDelegate.into())
+        );
+        assert_eq!(
+            delegate.validate_depositor(&escrow, &Address::new_from_array([9u8; 32])),
+            Err(InvalidWithdrawer.into())
+        );
+    }
+}
@@ -84,6 +84,13 @@ pub enum EscrowAccountDiscriminators {
     EscrowExtensionsDiscriminator = 2,
     ReceiptDiscriminator = 3,
     AllowedMintDiscriminator = 4,
+    HookBypassRequestDiscriminator = 5,
+    DepositorAllowanceDiscriminator = 6,
+    ReceiptCounterDiscriminator = 7,
+    PendingChangeDiscriminator = 8,
+    WithdrawDelegateDiscriminator = 9,
+    BasketReceiptDiscriminator = 10,
+    WithdrawRequestDiscriminator = 11,
 }
 
 /// Manual account deserialization (non-zero-copy)
@@ -240,5 +247,6 @@ mod tests {
         assert_eq!(EscrowAccountDiscriminators::EscrowExtensionsDiscriminator as u8, 2);
         assert_eq!(EscrowAccountDiscriminators::ReceiptDiscriminator as u8, 3);
         assert_eq!(EscrowAccountDiscriminators::AllowedMintDiscriminator as u8, 4);
+        assert_eq!(EscrowAccountDiscriminators::HookBypassRequestDiscriminator as u8, 5);
     }
 }
@@ -21,6 +21,32 @@ pub enum EventDiscriminators {
     ExtensionRemoved = 10,
     TokenExtensionUnblocked = 11,
     SetImmutable = 12,
+    HookBypassSet = 13,
+    HookBypassRequested = 14,
+    MintLimitsSet = 15,
+    DepositorAllowlistSet = 16,
+    SolvencyVerified = 17,
+    SurplusSwept = 18,
+    RolesSet = 19,
+    GovernanceDelaySet = 20,
+    ChangeQueued = 21,
+    ChangeExecuted = 22,
+    ChangeCancelled = 23,
+    ExtensionLocked = 24,
+    MintListLocked = 25,
+    MetadataSet = 26,
+    MintOverrideSet = 27,
+    WithdrawDelegateApproved = 28,
+    WithdrawDelegateRevoked = 29,
+    AutoReleaseSet = 30,
+    ReceiptReleased = 31,
+    WithdrawMany = 32,
+    BasketDeposit = 33,
+    BasketWithdraw = 34,
+    RefundAuthoritySet = 35,
+    ForceRefund = 36,
+    CooldownSet = 37,
+    WithdrawRequested = 38,
 }
 
 /// Event discriminator with Anchor-compatible prefix
@@ -16,6 +16,35 @@ pub enum EscrowInstructionDiscriminators {
     RemoveExtension = 10,
     UnblockTokenExtension = 11,
     SetImmutable = 12,
+    SetHookBypass = 13,
+    RequestHookBypass = 14,
+    SetMintLimits = 15,
+    SetDepositorAllowlist = 16,
+    VerifySolvency = 17,
+    SweepSurplus = 18,
+    CreateEscrowWithConfig = 19,
+    CreateEscrowWithSeed = 20,
+    SetRoles = 21,
+    SetGovernanceDelay = 22,
+    QueueChange = 23,
+    ExecuteQueuedChange = 24,
+    CancelQueuedChange = 25,
+    LockExtension = 26,
+    LockMintList = 27,
+    SetMetadata = 28,
+    SetMintOverride = 29,
+    ApproveWithdrawDelegate = 30,
+    RevokeWithdrawDelegate = 31,
+    SetAutoRelease = 32,
+    ReleaseMatured = 33,
+    WithdrawMany = 34,
+    DepositBasket = 35,
+    WithdrawBasket = 36,
+    SetRefundAuthority = 37,
+    ForceRefund = 38,
+    SetCooldown = 39,
+    RequestWithdraw = 40,
+    ClaimWithdraw = 41,
     EmitEvent = 228,
 }
 
@@ -37,6 +66,35 @@ impl TryFrom<u8> for EscrowInstructionDiscriminators {
             10 => Ok(Self::RemoveExtension),
             11 => Ok(Self::UnblockTokenExtension),
             12 => Ok(Self::SetImmutable),
+            13 => Ok(Self::SetHookBypass),
+            14 => Ok(Self::RequestHookBypass),
+            15 => Ok(Self::SetMintLimits),
+            16 => Ok(Self::SetDepositorAllowlist),
+            17 => Ok(Self::VerifySolvency),
+            18 => Ok(Self::SweepSurplus),
+            19 => Ok(Self::CreateEscrowWithConfig),
+            20 => Ok(Self::CreateEscrowWithSeed),
+            21 => Ok(Self::SetRoles),
+            22 => Ok(Self::SetGovernanceDelay),
+            23 => Ok(Self::QueueChange),
+            24 => Ok(Self::ExecuteQueuedChange),
+            25 => Ok(Self::CancelQueuedChange),
+            26 => Ok(Self::LockExtension),
+            27 => Ok(Self::LockMintList),
+            28 => Ok(Self::SetMetadata),
+            29 => Ok(Self::SetMintOverride),
+            30 => Ok(Self::ApproveWithdrawDelegate),
+            31 => Ok(Self::RevokeWithdrawDelegate),
+            32 => Ok(Self::SetAutoRelease),
+            33 => Ok(Self::ReleaseMatured),
+            34 => Ok(Self::WithdrawMany),
+            35 => Ok(Self::DepositBasket),
+            36 => Ok(Self::WithdrawBasket),
+            37 => Ok(Self::SetRefundAuthority),
+            38 => Ok(Self::ForceRefund),
+            39 => Ok(Self::SetCooldown),
+            40 => Ok(Self::RequestWithdraw),
+            41 => Ok(Self::ClaimWithdraw),
             228 => Ok(Self::EmitEvent),
             _ => Err(ProgramError::InvalidInstructionData),
         }
@@ -174,8 +232,211 @@ mod tests {
     }
 
     #[test]
-    fn test_discriminator_try_from_invalid() {
+    fn test_discriminator_try_from_set_hook_bypass() {
         let result = EscrowInstructionDiscriminators::try_from(13u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetHookBypass));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_request_hook_bypass() {
+        let result = EscrowInstructionDiscriminators::try_from(14u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::RequestHookBypass));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_mint_limits() {
+        let result = EscrowInstructionDiscriminators::try_from(15u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetMintLimits));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_depositor_allowlist() {
+        let result = EscrowInstructionDiscriminators::try_from(16u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetDepositorAllowlist));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_verify_solvency() {
+        let result = EscrowInstructionDiscriminators::try_from(17u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::VerifySolvency));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_sweep_surplus() {
+        let result = EscrowInstructionDiscriminators::try_from(18u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SweepSurplus));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_create_escrow_with_config() {
+        let result = EscrowInstructionDiscriminators::try_from(19u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::CreateEscrowWithConfig));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_create_escrow_with_seed() {
+        let result = EscrowInstructionDiscriminators::try_from(20u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::CreateEscrowWithSeed));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_roles() {
+        let result = EscrowInstructionDiscriminators::try_from(21u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetRoles));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_governance_delay() {
+        let result = EscrowInstructionDiscriminators::try_from(22u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetGovernanceDelay));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_queue_change() {
+        let result = EscrowInstructionDiscriminators::try_from(23u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::QueueChange));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_execute_queued_change() {
+        let result = EscrowInstructionDiscriminators::try_from(24u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::ExecuteQueuedChange));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_cancel_queued_change() {
+        let result = EscrowInstructionDiscriminators::try_from(25u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::CancelQueuedChange));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_lock_extension() {
+        let result = EscrowInstructionDiscriminators::try_from(26u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::LockExtension));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_lock_mint_list() {
+        let result = EscrowInstructionDiscriminators::try_from(27u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::LockMintList));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_metadata() {
+        let result = EscrowInstructionDiscriminators::try_from(28u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetMetadata));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_mint_override() {
+        let result = EscrowInstructionDiscriminators::try_from(29u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetMintOverride));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_approve_withdraw_delegate() {
+        let result = EscrowInstructionDiscriminators::try_from(30u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::ApproveWithdrawDelegate));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_revoke_withdraw_delegate() {
+        let result = EscrowInstructionDiscriminators::try_from(31u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::RevokeWithdrawDelegate));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_auto_release() {
+        let result = EscrowInstructionDiscriminators::try_from(32u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetAutoRelease));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_release_matured() {
+        let result = EscrowInstructionDiscriminators::try_from(33u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::ReleaseMatured));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_withdraw_many() {
+        let result = EscrowInstructionDiscriminators::try_from(34u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::WithdrawMany));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_deposit_basket() {
+        let result = EscrowInstructionDiscriminators::try_from(35u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::DepositBasket));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_withdraw_basket() {
+        let result = EscrowInstructionDiscriminators::try_from(36u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::WithdrawBasket));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_refund_authority() {
+        let result = EscrowInstructionDiscriminators::try_from(37u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetRefundAuthority));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_force_refund() {
+        let result = EscrowInstructionDiscriminators::try_from(38u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::ForceRefund));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_set_cooldown() {
+        let result = EscrowInstructionDiscriminators::try_from(39u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::SetCooldown));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_request_withdraw() {
+        let result = EscrowInstructionDiscriminators::try_from(40u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::RequestWithdraw));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_claim_withdraw() {
+        let result = EscrowInstructionDiscriminators::try_from(41u8);
+        assert!(result.is_ok());
+        assert!(matches!(result.unwrap(), EscrowInstructionDiscriminators::ClaimWithdraw));
+    }
+
+    #[test]
+    fn test_discriminator_try_from_invalid() {
+        let result = EscrowInstructionDiscriminators::try_from(42u8);
         assert!(matches!(result, Err(ProgramError::InvalidInstructionData)));
 
         let result = EscrowInstructionDiscriminators::try_from(255u8);
@@ -3,8 +3,8 @@ use pinocchio::error::ProgramError;
 
 use crate::{
     state::{
-        ArbiterData, BlockTokenExtensionsData, EscrowExtensionsHeader, ExtensionType, HookData, TimelockData,
-        TLV_HEADER_SIZE,
+        ArbiterData, BlockTokenExtensionsData, EscrowExtensionsHeader, ExtensionType, HookBypassData, HookData,
+        TimelockData, TLV_HEADER_SIZE,
     },
     traits::ExtensionData,
 };
@@ -21,6 +21,11 @@ impl<'a> TlvReader<'a> {
         Self { data: account_data, offset: EscrowExtensionsHeader::LEN }
     }
 
+    /// Create a reader for a TLV area starting at `offset`
+    pub fn with_offset(account_data: &'a [u8], offset: usize) -> Self {
+        Self { data: account_data, offset }
+    }
+
     /// Find and read a specific extension type
     pub fn find_extension(&self, ext_type: ExtensionType) -> Result<Option<&'a [u8]>, ProgramError> {
         let mut offset = self.offset;
@@ -67,6 +72,14 @@ impl<'a> TlvReader<'a> {
     pub fn read_arbiter(&self) -> Option<ArbiterData> {
         self.find_extension(ExtensionType::Arbiter).ok().flatten().and_then(|data| ArbiterData::from_bytes(data).ok())
     }
+
+    /// Read hook bypass extension if present
+    pub fn read_hook_bypass(&self) -> Option<HookBypassData> {
+        self.find_extension(ExtensionType::HookBypass)
+            .ok()
+            .flatten()
+            .and_then(|data| HookBypassData::from_bytes(data).ok())
+    }
 }
 
 /// Helper to write TLV extensions to account data
@@ -106,6 +119,11 @@ impl TlvWriter {
         self.write_extension(ExtensionType::Arbiter, &arbiter.to_bytes());
     }
 
+    /// Write hook bypass extension
+    pub fn write_hook_bypass(&mut self, hook_bypass: &HookBypassData) {
+        self.write_extension(ExtensionType::HookBypass, &hook_bypass.to_bytes());
+    }
+
     /// Get the total TLV data
     pub fn into_bytes(self) -> Vec<u8> {
         self.data
@@ -133,6 +151,7 @@ mod tests {
     use super::*;
     use crate::state::EscrowExtensionsHeader;
     use crate::traits::AccountSerialize;
+    use alloc::vec;
     use pinocchio::Address;
 
     #[test]
@@ -174,7 +193,7 @@ mod tests {
     fn test_tlv_writer_hook() {
         let mut writer = TlvWriter::new();
         let hook_program = Address::new_from_array([42u8; 32]);
-        let hook = HookData::new(hook_program);
+        let hook = HookData::new(hook_program, 0);
         writer.write_hook(&hook);
 
         let bytes = writer.into_bytes();
@@ -195,7 +214,7 @@ mod tests {
 
         let mut writer = TlvWriter::new();
         let hook_program = Address::new_from_array([99u8; 32]);
-        let hook = HookData::new(hook_program);
+        let hook = HookData::new(hook_program, 0);
         writer.write_hook(&hook);
 
         let mut account_data = header.to_bytes();
@@ -217,7 +236,7 @@ mod tests {
         writer.write_timelock(&timelock);
 
         let hook_program = Address::new_from_array([77u8; 32]);
-        let hook = HookData::new(hook_program);
+        let hook = HookData::new(hook_program, 0);
         writer.write_hook(&hook);
 
         let mut account_data = header.to_bytes();
@@ -233,6 +252,36 @@ mod tests {
         assert_eq!(read_hook.hook_program, hook_program);
     }
 
+    #[test]
+    fn test_tlv_reader_find_hook_bypass() {
+        let header = EscrowExtensionsHeader::new(255, 1);
+
+        let mut writer = TlvWriter::new();
+        let hook_bypass = HookBypassData::new(86_400);
+        writer.write_hook_bypass(&hook_bypass);
+
+        let mut account_data = header.to_bytes();
+        account_data.extend_from_slice(&writer.into_bytes());
+
+        // Read it back
+        let reader = TlvReader::new(&account_data);
+        let read_hook_bypass = reader.read_hook_bypass().unwrap();
+        assert_eq!(read_hook_bypass.grace_period, 86_400);
+    }
+
+    #[test]
+    fn test_tlv_reader_with_offset() {
+        let mut writer = TlvWriter::new();
+        writer.write_timelock(&TimelockData::new(60));
+
+        let mut account_data = vec![0u8; 10];
+        account_data.extend_from_slice(&writer.into_bytes());
+
+        let reader = TlvReader::with_offset(&account_data, 10);
+        assert_eq!(reader.read_timelock().unwrap().lock_duration, 60);
+        assert!(reader.read_hook().is_none());
+    }
+
     #[test]
     fn test_tlv_writer_is_empty() {
         let writer = TlvWriter::new();
@@ -1,7 +1,7 @@
 use pinocchio::ProgramResult;
 use pinocchio::{account::AccountView, address::Address, error::ProgramError};
 use pinocchio_associated_token_account::ID as ASSOCIATED_TOKEN_ACCOUNT_PROGRAM_ID;
-use pinocchio_token_2022::state::Mint;
+use pinocchio_token_2022::state::{Mint, TokenAccount};
 
 use crate::utils::verify_token_program_account;
 
@@ -85,3 +85,25 @@ pub fn get_mint_decimals(mint: &AccountView) -> Result<u8, ProgramError> {
     let mint_state = unsafe { Mint::from_bytes_unchecked(&mint_data) };
     Ok(mint_state.decimals())
 }
+
+/// Get the token balance held by a token account.
+///
+/// Works with both SPL Token and Token-2022 accounts since they share the same base layout.
+///
+/// # Arguments
+/// * `token_account` - The token account (must be owned by Token or Token-2022 program)
+///
+/// # Returns
+/// * `Result<u64, ProgramError>` - The token account amount
+#[inline(always)]
+pub fn get_token_account_amount(token_account: &AccountView) -> Result<u64, ProgramError> {
+    verify_token_program_account(token_account)?;
+
+    let token_account_data = token_account.try_borrow()?;
+    if token_account.data_len() < TokenAccount::BASE_LEN {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let token_account_state = unsafe { TokenAccount::from_bytes_unchecked(&token_account_data) };
+    Ok(token_account_state.amount())
+}
@@ -0,0 +1,68 @@
+use escrow_program_client::instructions::ApproveWithdrawDelegateBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::{
+    fixtures::WithdrawSetup,
+    utils::{find_withdraw_delegate_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct ApproveWithdrawDelegateFixture;
+
+impl ApproveWithdrawDelegateFixture {
+    pub fn build_with_setup(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        delegate: Pubkey,
+        expires_at: i64,
+    ) -> TestInstruction {
+        let (withdraw_delegate_pda, bump) = find_withdraw_delegate_pda(&setup.receipt_pda);
+
+        let instruction = ApproveWithdrawDelegateBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .receipt(setup.receipt_pda)
+            .withdraw_delegate(withdraw_delegate_pda)
+            .bump(bump)
+            .delegate(delegate)
+            .expires_at(expires_at)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for ApproveWithdrawDelegateFixture {
+    const INSTRUCTION_NAME: &'static str = "ApproveWithdrawDelegate";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::new(ctx);
+        Self::build_with_setup(ctx, &setup, Pubkey::new_unique(), 0)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: depositor (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 4: withdraw_delegate (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[4]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 + 32 + 8 // discriminator + bump + delegate + expires_at
+    }
+}
@@ -6,7 +6,7 @@ use solana_sdk::{
 
 use crate::fixtures::allow_mint::AllowMintSetup;
 use crate::utils::traits::{InstructionTestFixture, TestInstruction};
-use crate::utils::TestContext;
+use crate::utils::{find_extensions_pda, TestContext};
 
 pub struct BlockMintSetup {
     pub escrow_pda: Pubkey,
@@ -49,6 +49,7 @@ impl BlockMintSetup {
             .admin(self.admin.pubkey())
             .rent_recipient(rent_recipient)
             .escrow(self.escrow_pda)
+            .extensions(find_extensions_pda(&self.escrow_pda).0)
             .mint(self.mint_pubkey)
             .allowed_mint(self.allowed_mint_pda)
             .token_program(self.token_program)
@@ -0,0 +1,89 @@
+use escrow_program_client::instructions::CancelQueuedChangeBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::utils::extensions_utils::EXTENSION_TYPE_TIMELOCK;
+use crate::{
+    fixtures::{
+        CreateEscrowFixture, QueueChangeFixture, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::{find_escrow_pda, find_extensions_pda, find_pending_change_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct CancelQueuedChangeFixture;
+
+impl CancelQueuedChangeFixture {
+    pub fn build_with_escrow(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        change_id: u64,
+    ) -> TestInstruction {
+        let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+        let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, change_id);
+
+        let instruction = CancelQueuedChangeBuilder::new()
+            .admin(admin.pubkey())
+            .rent_recipient(ctx.payer.pubkey())
+            .escrow(escrow_pda)
+            .extensions(extensions_pda)
+            .pending_change(pending_change_pda)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for CancelQueuedChangeFixture {
+    const INSTRUCTION_NAME: &'static str = "CancelQueuedChange";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+        let admin = escrow_ix.signers[0].insecure_clone();
+        let escrow_seed = escrow_ix.signers[1].pubkey();
+        escrow_ix.send_expect_success(ctx);
+
+        let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+        QueueChangeFixture::build_with_escrow(
+            ctx,
+            escrow_pda,
+            admin.insecure_clone(),
+            DEFAULT_CHANGE_ID,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_TIMELOCK,
+            DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+        )
+        .send_expect_success(ctx);
+
+        Self::build_with_escrow(ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: admin
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 1: rent_recipient, 4: pending_change
+    fn required_writable() -> &'static [usize] {
+        &[1, 4]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        1 // Just the discriminator
+    }
+}
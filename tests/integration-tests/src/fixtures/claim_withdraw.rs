@@ -0,0 +1,94 @@
+use escrow_program_client::instructions::ClaimWithdrawBuilder;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signer};
+
+use crate::{
+    fixtures::{WithdrawSetup, DEFAULT_COOLDOWN_PERIOD},
+    utils::{find_withdraw_request_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct ClaimWithdrawFixture;
+
+impl ClaimWithdrawFixture {
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        let (withdraw_request_pda, _) = find_withdraw_request_pda(&setup.receipt_pda);
+        Self::build_with_request(ctx, setup, withdraw_request_pda)
+    }
+
+    pub fn build_with_request(ctx: &TestContext, setup: &WithdrawSetup, withdraw_request: Pubkey) -> TestInstruction {
+        let mut builder = ClaimWithdrawBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .rent_recipient(setup.rent_recipient)
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(setup.receipt_pda)
+            .withdraw_request(withdraw_request)
+            .vault(setup.vault)
+            .destination(setup.depositor.pubkey())
+            .destination_token_account(setup.depositor_token_account)
+            .mint(setup.mint.pubkey())
+            .allowed_mint(setup.allowed_mint_pda)
+            .token_program(setup.token_program);
+
+        let mut signers = vec![setup.depositor.insecure_clone()];
+
+        // Arbiter comes before hook_program in remaining_accounts
+        if let Some(ref arbiter) = setup.arbiter {
+            builder.add_remaining_account(AccountMeta::new_readonly(arbiter.pubkey(), true));
+            signers.push(arbiter.insecure_clone());
+        }
+
+        if let Some(hook_bypass_request) = setup.hook_bypass_request {
+            builder.add_remaining_account(AccountMeta::new(hook_bypass_request, false));
+        } else if let Some(hook_program) = setup.hook_program {
+            builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers, name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for ClaimWithdrawFixture {
+    const INSTRUCTION_NAME: &'static str = "ClaimWithdraw";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::builder(ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+        setup.request_withdraw(ctx);
+        ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 2: depositor
+    fn required_signers() -> &'static [usize] {
+        &[0, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 1: rent_recipient
+    /// 5: receipt
+    /// 6: withdraw_request
+    /// 7: vault
+    /// 9: destination_token_account
+    /// 16: allowed_mint
+    fn required_writable() -> &'static [usize] {
+        &[0, 1, 5, 6, 7, 9, 16]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(13)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(15)
+    }
+
+    fn data_len() -> usize {
+        1
+    }
+}
@@ -0,0 +1,166 @@
+use escrow_program_client::instructions::CreateEscrowWithConfigBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::utils::extensions_utils::{tlv_entry, EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_TIMELOCK};
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::{find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, TestContext};
+
+pub const DEFAULT_CONFIG_LOCK_DURATION: u64 = 3600;
+
+pub struct ConfigMint {
+    pub mint: Pubkey,
+    pub allowed_mint_pda: Pubkey,
+    pub allowed_mint_bump: u8,
+    pub vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+pub struct CreateEscrowWithConfigSetup {
+    pub admin: Keypair,
+    pub escrow_seed: Keypair,
+    pub escrow_pda: Pubkey,
+    pub escrow_bump: u8,
+    pub extensions_pda: Pubkey,
+    pub extensions_bump: u8,
+    pub extension_data: Vec<u8>,
+    pub arbiter: Option<Keypair>,
+    pub is_immutable: bool,
+    pub mints: Vec<ConfigMint>,
+}
+
+impl CreateEscrowWithConfigSetup {
+    /// Escrow with no extensions and no mints
+    pub fn new_empty(ctx: &mut TestContext) -> Self {
+        let admin = ctx.create_funded_keypair();
+        let escrow_seed = Keypair::new();
+        let (escrow_pda, escrow_bump) = find_escrow_pda(&escrow_seed.pubkey());
+        let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+        Self {
+            admin,
+            escrow_seed,
+            escrow_pda,
+            escrow_bump,
+            extensions_pda,
+            extensions_bump,
+            extension_data: Vec::new(),
+            arbiter: None,
+            is_immutable: false,
+            mints: Vec::new(),
+        }
+    }
+
+    /// Escrow with a timelock extension and one SPL Token mint
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let mut setup = Self::new_empty(ctx);
+        setup.add_extension(EXTENSION_TYPE_TIMELOCK, &DEFAULT_CONFIG_LOCK_DURATION.to_le_bytes());
+        setup.add_new_mint(ctx, TOKEN_PROGRAM_ID);
+        setup
+    }
+
+    pub fn add_extension(&mut self, ext_type: u16, value: &[u8]) {
+        self.extension_data.extend(tlv_entry(ext_type, value));
+    }
+
+    /// Adds an arbiter extension and signs the instruction with the arbiter
+    pub fn add_arbiter(&mut self) -> Pubkey {
+        let arbiter = Keypair::new();
+        self.add_extension(EXTENSION_TYPE_ARBITER, arbiter.pubkey().as_ref());
+        let arbiter_pubkey = arbiter.pubkey();
+        self.arbiter = Some(arbiter);
+        arbiter_pubkey
+    }
+
+    pub fn add_mint(&mut self, mint: Pubkey, token_program: Pubkey) {
+        let (allowed_mint_pda, allowed_mint_bump) = find_allowed_mint_pda(&self.escrow_pda, &mint);
+        let vault = get_associated_token_address_with_program_id(&self.escrow_pda, &mint, &token_program);
+        self.mints.push(ConfigMint { mint, allowed_mint_pda, allowed_mint_bump, vault, token_program });
+    }
+
+    pub fn add_new_mint(&mut self, ctx: &mut TestContext, token_program: Pubkey) -> Pubkey {
+        let mint = Keypair::new();
+        if token_program == TOKEN_2022_PROGRAM_ID {
+            ctx.create_token_2022_mint(&mint, &ctx.payer.pubkey(), 6);
+        } else {
+            ctx.create_mint(&mint, &ctx.payer.pubkey(), 6);
+        }
+        self.add_mint(mint.pubkey(), token_program);
+        mint.pubkey()
+    }
+
+    pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        let mut builder = CreateEscrowWithConfigBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .admin(self.admin.pubkey())
+            .escrow_seed(self.escrow_seed.pubkey())
+            .escrow(self.escrow_pda)
+            .extensions(self.extensions_pda)
+            .bump(self.escrow_bump)
+            .extensions_bump(self.extensions_bump)
+            .is_immutable(self.is_immutable)
+            .extension_data(self.extension_data.clone())
+            .allowed_mint_bumps(self.mints.iter().map(|m| m.allowed_mint_bump).collect());
+
+        let mut signers = vec![self.admin.insecure_clone(), self.escrow_seed.insecure_clone()];
+        if let Some(arbiter) = &self.arbiter {
+            builder.add_remaining_account(AccountMeta::new_readonly(arbiter.pubkey(), true));
+            signers.push(arbiter.insecure_clone());
+        }
+        for mint in &self.mints {
+            builder.add_remaining_account(AccountMeta::new_readonly(mint.mint, false));
+            builder.add_remaining_account(AccountMeta::new(mint.allowed_mint_pda, false));
+            builder.add_remaining_account(AccountMeta::new(mint.vault, false));
+            builder.add_remaining_account(AccountMeta::new_readonly(mint.token_program, false));
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers, name: "CreateEscrowWithConfig" }
+    }
+}
+
+pub struct CreateEscrowWithConfigFixture;
+
+impl InstructionTestFixture for CreateEscrowWithConfigFixture {
+    const INSTRUCTION_NAME: &'static str = "CreateEscrowWithConfig";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = CreateEscrowWithConfigSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: admin
+    /// 2: escrow_seed
+    fn required_signers() -> &'static [usize] {
+        &[0, 1, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 3: escrow
+    /// 4: extensions
+    fn required_writable() -> &'static [usize] {
+        &[0, 3, 4]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    /// discriminator + bump + extensions_bump + is_immutable + extension_data (4 + 12) + allowed_mint_bumps (4 + 1)
+    fn data_len() -> usize {
+        1 + 1 + 1 + 1 + 4 + 12 + 4 + 1
+    }
+}
@@ -0,0 +1,55 @@
+use escrow_program_client::instructions::CreateEscrowWithSeedBuilder;
+use solana_sdk::signature::Signer;
+
+use crate::utils::{find_admin_bound_escrow_pda, TestContext};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub const DEFAULT_ESCROW_SEED: [u8; 32] = [7u8; 32];
+
+pub struct CreateEscrowWithSeedFixture;
+
+impl InstructionTestFixture for CreateEscrowWithSeedFixture {
+    const INSTRUCTION_NAME: &'static str = "CreateEscrowWithSeed";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let admin = ctx.create_funded_keypair();
+        let (escrow_pda, bump) = find_admin_bound_escrow_pda(&admin.pubkey(), &DEFAULT_ESCROW_SEED);
+
+        let instruction = CreateEscrowWithSeedBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .escrow(escrow_pda)
+            .bump(bump)
+            .seed(DEFAULT_ESCROW_SEED)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: admin
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer
+    /// 2: escrow
+    fn required_writable() -> &'static [usize] {
+        &[0, 2]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(3)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 + 32 // discriminator + bump + seed
+    }
+}
@@ -10,7 +10,10 @@ use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 use crate::utils::traits::{InstructionTestFixture, TestInstruction};
-use crate::utils::{find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, find_receipt_pda, TestContext};
+use crate::utils::{
+    find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, find_receipt_counter_pda, find_receipt_pda,
+    TestContext,
+};
 
 pub const DEFAULT_DEPOSIT_AMOUNT: u64 = 1_000_000;
 
@@ -23,7 +26,8 @@ pub struct DepositSetup {
     pub vault: Pubkey,
     pub depositor: Keypair,
     pub depositor_token_account: Pubkey,
-    pub receipt_seed: Keypair,
+    pub receipt_counter: Pubkey,
+    pub nonce: u64,
     pub receipt_pda: Pubkey,
     pub bump: u8,
     pub token_program: Pubkey,
@@ -51,14 +55,37 @@ impl DepositSetup {
         Self::builder(ctx).token_2022().hook_program(hook_program).build()
     }
 
+    /// Points the setup at the depositor's next receipt after a successful deposit
+    pub fn advance_receipt(&mut self) {
+        self.nonce += 1;
+        (self.receipt_pda, self.bump) =
+            find_receipt_pda(&self.escrow_pda, &self.depositor.pubkey(), &self.mint.pubkey(), self.nonce);
+    }
+
     pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
+        self.build_instruction_with_amount(ctx, DEFAULT_DEPOSIT_AMOUNT)
+    }
+
+    pub fn build_instruction_with_amount(&self, ctx: &TestContext, amount: u64) -> TestInstruction {
+        self.build_instruction_with_proof(ctx, amount, vec![], None)
+    }
+
+    /// Builds a deposit carrying an allowlist proof, with the depositor allowance PDA
+    /// (if any) passed ahead of the hook program in remaining accounts.
+    pub fn build_instruction_with_proof(
+        &self,
+        ctx: &TestContext,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+        depositor_allowance: Option<Pubkey>,
+    ) -> TestInstruction {
         let mut builder = DepositBuilder::new();
         builder
             .payer(ctx.payer.pubkey())
             .depositor(self.depositor.pubkey())
             .escrow(self.escrow_pda)
             .allowed_mint(self.allowed_mint_pda)
-            .receipt_seed(self.receipt_seed.pubkey())
+            .receipt_counter(self.receipt_counter)
             .receipt(self.receipt_pda)
             .vault(self.vault)
             .depositor_token_account(self.depositor_token_account)
@@ -66,7 +93,13 @@ impl DepositSetup {
             .token_program(self.token_program)
             .extensions(self.extensions_pda)
             .bump(self.bump)
-            .amount(DEFAULT_DEPOSIT_AMOUNT);
+            .amount(amount)
+            .rent_recipient(ctx.payer.pubkey())
+            .proof(proof);
+
+        if let Some(depositor_allowance) = depositor_allowance {
+            builder.add_remaining_account(AccountMeta::new(depositor_allowance, false));
+        }
 
         if let Some(hook_program) = self.hook_program {
             builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
@@ -74,11 +107,7 @@ impl DepositSetup {
 
         let instruction = builder.instruction();
 
-        TestInstruction {
-            instruction,
-            signers: vec![self.depositor.insecure_clone(), self.receipt_seed.insecure_clone()],
-            name: "Deposit",
-        }
+        TestInstruction { instruction, signers: vec![self.depositor.insecure_clone()], name: "Deposit" }
     }
 }
 
@@ -86,12 +115,13 @@ pub struct DepositSetupBuilder<'a> {
     ctx: &'a mut TestContext,
     token_program: Pubkey,
     hook_program: Option<Pubkey>,
+    hook_flags: u8,
     mint_extension: Option<ExtensionType>,
 }
 
 impl<'a> DepositSetupBuilder<'a> {
     fn new(ctx: &'a mut TestContext) -> Self {
-        Self { ctx, token_program: TOKEN_PROGRAM_ID, hook_program: None, mint_extension: None }
+        Self { ctx, token_program: TOKEN_PROGRAM_ID, hook_program: None, hook_flags: 0, mint_extension: None }
     }
 
     pub fn token_2022(mut self) -> Self {
@@ -109,6 +139,11 @@ impl<'a> DepositSetupBuilder<'a> {
         self
     }
 
+    pub fn hook_flags(mut self, flags: u8) -> Self {
+        self.hook_flags = flags;
+        self
+    }
+
     pub fn mint_extension(mut self, extension: ExtensionType) -> Self {
         self.mint_extension = Some(extension);
         self.token_program = TOKEN_2022_PROGRAM_ID;
@@ -139,6 +174,7 @@ impl<'a> DepositSetupBuilder<'a> {
                 .extensions(extensions_pda)
                 .extensions_bump(extensions_bump)
                 .hook_program(Address::from(hook_id.to_bytes()))
+                .flags(self.hook_flags)
                 .instruction();
 
             self.ctx.send_transaction(set_hook_ix, &[&admin]).unwrap();
@@ -194,9 +230,8 @@ impl<'a> DepositSetupBuilder<'a> {
             );
         }
 
-        let receipt_seed = Keypair::new();
-        let (receipt_pda, bump) =
-            find_receipt_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey(), &receipt_seed.pubkey());
+        let (receipt_counter, _) = find_receipt_counter_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey());
+        let (receipt_pda, bump) = find_receipt_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey(), 0);
 
         DepositSetup {
             escrow_pda,
@@ -207,7 +242,8 @@ impl<'a> DepositSetupBuilder<'a> {
             vault,
             depositor,
             depositor_token_account,
-            receipt_seed,
+            receipt_counter,
+            nonce: 0,
             receipt_pda,
             bump,
             token_program,
@@ -229,18 +265,19 @@ impl InstructionTestFixture for DepositFixture {
     /// Account indices that must be signers:
     /// 0: payer (handled by TestContext)
     /// 1: depositor
-    /// 4: receipt_seed
     fn required_signers() -> &'static [usize] {
-        &[0, 1, 4]
+        &[0, 1]
     }
 
     /// Account indices that must be writable:
     /// 0: payer (handled by TestContext)
+    /// 3: allowed_mint
+    /// 4: receipt_counter
     /// 5: receipt
     /// 6: vault
     /// 7: depositor_token_account
     fn required_writable() -> &'static [usize] {
-        &[0, 5, 6, 7]
+        &[0, 3, 4, 5, 6, 7]
     }
 
     fn system_program_index() -> Option<usize> {
@@ -252,6 +289,6 @@ impl InstructionTestFixture for DepositFixture {
     }
 
     fn data_len() -> usize {
-        10
+        46
     }
 }
@@ -0,0 +1,323 @@
+use escrow_program_client::instructions::{
+    AddTimelockBuilder, AllowMintBuilder, CreatesEscrowBuilder, DepositBasketBuilder,
+};
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+use crate::fixtures::{SetArbiterFixture, SetHookBypassFixture, SetHookFixture, DEFAULT_DEPOSIT_AMOUNT};
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::{find_allowed_mint_pda, find_basket_receipt_pda, find_escrow_pda, find_extensions_pda, TestContext};
+
+/// Accounts for one mint of a basket escrow
+pub struct BasketMint {
+    pub mint: Keypair,
+    pub token_program: Pubkey,
+    pub allowed_mint_pda: Pubkey,
+    pub vault: Pubkey,
+    pub depositor_token_account: Pubkey,
+}
+
+impl BasketMint {
+    /// Leg accounts in the order `DepositBasket` and `WithdrawBasket` expect them
+    pub fn leg_accounts(&self) -> [AccountMeta; 5] {
+        [
+            AccountMeta::new_readonly(self.mint.pubkey(), false),
+            AccountMeta::new_readonly(self.token_program, false),
+            AccountMeta::new(self.allowed_mint_pda, false),
+            AccountMeta::new(self.vault, false),
+            AccountMeta::new(self.depositor_token_account, false),
+        ]
+    }
+}
+
+pub struct BasketSetup {
+    pub escrow_pda: Pubkey,
+    pub extensions_pda: Pubkey,
+    pub admin: Keypair,
+    pub depositor: Keypair,
+    pub mints: Vec<BasketMint>,
+    pub rent_recipient: Pubkey,
+    pub hook_program: Option<Pubkey>,
+    pub arbiter: Option<Keypair>,
+}
+
+impl BasketSetup {
+    pub fn builder(ctx: &mut TestContext) -> BasketSetupBuilder<'_> {
+        BasketSetupBuilder::new(ctx)
+    }
+
+    /// Escrow with an SPL Token mint and a Token-2022 mint allowed
+    pub fn new(ctx: &mut TestContext) -> Self {
+        Self::builder(ctx).build()
+    }
+
+    pub fn basket_receipt_pda(&self, basket_id: u64) -> (Pubkey, u8) {
+        find_basket_receipt_pda(&self.escrow_pda, &self.depositor.pubkey(), basket_id)
+    }
+
+    /// Deposits `DEFAULT_DEPOSIT_AMOUNT` of every mint into basket `basket_id`, returning its PDA
+    pub fn deposit(&self, ctx: &mut TestContext, basket_id: u64) -> Pubkey {
+        let amounts = vec![DEFAULT_DEPOSIT_AMOUNT; self.mints.len()];
+        DepositBasketFixture::build_with_setup(ctx, self, basket_id, &amounts).send_expect_success(ctx);
+        self.basket_receipt_pda(basket_id).0
+    }
+}
+
+pub struct BasketSetupBuilder<'a> {
+    ctx: &'a mut TestContext,
+    token_programs: Vec<Pubkey>,
+    timelock: Option<u64>,
+    hook_program: Option<Pubkey>,
+    hook_bypass: Option<u64>,
+    arbiter: bool,
+}
+
+impl<'a> BasketSetupBuilder<'a> {
+    fn new(ctx: &'a mut TestContext) -> Self {
+        Self {
+            ctx,
+            token_programs: vec![TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID],
+            timelock: None,
+            hook_program: None,
+            hook_bypass: None,
+            arbiter: false,
+        }
+    }
+
+    /// One allowed mint per entry, owned by the given token program
+    pub fn token_programs(mut self, token_programs: &[Pubkey]) -> Self {
+        self.token_programs = token_programs.to_vec();
+        self
+    }
+
+    pub fn timelock(mut self, lock_duration: u64) -> Self {
+        self.timelock = Some(lock_duration);
+        self
+    }
+
+    pub fn hook_program(mut self, program: Pubkey) -> Self {
+        self.hook_program = Some(program);
+        self
+    }
+
+    pub fn hook_bypass(mut self, grace_period: u64) -> Self {
+        self.hook_bypass = Some(grace_period);
+        self
+    }
+
+    pub fn arbiter(mut self) -> Self {
+        self.arbiter = true;
+        self
+    }
+
+    pub fn build(self) -> BasketSetup {
+        let admin = self.ctx.create_funded_keypair();
+        let escrow_seed = Keypair::new();
+        let (escrow_pda, escrow_bump) = find_escrow_pda(&escrow_seed.pubkey());
+
+        let create_escrow_ix = CreatesEscrowBuilder::new()
+            .payer(self.ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .escrow_seed(escrow_seed.pubkey())
+            .escrow(escrow_pda)
+            .bump(escrow_bump)
+            .instruction();
+
+        self.ctx.send_transaction(create_escrow_ix, &[&admin, &escrow_seed]).unwrap();
+
+        let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+        if let Some(lock_duration) = self.timelock {
+            let add_timelock_ix = AddTimelockBuilder::new()
+                .payer(self.ctx.payer.pubkey())
+                .admin(admin.pubkey())
+                .escrow(escrow_pda)
+                .extensions(extensions_pda)
+                .extensions_bump(extensions_bump)
+                .lock_duration(lock_duration)
+                .instruction();
+
+            self.ctx.send_transaction(add_timelock_ix, &[&admin]).unwrap();
+        }
+
+        if let Some(hook_id) = self.hook_program {
+            SetHookFixture::build_with_flags(self.ctx, escrow_pda, admin.insecure_clone(), hook_id, 0)
+                .send_expect_success(self.ctx);
+        }
+
+        if let Some(grace_period) = self.hook_bypass {
+            SetHookBypassFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), grace_period)
+                .send_expect_success(self.ctx);
+        }
+
+        let arbiter = if self.arbiter {
+            let arbiter_kp = self.ctx.create_funded_keypair();
+            SetArbiterFixture::build_with_escrow(
+                self.ctx,
+                escrow_pda,
+                admin.insecure_clone(),
+                arbiter_kp.insecure_clone(),
+            )
+            .send_expect_success(self.ctx);
+            Some(arbiter_kp)
+        } else {
+            None
+        };
+
+        let depositor = self.ctx.create_funded_keypair();
+        let mut mints = Vec::with_capacity(self.token_programs.len());
+
+        for token_program in self.token_programs {
+            let mint = Keypair::new();
+            let (vault, depositor_token_account);
+
+            if token_program == TOKEN_2022_PROGRAM_ID {
+                self.ctx.create_token_2022_mint(&mint, &self.ctx.payer.pubkey(), 6);
+                vault = self.ctx.create_token_2022_account(&escrow_pda, &mint.pubkey());
+                depositor_token_account = self.ctx.create_token_2022_account_with_balance(
+                    &depositor.pubkey(),
+                    &mint.pubkey(),
+                    DEFAULT_DEPOSIT_AMOUNT * 10,
+                );
+            } else {
+                self.ctx.create_mint(&mint, &self.ctx.payer.pubkey(), 6);
+                vault = self.ctx.create_token_account(&escrow_pda, &mint.pubkey());
+                depositor_token_account = self.ctx.create_token_account_with_balance(
+                    &depositor.pubkey(),
+                    &mint.pubkey(),
+                    DEFAULT_DEPOSIT_AMOUNT * 10,
+                );
+            }
+
+            let (allowed_mint_pda, allowed_mint_bump) = find_allowed_mint_pda(&escrow_pda, &mint.pubkey());
+
+            let allow_mint_ix = AllowMintBuilder::new()
+                .payer(self.ctx.payer.pubkey())
+                .admin(admin.pubkey())
+                .escrow(escrow_pda)
+                .escrow_extensions(extensions_pda)
+                .mint(mint.pubkey())
+                .allowed_mint(allowed_mint_pda)
+                .vault(vault)
+                .token_program(token_program)
+                .bump(allowed_mint_bump)
+                .instruction();
+
+            self.ctx.send_transaction(allow_mint_ix, &[&admin]).unwrap();
+
+            mints.push(BasketMint { mint, token_program, allowed_mint_pda, vault, depositor_token_account });
+        }
+
+        BasketSetup {
+            escrow_pda,
+            extensions_pda,
+            admin,
+            depositor,
+            mints,
+            rent_recipient: self.ctx.payer.pubkey(),
+            hook_program: self.hook_program,
+            arbiter,
+        }
+    }
+}
+
+pub struct DepositBasketFixture;
+
+impl DepositBasketFixture {
+    pub fn build_with_setup(
+        ctx: &TestContext,
+        setup: &BasketSetup,
+        basket_id: u64,
+        amounts: &[u64],
+    ) -> TestInstruction {
+        Self::build_with_proof(ctx, setup, basket_id, amounts, vec![], &[])
+    }
+
+    /// Builds a basket deposit carrying an allowlist proof, with the depositor allowance PDAs
+    /// passed after the leg accounts and ahead of the hook program.
+    pub fn build_with_proof(
+        ctx: &TestContext,
+        setup: &BasketSetup,
+        basket_id: u64,
+        amounts: &[u64],
+        proof: Vec<[u8; 32]>,
+        depositor_allowances: &[Pubkey],
+    ) -> TestInstruction {
+        let (basket_receipt, bump) = setup.basket_receipt_pda(basket_id);
+
+        let mut builder = DepositBasketBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .basket_receipt(basket_receipt)
+            .bump(bump)
+            .basket_id(basket_id)
+            .rent_recipient(setup.rent_recipient)
+            .amounts(amounts.to_vec())
+            .proof(proof);
+
+        // Leg accounts come first in remaining_accounts
+        for basket_mint in setup.mints.iter().take(amounts.len()) {
+            builder.add_remaining_accounts(&basket_mint.leg_accounts());
+        }
+
+        for depositor_allowance in depositor_allowances {
+            builder.add_remaining_account(AccountMeta::new(*depositor_allowance, false));
+        }
+
+        if let Some(hook_program) = setup.hook_program {
+            builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
+        }
+
+        TestInstruction {
+            instruction: builder.instruction(),
+            signers: vec![setup.depositor.insecure_clone()],
+            name: Self::INSTRUCTION_NAME,
+        }
+    }
+}
+
+impl InstructionTestFixture for DepositBasketFixture {
+    const INSTRUCTION_NAME: &'static str = "DepositBasket";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = BasketSetup::new(ctx);
+        Self::build_with_setup(ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT])
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 1: depositor
+    fn required_signers() -> &'static [usize] {
+        &[0, 1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 4: basket_receipt
+    /// 10: first leg allowed_mint
+    /// 11: first leg vault
+    /// 12: first leg depositor_token_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 4, 10, 11, 12]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    fn data_len() -> usize {
+        1 + 1 + 8 + 32 + 4 + 2 * 8 + 4 // discriminator + bump + basket_id + rent_recipient + amounts + proof length
+    }
+}
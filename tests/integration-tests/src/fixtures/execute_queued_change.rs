@@ -0,0 +1,93 @@
+use escrow_program_client::instructions::ExecuteQueuedChangeBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::utils::extensions_utils::EXTENSION_TYPE_TIMELOCK;
+use crate::{
+    fixtures::{
+        CreateEscrowFixture, QueueChangeFixture, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::{find_escrow_pda, find_extensions_pda, find_pending_change_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct ExecuteQueuedChangeFixture;
+
+impl ExecuteQueuedChangeFixture {
+    pub fn build_with_escrow(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        change_id: u64,
+    ) -> TestInstruction {
+        let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+        let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, change_id);
+
+        let instruction = ExecuteQueuedChangeBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .rent_recipient(ctx.payer.pubkey())
+            .escrow(escrow_pda)
+            .extensions(extensions_pda)
+            .pending_change(pending_change_pda)
+            .extensions_bump(extensions_bump)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for ExecuteQueuedChangeFixture {
+    const INSTRUCTION_NAME: &'static str = "ExecuteQueuedChange";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+        let admin = escrow_ix.signers[0].insecure_clone();
+        let escrow_seed = escrow_ix.signers[1].pubkey();
+        escrow_ix.send_expect_success(ctx);
+
+        let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+        // Without a governance delay the queued change is executable right away
+        QueueChangeFixture::build_with_escrow(
+            ctx,
+            escrow_pda,
+            admin.insecure_clone(),
+            DEFAULT_CHANGE_ID,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_TIMELOCK,
+            DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+        )
+        .send_expect_success(ctx);
+
+        Self::build_with_escrow(ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: admin (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 2: rent_recipient, 4: extensions, 5: pending_change (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[2, 4, 5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    /// discriminator + extensions_bump
+    fn data_len() -> usize {
+        1 + 1
+    }
+}
@@ -0,0 +1,101 @@
+use escrow_program_client::instructions::ForceRefundBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::{
+    fixtures::{WithdrawSetup, DEFAULT_REFUND_AUTHORITY_FLAGS},
+    utils::TestContext,
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct ForceRefundFixture;
+
+impl ForceRefundFixture {
+    /// Force refund signed by the escrow admin
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        Self::build_with_authority(ctx, setup, setup.admin.insecure_clone())
+    }
+
+    pub fn build_with_authority(ctx: &TestContext, setup: &WithdrawSetup, authority: Keypair) -> TestInstruction {
+        Self::build(ctx, setup, authority, setup.rent_recipient)
+    }
+
+    pub fn build_with_rent_recipient(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        rent_recipient: Pubkey,
+    ) -> TestInstruction {
+        Self::build(ctx, setup, setup.admin.insecure_clone(), rent_recipient)
+    }
+
+    fn build(ctx: &TestContext, setup: &WithdrawSetup, authority: Keypair, rent_recipient: Pubkey) -> TestInstruction {
+        let mut builder = ForceRefundBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .rent_recipient(rent_recipient)
+            .authority(authority.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(setup.receipt_pda)
+            .vault(setup.vault)
+            .depositor(setup.depositor.pubkey())
+            .depositor_token_account(setup.depositor_token_account)
+            .mint(setup.mint.pubkey())
+            .allowed_mint(setup.allowed_mint_pda)
+            .token_program(setup.token_program);
+
+        // A recorded hook bypass request is closed with the receipt
+        if let Some(hook_bypass_request) = setup.hook_bypass_request {
+            builder.add_remaining_account(AccountMeta::new(hook_bypass_request, false));
+        }
+
+        if let Some(hook_program) = setup.hook_program {
+            builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers: vec![authority], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for ForceRefundFixture {
+    const INSTRUCTION_NAME: &'static str = "ForceRefund";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::builder(ctx).refund_authority(DEFAULT_REFUND_AUTHORITY_FLAGS).build();
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 2: authority
+    fn required_signers() -> &'static [usize] {
+        &[0, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 1: rent_recipient
+    /// 5: receipt
+    /// 6: vault
+    /// 8: depositor_token_account
+    /// 15: allowed_mint
+    fn required_writable() -> &'static [usize] {
+        &[0, 1, 5, 6, 8, 15]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(12)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(14)
+    }
+
+    fn data_len() -> usize {
+        1
+    }
+}
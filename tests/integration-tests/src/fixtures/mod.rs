@@ -1,27 +1,91 @@
 pub mod add_timelock;
 pub mod allow_mint;
+pub mod approve_withdraw_delegate;
 pub mod block_mint;
 pub mod block_token_extension;
+pub mod cancel_queued_change;
+pub mod claim_withdraw;
 pub mod create_escrow;
+pub mod create_escrow_with_config;
+pub mod create_escrow_with_seed;
 pub mod deposit;
+pub mod deposit_basket;
+pub mod execute_queued_change;
+pub mod force_refund;
+pub mod lock_extension;
+pub mod lock_mint_list;
+pub mod queue_change;
+pub mod release_matured;
 pub mod remove_extension;
+pub mod request_hook_bypass;
+pub mod request_withdraw;
+pub mod revoke_withdraw_delegate;
 pub mod set_arbiter;
+pub mod set_auto_release;
+pub mod set_cooldown;
+pub mod set_depositor_allowlist;
+pub mod set_governance_delay;
 pub mod set_hook;
+pub mod set_hook_bypass;
 pub mod set_immutable;
+pub mod set_metadata;
+pub mod set_mint_limits;
+pub mod set_mint_override;
+pub mod set_refund_authority;
+pub mod set_roles;
+pub mod sweep_surplus;
 pub mod unblock_token_extension;
 pub mod update_admin;
+pub mod verify_solvency;
 pub mod withdraw;
+pub mod withdraw_basket;
+pub mod withdraw_many;
 
 pub use add_timelock::AddTimelockFixture;
 pub use allow_mint::{AllowMintFixture, AllowMintSetup};
+pub use approve_withdraw_delegate::ApproveWithdrawDelegateFixture;
 pub use block_mint::{BlockMintFixture, BlockMintSetup};
 pub use block_token_extension::AddBlockTokenExtensionsFixture;
+pub use cancel_queued_change::CancelQueuedChangeFixture;
+pub use claim_withdraw::ClaimWithdrawFixture;
 pub use create_escrow::CreateEscrowFixture;
+pub use create_escrow_with_config::{
+    CreateEscrowWithConfigFixture, CreateEscrowWithConfigSetup, DEFAULT_CONFIG_LOCK_DURATION,
+};
+pub use create_escrow_with_seed::{CreateEscrowWithSeedFixture, DEFAULT_ESCROW_SEED};
 pub use deposit::{DepositFixture, DepositSetup, DEFAULT_DEPOSIT_AMOUNT};
+pub use deposit_basket::{BasketMint, BasketSetup, DepositBasketFixture};
+pub use execute_queued_change::ExecuteQueuedChangeFixture;
+pub use force_refund::ForceRefundFixture;
+pub use lock_extension::LockExtensionFixture;
+pub use lock_mint_list::LockMintListFixture;
+pub use queue_change::{
+    QueueChangeFixture, CHANGE_ACTION_REMOVE, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_QUEUED_LOCK_DURATION,
+};
+pub use release_matured::ReleaseMaturedFixture;
 pub use remove_extension::RemoveExtensionFixture;
+pub use request_hook_bypass::RequestHookBypassFixture;
+pub use request_withdraw::RequestWithdrawFixture;
+pub use revoke_withdraw_delegate::RevokeWithdrawDelegateFixture;
 pub use set_arbiter::SetArbiterFixture;
+pub use set_auto_release::{SetAutoReleaseFixture, DEFAULT_AUTO_RELEASE_DELAY};
+pub use set_cooldown::{SetCooldownFixture, DEFAULT_COOLDOWN_PERIOD};
+pub use set_depositor_allowlist::{SetDepositorAllowlistFixture, DEFAULT_ALLOWLIST_ROOT};
+pub use set_governance_delay::{SetGovernanceDelayFixture, DEFAULT_GOVERNANCE_DELAY};
 pub use set_hook::SetHookFixture;
+pub use set_hook_bypass::{SetHookBypassFixture, DEFAULT_HOOK_BYPASS_GRACE_PERIOD};
 pub use set_immutable::SetImmutableFixture;
+pub use set_metadata::{
+    SetMetadataFixture, DEFAULT_METADATA_EXTERNAL_REFERENCE, DEFAULT_METADATA_NAME, DEFAULT_METADATA_URI,
+};
+pub use set_mint_limits::{SetMintLimitsFixture, SetMintLimitsSetup};
+pub use set_mint_override::{SetMintOverrideFixture, DEFAULT_OVERRIDE_LOCK_DURATION};
+pub use set_refund_authority::{SetRefundAuthorityFixture, DEFAULT_REFUND_AUTHORITY_FLAGS};
+pub use set_roles::SetRolesFixture;
+pub use sweep_surplus::{SweepSurplusFixture, SweepSurplusSetup, DEFAULT_SURPLUS_AMOUNT};
 pub use unblock_token_extension::UnblockTokenExtensionFixture;
 pub use update_admin::UpdateAdminFixture;
+pub use verify_solvency::{VerifySolvencyFixture, VerifySolvencySetup};
 pub use withdraw::{WithdrawFixture, WithdrawSetup};
+pub use withdraw_basket::WithdrawBasketFixture;
+pub use withdraw_many::WithdrawManyFixture;
@@ -0,0 +1,121 @@
+use escrow_program_client::instructions::QueueChangeBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::utils::extensions_utils::{EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_TIMELOCK};
+use crate::{
+    fixtures::CreateEscrowFixture,
+    utils::{find_escrow_pda, find_extensions_pda, find_pending_change_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub const CHANGE_ACTION_SET: u8 = 0;
+pub const CHANGE_ACTION_REMOVE: u8 = 1;
+pub const DEFAULT_CHANGE_ID: u64 = 1;
+pub const DEFAULT_QUEUED_LOCK_DURATION: u64 = 3_600;
+
+pub struct QueueChangeFixture;
+
+impl QueueChangeFixture {
+    pub fn build_with_escrow(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        change_id: u64,
+        action: u8,
+        extension_type: u16,
+        value: Vec<u8>,
+    ) -> TestInstruction {
+        let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+        let (pending_change_pda, bump) = find_pending_change_pda(&escrow_pda, change_id);
+
+        let instruction = QueueChangeBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .escrow(escrow_pda)
+            .extensions(extensions_pda)
+            .pending_change(pending_change_pda)
+            .bump(bump)
+            .change_id(change_id)
+            .action(action)
+            .extension_type(extension_type)
+            .value(value)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+
+    /// Queues setting the arbiter, with the new arbiter signing as the first remaining account
+    pub fn build_set_arbiter(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        change_id: u64,
+        arbiter: Keypair,
+    ) -> TestInstruction {
+        let mut test_ix = Self::build_with_escrow(
+            ctx,
+            escrow_pda,
+            admin,
+            change_id,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_ARBITER,
+            arbiter.pubkey().to_bytes().to_vec(),
+        );
+        test_ix.instruction.accounts.push(AccountMeta::new_readonly(arbiter.pubkey(), true));
+        test_ix.signers.push(arbiter);
+        test_ix
+    }
+}
+
+impl InstructionTestFixture for QueueChangeFixture {
+    const INSTRUCTION_NAME: &'static str = "QueueChange";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+        let admin = escrow_ix.signers[0].insecure_clone();
+        let escrow_seed = escrow_ix.signers[1].pubkey();
+        escrow_ix.send_expect_success(ctx);
+
+        let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+        Self::build_with_escrow(
+            ctx,
+            escrow_pda,
+            admin,
+            DEFAULT_CHANGE_ID,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_TIMELOCK,
+            DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+        )
+    }
+
+    /// Account indices that must be signers:
+    /// 1: admin (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 4: pending_change (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[4]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(7)
+    }
+
+    /// discriminator + bump + change_id + action + extension_type + value length prefix + timelock value
+    fn data_len() -> usize {
+        1 + 1 + 8 + 1 + 2 + 4 + 8
+    }
+}
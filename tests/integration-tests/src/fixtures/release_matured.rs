@@ -0,0 +1,79 @@
+use escrow_program_client::instructions::ReleaseMaturedBuilder;
+use solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signature::Signer};
+
+use crate::{fixtures::WithdrawSetup, utils::TestContext};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct ReleaseMaturedFixture;
+
+impl ReleaseMaturedFixture {
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        Self::build_with_rent_recipient(ctx, setup, setup.rent_recipient)
+    }
+
+    pub fn build_with_rent_recipient(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        rent_recipient: Pubkey,
+    ) -> TestInstruction {
+        let mut builder = ReleaseMaturedBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .rent_recipient(rent_recipient)
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(setup.receipt_pda)
+            .vault(setup.vault)
+            .depositor(setup.depositor.pubkey())
+            .depositor_token_account(setup.depositor_token_account)
+            .mint(setup.mint.pubkey())
+            .allowed_mint(setup.allowed_mint_pda)
+            .token_program(setup.token_program);
+
+        // A recorded hook bypass request is closed with the receipt
+        if let Some(hook_bypass_request) = setup.hook_bypass_request {
+            builder.add_remaining_account(AccountMeta::new(hook_bypass_request, false));
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers: vec![], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for ReleaseMaturedFixture {
+    const INSTRUCTION_NAME: &'static str = "ReleaseMatured";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::builder(ctx).auto_release(0).build();
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 1: rent_recipient
+    /// 4: receipt
+    /// 5: vault
+    /// 7: depositor_token_account
+    /// 14: allowed_mint
+    fn required_writable() -> &'static [usize] {
+        &[0, 1, 4, 5, 7, 14]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(11)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(13)
+    }
+
+    fn data_len() -> usize {
+        1
+    }
+}
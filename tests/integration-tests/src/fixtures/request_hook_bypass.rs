@@ -0,0 +1,97 @@
+use escrow_program_client::instructions::RequestHookBypassBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::{
+    fixtures::{BasketSetup, WithdrawSetup, DEFAULT_HOOK_BYPASS_GRACE_PERIOD},
+    utils::{find_hook_bypass_request_pda, TestContext, TEST_HOOK_ALLOW_ID},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct RequestHookBypassFixture;
+
+impl RequestHookBypassFixture {
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        Self::build_for_receipt(ctx, setup, setup.receipt_pda)
+    }
+
+    /// Requests a bypass for another receipt of the setup's depositor and mint
+    pub fn build_for_receipt(ctx: &TestContext, setup: &WithdrawSetup, receipt: Pubkey) -> TestInstruction {
+        let (hook_bypass_request_pda, bump) = find_hook_bypass_request_pda(&receipt);
+
+        let instruction = RequestHookBypassBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(receipt)
+            .hook_bypass_request(hook_bypass_request_pda)
+            .mint(setup.mint.pubkey())
+            .allowed_mint(setup.allowed_mint_pda)
+            .bump(bump)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: Self::INSTRUCTION_NAME }
+    }
+
+    /// Requests a bypass for a basket receipt, resolving the hook through the mint of leg `leg`
+    pub fn build_for_basket(
+        ctx: &TestContext,
+        setup: &BasketSetup,
+        basket_receipt: Pubkey,
+        leg: usize,
+    ) -> TestInstruction {
+        let (hook_bypass_request_pda, bump) = find_hook_bypass_request_pda(&basket_receipt);
+        let basket_mint = &setup.mints[leg];
+
+        let instruction = RequestHookBypassBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(basket_receipt)
+            .hook_bypass_request(hook_bypass_request_pda)
+            .mint(basket_mint.mint.pubkey())
+            .allowed_mint(basket_mint.allowed_mint_pda)
+            .bump(bump)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for RequestHookBypassFixture {
+    const INSTRUCTION_NAME: &'static str = "RequestHookBypass";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::builder(ctx)
+            .hook_program(TEST_HOOK_ALLOW_ID)
+            .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+            .build();
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: depositor (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 5: hook_bypass_request (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    fn data_len() -> usize {
+        2
+    }
+}
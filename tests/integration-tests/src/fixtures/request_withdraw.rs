@@ -0,0 +1,63 @@
+use escrow_program_client::instructions::RequestWithdrawBuilder;
+use solana_sdk::signature::Signer;
+
+use crate::{
+    fixtures::{WithdrawSetup, DEFAULT_COOLDOWN_PERIOD},
+    utils::{find_withdraw_request_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct RequestWithdrawFixture;
+
+impl RequestWithdrawFixture {
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        let (withdraw_request_pda, bump) = find_withdraw_request_pda(&setup.receipt_pda);
+
+        let instruction = RequestWithdrawBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .receipt(setup.receipt_pda)
+            .withdraw_request(withdraw_request_pda)
+            .bump(bump)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for RequestWithdrawFixture {
+    const INSTRUCTION_NAME: &'static str = "RequestWithdraw";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::builder(ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: depositor (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 4: receipt
+    /// 5: withdraw_request (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[4, 5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    fn data_len() -> usize {
+        2
+    }
+}
@@ -0,0 +1,60 @@
+use escrow_program_client::instructions::RevokeWithdrawDelegateBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::{
+    fixtures::WithdrawSetup,
+    utils::{find_withdraw_delegate_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct RevokeWithdrawDelegateFixture;
+
+impl RevokeWithdrawDelegateFixture {
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup) -> TestInstruction {
+        let (withdraw_delegate_pda, _) = find_withdraw_delegate_pda(&setup.receipt_pda);
+
+        let instruction = RevokeWithdrawDelegateBuilder::new()
+            .rent_recipient(ctx.payer.pubkey())
+            .depositor(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .withdraw_delegate(withdraw_delegate_pda)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for RevokeWithdrawDelegateFixture {
+    const INSTRUCTION_NAME: &'static str = "RevokeWithdrawDelegate";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::new(ctx);
+        setup.approve_withdraw_delegate(ctx, Pubkey::new_unique(), 0);
+        Self::build_with_setup(ctx, &setup)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: depositor
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: rent_recipient, 3: withdraw_delegate
+    fn required_writable() -> &'static [usize] {
+        &[0, 3]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(5)
+    }
+
+    fn data_len() -> usize {
+        1 // Just the discriminator
+    }
+}
@@ -20,6 +20,16 @@ impl SetHookFixture {
         escrow_pda: Pubkey,
         admin: Keypair,
         hook_program: Pubkey,
+    ) -> TestInstruction {
+        Self::build_with_flags(ctx, escrow_pda, admin, hook_program, 0)
+    }
+
+    pub fn build_with_flags(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        hook_program: Pubkey,
+        flags: u8,
     ) -> TestInstruction {
         let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
 
@@ -30,6 +40,7 @@ impl SetHookFixture {
             .extensions(extensions_pda)
             .extensions_bump(extensions_bump)
             .hook_program(Address::from(hook_program.to_bytes()))
+            .flags(flags)
             .instruction();
 
         TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
@@ -57,6 +68,7 @@ impl InstructionTestFixture for SetHookFixture {
             .extensions(extensions_pda)
             .extensions_bump(extensions_bump)
             .hook_program(Address::from(hook_program.to_bytes()))
+            .flags(0)
             .instruction();
 
         TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
@@ -83,6 +95,6 @@ impl InstructionTestFixture for SetHookFixture {
     }
 
     fn data_len() -> usize {
-        34
+        35
     }
 }
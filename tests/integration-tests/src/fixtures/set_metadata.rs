@@ -0,0 +1,91 @@
+use escrow_program_client::instructions::SetMetadataBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::{
+    fixtures::CreateEscrowFixture,
+    utils::{find_escrow_pda, find_extensions_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub const DEFAULT_METADATA_NAME: &str = "Payroll escrow";
+pub const DEFAULT_METADATA_URI: &str = "https://example.com/escrow.json";
+pub const DEFAULT_METADATA_EXTERNAL_REFERENCE: &[u8] = b"deal-42";
+
+pub struct SetMetadataFixture;
+
+impl SetMetadataFixture {
+    pub fn build_with_escrow(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        name: &str,
+        uri: &str,
+        external_reference: &[u8],
+    ) -> TestInstruction {
+        let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+        let instruction = SetMetadataBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .escrow(escrow_pda)
+            .extensions(extensions_pda)
+            .extensions_bump(extensions_bump)
+            .name(name.to_string())
+            .uri(uri.to_string())
+            .external_reference(external_reference.to_vec())
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for SetMetadataFixture {
+    const INSTRUCTION_NAME: &'static str = "SetMetadata";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+        let admin = escrow_ix.signers[0].insecure_clone();
+        let escrow_seed = escrow_ix.signers[1].pubkey();
+        escrow_ix.send_expect_success(ctx);
+
+        let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+        Self::build_with_escrow(
+            ctx,
+            escrow_pda,
+            admin,
+            DEFAULT_METADATA_NAME,
+            DEFAULT_METADATA_URI,
+            DEFAULT_METADATA_EXTERNAL_REFERENCE,
+        )
+    }
+
+    /// Account indices that must be signers:
+    /// 1: admin (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 3: extensions (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[3]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(4)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    /// discriminator + extensions_bump + name, uri and external_reference length prefixes
+    fn data_len() -> usize {
+        1 + 1 + 4 + 4 + 4
+    }
+}
@@ -0,0 +1,86 @@
+use escrow_program_client::instructions::SetMintLimitsBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::allow_mint::AllowMintSetup;
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::{find_extensions_pda, TestContext};
+
+pub struct SetMintLimitsSetup {
+    pub escrow_pda: Pubkey,
+    pub admin: Keypair,
+    pub mint_pubkey: Pubkey,
+    pub allowed_mint_pda: Pubkey,
+}
+
+impl SetMintLimitsSetup {
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let allow_mint_setup = AllowMintSetup::new(ctx);
+        allow_mint_setup.build_instruction(ctx).send_expect_success(ctx);
+
+        Self {
+            escrow_pda: allow_mint_setup.escrow_pda,
+            admin: allow_mint_setup.admin,
+            mint_pubkey: allow_mint_setup.mint_pubkey,
+            allowed_mint_pda: allow_mint_setup.allowed_mint_pda,
+        }
+    }
+
+    pub fn build_instruction(
+        &self,
+        _ctx: &TestContext,
+        max_total_deposits: u64,
+        min_deposit: u64,
+        max_deposit: u64,
+    ) -> TestInstruction {
+        let instruction = SetMintLimitsBuilder::new()
+            .admin(self.admin.pubkey())
+            .escrow(self.escrow_pda)
+            .extensions(find_extensions_pda(&self.escrow_pda).0)
+            .mint(self.mint_pubkey)
+            .allowed_mint(self.allowed_mint_pda)
+            .max_total_deposits(max_total_deposits)
+            .min_deposit(min_deposit)
+            .max_deposit(max_deposit)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![self.admin.insecure_clone()], name: "SetMintLimits" }
+    }
+}
+
+pub struct SetMintLimitsFixture;
+
+impl InstructionTestFixture for SetMintLimitsFixture {
+    const INSTRUCTION_NAME: &'static str = "SetMintLimits";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = SetMintLimitsSetup::new(ctx);
+        setup.build_instruction(ctx, 1_000_000, 10, 100_000)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: admin
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 4: allowed_mint (limits updated)
+    fn required_writable() -> &'static [usize] {
+        &[4]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        25 // discriminator (1) + max_total_deposits (8) + min_deposit (8) + max_deposit (8)
+    }
+}
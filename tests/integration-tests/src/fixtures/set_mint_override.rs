@@ -0,0 +1,109 @@
+use escrow_program_client::instructions::SetMintOverrideBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{AllowMintSetup, CHANGE_ACTION_SET};
+use crate::utils::extensions_utils::{EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_TIMELOCK};
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::{find_allowed_mint_pda, find_extensions_pda, TestContext};
+
+pub const DEFAULT_OVERRIDE_LOCK_DURATION: u64 = 7_200;
+
+pub struct SetMintOverrideFixture;
+
+impl SetMintOverrideFixture {
+    pub fn build_with_mint(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        mint: Pubkey,
+        action: u8,
+        extension_type: u16,
+        value: Vec<u8>,
+    ) -> TestInstruction {
+        let (allowed_mint_pda, _) = find_allowed_mint_pda(&escrow_pda, &mint);
+
+        let instruction = SetMintOverrideBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .admin(admin.pubkey())
+            .escrow(escrow_pda)
+            .extensions(find_extensions_pda(&escrow_pda).0)
+            .mint(mint)
+            .allowed_mint(allowed_mint_pda)
+            .action(action)
+            .extension_type(extension_type)
+            .value(value)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![admin], name: Self::INSTRUCTION_NAME }
+    }
+
+    /// Overrides the arbiter, with the new arbiter signing as the first remaining account
+    pub fn build_set_arbiter(
+        ctx: &mut TestContext,
+        escrow_pda: Pubkey,
+        admin: Keypair,
+        mint: Pubkey,
+        arbiter: Keypair,
+    ) -> TestInstruction {
+        let mut test_ix = Self::build_with_mint(
+            ctx,
+            escrow_pda,
+            admin,
+            mint,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_ARBITER,
+            arbiter.pubkey().to_bytes().to_vec(),
+        );
+        test_ix.instruction.accounts.push(AccountMeta::new_readonly(arbiter.pubkey(), true));
+        test_ix.signers.push(arbiter);
+        test_ix
+    }
+}
+
+impl InstructionTestFixture for SetMintOverrideFixture {
+    const INSTRUCTION_NAME: &'static str = "SetMintOverride";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = AllowMintSetup::new(ctx);
+        setup.build_instruction(ctx).send_expect_success(ctx);
+
+        Self::build_with_mint(
+            ctx,
+            setup.escrow_pda,
+            setup.admin,
+            setup.mint_pubkey,
+            CHANGE_ACTION_SET,
+            EXTENSION_TYPE_TIMELOCK,
+            DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+        )
+    }
+
+    /// Account indices that must be signers:
+    /// 1: admin (payer at 0 is handled separately by TestContext)
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 5: allowed_mint (payer at 0 is handled separately by TestContext)
+    fn required_writable() -> &'static [usize] {
+        &[5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    /// discriminator + action + extension_type + value length prefix + timelock value
+    fn data_len() -> usize {
+        1 + 1 + 2 + 4 + 8
+    }
+}
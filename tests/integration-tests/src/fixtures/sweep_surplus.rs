@@ -0,0 +1,96 @@
+use escrow_program_client::instructions::SweepSurplusBuilder;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::{DepositSetup, DEFAULT_DEPOSIT_AMOUNT};
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::TestContext;
+
+pub const DEFAULT_SURPLUS_AMOUNT: u64 = 250_000;
+
+pub struct SweepSurplusSetup {
+    pub escrow_pda: Pubkey,
+    pub admin: Keypair,
+    pub mint_pubkey: Pubkey,
+    pub allowed_mint_pda: Pubkey,
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl SweepSurplusSetup {
+    pub fn from_deposit_setup(ctx: &mut TestContext, setup: &DepositSetup) -> Self {
+        let destination = ctx.create_token_account(&setup.admin.pubkey(), &setup.mint.pubkey());
+
+        Self {
+            escrow_pda: setup.escrow_pda,
+            admin: setup.admin.insecure_clone(),
+            mint_pubkey: setup.mint.pubkey(),
+            allowed_mint_pda: setup.allowed_mint_pda,
+            vault: setup.vault,
+            destination,
+            token_program: setup.token_program,
+        }
+    }
+
+    /// Creates an escrow with one outstanding deposit and `DEFAULT_SURPLUS_AMOUNT` sent straight to the vault
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let deposit_setup = DepositSetup::new(ctx);
+        deposit_setup.build_instruction(ctx).send_expect_success(ctx);
+        ctx.set_token_balance(&deposit_setup.vault, DEFAULT_DEPOSIT_AMOUNT + DEFAULT_SURPLUS_AMOUNT);
+
+        Self::from_deposit_setup(ctx, &deposit_setup)
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        let instruction = SweepSurplusBuilder::new()
+            .admin(self.admin.pubkey())
+            .escrow(self.escrow_pda)
+            .mint(self.mint_pubkey)
+            .allowed_mint(self.allowed_mint_pda)
+            .vault(self.vault)
+            .destination(self.destination)
+            .token_program(self.token_program)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![self.admin.insecure_clone()], name: "SweepSurplus" }
+    }
+}
+
+pub struct SweepSurplusFixture;
+
+impl InstructionTestFixture for SweepSurplusFixture {
+    const INSTRUCTION_NAME: &'static str = "SweepSurplus";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = SweepSurplusSetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: admin
+    fn required_signers() -> &'static [usize] {
+        &[0]
+    }
+
+    /// Account indices that must be writable:
+    /// 4: vault (source)
+    /// 5: destination
+    fn required_writable() -> &'static [usize] {
+        &[4, 5]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(8)
+    }
+
+    fn data_len() -> usize {
+        1 // Just the discriminator
+    }
+}
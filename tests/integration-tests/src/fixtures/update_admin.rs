@@ -6,7 +6,7 @@ use solana_sdk::{
 
 use crate::{
     fixtures::CreateEscrowFixture,
-    utils::{find_escrow_pda, TestContext},
+    utils::{find_escrow_pda, find_extensions_pda, TestContext},
 };
 
 use crate::utils::traits::{InstructionTestFixture, TestInstruction};
@@ -24,6 +24,7 @@ impl UpdateAdminFixture {
             .admin(admin.pubkey())
             .new_admin(new_admin.pubkey())
             .escrow(escrow_pda)
+            .extensions(find_extensions_pda(&escrow_pda).0)
             .instruction();
 
         TestInstruction { instruction, signers: vec![admin, new_admin], name: Self::INSTRUCTION_NAME }
@@ -46,6 +47,7 @@ impl InstructionTestFixture for UpdateAdminFixture {
             .admin(admin.pubkey())
             .new_admin(new_admin.pubkey())
             .escrow(escrow_pda)
+            .extensions(find_extensions_pda(&escrow_pda).0)
             .instruction();
 
         TestInstruction { instruction, signers: vec![admin, new_admin], name: Self::INSTRUCTION_NAME }
@@ -69,7 +71,7 @@ impl InstructionTestFixture for UpdateAdminFixture {
     }
 
     fn current_program_index() -> Option<usize> {
-        Some(4)
+        Some(5)
     }
 
     fn data_len() -> usize {
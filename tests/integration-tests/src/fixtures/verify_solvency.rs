@@ -0,0 +1,78 @@
+use escrow_program_client::instructions::VerifySolvencyBuilder;
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+use crate::fixtures::DepositSetup;
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::TestContext;
+
+pub struct VerifySolvencySetup {
+    pub escrow_pda: Pubkey,
+    pub mint_pubkey: Pubkey,
+    pub allowed_mint_pda: Pubkey,
+    pub vault: Pubkey,
+    pub token_program: Pubkey,
+}
+
+impl VerifySolvencySetup {
+    pub fn from_deposit_setup(setup: &DepositSetup) -> Self {
+        Self {
+            escrow_pda: setup.escrow_pda,
+            mint_pubkey: setup.mint.pubkey(),
+            allowed_mint_pda: setup.allowed_mint_pda,
+            vault: setup.vault,
+            token_program: setup.token_program,
+        }
+    }
+
+    /// Creates an escrow with one outstanding deposit
+    pub fn new(ctx: &mut TestContext) -> Self {
+        let deposit_setup = DepositSetup::new(ctx);
+        deposit_setup.build_instruction(ctx).send_expect_success(ctx);
+        Self::from_deposit_setup(&deposit_setup)
+    }
+
+    pub fn build_instruction(&self, _ctx: &TestContext) -> TestInstruction {
+        let instruction = VerifySolvencyBuilder::new()
+            .escrow(self.escrow_pda)
+            .mint(self.mint_pubkey)
+            .allowed_mint(self.allowed_mint_pda)
+            .vault(self.vault)
+            .token_program(self.token_program)
+            .instruction();
+
+        TestInstruction { instruction, signers: vec![], name: "VerifySolvency" }
+    }
+}
+
+pub struct VerifySolvencyFixture;
+
+impl InstructionTestFixture for VerifySolvencyFixture {
+    const INSTRUCTION_NAME: &'static str = "VerifySolvency";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = VerifySolvencySetup::new(ctx);
+        setup.build_instruction(ctx)
+    }
+
+    /// Permissionless - no signers required
+    fn required_signers() -> &'static [usize] {
+        &[]
+    }
+
+    /// Read-only instruction - no writable accounts
+    fn required_writable() -> &'static [usize] {
+        &[]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        1 // Just the discriminator
+    }
+}
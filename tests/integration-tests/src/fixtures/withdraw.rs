@@ -1,17 +1,28 @@
-use escrow_program_client::instructions::{
-    AddTimelockBuilder, AllowMintBuilder, CreatesEscrowBuilder, DepositBuilder, WithdrawBuilder,
+use escrow_program_client::{
+    instructions::{AddTimelockBuilder, AllowMintBuilder, CreatesEscrowBuilder, DepositBuilder, WithdrawBuilder},
+    ESCROW_PROGRAM_ID,
 };
 use solana_sdk::{
+    account::Account,
     instruction::AccountMeta,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
-use crate::fixtures::{SetArbiterFixture, SetHookFixture, DEFAULT_DEPOSIT_AMOUNT};
+use crate::fixtures::{
+    ApproveWithdrawDelegateFixture, RequestHookBypassFixture, RequestWithdrawFixture, SetArbiterFixture,
+    SetAutoReleaseFixture, SetCooldownFixture, SetHookBypassFixture, SetHookFixture, SetRefundAuthorityFixture,
+    DEFAULT_DEPOSIT_AMOUNT,
+};
+use crate::utils::extensions_utils::{find_extension_offset, EXTENSION_TYPE_HOOK};
 use crate::utils::traits::{InstructionTestFixture, TestInstruction};
-use crate::utils::{find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, find_receipt_pda, TestContext};
+use crate::utils::{
+    find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, find_hook_bypass_request_pda, find_legacy_receipt_pda,
+    find_receipt_counter_pda, find_receipt_pda, find_withdraw_delegate_pda, find_withdraw_request_pda, TestContext,
+};
 
 pub struct WithdrawSetup {
     pub escrow_pda: Pubkey,
@@ -19,15 +30,18 @@ pub struct WithdrawSetup {
     pub escrow_seed: Keypair,
     pub extensions_pda: Pubkey,
     pub mint: Keypair,
+    pub allowed_mint_pda: Pubkey,
     pub vault: Pubkey,
     pub depositor: Keypair,
     pub depositor_token_account: Pubkey,
-    pub receipt_seed: Keypair,
+    pub receipt_counter: Pubkey,
     pub receipt_pda: Pubkey,
+    pub rent_recipient: Pubkey,
     pub admin: Keypair,
     pub token_program: Pubkey,
     pub hook_program: Option<Pubkey>,
     pub arbiter: Option<Keypair>,
+    pub hook_bypass_request: Option<Pubkey>,
 }
 
 impl WithdrawSetup {
@@ -83,32 +97,177 @@ impl WithdrawSetup {
         self.hook_program = Some(hook_program);
     }
 
+    /// Overwrites the stored hook program directly, bypassing admin checks and the deposit-time hook.
+    pub fn replace_hook_program(&mut self, ctx: &mut TestContext, hook_program: Pubkey) {
+        let mut extensions_account = ctx.get_account(&self.extensions_pda).expect("Extensions account should exist");
+        let offset =
+            find_extension_offset(&extensions_account.data, EXTENSION_TYPE_HOOK).expect("Hook extension not found");
+        extensions_account.data[offset..offset + 32].copy_from_slice(&hook_program.to_bytes());
+        ctx.svm.set_account(self.extensions_pda, extensions_account).unwrap();
+        self.hook_program = Some(hook_program);
+    }
+
+    pub fn request_hook_bypass(&mut self, ctx: &mut TestContext) -> Pubkey {
+        let test_ix = RequestHookBypassFixture::build_with_setup(ctx, self);
+        test_ix.send_expect_success(ctx);
+        let (hook_bypass_request_pda, _) = find_hook_bypass_request_pda(&self.receipt_pda);
+        self.hook_bypass_request = Some(hook_bypass_request_pda);
+        hook_bypass_request_pda
+    }
+
+    pub fn request_withdraw(&self, ctx: &mut TestContext) -> Pubkey {
+        let test_ix = RequestWithdrawFixture::build_with_setup(ctx, self);
+        test_ix.send_expect_success(ctx);
+        let (withdraw_request_pda, _) = find_withdraw_request_pda(&self.receipt_pda);
+        withdraw_request_pda
+    }
+
+    /// Deposits another receipt for the same depositor and mint, returning its PDA
+    pub fn deposit_receipt(&self, ctx: &mut TestContext, nonce: u64) -> Pubkey {
+        let (receipt_pda, bump) =
+            find_receipt_pda(&self.escrow_pda, &self.depositor.pubkey(), &self.mint.pubkey(), nonce);
+
+        let mut deposit_builder = DepositBuilder::new();
+        deposit_builder
+            .payer(ctx.payer.pubkey())
+            .depositor(self.depositor.pubkey())
+            .escrow(self.escrow_pda)
+            .allowed_mint(self.allowed_mint_pda)
+            .receipt_counter(self.receipt_counter)
+            .receipt(receipt_pda)
+            .vault(self.vault)
+            .depositor_token_account(self.depositor_token_account)
+            .mint(self.mint.pubkey())
+            .token_program(self.token_program)
+            .extensions(self.extensions_pda)
+            .bump(bump)
+            .amount(DEFAULT_DEPOSIT_AMOUNT)
+            .rent_recipient(self.rent_recipient)
+            .proof(vec![]);
+
+        if let Some(hook_id) = self.hook_program {
+            deposit_builder.add_remaining_account(AccountMeta::new_readonly(hook_id, false));
+        }
+
+        ctx.send_transaction(deposit_builder.instruction(), &[&self.depositor]).unwrap();
+        receipt_pda
+    }
+
+    /// Replaces the deposited receipt with a version 1 receipt for the same deposit, returning its PDA
+    ///
+    /// The account is written byte for byte in the version 1 layout and derived from a
+    /// `receipt_seed`, as receipts were before the nonce and rent recipient were added.
+    pub fn replace_with_legacy_receipt(&mut self, ctx: &mut TestContext) -> Pubkey {
+        let receipt_account = ctx.get_account(&self.receipt_pda).expect("Receipt account should exist");
+        let amount = &receipt_account.data[114..122];
+        let deposited_at = &receipt_account.data[122..130];
+
+        let receipt_seed = Pubkey::new_unique();
+        let (legacy_receipt_pda, bump) =
+            find_legacy_receipt_pda(&self.escrow_pda, &self.depositor.pubkey(), &self.mint.pubkey(), &receipt_seed);
+
+        let mut data = vec![3u8, 1u8, bump]; // discriminator, version, bump
+        data.extend_from_slice(&[0u8; 7]); // padding
+        data.extend_from_slice(self.escrow_pda.as_ref());
+        data.extend_from_slice(self.depositor.pubkey().as_ref());
+        data.extend_from_slice(self.mint.pubkey().as_ref());
+        data.extend_from_slice(receipt_seed.as_ref());
+        data.extend_from_slice(amount);
+        data.extend_from_slice(deposited_at);
+        assert_eq!(data.len(), 154);
+
+        let lamports = ctx.svm.minimum_balance_for_rent_exemption(data.len());
+        ctx.svm
+            .set_account(legacy_receipt_pda, Account { lamports, data, owner: ESCROW_PROGRAM_ID, ..Account::default() })
+            .unwrap();
+        ctx.svm.set_account(self.receipt_pda, Account::default()).unwrap();
+
+        self.receipt_pda = legacy_receipt_pda;
+        legacy_receipt_pda
+    }
+
     pub fn build_instruction(&self, ctx: &TestContext) -> TestInstruction {
-        self.build_instruction_with_rent_recipient(ctx, ctx.payer.pubkey())
+        self.build_instruction_with_rent_recipient(ctx, self.rent_recipient)
+    }
+
+    pub fn build_instruction_with_rent_recipient(&self, ctx: &TestContext, rent_recipient: Pubkey) -> TestInstruction {
+        self.build_instruction_with_destination(ctx, rent_recipient, self.depositor.pubkey())
+    }
+
+    /// Builds a withdraw paying out to `destination`'s ATA, which is created if it does not exist
+    pub fn build_instruction_with_destination(
+        &self,
+        ctx: &TestContext,
+        rent_recipient: Pubkey,
+        destination: Pubkey,
+    ) -> TestInstruction {
+        self.build_withdraw(ctx, &self.depositor, rent_recipient, destination, None)
+    }
+
+    /// Builds a withdraw signed by `delegate`, passing the receipt's withdraw delegate PDA
+    pub fn build_delegated_instruction(
+        &self,
+        ctx: &TestContext,
+        delegate: &Keypair,
+        destination: Pubkey,
+    ) -> TestInstruction {
+        let (withdraw_delegate_pda, _) = find_withdraw_delegate_pda(&self.receipt_pda);
+        self.build_withdraw(ctx, delegate, self.rent_recipient, destination, Some(withdraw_delegate_pda))
     }
 
-    pub fn build_instruction_with_rent_recipient(&self, _ctx: &TestContext, rent_recipient: Pubkey) -> TestInstruction {
+    pub fn approve_withdraw_delegate(&self, ctx: &mut TestContext, delegate: Pubkey, expires_at: i64) -> Pubkey {
+        let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(ctx, self, delegate, expires_at);
+        test_ix.send_expect_success(ctx);
+        let (withdraw_delegate_pda, _) = find_withdraw_delegate_pda(&self.receipt_pda);
+        withdraw_delegate_pda
+    }
+
+    fn build_withdraw(
+        &self,
+        ctx: &TestContext,
+        withdrawer: &Keypair,
+        rent_recipient: Pubkey,
+        destination: Pubkey,
+        withdraw_delegate: Option<Pubkey>,
+    ) -> TestInstruction {
+        let destination_token_account = if destination == self.depositor.pubkey() {
+            self.depositor_token_account
+        } else {
+            get_associated_token_address_with_program_id(&destination, &self.mint.pubkey(), &self.token_program)
+        };
+
         let mut builder = WithdrawBuilder::new();
         builder
+            .payer(ctx.payer.pubkey())
             .rent_recipient(rent_recipient)
-            .withdrawer(self.depositor.pubkey())
+            .withdrawer(withdrawer.pubkey())
             .escrow(self.escrow_pda)
             .extensions(self.extensions_pda)
             .receipt(self.receipt_pda)
             .vault(self.vault)
-            .withdrawer_token_account(self.depositor_token_account)
+            .destination(destination)
+            .destination_token_account(destination_token_account)
             .mint(self.mint.pubkey())
+            .allowed_mint(self.allowed_mint_pda)
             .token_program(self.token_program);
 
-        let mut signers = vec![self.depositor.insecure_clone()];
+        let mut signers = vec![withdrawer.insecure_clone()];
+
+        // Withdraw delegate must be first in remaining_accounts (before arbiter and hook_program)
+        if let Some(withdraw_delegate) = withdraw_delegate {
+            builder.add_remaining_account(AccountMeta::new(withdraw_delegate, false));
+        }
 
-        // Arbiter must be first in remaining_accounts (before hook_program)
+        // Arbiter comes before hook_program in remaining_accounts
         if let Some(ref arbiter) = self.arbiter {
             builder.add_remaining_account(AccountMeta::new_readonly(arbiter.pubkey(), true));
             signers.push(arbiter.insecure_clone());
         }
 
-        if let Some(hook_program) = self.hook_program {
+        // A hook bypass request takes the place of the hook program
+        if let Some(hook_bypass_request) = self.hook_bypass_request {
+            builder.add_remaining_account(AccountMeta::new(hook_bypass_request, false));
+        } else if let Some(hook_program) = self.hook_program {
             builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
         }
 
@@ -123,12 +282,30 @@ pub struct WithdrawSetupBuilder<'a> {
     token_program: Pubkey,
     timelock: Option<u64>,
     hook_program: Option<Pubkey>,
+    hook_flags: u8,
+    hook_bypass: Option<u64>,
+    auto_release: Option<u64>,
+    refund_authority: Option<u8>,
+    cooldown: Option<u64>,
     arbiter: bool,
+    rent_recipient: Option<Pubkey>,
 }
 
 impl<'a> WithdrawSetupBuilder<'a> {
     fn new(ctx: &'a mut TestContext) -> Self {
-        Self { ctx, token_program: TOKEN_PROGRAM_ID, timelock: None, hook_program: None, arbiter: false }
+        Self {
+            ctx,
+            token_program: TOKEN_PROGRAM_ID,
+            timelock: None,
+            hook_program: None,
+            hook_flags: 0,
+            hook_bypass: None,
+            auto_release: None,
+            refund_authority: None,
+            cooldown: None,
+            arbiter: false,
+            rent_recipient: None,
+        }
     }
 
     pub fn token_2022(mut self) -> Self {
@@ -151,11 +328,42 @@ impl<'a> WithdrawSetupBuilder<'a> {
         self
     }
 
+    pub fn hook_flags(mut self, flags: u8) -> Self {
+        self.hook_flags = flags;
+        self
+    }
+
+    pub fn hook_bypass(mut self, grace_period: u64) -> Self {
+        self.hook_bypass = Some(grace_period);
+        self
+    }
+
+    pub fn auto_release(mut self, release_delay: u64) -> Self {
+        self.auto_release = Some(release_delay);
+        self
+    }
+
+    pub fn refund_authority(mut self, flags: u8) -> Self {
+        self.refund_authority = Some(flags);
+        self
+    }
+
+    pub fn cooldown(mut self, cooldown_period: u64) -> Self {
+        self.cooldown = Some(cooldown_period);
+        self
+    }
+
     pub fn arbiter(mut self) -> Self {
         self.arbiter = true;
         self
     }
 
+    /// Designates the receipt's rent recipient at deposit (defaults to the payer)
+    pub fn rent_recipient(mut self, rent_recipient: Pubkey) -> Self {
+        self.rent_recipient = Some(rent_recipient);
+        self
+    }
+
     pub fn build(self) -> WithdrawSetup {
         let admin = self.ctx.create_funded_keypair();
         let escrow_seed = Keypair::new();
@@ -187,7 +395,37 @@ impl<'a> WithdrawSetupBuilder<'a> {
         }
 
         if let Some(hook_id) = self.hook_program {
-            let test_ix = SetHookFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), hook_id);
+            let test_ix = SetHookFixture::build_with_flags(
+                self.ctx,
+                escrow_pda,
+                admin.insecure_clone(),
+                hook_id,
+                self.hook_flags,
+            );
+            test_ix.send_expect_success(self.ctx);
+        }
+
+        if let Some(grace_period) = self.hook_bypass {
+            let test_ix =
+                SetHookBypassFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), grace_period);
+            test_ix.send_expect_success(self.ctx);
+        }
+
+        if let Some(release_delay) = self.auto_release {
+            let test_ix =
+                SetAutoReleaseFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), release_delay);
+            test_ix.send_expect_success(self.ctx);
+        }
+
+        if let Some(flags) = self.refund_authority {
+            let test_ix =
+                SetRefundAuthorityFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), flags);
+            test_ix.send_expect_success(self.ctx);
+        }
+
+        if let Some(cooldown_period) = self.cooldown {
+            let test_ix =
+                SetCooldownFixture::build_with_escrow(self.ctx, escrow_pda, admin.insecure_clone(), cooldown_period);
             test_ix.send_expect_success(self.ctx);
         }
 
@@ -248,9 +486,9 @@ impl<'a> WithdrawSetupBuilder<'a> {
             );
         }
 
-        let receipt_seed = Keypair::new();
-        let (receipt_pda, bump) =
-            find_receipt_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey(), &receipt_seed.pubkey());
+        let (receipt_counter, _) = find_receipt_counter_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey());
+        let (receipt_pda, bump) = find_receipt_pda(&escrow_pda, &depositor.pubkey(), &mint.pubkey(), 0);
+        let rent_recipient = self.rent_recipient.unwrap_or(self.ctx.payer.pubkey());
 
         let mut deposit_builder = DepositBuilder::new();
         deposit_builder
@@ -258,7 +496,7 @@ impl<'a> WithdrawSetupBuilder<'a> {
             .depositor(depositor.pubkey())
             .escrow(escrow_pda)
             .allowed_mint(allowed_mint_pda)
-            .receipt_seed(receipt_seed.pubkey())
+            .receipt_counter(receipt_counter)
             .receipt(receipt_pda)
             .vault(vault)
             .depositor_token_account(depositor_token_account)
@@ -266,14 +504,16 @@ impl<'a> WithdrawSetupBuilder<'a> {
             .token_program(token_program)
             .extensions(extensions_pda)
             .bump(bump)
-            .amount(DEFAULT_DEPOSIT_AMOUNT);
+            .amount(DEFAULT_DEPOSIT_AMOUNT)
+            .rent_recipient(rent_recipient)
+            .proof(vec![]);
 
         if let Some(hook_id) = self.hook_program {
             deposit_builder.add_remaining_account(AccountMeta::new_readonly(hook_id, false));
         }
 
         let deposit_ix = deposit_builder.instruction();
-        self.ctx.send_transaction(deposit_ix, &[&depositor, &receipt_seed]).unwrap();
+        self.ctx.send_transaction(deposit_ix, &[&depositor]).unwrap();
 
         WithdrawSetup {
             escrow_pda,
@@ -281,15 +521,18 @@ impl<'a> WithdrawSetupBuilder<'a> {
             escrow_seed,
             extensions_pda,
             mint,
+            allowed_mint_pda,
             vault,
             depositor,
             depositor_token_account,
-            receipt_seed,
+            receipt_counter,
             receipt_pda,
+            rent_recipient,
             admin,
             token_program,
             hook_program: self.hook_program,
             arbiter,
+            hook_bypass_request: None,
         }
     }
 }
@@ -306,17 +549,20 @@ impl InstructionTestFixture for WithdrawFixture {
 
     /// Account indices that must be signers:
     /// 1: withdrawer
+    /// 13: payer (handled by TestContext)
     fn required_signers() -> &'static [usize] {
-        &[1]
+        &[1, 13]
     }
 
     /// Account indices that must be writable:
     /// 0: rent_recipient
     /// 4: receipt
     /// 5: vault
-    /// 6: withdrawer_token_account
+    /// 6: destination_token_account
+    /// 12: allowed_mint
+    /// 13: payer (handled by TestContext)
     fn required_writable() -> &'static [usize] {
-        &[0, 4, 5, 6]
+        &[0, 4, 5, 6, 12, 13]
     }
 
     fn system_program_index() -> Option<usize> {
@@ -0,0 +1,103 @@
+use escrow_program_client::instructions::WithdrawBasketBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::fixtures::BasketSetup;
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+use crate::utils::TestContext;
+
+pub struct WithdrawBasketFixture;
+
+impl WithdrawBasketFixture {
+    /// Builds a withdrawal passing the setup's arbiter and hook for every leg
+    pub fn build_with_setup(setup: &BasketSetup, basket_receipt: Pubkey) -> TestInstruction {
+        let mut leg_auth_accounts = Vec::with_capacity(setup.mints.len());
+        for _ in &setup.mints {
+            // Each leg's arbiter comes before its hook_program
+            let mut accounts = Vec::new();
+            if let Some(ref arbiter) = setup.arbiter {
+                accounts.push(AccountMeta::new_readonly(arbiter.pubkey(), true));
+            }
+            if let Some(hook_program) = setup.hook_program {
+                accounts.push(AccountMeta::new_readonly(hook_program, false));
+            }
+            leg_auth_accounts.push(accounts);
+        }
+
+        let extra_signers = setup.arbiter.iter().map(|arbiter| arbiter.insecure_clone()).collect();
+        Self::build_with_leg_accounts(setup, basket_receipt, &leg_auth_accounts, vec![], extra_signers)
+    }
+
+    /// Builds a withdrawal with the given arbiter and hook accounts after each leg's accounts
+    pub fn build_with_leg_accounts(
+        setup: &BasketSetup,
+        basket_receipt: Pubkey,
+        leg_auth_accounts: &[Vec<AccountMeta>],
+        hook_account_counts: Vec<u8>,
+        extra_signers: Vec<Keypair>,
+    ) -> TestInstruction {
+        let mut builder = WithdrawBasketBuilder::new();
+        builder
+            .rent_recipient(setup.rent_recipient)
+            .withdrawer(setup.depositor.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .basket_receipt(basket_receipt)
+            .hook_account_counts(hook_account_counts);
+
+        let mut signers = vec![setup.depositor.insecure_clone()];
+        signers.extend(extra_signers);
+
+        // Leg accounts come first in remaining_accounts
+        for basket_mint in &setup.mints {
+            builder.add_remaining_accounts(&basket_mint.leg_accounts());
+        }
+
+        for accounts in leg_auth_accounts {
+            builder.add_remaining_accounts(accounts);
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers, name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for WithdrawBasketFixture {
+    const INSTRUCTION_NAME: &'static str = "WithdrawBasket";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = BasketSetup::new(ctx);
+        let basket_receipt = setup.deposit(ctx, 0);
+        Self::build_with_setup(&setup, basket_receipt)
+    }
+
+    /// Account indices that must be signers:
+    /// 1: withdrawer
+    fn required_signers() -> &'static [usize] {
+        &[1]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: rent_recipient
+    /// 4: basket_receipt
+    /// 9: first leg allowed_mint
+    /// 10: first leg vault
+    /// 11: first leg depositor_token_account
+    fn required_writable() -> &'static [usize] {
+        &[0, 4, 9, 10, 11]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        None
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(6)
+    }
+
+    fn data_len() -> usize {
+        1 + 4 // discriminator + hook_account_counts length
+    }
+}
@@ -0,0 +1,143 @@
+use escrow_program_client::instructions::WithdrawManyBuilder;
+use solana_sdk::{
+    instruction::AccountMeta,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+use crate::{
+    fixtures::{RequestHookBypassFixture, WithdrawSetup},
+    utils::{find_hook_bypass_request_pda, TestContext},
+};
+
+use crate::utils::traits::{InstructionTestFixture, TestInstruction};
+
+pub struct WithdrawManyFixture;
+
+impl WithdrawManyFixture {
+    /// Deposits `count - 1` more receipts on top of the setup's first one, returning all of them
+    pub fn deposit_receipts(ctx: &mut TestContext, setup: &WithdrawSetup, count: u64) -> Vec<Pubkey> {
+        let mut receipts = vec![setup.receipt_pda];
+        receipts.extend((1..count).map(|nonce| setup.deposit_receipt(ctx, nonce)));
+        receipts
+    }
+
+    /// Requests a hook bypass for each receipt, returning the request PDAs
+    pub fn request_hook_bypasses(ctx: &mut TestContext, setup: &WithdrawSetup, receipts: &[Pubkey]) -> Vec<Pubkey> {
+        receipts
+            .iter()
+            .map(|receipt| {
+                RequestHookBypassFixture::build_for_receipt(ctx, setup, *receipt).send_expect_success(ctx);
+                find_hook_bypass_request_pda(receipt).0
+            })
+            .collect()
+    }
+
+    pub fn build_with_setup(ctx: &TestContext, setup: &WithdrawSetup, receipts: &[Pubkey]) -> TestInstruction {
+        Self::build_with_withdrawer(ctx, setup, &setup.depositor, receipts)
+    }
+
+    pub fn build_with_withdrawer(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        withdrawer: &Keypair,
+        receipts: &[Pubkey],
+    ) -> TestInstruction {
+        Self::build(ctx, setup, withdrawer, receipts, &[])
+    }
+
+    /// Passes the hook bypass requests between the arbiter and the hook program
+    pub fn build_with_hook_bypass_requests(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        receipts: &[Pubkey],
+        hook_bypass_requests: &[Pubkey],
+    ) -> TestInstruction {
+        Self::build(ctx, setup, &setup.depositor, receipts, hook_bypass_requests)
+    }
+
+    fn build(
+        ctx: &TestContext,
+        setup: &WithdrawSetup,
+        withdrawer: &Keypair,
+        receipts: &[Pubkey],
+        hook_bypass_requests: &[Pubkey],
+    ) -> TestInstruction {
+        let mut builder = WithdrawManyBuilder::new();
+        builder
+            .payer(ctx.payer.pubkey())
+            .rent_recipient(setup.rent_recipient)
+            .withdrawer(withdrawer.pubkey())
+            .escrow(setup.escrow_pda)
+            .extensions(setup.extensions_pda)
+            .vault(setup.vault)
+            .destination(setup.depositor.pubkey())
+            .destination_token_account(setup.depositor_token_account)
+            .mint(setup.mint.pubkey())
+            .allowed_mint(setup.allowed_mint_pda)
+            .token_program(setup.token_program)
+            .receipt_count(receipts.len() as u8);
+
+        let mut signers = vec![withdrawer.insecure_clone()];
+
+        // Receipts come first in remaining_accounts
+        for receipt in receipts {
+            builder.add_remaining_account(AccountMeta::new(*receipt, false));
+        }
+
+        // Arbiter comes before hook_program in remaining_accounts
+        if let Some(ref arbiter) = setup.arbiter {
+            builder.add_remaining_account(AccountMeta::new_readonly(arbiter.pubkey(), true));
+            signers.push(arbiter.insecure_clone());
+        }
+
+        for hook_bypass_request in hook_bypass_requests {
+            builder.add_remaining_account(AccountMeta::new(*hook_bypass_request, false));
+        }
+
+        if let Some(hook_program) = setup.hook_program {
+            builder.add_remaining_account(AccountMeta::new_readonly(hook_program, false));
+        }
+
+        TestInstruction { instruction: builder.instruction(), signers, name: Self::INSTRUCTION_NAME }
+    }
+}
+
+impl InstructionTestFixture for WithdrawManyFixture {
+    const INSTRUCTION_NAME: &'static str = "WithdrawMany";
+
+    fn build_valid(ctx: &mut TestContext) -> TestInstruction {
+        let setup = WithdrawSetup::new(ctx);
+        let receipts = Self::deposit_receipts(ctx, &setup, 2);
+        Self::build_with_setup(ctx, &setup, &receipts)
+    }
+
+    /// Account indices that must be signers:
+    /// 0: payer (handled by TestContext)
+    /// 2: withdrawer
+    fn required_signers() -> &'static [usize] {
+        &[0, 2]
+    }
+
+    /// Account indices that must be writable:
+    /// 0: payer (handled by TestContext)
+    /// 1: rent_recipient
+    /// 5: vault
+    /// 7: destination_token_account
+    /// 14: allowed_mint
+    fn required_writable() -> &'static [usize] {
+        &[0, 1, 5, 7, 14]
+    }
+
+    fn system_program_index() -> Option<usize> {
+        Some(11)
+    }
+
+    fn current_program_index() -> Option<usize> {
+        Some(13)
+    }
+
+    fn data_len() -> usize {
+        2 // discriminator + receipt_count
+    }
+}
@@ -6,24 +6,82 @@ mod test_add_timelock;
 #[cfg(test)]
 mod test_allow_mint;
 #[cfg(test)]
+mod test_approve_withdraw_delegate;
+#[cfg(test)]
 mod test_block_mint;
 #[cfg(test)]
 mod test_block_token_extension;
 #[cfg(test)]
+mod test_cancel_queued_change;
+#[cfg(test)]
+mod test_claim_withdraw;
+#[cfg(test)]
 mod test_create_escrow;
 #[cfg(test)]
+mod test_create_escrow_with_config;
+#[cfg(test)]
+mod test_create_escrow_with_seed;
+#[cfg(test)]
 mod test_deposit;
 #[cfg(test)]
+mod test_deposit_basket;
+#[cfg(test)]
+mod test_execute_queued_change;
+#[cfg(test)]
+mod test_force_refund;
+#[cfg(test)]
+mod test_lock_extension;
+#[cfg(test)]
+mod test_lock_mint_list;
+#[cfg(test)]
+mod test_queue_change;
+#[cfg(test)]
+mod test_release_matured;
+#[cfg(test)]
 mod test_remove_extension;
 #[cfg(test)]
+mod test_request_hook_bypass;
+#[cfg(test)]
+mod test_request_withdraw;
+#[cfg(test)]
+mod test_revoke_withdraw_delegate;
+#[cfg(test)]
 mod test_set_arbiter;
 #[cfg(test)]
+mod test_set_auto_release;
+#[cfg(test)]
+mod test_set_cooldown;
+#[cfg(test)]
+mod test_set_depositor_allowlist;
+#[cfg(test)]
+mod test_set_governance_delay;
+#[cfg(test)]
 mod test_set_hook;
 #[cfg(test)]
+mod test_set_hook_bypass;
+#[cfg(test)]
 mod test_set_immutable;
 #[cfg(test)]
+mod test_set_metadata;
+#[cfg(test)]
+mod test_set_mint_limits;
+#[cfg(test)]
+mod test_set_mint_override;
+#[cfg(test)]
+mod test_set_refund_authority;
+#[cfg(test)]
+mod test_set_roles;
+#[cfg(test)]
+mod test_sweep_surplus;
+#[cfg(test)]
 mod test_unblock_token_extension;
 #[cfg(test)]
 mod test_update_admin;
 #[cfg(test)]
+mod test_verify_solvency;
+#[cfg(test)]
 mod test_withdraw;
+#[cfg(test)]
+mod test_withdraw_basket;
+#[cfg(test)]
+mod test_withdraw_many;
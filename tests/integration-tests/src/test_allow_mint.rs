@@ -1,10 +1,10 @@
 use crate::{
     fixtures::{AllowMintFixture, AllowMintSetup},
     utils::{
-        assert_account_exists, assert_allowed_mint_account, assert_escrow_error, assert_instruction_error,
-        find_allowed_mint_pda, find_noncanonical_program_address, test_missing_signer, test_not_writable,
-        test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext,
-        RANDOM_PUBKEY,
+        assert_account_exists, assert_allowed_mint_account, assert_allowed_mint_opening_balance, assert_escrow_error,
+        assert_instruction_error, find_allowed_mint_pda, find_noncanonical_program_address, test_missing_signer,
+        test_not_writable, test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture,
+        TestContext, RANDOM_PUBKEY,
     },
 };
 use escrow_program_client::instructions::{AllowMintBuilder, SetImmutableBuilder};
@@ -182,6 +182,7 @@ fn test_allow_mint_success() {
 
     assert_account_exists(&ctx, &setup.allowed_mint_pda);
     assert_allowed_mint_account(&ctx, &setup.allowed_mint_pda, setup.allowed_mint_bump);
+    assert_allowed_mint_opening_balance(&ctx, &setup.allowed_mint_pda, 0);
 }
 
 #[test]
@@ -0,0 +1,177 @@
+use crate::{
+    fixtures::{ApproveWithdrawDelegateFixture, WithdrawSetup, DEFAULT_COOLDOWN_PERIOD},
+    utils::{
+        assert_escrow_error, assert_instruction_error, find_withdraw_delegate_pda, test_empty_data,
+        test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account, test_wrong_current_program,
+        test_wrong_owner, test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext,
+    },
+};
+use escrow_program_client::accounts::WithdrawDelegate;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_approve_withdraw_delegate_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ApproveWithdrawDelegateFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_withdraw_delegate_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ApproveWithdrawDelegateFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<ApproveWithdrawDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ApproveWithdrawDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ApproveWithdrawDelegateFixture>(&mut ctx, 6, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_approve_withdraw_delegate_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ApproveWithdrawDelegateFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ApproveWithdrawDelegateFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<ApproveWithdrawDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<ApproveWithdrawDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_invalid_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = ApproveWithdrawDelegateFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_sub(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_approve_withdraw_delegate_wrong_depositor() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let impostor = ctx.create_funded_keypair();
+    let mut test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, impostor.pubkey(), 0)
+        .with_account_at(1, impostor.pubkey());
+    test_ix.instruction.accounts[1].is_signer = true;
+    test_ix.signers = vec![impostor];
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_default_delegate_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, Pubkey::default(), 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_depositor_as_delegate_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, setup.depositor.pubkey(), 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_past_expiry_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let now = ctx.get_current_timestamp();
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, Pubkey::new_unique(), now);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::WithdrawDelegateExpired);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    setup.approve_withdraw_delegate(&mut ctx, Pubkey::new_unique(), 0);
+
+    ctx.warp_to_slot(2);
+
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, Pubkey::new_unique(), 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::AccountAlreadyInitialized);
+}
+
+#[test]
+fn test_approve_withdraw_delegate_requested_receipt_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, Pubkey::new_unique(), 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::WithdrawRequestPending);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_approve_withdraw_delegate_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let delegate = Pubkey::new_unique();
+    let expires_at = ctx.get_current_timestamp() + 3600;
+    let test_ix = ApproveWithdrawDelegateFixture::build_with_setup(&ctx, &setup, delegate, expires_at);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (withdraw_delegate_pda, bump) = find_withdraw_delegate_pda(&setup.receipt_pda);
+    let account = ctx.get_account(&withdraw_delegate_pda).expect("Withdraw delegate should exist");
+    let withdraw_delegate = WithdrawDelegate::from_bytes(&account.data).expect("Should deserialize withdraw delegate");
+
+    assert_eq!(withdraw_delegate.bump, bump);
+    assert_eq!(withdraw_delegate.escrow, setup.escrow_pda);
+    assert_eq!(withdraw_delegate.receipt, setup.receipt_pda);
+    assert_eq!(withdraw_delegate.depositor, setup.depositor.pubkey());
+    assert_eq!(withdraw_delegate.delegate, delegate);
+    assert_eq!(withdraw_delegate.expires_at, expires_at);
+}
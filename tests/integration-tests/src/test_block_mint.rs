@@ -1,9 +1,12 @@
 use crate::{
-    fixtures::{AllowMintSetup, BlockMintFixture, BlockMintSetup},
+    fixtures::{
+        AllowMintSetup, BlockMintFixture, BlockMintSetup, SetMintOverrideFixture, WithdrawSetup, CHANGE_ACTION_SET,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_TIMELOCK,
     utils::{
         assert_account_exists, assert_account_not_exists, assert_escrow_error, assert_instruction_error,
-        find_allowed_mint_pda, test_missing_signer, test_not_writable, test_wrong_current_program, EscrowError,
-        InstructionTestFixture, TestContext, TestInstruction, RANDOM_PUBKEY,
+        find_allowed_mint_pda, find_extensions_pda, test_missing_signer, test_not_writable, test_wrong_current_program,
+        EscrowError, InstructionTestFixture, TestContext, TestInstruction, RANDOM_PUBKEY,
     },
 };
 use escrow_program_client::instructions::{AllowMintBuilder, BlockMintBuilder, SetImmutableBuilder};
@@ -50,6 +53,7 @@ fn test_block_mint_wrong_admin() {
         .admin(wrong_admin.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .escrow(setup.escrow_pda)
+        .extensions(find_extensions_pda(&setup.escrow_pda).0)
         .mint(setup.mint_pubkey)
         .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
@@ -82,6 +86,13 @@ fn test_block_mint_wrong_escrow() {
     assert_instruction_error(error, InstructionError::InvalidAccountOwner);
 }
 
+#[test]
+fn test_block_mint_wrong_extensions() {
+    let mut ctx = TestContext::new();
+    let error = BlockMintFixture::build_valid(&mut ctx).with_account_at(8, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
 #[test]
 fn test_block_mint_wrong_mint() {
     let mut ctx = TestContext::new();
@@ -117,6 +128,7 @@ fn test_block_mint_allowed_mint_escrow_mismatch() {
         .admin(first_setup.admin.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .escrow(first_setup.escrow_pda)
+        .extensions(find_extensions_pda(&first_setup.escrow_pda).0)
         .mint(first_setup.mint_pubkey)
         .allowed_mint(second_setup.allowed_mint_pda)
         .token_program(first_setup.token_program)
@@ -128,6 +140,52 @@ fn test_block_mint_allowed_mint_escrow_mismatch() {
     assert_instruction_error(error, InstructionError::InvalidSeeds);
 }
 
+#[test]
+fn test_block_mint_with_outstanding_receipts_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let instruction = BlockMintBuilder::new()
+        .admin(setup.admin.pubkey())
+        .rent_recipient(ctx.payer.pubkey())
+        .escrow(setup.escrow_pda)
+        .extensions(find_extensions_pda(&setup.escrow_pda).0)
+        .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
+        .token_program(setup.token_program)
+        .instruction();
+    let error = ctx.send_transaction_expect_error(instruction.clone(), &[&setup.admin]);
+    assert_escrow_error(error, EscrowError::OutstandingReceipts);
+    assert_account_exists(&ctx, &setup.allowed_mint_pda);
+
+    // Once the receipt is withdrawn the mint can be blocked
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    ctx.svm.expire_blockhash();
+    ctx.send_transaction(instruction, &[&setup.admin]).unwrap();
+    assert_account_not_exists(&ctx, &setup.allowed_mint_pda);
+}
+
+#[test]
+fn test_block_mint_with_overrides_fails() {
+    let mut ctx = TestContext::new();
+    let setup = BlockMintSetup::new(&mut ctx);
+
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        3600u64.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MintOverridesPresent);
+    assert_account_exists(&ctx, &setup.allowed_mint_pda);
+}
+
 // ============================================================================
 // Happy Path Tests
 // ============================================================================
@@ -198,6 +256,7 @@ fn test_block_multiple_mints_same_escrow() {
         .admin(first_setup.admin.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .escrow(first_setup.escrow_pda)
+        .extensions(find_extensions_pda(&first_setup.escrow_pda).0)
         .mint(first_setup.mint_pubkey)
         .allowed_mint(first_setup.allowed_mint_pda)
         .token_program(first_setup.token_program)
@@ -217,6 +276,7 @@ fn test_block_multiple_mints_same_escrow() {
         .admin(first_setup.admin.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .escrow(first_setup.escrow_pda)
+        .extensions(find_extensions_pda(&first_setup.escrow_pda).0)
         .mint(second_mint.pubkey())
         .allowed_mint(second_allowed_mint_pda)
         .token_program(first_setup.token_program)
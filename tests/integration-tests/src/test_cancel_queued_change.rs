@@ -0,0 +1,180 @@
+use crate::{
+    fixtures::{
+        CancelQueuedChangeFixture, CreateEscrowFixture, ExecuteQueuedChangeFixture, QueueChangeFixture,
+        SetGovernanceDelayFixture, SetImmutableFixture, SetRolesFixture, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID,
+        DEFAULT_GOVERNANCE_DELAY, DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_TIMELOCK,
+    utils::{
+        assert_account_not_exists, assert_escrow_error, assert_instruction_error, find_escrow_pda,
+        find_pending_change_pda, test_missing_signer, test_not_writable, test_wrong_account,
+        test_wrong_current_program, EscrowError, InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow with the default governance delay and queues a timelock change
+fn setup_queued_change(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+    SetGovernanceDelayFixture::build_with_escrow(ctx, escrow_pda, admin.insecure_clone(), DEFAULT_GOVERNANCE_DELAY)
+        .send_expect_success(ctx);
+    QueueChangeFixture::build_with_escrow(
+        ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(ctx);
+
+    (escrow_pda, admin)
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_cancel_queued_change_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CancelQueuedChangeFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_cancel_queued_change_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CancelQueuedChangeFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_cancel_queued_change_pending_change_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CancelQueuedChangeFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_cancel_queued_change_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<CancelQueuedChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_cancel_queued_change_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<CancelQueuedChangeFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_cancel_queued_change_pending_change_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<CancelQueuedChangeFixture>(&mut ctx, 4, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_cancel_queued_change_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_queued_change(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = CancelQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_cancel_queued_change_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = CancelQueuedChangeFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_cancel_queued_change_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_queued_change(&mut ctx);
+
+    CancelQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    assert_account_not_exists(&ctx, &pending_change_pda);
+
+    // A cancelled change can no longer be executed
+    let now = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(now + DEFAULT_GOVERNANCE_DELAY as i64);
+    let error = ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_cancel_queued_change_by_extension_manager() {
+    let mut ctx = TestContext::new();
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+
+    let extension_manager = Keypair::new();
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        extension_manager.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(&mut ctx);
+
+    // The extension manager holds the role exclusively, so the admin cannot cancel
+    let error = CancelQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    CancelQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, extension_manager, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_cancel_queued_change_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_queued_change(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    CancelQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+}
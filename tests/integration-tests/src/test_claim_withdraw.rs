@@ -0,0 +1,237 @@
+use crate::{
+    fixtures::{
+        ClaimWithdrawFixture, RemoveExtensionFixture, WithdrawSetup, DEFAULT_COOLDOWN_PERIOD, DEFAULT_DEPOSIT_AMOUNT,
+        DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_COOLDOWN,
+    utils::{
+        assert_custom_error, assert_escrow_error, test_missing_signer, test_not_writable, test_wrong_account,
+        test_wrong_current_program, test_wrong_owner, test_wrong_system_program, test_wrong_token_program, EscrowError,
+        TestContext, TEST_HOOK_ALLOW_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+    },
+};
+use solana_sdk::{account::Account, instruction::InstructionError, pubkey::Pubkey};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_claim_withdraw_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ClaimWithdrawFixture>(&mut ctx, 2, 0);
+}
+
+#[test]
+fn test_claim_withdraw_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_claim_withdraw_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_claim_withdraw_request_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 6);
+}
+
+#[test]
+fn test_claim_withdraw_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 7);
+}
+
+#[test]
+fn test_claim_withdraw_destination_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_claim_withdraw_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ClaimWithdrawFixture>(&mut ctx, 16);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<ClaimWithdrawFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ClaimWithdrawFixture>(&mut ctx);
+}
+
+#[test]
+fn test_claim_withdraw_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ClaimWithdrawFixture>(&mut ctx, 14, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_claim_withdraw_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<ClaimWithdrawFixture>(&mut ctx, 11);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ClaimWithdrawFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ClaimWithdrawFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_request_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ClaimWithdrawFixture>(&mut ctx, 6);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_claim_withdraw_before_cooldown_elapsed() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64 - 1);
+
+    let error = ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::CooldownNotElapsed);
+}
+
+#[test]
+fn test_claim_withdraw_request_for_other_receipt_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+
+    // Request a withdrawal of a second receipt, then present it for the first
+    let first_receipt = setup.receipt_pda;
+    setup.receipt_pda = setup.deposit_receipt(&mut ctx, 1);
+    let other_request = setup.request_withdraw(&mut ctx);
+    setup.receipt_pda = first_receipt;
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+
+    let error = ClaimWithdrawFixture::build_with_request(&ctx, &setup, other_request).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawRequest);
+}
+
+#[test]
+fn test_claim_withdraw_wrong_rent_recipient_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+
+    let wrong_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(wrong_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+
+    let error = ClaimWithdrawFixture::build_with_setup(&ctx, &setup)
+        .with_account_at(1, wrong_recipient)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRentRecipient);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_claim_withdraw_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    let withdraw_request_pda = setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+    ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&withdraw_request_pda).is_none(), "Withdraw request should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.vault), 0);
+}
+
+#[test]
+fn test_claim_withdraw_with_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup =
+        WithdrawSetup::builder(&mut ctx).hook_program(TEST_HOOK_ALLOW_ID).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+
+    ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_claim_withdraw_with_hook_bypass_success() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .cooldown(DEFAULT_COOLDOWN_PERIOD)
+        .build();
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_DENY_ID);
+    let withdraw_request_pda = setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_COOLDOWN_PERIOD as i64);
+
+    // Hook blocks the claim
+    let error = ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+
+    let hook_bypass_request_pda = setup.request_hook_bypass(&mut ctx);
+
+    ctx.warp_to_timestamp(ctx.get_current_timestamp() + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+    ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&withdraw_request_pda).is_none(), "Withdraw request should be closed");
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_none(), "Hook bypass request should be closed");
+}
+
+#[test]
+fn test_claim_withdraw_after_cooldown_removed() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    let remove_ix = RemoveExtensionFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        EXTENSION_TYPE_COOLDOWN,
+    );
+    remove_ix.send_expect_success(&mut ctx);
+
+    // Pending requests become claimable immediately once the escrow drops its cooldown
+    ClaimWithdrawFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
@@ -0,0 +1,395 @@
+use crate::{
+    fixtures::{CreateEscrowWithConfigFixture, CreateEscrowWithConfigSetup, DEFAULT_CONFIG_LOCK_DURATION},
+    utils::{
+        assert_account_exists, assert_account_not_exists, assert_allowed_mint_account,
+        assert_allowed_mint_opening_balance, assert_arbiter_extension, assert_block_token_extensions_extension,
+        assert_depositor_allowlist_extension, assert_escrow_account, assert_escrow_error, assert_escrow_mutability,
+        assert_extensions_header, assert_hook_bypass_extension, assert_hook_extension_with_flags,
+        assert_instruction_error, assert_timelock_extension,
+        extensions_utils::{
+            EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, EXTENSION_TYPE_DEPOSITOR_ALLOWLIST, EXTENSION_TYPE_HOOK,
+            EXTENSION_TYPE_HOOK_BYPASS, EXTENSION_TYPE_TIMELOCK, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS,
+        },
+        test_empty_data, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account,
+        test_wrong_current_program, test_wrong_system_program, EscrowError, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signature::Signer};
+use spl_token_2022::{extension::ExtensionType, ID as TOKEN_2022_PROGRAM_ID};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_create_escrow_with_config_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CreateEscrowWithConfigFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_create_escrow_with_config_missing_escrow_seed_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<CreateEscrowWithConfigFixture>(&mut ctx, 2, 1);
+}
+
+#[test]
+fn test_create_escrow_with_config_escrow_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CreateEscrowWithConfigFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_create_escrow_with_config_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<CreateEscrowWithConfigFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<CreateEscrowWithConfigFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<CreateEscrowWithConfigFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_escrow_with_config_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<CreateEscrowWithConfigFixture>(&mut ctx, 7, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_associated_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<CreateEscrowWithConfigFixture>(&mut ctx, 5, InstructionError::IncorrectProgramId);
+}
+
+#[test]
+fn test_create_escrow_with_config_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<CreateEscrowWithConfigFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_escrow_with_config_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<CreateEscrowWithConfigFixture>(&mut ctx);
+}
+
+#[test]
+fn test_create_escrow_with_config_invalid_escrow_bump() {
+    let mut ctx = TestContext::new();
+    let valid_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+    let invalid_bump = valid_ix.instruction.data[1].wrapping_add(1);
+
+    let error = valid_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_create_escrow_with_config_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let valid_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+    let invalid_bump = valid_ix.instruction.data[2].wrapping_add(1);
+
+    let error = valid_ix.with_data_byte_at(2, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_create_escrow_with_config_invalid_immutable_flag() {
+    let mut ctx = TestContext::new();
+    let valid_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+
+    let error = valid_ix.with_data_byte_at(3, 2).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_create_escrow_with_config_unknown_extension_type() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new_empty(&mut ctx);
+    setup.add_extension(99, &[0u8; 8]);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_create_escrow_with_config_duplicate_extension() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    setup.add_extension(EXTENSION_TYPE_TIMELOCK, &1u64.to_le_bytes());
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_create_escrow_with_config_malformed_extension_length() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new_empty(&mut ctx);
+    // Timelock value with a trailing byte
+    setup.add_extension(EXTENSION_TYPE_TIMELOCK, &[0u8; 9]);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_create_escrow_with_config_trailing_tlv_bytes() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    setup.extension_data.push(0);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_create_escrow_with_config_duplicate_blocked_extension() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new_empty(&mut ctx);
+    let blocked = ExtensionType::MetadataPointer as u16;
+    let mut value = vec![2u8];
+    value.extend_from_slice(&blocked.to_le_bytes());
+    value.extend_from_slice(&blocked.to_le_bytes());
+    setup.add_extension(EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, &value);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::TokenExtensionAlreadyBlocked);
+}
+
+#[test]
+fn test_create_escrow_with_config_missing_arbiter_signer() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    setup.add_arbiter();
+
+    // arbiter is the first remaining account (index 9), signer vec index 2
+    let error = setup.build_instruction(&ctx).without_signer(9, 2).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_arbiter() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    setup.add_arbiter();
+    let wrong_arbiter = Keypair::new();
+
+    let mut test_ix = setup.build_instruction(&ctx).with_account_at(9, wrong_arbiter.pubkey());
+    test_ix.signers[2] = wrong_arbiter;
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+}
+
+#[test]
+fn test_create_escrow_with_config_missing_mint_accounts() {
+    let mut ctx = TestContext::new();
+    let mut test_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+    test_ix.instruction.accounts.pop();
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_create_escrow_with_config_extra_mint_accounts() {
+    let mut ctx = TestContext::new();
+    let mut test_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+    let extra = test_ix.instruction.accounts[9].clone();
+    test_ix.instruction.accounts.push(extra);
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_mint_token_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+
+    // token_program is the last account of the first mint group
+    let error = test_ix.with_account_at(12, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::IncorrectProgramId);
+}
+
+#[test]
+fn test_create_escrow_with_config_wrong_vault() {
+    let mut ctx = TestContext::new();
+    let test_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(11, Pubkey::new_unique()).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_create_escrow_with_config_invalid_allowed_mint_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = CreateEscrowWithConfigFixture::build_valid(&mut ctx);
+    let last = test_ix.instruction.data.len() - 1;
+    let invalid_bump = test_ix.instruction.data[last].wrapping_add(1);
+
+    let error = test_ix.with_data_byte_at(last, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_create_escrow_with_config_duplicate_mint() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    let mint = setup.mints[0].mint;
+    setup.add_mint(mint, TOKEN_PROGRAM_ID);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::AccountAlreadyInitialized);
+}
+
+#[test]
+fn test_create_escrow_with_config_rejects_globally_blocked_mint() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    let mint = Keypair::new();
+    ctx.create_token_2022_mint_with_extension(&mint, &ctx.payer.pubkey(), 6, ExtensionType::PermanentDelegate);
+    setup.add_mint(mint.pubkey(), TOKEN_2022_PROGRAM_ID);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::PermanentDelegateNotAllowed);
+}
+
+#[test]
+fn test_create_escrow_with_config_rejects_mint_blocked_in_same_instruction() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new_empty(&mut ctx);
+    let mut value = vec![1u8];
+    value.extend_from_slice(&(ExtensionType::MetadataPointer as u16).to_le_bytes());
+    setup.add_extension(EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, &value);
+
+    let mint = Keypair::new();
+    ctx.create_token_2022_mint_with_extension(&mint, &ctx.payer.pubkey(), 6, ExtensionType::MetadataPointer);
+    setup.add_mint(mint.pubkey(), TOKEN_2022_PROGRAM_ID);
+
+    let escrow_pda = setup.escrow_pda;
+    let extensions_pda = setup.extensions_pda;
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MintNotAllowed);
+
+    // Nothing is left behind when any part of the configuration fails
+    assert_account_not_exists(&ctx, &escrow_pda);
+    assert_account_not_exists(&ctx, &extensions_pda);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_create_escrow_with_config_success() {
+    let mut ctx = TestContext::new();
+    let setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_escrow_account(
+        &ctx,
+        &setup.escrow_pda,
+        &setup.admin.pubkey(),
+        setup.escrow_bump,
+        &setup.escrow_seed.pubkey(),
+    );
+    assert_escrow_mutability(&ctx, &setup.escrow_pda, false);
+    assert_extensions_header(&ctx, &setup.extensions_pda, setup.extensions_bump, 1);
+    assert_timelock_extension(&ctx, &setup.extensions_pda, DEFAULT_CONFIG_LOCK_DURATION);
+
+    let mint = &setup.mints[0];
+    assert_allowed_mint_account(&ctx, &mint.allowed_mint_pda, mint.allowed_mint_bump);
+    assert_allowed_mint_opening_balance(&ctx, &mint.allowed_mint_pda, 0);
+    assert_account_exists(&ctx, &mint.vault);
+}
+
+#[test]
+fn test_create_escrow_with_config_empty_config() {
+    let mut ctx = TestContext::new();
+    let setup = CreateEscrowWithConfigSetup::new_empty(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_escrow_account(
+        &ctx,
+        &setup.escrow_pda,
+        &setup.admin.pubkey(),
+        setup.escrow_bump,
+        &setup.escrow_seed.pubkey(),
+    );
+    assert_account_not_exists(&ctx, &setup.extensions_pda);
+}
+
+#[test]
+fn test_create_escrow_with_config_full_config() {
+    let mut ctx = TestContext::new();
+    let mut setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+
+    let hook_program = Pubkey::new_unique();
+    let mut hook_value = hook_program.to_bytes().to_vec();
+    hook_value.push(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS);
+    setup.add_extension(EXTENSION_TYPE_HOOK, &hook_value);
+
+    let blocked = [ExtensionType::MetadataPointer as u16, ExtensionType::InterestBearingConfig as u16];
+    let mut blocked_value = vec![blocked.len() as u8];
+    blocked.iter().for_each(|ext| blocked_value.extend_from_slice(&ext.to_le_bytes()));
+    setup.add_extension(EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, &blocked_value);
+
+    let arbiter = setup.add_arbiter();
+    setup.add_extension(EXTENSION_TYPE_HOOK_BYPASS, &86_400u64.to_le_bytes());
+
+    let merkle_root = [7u8; 32];
+    let mut allowlist_value = merkle_root.to_vec();
+    allowlist_value.extend_from_slice(&1_000u64.to_le_bytes());
+    setup.add_extension(EXTENSION_TYPE_DEPOSITOR_ALLOWLIST, &allowlist_value);
+
+    setup.add_new_mint(&mut ctx, TOKEN_2022_PROGRAM_ID);
+    setup.is_immutable = true;
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_escrow_mutability(&ctx, &setup.escrow_pda, true);
+    assert_extensions_header(&ctx, &setup.extensions_pda, setup.extensions_bump, 6);
+    assert_timelock_extension(&ctx, &setup.extensions_pda, DEFAULT_CONFIG_LOCK_DURATION);
+    assert_hook_extension_with_flags(&ctx, &setup.extensions_pda, &hook_program, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS);
+    assert_block_token_extensions_extension(&ctx, &setup.extensions_pda, &blocked);
+    assert_arbiter_extension(&ctx, &setup.extensions_pda, &arbiter);
+    assert_hook_bypass_extension(&ctx, &setup.extensions_pda, 86_400);
+    assert_depositor_allowlist_extension(&ctx, &setup.extensions_pda, &merkle_root, 1_000);
+
+    assert_eq!(setup.mints.len(), 2);
+    for mint in &setup.mints {
+        assert_allowed_mint_account(&ctx, &mint.allowed_mint_pda, mint.allowed_mint_bump);
+        assert_account_exists(&ctx, &mint.vault);
+    }
+}
+
+#[test]
+fn test_create_escrow_with_config_records_prefunded_vault_as_opening_balance() {
+    let mut ctx = TestContext::new();
+    let setup = CreateEscrowWithConfigSetup::new(&mut ctx);
+    let mint = &setup.mints[0];
+
+    // Tokens sent to the vault address before the escrow existed
+    ctx.create_token_account_at_address(&mint.vault, &setup.escrow_pda, &mint.mint, 5_000);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_opening_balance(&ctx, &mint.allowed_mint_pda, 5_000);
+}
@@ -1,21 +1,35 @@
 use crate::{
     fixtures::{
-        AddBlockTokenExtensionsFixture, AllowMintSetup, DepositFixture, DepositSetup, UnblockTokenExtensionFixture,
-        DEFAULT_DEPOSIT_AMOUNT,
+        AddBlockTokenExtensionsFixture, AllowMintSetup, DepositFixture, DepositSetup, SetDepositorAllowlistFixture,
+        SetMintOverrideFixture, UnblockTokenExtensionFixture, CHANGE_ACTION_SET, DEFAULT_DEPOSIT_AMOUNT,
+    },
+    utils::extensions_utils::{EXTENSION_TYPE_HOOK, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS},
+    utils::hook_config::{
+        create_hook_config, create_hook_log, read_hook_log, HookConfig, HookLogEntry, HOOK_POINT_POST_DEPOSIT,
+        HOOK_POINT_PRE_DEPOSIT, TEST_HOOK_ASSERT_FAILED_CODE,
     },
+    utils::merkle_utils::DepositorMerkleTree,
     utils::{
-        assert_custom_error, assert_escrow_error, assert_instruction_error, find_noncanonical_program_address,
-        find_receipt_pda, test_empty_data, test_missing_signer, test_not_writable, test_wrong_account,
-        test_wrong_current_program, test_wrong_owner, test_wrong_system_program, test_wrong_token_program, EscrowError,
-        TestContext, TestInstruction, TEST_HOOK_ALLOW_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+        assert_allowed_mint_opening_balance, assert_allowed_mint_outstanding_receipts,
+        assert_allowed_mint_total_deposited, assert_custom_error, assert_depositor_allowance, assert_escrow_error,
+        assert_hook_error, assert_instruction_error, assert_receipt_counter, assert_receipt_nonce,
+        assert_receipt_rent_recipient, find_depositor_allowance_pda, find_extensions_pda,
+        find_noncanonical_program_address, find_receipt_counter_pda, find_receipt_pda, test_empty_data,
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, test_wrong_token_program, EscrowError, TestContext, TestInstruction,
+        TEST_HOOK_ALLOW_ID, TEST_HOOK_CONFIGURABLE_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID, TEST_HOOK_REENTER_ID,
+        TEST_HOOK_REJECT_CODE, TEST_HOOK_REJECT_ID, TEST_HOOK_RELAY_ID,
     },
 };
-use escrow_program_client::instructions::DepositBuilder;
+use escrow_program_client::{
+    instructions::{DepositBuilder, SetMintLimitsBuilder},
+    ESCROW_PROGRAM_ID,
+};
 use solana_sdk::{
     account::Account,
     instruction::{AccountMeta, InstructionError},
     pubkey::Pubkey,
-    signature::{Keypair, Signer},
+    signature::Signer,
 };
 use spl_token_2022::extension::ExtensionType;
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
@@ -31,9 +45,9 @@ fn test_deposit_missing_depositor_signer() {
 }
 
 #[test]
-fn test_deposit_missing_receipt_seed_signer() {
+fn test_deposit_receipt_counter_not_writable() {
     let mut ctx = TestContext::new();
-    test_missing_signer::<DepositFixture>(&mut ctx, 4, 1);
+    test_not_writable::<DepositFixture>(&mut ctx, 4);
 }
 
 #[test]
@@ -93,9 +107,9 @@ fn test_deposit_noncanonical_receipt_pda_rejected() {
 
     let depositor = setup.depositor.pubkey();
     let mint = setup.mint.pubkey();
-    let receipt_seed = setup.receipt_seed.pubkey();
+    let nonce = setup.nonce.to_le_bytes();
     let (noncanonical_receipt, noncanonical_bump) = find_noncanonical_program_address(
-        &[b"receipt", setup.escrow_pda.as_ref(), depositor.as_ref(), mint.as_ref(), receipt_seed.as_ref()],
+        &[b"receipt", setup.escrow_pda.as_ref(), depositor.as_ref(), mint.as_ref(), nonce.as_ref()],
         &program_id,
     )
     .expect("expected at least one noncanonical bump");
@@ -150,16 +164,15 @@ fn test_deposit_succeeds_when_escrow_is_mutable() {
         ctx.create_token_account_with_balance(&depositor.pubkey(), &setup.mint_pubkey, DEFAULT_DEPOSIT_AMOUNT * 10);
     let initial_depositor_balance = ctx.get_token_balance(&depositor_token_account);
     let initial_vault_balance = ctx.get_token_balance(&setup.vault);
-    let receipt_seed = Keypair::new();
-    let (receipt_pda, bump) =
-        find_receipt_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey, &receipt_seed.pubkey());
+    let (receipt_counter, _) = find_receipt_counter_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey);
+    let (receipt_pda, bump) = find_receipt_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey, 0);
 
     let instruction = DepositBuilder::new()
         .payer(ctx.payer.pubkey())
         .depositor(depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(receipt_seed.pubkey())
+        .receipt_counter(receipt_counter)
         .receipt(receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(depositor_token_account)
@@ -168,9 +181,11 @@ fn test_deposit_succeeds_when_escrow_is_mutable() {
         .extensions(setup.escrow_extensions_pda)
         .bump(bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    ctx.send_transaction(instruction, &[&depositor, &receipt_seed]).unwrap();
+    ctx.send_transaction(instruction, &[&depositor]).unwrap();
 
     let final_depositor_balance = ctx.get_token_balance(&depositor_token_account);
     let final_vault_balance = ctx.get_token_balance(&setup.vault);
@@ -213,16 +228,15 @@ fn test_deposit_rejects_newly_blocked_mint_extension() {
     let depositor = ctx.create_funded_keypair();
     let depositor_token_account =
         ctx.create_token_2022_account_with_balance(&depositor.pubkey(), &setup.mint_pubkey, DEFAULT_DEPOSIT_AMOUNT);
-    let receipt_seed = Keypair::new();
-    let (receipt_pda, bump) =
-        find_receipt_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey, &receipt_seed.pubkey());
+    let (receipt_counter, _) = find_receipt_counter_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey);
+    let (receipt_pda, bump) = find_receipt_pda(&setup.escrow_pda, &depositor.pubkey(), &setup.mint_pubkey, 0);
 
     let instruction = DepositBuilder::new()
         .payer(ctx.payer.pubkey())
         .depositor(depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(receipt_seed.pubkey())
+        .receipt_counter(receipt_counter)
         .receipt(receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(depositor_token_account)
@@ -231,9 +245,11 @@ fn test_deposit_rejects_newly_blocked_mint_extension() {
         .extensions(setup.escrow_extensions_pda)
         .bump(bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    let error = ctx.send_transaction_expect_error(instruction, &[&depositor, &receipt_seed]);
+    let error = ctx.send_transaction_expect_error(instruction, &[&depositor]);
     assert_escrow_error(error, EscrowError::MintNotAllowed);
 }
 
@@ -292,7 +308,7 @@ fn test_deposit_zero_amount() {
         .depositor(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(setup.receipt_seed.pubkey())
+        .receipt_counter(setup.receipt_counter)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(setup.depositor_token_account)
@@ -301,19 +317,46 @@ fn test_deposit_zero_amount() {
         .extensions(setup.extensions_pda)
         .bump(setup.bump)
         .amount(0) // Zero amount
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    let test_ix = TestInstruction {
-        instruction,
-        signers: vec![setup.depositor.insecure_clone(), setup.receipt_seed.insecure_clone()],
-        name: "Deposit",
-    };
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Deposit" };
 
     let error = test_ix.send_expect_error(&mut ctx);
     // ZeroDepositAmount = error code 13
     assert_escrow_error(error, EscrowError::ZeroDepositAmount);
 }
 
+#[test]
+fn test_deposit_default_rent_recipient_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+
+    let instruction = DepositBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .depositor(setup.depositor.pubkey())
+        .escrow(setup.escrow_pda)
+        .allowed_mint(setup.allowed_mint_pda)
+        .receipt_counter(setup.receipt_counter)
+        .receipt(setup.receipt_pda)
+        .vault(setup.vault)
+        .depositor_token_account(setup.depositor_token_account)
+        .mint(setup.mint.pubkey())
+        .token_program(setup.token_program)
+        .extensions(setup.extensions_pda)
+        .bump(setup.bump)
+        .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(Pubkey::default())
+        .proof(vec![])
+        .instruction();
+
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Deposit" };
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
 #[test]
 fn test_deposit_invalid_event_authority() {
     let mut ctx = TestContext::new();
@@ -336,7 +379,7 @@ fn test_deposit_wrong_vault_ata() {
         .depositor(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(setup.receipt_seed.pubkey())
+        .receipt_counter(setup.receipt_counter)
         .receipt(setup.receipt_pda)
         .vault(wrong_vault) // Wrong vault address
         .depositor_token_account(setup.depositor_token_account)
@@ -345,13 +388,11 @@ fn test_deposit_wrong_vault_ata() {
         .extensions(setup.extensions_pda)
         .bump(setup.bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    let test_ix = TestInstruction {
-        instruction,
-        signers: vec![setup.depositor.insecure_clone(), setup.receipt_seed.insecure_clone()],
-        name: "Deposit",
-    };
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Deposit" };
 
     let error = test_ix.send_expect_error(&mut ctx);
     assert_instruction_error(error, InstructionError::InvalidAccountData);
@@ -377,7 +418,7 @@ fn test_deposit_wrong_depositor_token_account_owner() {
         .depositor(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(setup.receipt_seed.pubkey())
+        .receipt_counter(setup.receipt_counter)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(wrong_token_account) // Wrong depositor token account
@@ -386,13 +427,11 @@ fn test_deposit_wrong_depositor_token_account_owner() {
         .extensions(setup.extensions_pda)
         .bump(setup.bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    let test_ix = TestInstruction {
-        instruction,
-        signers: vec![setup.depositor.insecure_clone(), setup.receipt_seed.insecure_clone()],
-        name: "Deposit",
-    };
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Deposit" };
 
     let error = test_ix.send_expect_error(&mut ctx);
     assert_instruction_error(error, InstructionError::InvalidAccountData);
@@ -431,20 +470,15 @@ fn test_deposit_multiple_deposits() {
     let test_ix = setup.build_instruction(&ctx);
     test_ix.send_expect_success(&mut ctx);
 
-    let new_receipt_seed = solana_sdk::signature::Keypair::new();
-    let (new_receipt_pda, new_bump) = find_receipt_pda(
-        &setup.escrow_pda,
-        &setup.depositor.pubkey(),
-        &setup.mint.pubkey(),
-        &new_receipt_seed.pubkey(),
-    );
+    let (new_receipt_pda, new_bump) =
+        find_receipt_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey(), 1);
 
     let second_deposit_ix = DepositBuilder::new()
         .payer(ctx.payer.pubkey())
         .depositor(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(setup.allowed_mint_pda)
-        .receipt_seed(new_receipt_seed.pubkey())
+        .receipt_counter(setup.receipt_counter)
         .receipt(new_receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(setup.depositor_token_account)
@@ -452,9 +486,11 @@ fn test_deposit_multiple_deposits() {
         .extensions(setup.extensions_pda)
         .bump(new_bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT / 2)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    ctx.send_transaction(second_deposit_ix, &[&setup.depositor, &new_receipt_seed]).unwrap();
+    ctx.send_transaction(second_deposit_ix, &[&setup.depositor]).unwrap();
 
     let first_receipt = ctx.get_account(&setup.receipt_pda).expect("First receipt should exist");
     let second_receipt = ctx.get_account(&new_receipt_pda).expect("Second receipt should exist");
@@ -463,6 +499,93 @@ fn test_deposit_multiple_deposits() {
     assert!(!second_receipt.data.is_empty());
 }
 
+#[test]
+fn test_deposit_creates_receipt_counter() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_counter).is_none());
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_receipt_counter(&ctx, &setup.receipt_counter, 1);
+    assert_receipt_nonce(&ctx, &setup.receipt_pda, 0);
+}
+
+#[test]
+fn test_deposit_records_payer_as_rent_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_receipt_rent_recipient(&ctx, &setup.receipt_pda, &ctx.payer.pubkey());
+}
+
+#[test]
+fn test_deposit_receipt_nonces_are_sequential() {
+    let mut ctx = TestContext::new();
+    let mut setup = DepositSetup::new(&mut ctx);
+
+    for nonce in 0..3 {
+        setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+        assert_receipt_nonce(&ctx, &setup.receipt_pda, nonce);
+        setup.advance_receipt();
+    }
+
+    assert_receipt_counter(&ctx, &setup.receipt_counter, 3);
+}
+
+#[test]
+fn test_deposit_receipt_for_wrong_nonce_rejected() {
+    let mut ctx = TestContext::new();
+    let mut setup = DepositSetup::new(&mut ctx);
+
+    // Skip ahead to nonce 1 while the counter is still at 0
+    setup.advance_receipt();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_reused_receipt_nonce_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    // The counter has moved on, so nonce 0 no longer matches
+    ctx.warp_to_slot(2);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_wrong_receipt_counter_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+
+    // Counter derived for another depositor
+    let (wrong_counter, _) = find_receipt_counter_pda(&setup.escrow_pda, &Pubkey::new_unique(), &setup.mint.pubkey());
+    let error = setup.build_instruction(&ctx).with_account_at(4, wrong_counter).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_receipt_counter_wrong_owner() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let mut counter_account = ctx.get_account(&setup.receipt_counter).expect("Counter should exist");
+    counter_account.owner = Pubkey::new_unique();
+    ctx.svm.set_account(setup.receipt_counter, counter_account).unwrap();
+
+    let mut setup = setup;
+    setup.advance_receipt();
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
 // ============================================================================
 // Token 2022 Happy Path Tests
 // ============================================================================
@@ -488,6 +611,363 @@ fn test_deposit_token_2022_success() {
     assert!(!receipt_account.data.is_empty());
 }
 
+// ============================================================================
+// Mint Limit Tests
+// ============================================================================
+
+fn set_mint_limits(ctx: &mut TestContext, setup: &DepositSetup, max_total_deposits: u64, min: u64, max: u64) {
+    let instruction = SetMintLimitsBuilder::new()
+        .admin(setup.admin.pubkey())
+        .escrow(setup.escrow_pda)
+        .extensions(find_extensions_pda(&setup.escrow_pda).0)
+        .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
+        .max_total_deposits(max_total_deposits)
+        .min_deposit(min)
+        .max_deposit(max)
+        .instruction();
+    ctx.send_transaction(instruction, &[&setup.admin]).unwrap();
+}
+
+#[test]
+fn test_deposit_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DepositFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_deposit_records_total_deposited() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, 0);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+}
+
+#[test]
+fn test_deposit_below_minimum() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    set_mint_limits(&mut ctx, &setup, 0, DEFAULT_DEPOSIT_AMOUNT + 1, 0);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositBelowMinimum);
+}
+
+#[test]
+fn test_deposit_above_maximum() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    set_mint_limits(&mut ctx, &setup, 0, 0, DEFAULT_DEPOSIT_AMOUNT - 1);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositAboveMaximum);
+}
+
+#[test]
+fn test_deposit_at_exact_limits_succeeds() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    set_mint_limits(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_deposit_cap_exceeded() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    set_mint_limits(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT + DEFAULT_DEPOSIT_AMOUNT / 2, 0, 0);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let (new_receipt_pda, new_bump) =
+        find_receipt_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey(), 1);
+
+    let second_deposit_ix = DepositBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .depositor(setup.depositor.pubkey())
+        .escrow(setup.escrow_pda)
+        .allowed_mint(setup.allowed_mint_pda)
+        .receipt_counter(setup.receipt_counter)
+        .receipt(new_receipt_pda)
+        .vault(setup.vault)
+        .depositor_token_account(setup.depositor_token_account)
+        .mint(setup.mint.pubkey())
+        .extensions(setup.extensions_pda)
+        .bump(new_bump)
+        .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
+        .instruction();
+
+    let error = ctx.send_transaction_expect_error(second_deposit_ix, &[&setup.depositor]);
+    assert_escrow_error(error, EscrowError::DepositCapExceeded);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+}
+
+#[test]
+fn test_deposit_grows_legacy_allowed_mint() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+
+    // A version 1 AllowedMint whose vault already backs receipts it never counted
+    ctx.set_token_balance(&setup.vault, 5_000);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let allowed_mint_account = ctx.get_account(&setup.allowed_mint_pda).unwrap();
+    assert_eq!(allowed_mint_account.data.len(), 58);
+    assert_eq!(allowed_mint_account.data[1], 2, "AllowedMint should be rewritten as version 2");
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+    assert_allowed_mint_opening_balance(&ctx, &setup.allowed_mint_pda, 5_000);
+}
+
+// ============================================================================
+// Depositor Allowlist Tests
+// ============================================================================
+
+fn set_depositor_allowlist(ctx: &mut TestContext, setup: &DepositSetup, merkle_root: [u8; 32], per_address_cap: u64) {
+    SetDepositorAllowlistFixture::build_with_escrow(
+        ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        merkle_root,
+        per_address_cap,
+    )
+    .send_expect_success(ctx);
+}
+
+#[test]
+fn test_deposit_allowlisted_depositor_succeeds() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[
+        Pubkey::new_unique(),
+        setup.depositor.pubkey(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), 0);
+
+    let proof = tree.proof(&setup.depositor.pubkey());
+    setup.build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof, None).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.vault), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_deposit_single_leaf_allowlist_empty_proof() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), 0);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_deposit_missing_proof_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), 0);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorNotAllowlisted);
+}
+
+#[test]
+fn test_deposit_non_allowlisted_depositor_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let other = Pubkey::new_unique();
+    let tree = DepositorMerkleTree::new(&[other, Pubkey::new_unique(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), 0);
+
+    // Another member's proof does not verify for this depositor
+    let proof = tree.proof(&other);
+    let error =
+        setup.build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof, None).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorNotAllowlisted);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should not be created for rejected depositor");
+}
+
+#[test]
+fn test_deposit_rejected_after_root_rotation() {
+    let mut ctx = TestContext::new();
+    let mut setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), 0);
+
+    let proof = tree.proof(&setup.depositor.pubkey());
+    setup.build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof.clone(), None).send_expect_success(&mut ctx);
+
+    let rotated = DepositorMerkleTree::new(&[Pubkey::new_unique(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, rotated.root(), 0);
+
+    setup.advance_receipt();
+    let error =
+        setup.build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof, None).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorNotAllowlisted);
+}
+
+#[test]
+fn test_deposit_with_cap_creates_allowance() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT * 2);
+
+    let (allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey());
+    let proof = tree.proof(&setup.depositor.pubkey());
+    setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof, Some(allowance_pda))
+        .send_expect_success(&mut ctx);
+
+    assert_depositor_allowance(
+        &ctx,
+        &allowance_pda,
+        &setup.escrow_pda,
+        &setup.depositor.pubkey(),
+        &setup.mint.pubkey(),
+        DEFAULT_DEPOSIT_AMOUNT,
+    );
+}
+
+#[test]
+fn test_deposit_cap_is_cumulative() {
+    let mut ctx = TestContext::new();
+    let mut setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    let cap = DEFAULT_DEPOSIT_AMOUNT + DEFAULT_DEPOSIT_AMOUNT / 2;
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), cap);
+
+    let (allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey());
+    let proof = tree.proof(&setup.depositor.pubkey());
+    setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof.clone(), Some(allowance_pda))
+        .send_expect_success(&mut ctx);
+
+    setup.advance_receipt();
+    let error = setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof.clone(), Some(allowance_pda))
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorCapExceeded);
+
+    // The remaining allowance can still be used
+    setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT / 2, proof, Some(allowance_pda))
+        .send_expect_success(&mut ctx);
+
+    assert_depositor_allowance(
+        &ctx,
+        &allowance_pda,
+        &setup.escrow_pda,
+        &setup.depositor.pubkey(),
+        &setup.mint.pubkey(),
+        cap,
+    );
+}
+
+#[test]
+fn test_deposit_above_cap_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT - 1);
+
+    let (allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey());
+    let error = setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, vec![], Some(allowance_pda))
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorCapExceeded);
+    assert!(ctx.get_account(&allowance_pda).is_none(), "Allowance should not be created for rejected deposit");
+}
+
+#[test]
+fn test_deposit_with_cap_missing_allowance_account() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_deposit_with_cap_wrong_allowance_account() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT);
+
+    // Allowance derived for a different depositor
+    let (wrong_allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &Pubkey::new_unique(), &setup.mint.pubkey());
+    let error = setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, vec![], Some(wrong_allowance_pda))
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_with_cap_allowance_for_other_mint() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new(&mut ctx);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT);
+
+    // Allowance derived for the right depositor but a different mint
+    let (wrong_allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &Pubkey::new_unique());
+    let error = setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, vec![], Some(wrong_allowance_pda))
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_allowlist_with_hook() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    set_depositor_allowlist(&mut ctx, &setup, tree.root(), DEFAULT_DEPOSIT_AMOUNT);
+
+    // Allowance goes ahead of the hook program in remaining accounts
+    let (allowance_pda, _) =
+        find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &setup.mint.pubkey());
+    let proof = tree.proof(&setup.depositor.pubkey());
+    setup
+        .build_instruction_with_proof(&ctx, DEFAULT_DEPOSIT_AMOUNT, proof, Some(allowance_pda))
+        .send_expect_success(&mut ctx);
+
+    assert_depositor_allowance(
+        &ctx,
+        &allowance_pda,
+        &setup.escrow_pda,
+        &setup.depositor.pubkey(),
+        &setup.mint.pubkey(),
+        DEFAULT_DEPOSIT_AMOUNT,
+    );
+}
+
 // ============================================================================
 // Hook Program Tests
 // ============================================================================
@@ -526,6 +1006,141 @@ fn test_deposit_with_hook_rejected() {
     assert_custom_error(error, TEST_HOOK_DENY_ERROR);
 }
 
+/// Sad path: A per-mint hook override is invoked in place of the escrow-level hook
+#[test]
+fn test_deposit_with_mint_hook_override_rejected() {
+    let mut ctx = TestContext::new();
+    let mut setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+
+    let mut hook_value = TEST_HOOK_DENY_ID.to_bytes().to_vec();
+    hook_value.push(0); // flags
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value,
+    )
+    .send_expect_success(&mut ctx);
+
+    setup.hook_program = Some(TEST_HOOK_DENY_ID);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+/// Sad path: Deposit with hook program that rejects with a reason code
+#[test]
+fn test_deposit_with_hook_rejected_with_code() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_REJECT_ID);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_hook_error(error, TEST_HOOK_REJECT_CODE);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should not be created after rejected deposit");
+}
+
+#[test]
+fn test_deposit_with_reentrant_hook_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_REENTER_ID);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    // The malicious hook needs the escrow program account to call back into it
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should not be created after reentry attempt");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+
+#[test]
+fn test_deposit_with_reentrant_hook_through_relay_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_REENTER_ID);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    // The hook forwards to the relay program, which calls back into the escrow
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(TEST_HOOK_RELAY_ID, false));
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should not be created after reentry attempt");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+#[test]
+fn test_deposit_hook_writes_owned_account_when_enabled() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS)
+        .build();
+    let hook_state = ctx.create_program_owned_account(&TEST_HOOK_ALLOW_ID, 8);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new(hook_state, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    // Pre-deposit and post-deposit hooks each bump the counter
+    let hook_state_account = ctx.get_account(&hook_state).expect("Hook state should exist");
+    assert_eq!(hook_state_account.data[0], 2);
+}
+
+#[test]
+fn test_deposit_hook_owned_account_read_only_without_flag() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    let hook_state = ctx.create_program_owned_account(&TEST_HOOK_ALLOW_ID, 8);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new(hook_state, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let hook_state_account = ctx.get_account(&hook_state).expect("Hook state should exist");
+    assert_eq!(hook_state_account.data[0], 0, "Hook state should be passed read-only");
+}
+
+#[test]
+fn test_deposit_hook_non_owned_account_stays_read_only() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS)
+        .build();
+    let foreign_state = ctx.create_program_owned_account(&Pubkey::new_unique(), 8);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new(foreign_state, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let foreign_state_account = ctx.get_account(&foreign_state).expect("Account should exist");
+    assert_eq!(foreign_state_account.data[0], 0);
+}
+
+#[test]
+fn test_deposit_too_many_hook_accounts() {
+    let mut ctx = TestContext::new();
+    let setup = DepositSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    let extra_account = Pubkey::new_unique();
+
+    // 3 core accounts + 62 extras exceeds the 64 account hook CPI bound
+    let mut test_ix = setup.build_instruction(&ctx);
+    for _ in 0..62 {
+        test_ix.instruction.accounts.push(AccountMeta::new_readonly(extra_account, false));
+    }
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_escrow_error(error, EscrowError::TooManyHookAccounts);
+}
+
 #[test]
 fn test_deposit_with_hook_extra_signer_is_downgraded() {
     let mut ctx = TestContext::new();
@@ -542,6 +1157,143 @@ fn test_deposit_with_hook_extra_signer_is_downgraded() {
     assert!(!receipt_account.data.is_empty());
 }
 
+// ============================================================================
+// Configurable Hook Tests
+// ============================================================================
+
+/// Builds a deposit setup wired to the configurable hook, with writable hook accounts enabled for logging
+fn configurable_hook_setup(ctx: &mut TestContext) -> DepositSetup {
+    DepositSetup::builder(ctx)
+        .hook_program(TEST_HOOK_CONFIGURABLE_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS)
+        .build()
+}
+
+#[test]
+fn test_deposit_hook_receives_expected_context() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let core_accounts = [setup.escrow_pda, setup.mint.pubkey(), setup.receipt_pda];
+    let config = create_hook_config(
+        &mut ctx,
+        &HookConfig::new().expect(&[HOOK_POINT_PRE_DEPOSIT, HOOK_POINT_POST_DEPOSIT], &core_accounts).log(),
+    );
+    let log = create_hook_log(&mut ctx, &config);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let (count, entries) = read_hook_log(&ctx, &log);
+    assert_eq!(count, 2);
+    for (entry, hook_point) in entries.iter().zip([HOOK_POINT_PRE_DEPOSIT, HOOK_POINT_POST_DEPOSIT]) {
+        assert_eq!(
+            *entry,
+            HookLogEntry {
+                hook_point,
+                account_count: 5,
+                writable_count: 1,
+                signer_count: 0,
+                core_accounts: core_accounts.to_vec(),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_deposit_hook_unexpected_account_order_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let config = create_hook_config(
+        &mut ctx,
+        &HookConfig::new().expect(
+            &[HOOK_POINT_PRE_DEPOSIT, HOOK_POINT_POST_DEPOSIT],
+            &[setup.mint.pubkey(), setup.escrow_pda, setup.receipt_pda],
+        ),
+    );
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_hook_error(error, TEST_HOOK_ASSERT_FAILED_CODE);
+}
+
+#[test]
+fn test_deposit_hook_rejects_post_deposit_only() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().reject_at(HOOK_POINT_POST_DEPOSIT, 99));
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    // The transfer between the hook points is rolled back with the rest of the transaction
+    assert_hook_error(error, 99);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should not be created");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+
+#[test]
+fn test_deposit_hook_extra_signer_not_forwarded() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().log());
+    let log = create_hook_log(&mut ctx, &config);
+    let extra_signer = ctx.create_funded_keypair();
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(extra_signer.pubkey(), true));
+    test_ix.signers.push(extra_signer.insecure_clone());
+    test_ix.send_expect_success(&mut ctx);
+
+    let (_, entries) = read_hook_log(&ctx, &log);
+    let entry = &entries[0];
+    assert_eq!(entry.account_count, 6);
+    assert_eq!(entry.signer_count, 0, "Signer privilege must not reach the hook");
+    assert_eq!(entry.writable_count, 1, "Only the hook-owned log should be writable");
+}
+
+#[test]
+fn test_deposit_hook_compute_burn_is_charged_to_deposit() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let baseline_config = create_hook_config(&mut ctx, &HookConfig::new());
+    let mut baseline_ix = setup.build_instruction(&ctx);
+    baseline_ix.instruction.accounts.push(AccountMeta::new_readonly(baseline_config, false));
+    let baseline_cus = baseline_ix.send_expect_success(&mut ctx);
+
+    let setup = configurable_hook_setup(&mut ctx);
+    let burn_config = create_hook_config(&mut ctx, &HookConfig::new().burn(2_000));
+    let mut burn_ix = setup.build_instruction(&ctx);
+    burn_ix.instruction.accounts.push(AccountMeta::new_readonly(burn_config, false));
+    let burn_cus = burn_ix.send_expect_success(&mut ctx);
+
+    assert!(
+        burn_cus > baseline_cus,
+        "Burning hook ({burn_cus} CUs) should cost more than baseline ({baseline_cus} CUs)"
+    );
+}
+
+#[test]
+fn test_deposit_configurable_hook_reentry_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().reenter());
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+}
+
 // ============================================================================
 // Additional Tests
 // ============================================================================
@@ -559,7 +1311,7 @@ fn test_deposit_allowed_mint_not_owned_by_program() {
         .depositor(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .allowed_mint(wrong_allowed_mint) // Invalid AllowedMint PDA
-        .receipt_seed(setup.receipt_seed.pubkey())
+        .receipt_counter(setup.receipt_counter)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
         .depositor_token_account(setup.depositor_token_account)
@@ -568,13 +1320,11 @@ fn test_deposit_allowed_mint_not_owned_by_program() {
         .extensions(setup.extensions_pda)
         .bump(setup.bump)
         .amount(DEFAULT_DEPOSIT_AMOUNT)
+        .rent_recipient(ctx.payer.pubkey())
+        .proof(vec![])
         .instruction();
 
-    let test_ix = TestInstruction {
-        instruction,
-        signers: vec![setup.depositor.insecure_clone(), setup.receipt_seed.insecure_clone()],
-        name: "Deposit",
-    };
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Deposit" };
 
     let error = test_ix.send_expect_error(&mut ctx);
     // Wrong AllowedMint address (not owned by program) should fail with InvalidAccountOwner
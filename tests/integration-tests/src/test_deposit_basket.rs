@@ -0,0 +1,364 @@
+use crate::{
+    fixtures::{
+        BasketSetup, DepositBasketFixture, SetDepositorAllowlistFixture, SetMintOverrideFixture, CHANGE_ACTION_SET,
+        DEFAULT_DEPOSIT_AMOUNT, DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_HOOK,
+    utils::merkle_utils::DepositorMerkleTree,
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_allowed_mint_total_deposited, assert_custom_error,
+        assert_depositor_allowance, assert_escrow_error, assert_instruction_error, find_depositor_allowance_pda,
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, test_wrong_token_program, EscrowError, TestContext, TEST_HOOK_ALLOW_ID,
+        TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+    },
+};
+use escrow_program_client::accounts::BasketReceipt;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+/// Sets a depositor allowlist containing the basket depositor, returning the depositor's proof
+fn set_depositor_allowlist(ctx: &mut TestContext, setup: &BasketSetup, per_address_cap: u64) -> Vec<[u8; 32]> {
+    let tree = DepositorMerkleTree::new(&[setup.depositor.pubkey(), Pubkey::new_unique()]);
+    SetDepositorAllowlistFixture::build_with_escrow(
+        ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        tree.root(),
+        per_address_cap,
+    )
+    .send_expect_success(ctx);
+    tree.proof(&setup.depositor.pubkey())
+}
+
+/// One depositor allowance PDA per basket mint, in leg order
+fn depositor_allowances(setup: &BasketSetup) -> Vec<Pubkey> {
+    setup
+        .mints
+        .iter()
+        .map(|basket_mint| {
+            find_depositor_allowance_pda(&setup.escrow_pda, &setup.depositor.pubkey(), &basket_mint.mint.pubkey()).0
+        })
+        .collect()
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_deposit_basket_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<DepositBasketFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_deposit_basket_basket_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DepositBasketFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_deposit_basket_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DepositBasketFixture>(&mut ctx, 10);
+}
+
+#[test]
+fn test_deposit_basket_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DepositBasketFixture>(&mut ctx, 11);
+}
+
+#[test]
+fn test_deposit_basket_depositor_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<DepositBasketFixture>(&mut ctx, 12);
+}
+
+#[test]
+fn test_deposit_basket_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<DepositBasketFixture>(&mut ctx);
+}
+
+#[test]
+fn test_deposit_basket_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<DepositBasketFixture>(&mut ctx);
+}
+
+#[test]
+fn test_deposit_basket_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<DepositBasketFixture>(&mut ctx, 6, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_deposit_basket_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<DepositBasketFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_deposit_basket_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<DepositBasketFixture>(&mut ctx, 2);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_deposit_basket_duplicate_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+
+    // Point the second leg's mint at the first leg's mint
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT; 2])
+        .with_account_at(13, setup.mints[0].mint.pubkey())
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DuplicateBasketMint);
+}
+
+#[test]
+fn test_deposit_basket_missing_leg_accounts() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+
+    // Three amounts but only two groups of leg accounts
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT; 3])
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_deposit_basket_zero_amount() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT, 0])
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ZeroDepositAmount);
+}
+
+#[test]
+fn test_deposit_basket_with_deny_hook_fails() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).hook_program(TEST_HOOK_DENY_ID).build();
+
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT; 2])
+        .send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+#[test]
+fn test_deposit_basket_mixed_arbiters_fails() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).arbiter().build();
+
+    // The second leg resolves to its own arbiter, which WithdrawBasket could never satisfy together
+    // with the escrow-level one
+    let mint_arbiter = ctx.create_funded_keypair();
+    SetMintOverrideFixture::build_set_arbiter(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mints[1].mint.pubkey(),
+        mint_arbiter,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT; 2])
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MixedBasketLegs);
+}
+
+#[test]
+fn test_deposit_basket_mixed_hooks_fails() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+
+    let mut hook_value = TEST_HOOK_ALLOW_ID.to_bytes().to_vec();
+    hook_value.push(0); // flags
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mints[0].mint.pubkey(),
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT; 2])
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MixedBasketLegs);
+}
+
+#[test]
+fn test_deposit_basket_with_cap_missing_allowance_account() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let proof = set_depositor_allowlist(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT);
+
+    // Only the first leg's allowance is passed
+    let allowances = depositor_allowances(&setup);
+    let error = DepositBasketFixture::build_with_proof(
+        &ctx,
+        &setup,
+        0,
+        &[DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT],
+        proof,
+        &allowances[..1],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_deposit_basket_with_cap_allowances_out_of_order() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let proof = set_depositor_allowlist(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT);
+
+    let mut allowances = depositor_allowances(&setup);
+    allowances.reverse();
+    let error = DepositBasketFixture::build_with_proof(
+        &ctx,
+        &setup,
+        0,
+        &[DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT],
+        proof,
+        &allowances,
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_deposit_basket_cap_exceeded_for_one_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let proof = set_depositor_allowlist(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT);
+    let allowances = depositor_allowances(&setup);
+
+    DepositBasketFixture::build_with_proof(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT, 1], proof.clone(), &allowances)
+        .send_expect_success(&mut ctx);
+
+    // The second mint still has allowance left, but the first mint's is used up
+    let error = DepositBasketFixture::build_with_proof(&ctx, &setup, 1, &[1, 1], proof, &allowances)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DepositorCapExceeded);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_deposit_basket_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let amounts = [DEFAULT_DEPOSIT_AMOUNT, 2 * DEFAULT_DEPOSIT_AMOUNT];
+
+    DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &amounts).send_expect_success(&mut ctx);
+
+    for (basket_mint, amount) in setup.mints.iter().zip(amounts) {
+        assert_eq!(ctx.get_token_balance(&basket_mint.vault), amount);
+        assert_eq!(ctx.get_token_balance(&basket_mint.depositor_token_account), DEFAULT_DEPOSIT_AMOUNT * 10 - amount);
+        assert_allowed_mint_total_deposited(&ctx, &basket_mint.allowed_mint_pda, amount);
+        assert_allowed_mint_outstanding_receipts(&ctx, &basket_mint.allowed_mint_pda, 1);
+    }
+
+    let (basket_receipt, bump) = setup.basket_receipt_pda(0);
+    let account = ctx.get_account(&basket_receipt).expect("Basket receipt should exist");
+    let basket = BasketReceipt::from_bytes(&account.data).unwrap();
+    assert_eq!(basket.bump, bump);
+    assert_eq!(basket.escrow, setup.escrow_pda);
+    assert_eq!(basket.depositor, setup.depositor.pubkey());
+    assert_eq!(basket.basket_id, 0);
+    assert_eq!(basket.rent_recipient, setup.rent_recipient);
+    assert_eq!(basket.legs.len(), 2);
+    assert_eq!(basket.legs[0].mint, setup.mints[0].mint.pubkey());
+    assert_eq!(basket.legs[1].amount, 2 * DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_deposit_basket_single_mint_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).token_programs(&[TOKEN_PROGRAM_ID]).build();
+
+    DepositBasketFixture::build_with_setup(&ctx, &setup, 0, &[DEFAULT_DEPOSIT_AMOUNT]).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.mints[0].vault), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_deposit_basket_multiple_baskets() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+
+    let first = setup.deposit(&mut ctx, 0);
+    let second = setup.deposit(&mut ctx, 1);
+
+    assert_ne!(first, second);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.mints[0].allowed_mint_pda, 2);
+    assert_eq!(ctx.get_token_balance(&setup.mints[1].vault), 2 * DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_deposit_basket_hook_with_hook_bypass_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+    assert!(ctx.get_account(&basket_receipt).is_some(), "Basket receipt should exist");
+}
+
+#[test]
+fn test_deposit_basket_with_allow_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).hook_program(TEST_HOOK_ALLOW_ID).build();
+
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let account = ctx.get_account(&basket_receipt).expect("Basket receipt should exist");
+    let basket = BasketReceipt::from_bytes(&account.data).unwrap();
+    assert_eq!(basket.legs.len(), setup.mints.len(), "Basket receipt should record every leg");
+}
+
+#[test]
+fn test_deposit_basket_cap_applies_per_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let proof = set_depositor_allowlist(&mut ctx, &setup, DEFAULT_DEPOSIT_AMOUNT);
+    let allowances = depositor_allowances(&setup);
+
+    // Each mint may be deposited up to the full cap under the same allowlist
+    DepositBasketFixture::build_with_proof(
+        &ctx,
+        &setup,
+        0,
+        &[DEFAULT_DEPOSIT_AMOUNT, DEFAULT_DEPOSIT_AMOUNT],
+        proof,
+        &allowances,
+    )
+    .send_expect_success(&mut ctx);
+
+    for (basket_mint, allowance_pda) in setup.mints.iter().zip(&allowances) {
+        assert_depositor_allowance(
+            &ctx,
+            allowance_pda,
+            &setup.escrow_pda,
+            &setup.depositor.pubkey(),
+            &basket_mint.mint.pubkey(),
+            DEFAULT_DEPOSIT_AMOUNT,
+        );
+    }
+}
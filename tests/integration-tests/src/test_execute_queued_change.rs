@@ -0,0 +1,365 @@
+use crate::{
+    fixtures::{
+        AddTimelockFixture, CreateEscrowFixture, ExecuteQueuedChangeFixture, QueueChangeFixture,
+        SetGovernanceDelayFixture, SetImmutableFixture, CHANGE_ACTION_REMOVE, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID,
+        DEFAULT_GOVERNANCE_DELAY, DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::extensions_utils::{EXTENSION_TYPE_GOVERNANCE_DELAY, EXTENSION_TYPE_ROLES, EXTENSION_TYPE_TIMELOCK},
+    utils::{
+        assert_account_not_exists, assert_arbiter_extension, assert_escrow_error, assert_extension_missing,
+        assert_governance_delay_extension, assert_instruction_error, assert_roles_extension, assert_timelock_extension,
+        find_escrow_pda, find_extensions_pda, find_pending_change_pda, test_empty_data, test_missing_signer,
+        test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+/// Creates an escrow with the default governance delay and queues a timelock change
+fn setup_delayed_timelock_change(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let (escrow_pda, admin) = setup_escrow(ctx);
+
+    SetGovernanceDelayFixture::build_with_escrow(ctx, escrow_pda, admin.insecure_clone(), DEFAULT_GOVERNANCE_DELAY)
+        .send_expect_success(ctx);
+    queue_change(ctx, escrow_pda, &admin, CHANGE_ACTION_SET, EXTENSION_TYPE_TIMELOCK, timelock_value());
+
+    (escrow_pda, admin)
+}
+
+fn queue_change(ctx: &mut TestContext, escrow_pda: Pubkey, admin: &Keypair, action: u8, ext_type: u16, value: Vec<u8>) {
+    QueueChangeFixture::build_with_escrow(
+        ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        action,
+        ext_type,
+        value,
+    )
+    .send_expect_success(ctx);
+}
+
+fn timelock_value() -> Vec<u8> {
+    DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec()
+}
+
+fn warp_past_governance_delay(ctx: &mut TestContext) {
+    let now = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(now + DEFAULT_GOVERNANCE_DELAY as i64);
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_execute_queued_change_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ExecuteQueuedChangeFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_execute_queued_change_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ExecuteQueuedChangeFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_execute_queued_change_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ExecuteQueuedChangeFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_execute_queued_change_pending_change_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ExecuteQueuedChangeFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_execute_queued_change_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<ExecuteQueuedChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_execute_queued_change_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ExecuteQueuedChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_execute_queued_change_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ExecuteQueuedChangeFixture>(&mut ctx, 7, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_execute_queued_change_pending_change_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ExecuteQueuedChangeFixture>(&mut ctx, 5, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_execute_queued_change_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = ExecuteQueuedChangeFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_execute_queued_change_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<ExecuteQueuedChangeFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_execute_queued_change_before_delay_elapsed() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_delayed_timelock_change(&mut ctx);
+
+    let now = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(now + DEFAULT_GOVERNANCE_DELAY as i64 - 1);
+
+    let error = ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayNotElapsed);
+}
+
+#[test]
+fn test_execute_queued_change_uses_current_governance_delay() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_delayed_timelock_change(&mut ctx);
+    let queued_at = ctx.get_current_timestamp();
+
+    // Lengthen the delay after the timelock change was queued
+    let new_delay = DEFAULT_GOVERNANCE_DELAY * 2;
+    let delay_change_id = DEFAULT_CHANGE_ID + 1;
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        delay_change_id,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_GOVERNANCE_DELAY,
+        new_delay.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(&mut ctx);
+    warp_past_governance_delay(&mut ctx);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), delay_change_id)
+        .send_expect_success(&mut ctx);
+
+    // The timelock change is now held back by the longer delay
+    let error =
+        ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), DEFAULT_CHANGE_ID)
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayNotElapsed);
+
+    ctx.warp_to_timestamp(queued_at + new_delay as i64);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_execute_queued_change_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_delayed_timelock_change(&mut ctx);
+    warp_past_governance_delay(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_execute_queued_change_for_other_escrow_fails() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_delayed_timelock_change(&mut ctx);
+    let (other_escrow_pda, other_admin) = setup_escrow(&mut ctx);
+    warp_past_governance_delay(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    let error =
+        ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, other_escrow_pda, other_admin, DEFAULT_CHANGE_ID)
+            .with_account_at(5, pending_change_pda)
+            .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+
+    // The change can still be executed against its own escrow
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_execute_queued_change_fails_when_escrow_became_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_delayed_timelock_change(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+    warp_past_governance_delay(&mut ctx);
+
+    let error = ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_execute_queued_change_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = ExecuteQueuedChangeFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(3, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_execute_queued_change_without_governance_delay() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    queue_change(&mut ctx, escrow_pda, &admin, CHANGE_ACTION_SET, EXTENSION_TYPE_TIMELOCK, timelock_value());
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    assert_account_not_exists(&ctx, &pending_change_pda);
+    assert_timelock_extension(&ctx, &extensions_pda, DEFAULT_QUEUED_LOCK_DURATION);
+}
+
+#[test]
+fn test_execute_queued_change_after_delay_elapsed() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_delayed_timelock_change(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    warp_past_governance_delay(&mut ctx);
+
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    assert_account_not_exists(&ctx, &pending_change_pda);
+    assert_timelock_extension(&ctx, &extensions_pda, DEFAULT_QUEUED_LOCK_DURATION);
+}
+
+#[test]
+fn test_execute_queued_change_removes_extension() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    AddTimelockFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), DEFAULT_QUEUED_LOCK_DURATION)
+        .send_expect_success(&mut ctx);
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    queue_change(&mut ctx, escrow_pda, &admin, CHANGE_ACTION_REMOVE, EXTENSION_TYPE_TIMELOCK, vec![]);
+    warp_past_governance_delay(&mut ctx);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    assert_extension_missing(&ctx, &extensions_pda, EXTENSION_TYPE_TIMELOCK);
+}
+
+#[test]
+fn test_execute_queued_change_updates_governance_delay() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    let new_delay = DEFAULT_GOVERNANCE_DELAY * 2;
+    queue_change(
+        &mut ctx,
+        escrow_pda,
+        &admin,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_GOVERNANCE_DELAY,
+        new_delay.to_le_bytes().to_vec(),
+    );
+    warp_past_governance_delay(&mut ctx);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    assert_governance_delay_extension(&ctx, &extensions_pda, new_delay);
+}
+
+#[test]
+fn test_execute_queued_change_sets_roles() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    let extension_manager = Pubkey::new_unique();
+    let mut roles_value = vec![0u8; 96];
+    roles_value[32..64].copy_from_slice(extension_manager.as_ref());
+    queue_change(&mut ctx, escrow_pda, &admin, CHANGE_ACTION_SET, EXTENSION_TYPE_ROLES, roles_value);
+    warp_past_governance_delay(&mut ctx);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    assert_roles_extension(&ctx, &extensions_pda, &Pubkey::default(), &extension_manager, &Pubkey::default());
+}
+
+#[test]
+fn test_execute_queued_change_sets_arbiter_without_arbiter_signer() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    let arbiter = Keypair::new();
+    let arbiter_pubkey = arbiter.pubkey();
+
+    // The arbiter signs when the change is queued, not when it is executed
+    QueueChangeFixture::build_set_arbiter(&mut ctx, escrow_pda, admin.insecure_clone(), DEFAULT_CHANGE_ID, arbiter)
+        .send_expect_success(&mut ctx);
+    ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_success(&mut ctx);
+
+    assert_arbiter_extension(&ctx, &extensions_pda, &arbiter_pubkey);
+}
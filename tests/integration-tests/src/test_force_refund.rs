@@ -0,0 +1,270 @@
+use crate::{
+    fixtures::{
+        ForceRefundFixture, WithdrawSetup, DEFAULT_COOLDOWN_PERIOD, DEFAULT_DEPOSIT_AMOUNT,
+        DEFAULT_HOOK_BYPASS_GRACE_PERIOD, DEFAULT_REFUND_AUTHORITY_FLAGS,
+    },
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_custom_error, assert_escrow_error,
+        extensions_utils::{REFUND_AUTHORITY_FLAG_ADMIN, REFUND_AUTHORITY_FLAG_ARBITER},
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, test_wrong_token_program, EscrowError, TestContext, TEST_HOOK_ALLOW_ID,
+        TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+    },
+};
+use solana_sdk::{account::Account, instruction::InstructionError, pubkey::Pubkey};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_force_refund_missing_authority_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<ForceRefundFixture>(&mut ctx, 2, 0);
+}
+
+#[test]
+fn test_force_refund_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ForceRefundFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_force_refund_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ForceRefundFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_force_refund_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ForceRefundFixture>(&mut ctx, 6);
+}
+
+#[test]
+fn test_force_refund_depositor_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ForceRefundFixture>(&mut ctx, 8);
+}
+
+#[test]
+fn test_force_refund_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ForceRefundFixture>(&mut ctx, 15);
+}
+
+#[test]
+fn test_force_refund_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<ForceRefundFixture>(&mut ctx);
+}
+
+#[test]
+fn test_force_refund_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ForceRefundFixture>(&mut ctx);
+}
+
+#[test]
+fn test_force_refund_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ForceRefundFixture>(&mut ctx, 13, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_force_refund_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<ForceRefundFixture>(&mut ctx, 10);
+}
+
+#[test]
+fn test_force_refund_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ForceRefundFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_force_refund_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ForceRefundFixture>(&mut ctx, 5);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_force_refund_not_enabled() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let error = ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ForceRefundNotEnabled);
+}
+
+#[test]
+fn test_force_refund_wrong_authority() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).refund_authority(DEFAULT_REFUND_AUTHORITY_FLAGS).build();
+
+    let wrong_authority = ctx.create_funded_keypair();
+    let error = ForceRefundFixture::build_with_authority(&ctx, &setup, wrong_authority).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRefundAuthority);
+}
+
+#[test]
+fn test_force_refund_depositor_cannot_force_refund() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).refund_authority(DEFAULT_REFUND_AUTHORITY_FLAGS).build();
+
+    let depositor = setup.depositor.insecure_clone();
+    let error = ForceRefundFixture::build_with_authority(&ctx, &setup, depositor).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRefundAuthority);
+}
+
+#[test]
+fn test_force_refund_admin_rejected_when_only_arbiter_allowed() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).arbiter().refund_authority(REFUND_AUTHORITY_FLAG_ARBITER).build();
+
+    let error = ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRefundAuthority);
+}
+
+#[test]
+fn test_force_refund_arbiter_flag_without_arbiter_extension() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).refund_authority(REFUND_AUTHORITY_FLAG_ARBITER).build();
+
+    let admin = setup.admin.insecure_clone();
+    let error = ForceRefundFixture::build_with_authority(&ctx, &setup, admin).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRefundAuthority);
+}
+
+#[test]
+fn test_force_refund_wrong_rent_recipient_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).refund_authority(REFUND_AUTHORITY_FLAG_ADMIN).build();
+
+    let wrong_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(wrong_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+
+    let error =
+        ForceRefundFixture::build_with_rent_recipient(&ctx, &setup, wrong_recipient).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRentRecipient);
+}
+
+#[test]
+fn test_force_refund_blocked_by_deny_hook() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .refund_authority(REFUND_AUTHORITY_FLAG_ADMIN)
+        .build();
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_DENY_ID);
+
+    let error = ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+#[test]
+fn test_force_refund_requested_receipt_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .refund_authority(REFUND_AUTHORITY_FLAG_ADMIN)
+        .cooldown(DEFAULT_COOLDOWN_PERIOD)
+        .build();
+    setup.request_withdraw(&mut ctx);
+
+    let error = ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::WithdrawRequestPending);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_force_refund_admin_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).refund_authority(REFUND_AUTHORITY_FLAG_ADMIN).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.vault), 0);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+}
+
+#[test]
+fn test_force_refund_arbiter_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).arbiter().refund_authority(REFUND_AUTHORITY_FLAG_ARBITER).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+    let arbiter = setup.arbiter.as_ref().unwrap().insecure_clone();
+
+    ForceRefundFixture::build_with_authority(&ctx, &setup, arbiter).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_force_refund_ignores_timelock() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).timelock(3600).refund_authority(REFUND_AUTHORITY_FLAG_ADMIN).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_force_refund_with_allow_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .refund_authority(REFUND_AUTHORITY_FLAG_ADMIN)
+        .build();
+
+    ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_force_refund_closes_hook_bypass_request() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .refund_authority(REFUND_AUTHORITY_FLAG_ADMIN)
+        .build();
+    let hook_bypass_request_pda = setup.request_hook_bypass(&mut ctx);
+
+    // The grace period has not elapsed, but the request is closed with the refunded receipt
+    ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_none(), "Hook bypass request should be closed");
+}
+
+#[test]
+fn test_force_refund_token_2022_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).token_2022().refund_authority(REFUND_AUTHORITY_FLAG_ADMIN).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    ForceRefundFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+}
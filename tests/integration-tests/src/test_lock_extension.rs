@@ -0,0 +1,315 @@
+use crate::{
+    fixtures::{
+        CreateEscrowFixture, ExecuteQueuedChangeFixture, LockExtensionFixture, QueueChangeFixture,
+        RemoveExtensionFixture, SetArbiterFixture, SetGovernanceDelayFixture, SetHookFixture, SetImmutableFixture,
+        SetRolesFixture, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_GOVERNANCE_DELAY, DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::extensions_utils::{
+        EXTENSION_TYPE_EXTENSION_LOCKS, EXTENSION_TYPE_HOOK, EXTENSION_TYPE_ROLES, EXTENSION_TYPE_TIMELOCK,
+    },
+    utils::{
+        assert_arbiter_extension, assert_escrow_error, assert_extension_locks, assert_extension_missing,
+        assert_extensions_header, assert_hook_extension, assert_instruction_error, find_escrow_pda,
+        find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable, test_truncated_data,
+        test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture,
+        TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+/// Creates an escrow with a hook set and the hook extension locked
+fn setup_locked_hook(ctx: &mut TestContext) -> (Pubkey, Keypair, Pubkey) {
+    let (escrow_pda, admin) = setup_escrow(ctx);
+    let hook_program = Pubkey::new_unique();
+
+    SetHookFixture::build_with_escrow(ctx, escrow_pda, admin.insecure_clone(), hook_program).send_expect_success(ctx);
+    LockExtensionFixture::build_with_escrow(ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_HOOK)
+        .send_expect_success(ctx);
+
+    (escrow_pda, admin, hook_program)
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_lock_extension_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<LockExtensionFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_lock_extension_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<LockExtensionFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_lock_extension_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<LockExtensionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_extension_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<LockExtensionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_extension_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<LockExtensionFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_lock_extension_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = LockExtensionFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_lock_extension_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<LockExtensionFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_extension_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<LockExtensionFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_lock_extension_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, EXTENSION_TYPE_HOOK)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_lock_extension_extension_manager_cannot_lock() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, extension_manager, EXTENSION_TYPE_HOOK)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_lock_extension_unknown_extension_type() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, 999).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_lock_extension_cannot_lock_extension_locks() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_EXTENSION_LOCKS)
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_lock_extension_already_locked() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin, _) = setup_locked_hook(&mut ctx);
+
+    ctx.warp_to_slot(2);
+
+    let error = LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_HOOK)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+}
+
+#[test]
+fn test_lock_extension_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = LockExtensionFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_lock_extension_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_HOOK)
+        .send_expect_success(&mut ctx);
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_TIMELOCK)
+        .send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_extension_locks(&ctx, &extensions_pda, &[EXTENSION_TYPE_HOOK, EXTENSION_TYPE_TIMELOCK], false);
+}
+
+#[test]
+fn test_lock_extension_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_ROLES)
+        .send_expect_success(&mut ctx);
+    assert_extension_locks(&ctx, &extensions_pda, &[EXTENSION_TYPE_ROLES], false);
+
+    // Roles stay settable on immutable escrows unless locked
+    let mint_manager = Pubkey::new_unique();
+    let error = SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        mint_manager,
+        Pubkey::default(),
+        Pubkey::default(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+}
+
+// ============================================================================
+// Lock Enforcement Tests
+// ============================================================================
+
+#[test]
+fn test_locked_extension_cannot_be_updated_or_removed() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin, hook_program) = setup_locked_hook(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    let error = SetHookFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), Pubkey::new_unique())
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+
+    let error = RemoveExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_HOOK)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+
+    assert_hook_extension(&ctx, &extensions_pda, &hook_program);
+}
+
+#[test]
+fn test_locked_absent_extension_cannot_be_added() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_HOOK)
+        .send_expect_success(&mut ctx);
+
+    let error = SetHookFixture::build_with_escrow(&mut ctx, escrow_pda, admin, Pubkey::new_unique())
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+    assert_extension_missing(&ctx, &extensions_pda, EXTENSION_TYPE_HOOK);
+}
+
+#[test]
+fn test_unlocked_extensions_remain_settable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin, _) = setup_locked_hook(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    let arbiter = Keypair::new();
+    let arbiter_pubkey = arbiter.pubkey();
+
+    SetArbiterFixture::build_with_escrow(&mut ctx, escrow_pda, admin, arbiter).send_expect_success(&mut ctx);
+
+    assert_arbiter_extension(&ctx, &extensions_pda, &arbiter_pubkey);
+}
+
+#[test]
+fn test_extension_locks_cannot_be_removed() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin, _) = setup_locked_hook(&mut ctx);
+
+    let error = RemoveExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_EXTENSION_LOCKS)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+}
+
+#[test]
+fn test_locked_extension_blocks_queued_change() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(&mut ctx);
+
+    // Locking after the change was queued still prevents it from being applied
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_TIMELOCK)
+        .send_expect_success(&mut ctx);
+
+    let now = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(now + DEFAULT_GOVERNANCE_DELAY as i64);
+
+    let error = ExecuteQueuedChangeFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+}
@@ -0,0 +1,189 @@
+use crate::{
+    fixtures::{
+        AllowMintSetup, BlockMintSetup, CreateEscrowFixture, LockMintListFixture, SetImmutableFixture,
+        SetMintLimitsSetup,
+    },
+    utils::{
+        assert_account_exists, assert_account_not_exists, assert_allowed_mint_limits, assert_escrow_error,
+        assert_extension_locks, assert_instruction_error, find_escrow_pda, find_extensions_pda, test_empty_data,
+        test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account, test_wrong_current_program,
+        test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_lock_mint_list_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<LockMintListFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_lock_mint_list_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<LockMintListFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_lock_mint_list_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<LockMintListFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_mint_list_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<LockMintListFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_mint_list_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<LockMintListFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_lock_mint_list_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = LockMintListFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_lock_mint_list_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<LockMintListFixture>(&mut ctx);
+}
+
+#[test]
+fn test_lock_mint_list_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<LockMintListFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_lock_mint_list_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = LockMintListFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_lock_mint_list_already_locked() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    LockMintListFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    ctx.warp_to_slot(2);
+
+    let error = LockMintListFixture::build_with_escrow(&mut ctx, escrow_pda, admin).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MintListLocked);
+}
+
+#[test]
+fn test_lock_mint_list_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = LockMintListFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_lock_mint_list_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    LockMintListFixture::build_with_escrow(&mut ctx, escrow_pda, admin).send_expect_success(&mut ctx);
+
+    assert_extension_locks(&ctx, &extensions_pda, &[], true);
+}
+
+#[test]
+fn test_lock_mint_list_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+    LockMintListFixture::build_with_escrow(&mut ctx, escrow_pda, admin).send_expect_success(&mut ctx);
+
+    assert_extension_locks(&ctx, &extensions_pda, &[], true);
+}
+
+// ============================================================================
+// Lock Enforcement Tests
+// ============================================================================
+
+#[test]
+fn test_locked_mint_list_blocks_allow_mint() {
+    let mut ctx = TestContext::new();
+    let setup = AllowMintSetup::new(&mut ctx);
+
+    LockMintListFixture::build_with_escrow(&mut ctx, setup.escrow_pda, setup.admin.insecure_clone())
+        .send_expect_success(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MintListLocked);
+    assert_account_not_exists(&ctx, &setup.allowed_mint_pda);
+}
+
+#[test]
+fn test_locked_mint_list_blocks_block_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BlockMintSetup::new(&mut ctx);
+
+    LockMintListFixture::build_with_escrow(&mut ctx, setup.escrow_pda, setup.admin.insecure_clone())
+        .send_expect_success(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::MintListLocked);
+    assert_account_exists(&ctx, &setup.allowed_mint_pda);
+}
+
+#[test]
+fn test_locked_mint_list_allows_set_mint_limits() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    LockMintListFixture::build_with_escrow(&mut ctx, setup.escrow_pda, setup.admin.insecure_clone())
+        .send_expect_success(&mut ctx);
+
+    setup.build_instruction(&ctx, 1_000_000, 10, 100_000).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 1_000_000, 10, 100_000);
+}
@@ -0,0 +1,342 @@
+use crate::{
+    fixtures::{
+        CreateEscrowFixture, QueueChangeFixture, SetGovernanceDelayFixture, SetImmutableFixture, SetRolesFixture,
+        CHANGE_ACTION_REMOVE, CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_GOVERNANCE_DELAY,
+        DEFAULT_QUEUED_LOCK_DURATION,
+    },
+    utils::extensions_utils::{EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_ROLES, EXTENSION_TYPE_TIMELOCK},
+    utils::{
+        assert_escrow_error, assert_instruction_error, assert_pending_change, find_escrow_pda, find_pending_change_pda,
+        test_empty_data, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account,
+        test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext,
+        RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+fn timelock_value() -> Vec<u8> {
+    DEFAULT_QUEUED_LOCK_DURATION.to_le_bytes().to_vec()
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_queue_change_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<QueueChangeFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_queue_change_pending_change_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<QueueChangeFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_queue_change_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<QueueChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_queue_change_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<QueueChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_queue_change_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<QueueChangeFixture>(&mut ctx, 6, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_queue_change_invalid_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = QueueChangeFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_queue_change_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<QueueChangeFixture>(&mut ctx);
+}
+
+#[test]
+fn test_queue_change_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<QueueChangeFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_queue_change_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        wrong_admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_queue_change_extension_manager_cannot_queue_roles() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        extension_manager,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_ROLES,
+        vec![],
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_queue_change_invalid_extension_value() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![1, 2, 3],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_queue_change_remove_with_value_fails() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_queue_change_set_arbiter_requires_arbiter_signer() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_ARBITER,
+        Pubkey::new_unique().to_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+}
+
+#[test]
+fn test_queue_change_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_queue_change_duplicate_change_id_fails() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_success(&mut ctx);
+
+    ctx.warp_to_slot(2);
+
+    let error = QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::AccountAlreadyInitialized);
+}
+
+#[test]
+fn test_queue_change_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = QueueChangeFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_queue_change_without_governance_delay_is_executable_immediately() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let now = ctx.get_current_timestamp();
+
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    assert_pending_change(&ctx, &pending_change_pda, &escrow_pda, DEFAULT_CHANGE_ID, now);
+}
+
+#[test]
+fn test_queue_change_with_governance_delay() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    ctx.warp_to_timestamp(1_000);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        timelock_value(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    assert_pending_change(&ctx, &pending_change_pda, &escrow_pda, DEFAULT_CHANGE_ID, 1_000);
+}
+
+#[test]
+fn test_queue_change_set_arbiter_with_arbiter_signer() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let arbiter = Keypair::new();
+
+    QueueChangeFixture::build_set_arbiter(&mut ctx, escrow_pda, admin, DEFAULT_CHANGE_ID, arbiter)
+        .send_expect_success(&mut ctx);
+
+    let (pending_change_pda, _) = find_pending_change_pda(&escrow_pda, DEFAULT_CHANGE_ID);
+    let now = ctx.get_current_timestamp();
+    assert_pending_change(&ctx, &pending_change_pda, &escrow_pda, DEFAULT_CHANGE_ID, now);
+}
+
+#[test]
+fn test_queue_change_roles_on_immutable_escrow() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_ROLES,
+        vec![],
+    )
+    .send_expect_success(&mut ctx);
+}
@@ -0,0 +1,232 @@
+use crate::{
+    fixtures::{
+        ReleaseMaturedFixture, RemoveExtensionFixture, WithdrawSetup, DEFAULT_DEPOSIT_AMOUNT,
+        DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_HOOK,
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_escrow_error, test_not_writable, test_wrong_account,
+        test_wrong_current_program, test_wrong_owner, test_wrong_system_program, test_wrong_token_program, EscrowError,
+        TestContext, TEST_HOOK_ALLOW_ID,
+    },
+};
+use solana_sdk::{account::Account, instruction::InstructionError, pubkey::Pubkey};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_release_matured_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ReleaseMaturedFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_release_matured_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ReleaseMaturedFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_release_matured_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ReleaseMaturedFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_release_matured_depositor_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ReleaseMaturedFixture>(&mut ctx, 7);
+}
+
+#[test]
+fn test_release_matured_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<ReleaseMaturedFixture>(&mut ctx, 14);
+}
+
+#[test]
+fn test_release_matured_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<ReleaseMaturedFixture>(&mut ctx);
+}
+
+#[test]
+fn test_release_matured_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<ReleaseMaturedFixture>(&mut ctx);
+}
+
+#[test]
+fn test_release_matured_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<ReleaseMaturedFixture>(&mut ctx, 12, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_release_matured_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<ReleaseMaturedFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_release_matured_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ReleaseMaturedFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_release_matured_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<ReleaseMaturedFixture>(&mut ctx, 4);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_release_matured_not_enabled() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let error = ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::AutoReleaseNotEnabled);
+}
+
+#[test]
+fn test_release_matured_blocked_by_hook() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).hook_program(TEST_HOOK_ALLOW_ID).auto_release(0).build();
+
+    let error = ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::AutoReleaseBlocked);
+}
+
+#[test]
+fn test_release_matured_blocked_by_arbiter() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).arbiter().auto_release(0).build();
+
+    let error = ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::AutoReleaseBlocked);
+}
+
+#[test]
+fn test_release_matured_before_release_delay() {
+    let mut ctx = TestContext::new();
+    let release_delay = 3600;
+    let setup = WithdrawSetup::builder(&mut ctx).auto_release(release_delay).build();
+
+    let error = ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ReceiptNotMatured);
+}
+
+#[test]
+fn test_release_matured_delay_runs_from_timelock_expiry() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let release_delay = 600;
+    let setup = WithdrawSetup::builder(&mut ctx).timelock(lock_duration).auto_release(release_delay).build();
+
+    // Unlocked, but the release delay has not elapsed since
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + lock_duration as i64 + 1);
+    let error = ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ReceiptNotMatured);
+
+    ctx.warp_to_timestamp(current_time + (lock_duration + release_delay) as i64 + 1);
+    ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_release_matured_wrong_rent_recipient_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).auto_release(0).build();
+
+    let wrong_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(wrong_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+
+    let error =
+        ReleaseMaturedFixture::build_with_rent_recipient(&ctx, &setup, wrong_recipient).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRentRecipient);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_release_matured_success() {
+    let mut ctx = TestContext::new();
+    let release_delay = 3600;
+    let setup = WithdrawSetup::builder(&mut ctx).auto_release(release_delay).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + release_delay as i64 + 1);
+
+    // Sent and paid for by the test payer, not the depositor
+    ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.vault), 0);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+}
+
+#[test]
+fn test_release_matured_token_2022_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).token_2022().auto_release(0).build();
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_release_matured_refunds_receipt_rent_recipient() {
+    let mut ctx = TestContext::new();
+    let rent_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(rent_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+    let setup = WithdrawSetup::builder(&mut ctx).rent_recipient(rent_recipient).auto_release(0).build();
+
+    let receipt_lamports = ctx.get_account(&setup.receipt_pda).unwrap().lamports;
+
+    ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_account(&rent_recipient).unwrap().lamports, 1_000_000 + receipt_lamports);
+}
+
+#[test]
+fn test_release_matured_closes_hook_bypass_request() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .auto_release(0)
+        .build();
+    let hook_bypass_request_pda = setup.request_hook_bypass(&mut ctx);
+
+    // Once the hook is removed the receipt can be released, taking the leftover request with it
+    RemoveExtensionFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        EXTENSION_TYPE_HOOK,
+    )
+    .send_expect_success(&mut ctx);
+    setup.hook_program = None;
+
+    ReleaseMaturedFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_none(), "Hook bypass request should be closed");
+}
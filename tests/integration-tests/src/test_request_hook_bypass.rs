@@ -0,0 +1,229 @@
+use crate::{
+    fixtures::{
+        BasketSetup, RequestHookBypassFixture, SetMintOverrideFixture, WithdrawSetup, CHANGE_ACTION_SET,
+        DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_HOOK,
+    utils::{
+        assert_escrow_error, assert_instruction_error, find_hook_bypass_request_pda, test_empty_data,
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext, TEST_HOOK_ALLOW_ID,
+    },
+};
+use escrow_program_client::accounts::HookBypassRequest;
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_request_hook_bypass_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<RequestHookBypassFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_request_hook_bypass_request_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RequestHookBypassFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_request_hook_bypass_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<RequestHookBypassFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_hook_bypass_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<RequestHookBypassFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_hook_bypass_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<RequestHookBypassFixture>(&mut ctx, 7, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_request_hook_bypass_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<RequestHookBypassFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_request_hook_bypass_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<RequestHookBypassFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_request_hook_bypass_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<RequestHookBypassFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_hook_bypass_invalid_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = RequestHookBypassFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_sub(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_request_hook_bypass_wrong_depositor() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+
+    let impostor = ctx.create_funded_keypair();
+    let mut test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup).with_account_at(1, impostor.pubkey());
+    test_ix.instruction.accounts[1].is_signer = true;
+    test_ix.signers = vec![impostor];
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_request_hook_bypass_without_hook_bypass_extension() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::HookBypassNotEnabled);
+}
+
+#[test]
+fn test_request_hook_bypass_without_hook_extension() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD).build();
+
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::HookBypassNotEnabled);
+}
+
+#[test]
+fn test_request_hook_bypass_wrong_mint() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup).with_account_at(9, Pubkey::new_unique());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_request_hook_bypass_basket_wrong_mint() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let test_ix = RequestHookBypassFixture::build_for_basket(&ctx, &setup, basket_receipt, 0)
+        .with_account_at(9, Pubkey::new_unique());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_request_hook_bypass_twice_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    setup.request_hook_bypass(&mut ctx);
+
+    ctx.warp_to_slot(2);
+
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::AccountAlreadyInitialized);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_request_hook_bypass_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+
+    let requested_at = ctx.get_current_timestamp();
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (hook_bypass_request_pda, bump) = find_hook_bypass_request_pda(&setup.receipt_pda);
+    let account = ctx.get_account(&hook_bypass_request_pda).expect("Hook bypass request should exist");
+    let request = HookBypassRequest::from_bytes(&account.data).expect("Should deserialize hook bypass request");
+
+    assert_eq!(request.bump, bump);
+    assert_eq!(request.receipt, setup.receipt_pda);
+    assert_eq!(request.requested_at, requested_at);
+}
+
+#[test]
+fn test_request_hook_bypass_with_mint_hook_override() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD).build();
+
+    // The escrow has no hook of its own, only the mint does
+    let mut hook_value = TEST_HOOK_ALLOW_ID.to_bytes().to_vec();
+    hook_value.push(0); // flags
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value,
+    )
+    .send_expect_success(&mut ctx);
+
+    let test_ix = RequestHookBypassFixture::build_with_setup(&ctx, &setup);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (hook_bypass_request_pda, _) = find_hook_bypass_request_pda(&setup.receipt_pda);
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_some(), "Hook bypass request should exist");
+}
+
+#[test]
+fn test_request_hook_bypass_for_basket_receipt() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    // Any leg's mint resolves the hook for the whole basket
+    RequestHookBypassFixture::build_for_basket(&ctx, &setup, basket_receipt, 1).send_expect_success(&mut ctx);
+
+    let (hook_bypass_request_pda, _) = find_hook_bypass_request_pda(&basket_receipt);
+    let account = ctx.get_account(&hook_bypass_request_pda).expect("Hook bypass request should exist");
+    let request = HookBypassRequest::from_bytes(&account.data).expect("Should deserialize hook bypass request");
+    assert_eq!(request.receipt, basket_receipt);
+}
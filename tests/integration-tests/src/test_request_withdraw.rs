@@ -0,0 +1,144 @@
+use crate::{
+    fixtures::{RequestWithdrawFixture, WithdrawSetup, DEFAULT_COOLDOWN_PERIOD},
+    utils::{
+        assert_escrow_error, assert_instruction_error, find_withdraw_request_pda, test_empty_data, test_missing_signer,
+        test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner, test_wrong_system_program,
+        EscrowError, InstructionTestFixture, TestContext,
+    },
+};
+use escrow_program_client::accounts::{Receipt, WithdrawRequest};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_request_withdraw_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<RequestWithdrawFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_request_withdraw_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RequestWithdrawFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_request_withdraw_request_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RequestWithdrawFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_request_withdraw_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<RequestWithdrawFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_withdraw_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<RequestWithdrawFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_withdraw_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<RequestWithdrawFixture>(&mut ctx, 7, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_request_withdraw_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<RequestWithdrawFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_request_withdraw_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<RequestWithdrawFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_request_withdraw_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<RequestWithdrawFixture>(&mut ctx);
+}
+
+#[test]
+fn test_request_withdraw_invalid_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = RequestWithdrawFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_sub(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_request_withdraw_wrong_depositor() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+
+    let impostor = ctx.create_funded_keypair();
+    let mut test_ix = RequestWithdrawFixture::build_with_setup(&ctx, &setup).with_account_at(1, impostor.pubkey());
+    test_ix.instruction.accounts[1].is_signer = true;
+    test_ix.signers = vec![impostor];
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_request_withdraw_without_cooldown_extension() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let test_ix = RequestWithdrawFixture::build_with_setup(&ctx, &setup);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::CooldownNotEnabled);
+}
+
+#[test]
+fn test_request_withdraw_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+    setup.request_withdraw(&mut ctx);
+
+    ctx.warp_to_slot(2);
+
+    let test_ix = RequestWithdrawFixture::build_with_setup(&ctx, &setup);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::WithdrawRequestPending);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_request_withdraw_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+
+    let requested_at = ctx.get_current_timestamp();
+    let test_ix = RequestWithdrawFixture::build_with_setup(&ctx, &setup);
+    test_ix.send_expect_success(&mut ctx);
+
+    let (withdraw_request_pda, bump) = find_withdraw_request_pda(&setup.receipt_pda);
+    let account = ctx.get_account(&withdraw_request_pda).expect("Withdraw request should exist");
+    let request = WithdrawRequest::from_bytes(&account.data).expect("Should deserialize withdraw request");
+
+    assert_eq!(request.bump, bump);
+    assert_eq!(request.receipt, setup.receipt_pda);
+    assert_eq!(request.requested_at, requested_at);
+
+    let receipt_account = ctx.get_account(&setup.receipt_pda).expect("Receipt should exist");
+    let receipt = Receipt::from_bytes(&receipt_account.data).expect("Should deserialize receipt");
+    assert!(receipt.withdraw_requested, "Receipt should be marked as requested");
+}
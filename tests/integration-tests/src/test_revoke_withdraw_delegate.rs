@@ -0,0 +1,127 @@
+use crate::{
+    fixtures::{RevokeWithdrawDelegateFixture, WithdrawSetup},
+    utils::{
+        assert_account_not_exists, assert_escrow_error, assert_instruction_error, find_withdraw_delegate_pda,
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_revoke_withdraw_delegate_missing_depositor_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<RevokeWithdrawDelegateFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RevokeWithdrawDelegateFixture>(&mut ctx, 0);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_withdraw_delegate_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<RevokeWithdrawDelegateFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<RevokeWithdrawDelegateFixture>(&mut ctx);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<RevokeWithdrawDelegateFixture>(&mut ctx, 4, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<RevokeWithdrawDelegateFixture>(&mut ctx, 3, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = RevokeWithdrawDelegateFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_revoke_withdraw_delegate_wrong_depositor() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    setup.approve_withdraw_delegate(&mut ctx, Pubkey::new_unique(), 0);
+
+    let impostor = ctx.create_funded_keypair();
+    let mut test_ix =
+        RevokeWithdrawDelegateFixture::build_with_setup(&ctx, &setup).with_account_at(1, impostor.pubkey());
+    test_ix.instruction.accounts[1].is_signer = true;
+    test_ix.signers = vec![impostor];
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_wrong_escrow() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    setup.approve_withdraw_delegate(&mut ctx, Pubkey::new_unique(), 0);
+    let other_setup = WithdrawSetup::new(&mut ctx);
+
+    let test_ix =
+        RevokeWithdrawDelegateFixture::build_with_setup(&ctx, &setup).with_account_at(2, other_setup.escrow_pda);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawDelegate);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_revoke_withdraw_delegate_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    let withdraw_delegate_pda = setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    RevokeWithdrawDelegateFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    assert_account_not_exists(&ctx, &withdraw_delegate_pda);
+
+    // A revoked delegate can no longer withdraw
+    let error =
+        setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey()).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_revoke_withdraw_delegate_after_receipt_closed() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    setup.approve_withdraw_delegate(&mut ctx, Pubkey::new_unique(), 0);
+
+    // Depositor withdraws themselves, leaving the approval behind
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    RevokeWithdrawDelegateFixture::build_with_setup(&ctx, &setup).send_expect_success(&mut ctx);
+
+    let (withdraw_delegate_pda, _) = find_withdraw_delegate_pda(&setup.receipt_pda);
+    assert_account_not_exists(&ctx, &withdraw_delegate_pda);
+}
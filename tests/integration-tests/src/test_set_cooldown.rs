@@ -0,0 +1,164 @@
+use crate::{
+    fixtures::{CreateEscrowFixture, SetCooldownFixture, SetImmutableFixture, DEFAULT_COOLDOWN_PERIOD},
+    utils::{
+        assert_cooldown_extension, assert_escrow_error, assert_extensions_header, assert_instruction_error,
+        find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable,
+        test_truncated_data, test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_cooldown_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetCooldownFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_cooldown_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetCooldownFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_cooldown_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetCooldownFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_cooldown_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetCooldownFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_cooldown_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetCooldownFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_cooldown_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetCooldownFixture::build_valid(&mut ctx);
+    let correct_bump = test_ix.instruction.data[1];
+    let invalid_bump = correct_bump.wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_cooldown_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetCooldownFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_cooldown_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetCooldownFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_cooldown_wrong_admin() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let wrong_admin = ctx.create_funded_keypair();
+    let test_ix = SetCooldownFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_COOLDOWN_PERIOD);
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_cooldown_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetCooldownFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_cooldown_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let set_immutable_ix = SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone());
+    set_immutable_ix.send_expect_success(&mut ctx);
+
+    let test_ix = SetCooldownFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_COOLDOWN_PERIOD);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_set_cooldown_cooldown_period_overflow() {
+    let mut ctx = TestContext::new();
+    let mut test_ix = SetCooldownFixture::build_valid(&mut ctx);
+    test_ix.instruction.data[2..10].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_set_cooldown_success() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetCooldownFixture::build_valid(&mut ctx);
+
+    let extensions_pda = test_ix.instruction.accounts[3].pubkey;
+    let extensions_bump = test_ix.instruction.data[1];
+
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_cooldown_extension(&ctx, &extensions_pda, DEFAULT_COOLDOWN_PERIOD);
+}
+
+#[test]
+fn test_set_cooldown_updates_existing_extension() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    let first_ix = SetCooldownFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), 3600);
+    first_ix.send_expect_success(&mut ctx);
+    assert_cooldown_extension(&ctx, &extensions_pda, 3600);
+
+    let second_ix = SetCooldownFixture::build_with_escrow(&mut ctx, escrow_pda, admin, 7200);
+    second_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_cooldown_extension(&ctx, &extensions_pda, 7200);
+}
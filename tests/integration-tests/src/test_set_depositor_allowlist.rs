@@ -0,0 +1,163 @@
+use crate::{
+    fixtures::{CreateEscrowFixture, SetDepositorAllowlistFixture, SetImmutableFixture, DEFAULT_ALLOWLIST_ROOT},
+    utils::{
+        assert_depositor_allowlist_extension, assert_escrow_error, assert_extensions_header, assert_instruction_error,
+        find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable,
+        test_truncated_data, test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_depositor_allowlist_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetDepositorAllowlistFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_depositor_allowlist_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetDepositorAllowlistFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_depositor_allowlist_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetDepositorAllowlistFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_depositor_allowlist_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetDepositorAllowlistFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_depositor_allowlist_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetDepositorAllowlistFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_depositor_allowlist_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetDepositorAllowlistFixture::build_valid(&mut ctx);
+    let correct_bump = test_ix.instruction.data[1];
+    let invalid_bump = correct_bump.wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_depositor_allowlist_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetDepositorAllowlistFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_depositor_allowlist_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetDepositorAllowlistFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_depositor_allowlist_wrong_admin() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let wrong_admin = ctx.create_funded_keypair();
+    let test_ix =
+        SetDepositorAllowlistFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_ALLOWLIST_ROOT, 0);
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_depositor_allowlist_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetDepositorAllowlistFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_depositor_allowlist_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let set_immutable_ix = SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone());
+    set_immutable_ix.send_expect_success(&mut ctx);
+
+    let test_ix =
+        SetDepositorAllowlistFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_ALLOWLIST_ROOT, 0);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_set_depositor_allowlist_success() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetDepositorAllowlistFixture::build_valid(&mut ctx);
+
+    let extensions_pda = test_ix.instruction.accounts[3].pubkey;
+    let extensions_bump = test_ix.instruction.data[1];
+
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_depositor_allowlist_extension(&ctx, &extensions_pda, &DEFAULT_ALLOWLIST_ROOT, 0);
+}
+
+#[test]
+fn test_set_depositor_allowlist_rotates_root() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    let first_ix = SetDepositorAllowlistFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_ALLOWLIST_ROOT,
+        1_000,
+    );
+    first_ix.send_expect_success(&mut ctx);
+    assert_depositor_allowlist_extension(&ctx, &extensions_pda, &DEFAULT_ALLOWLIST_ROOT, 1_000);
+
+    let rotated_root = [9u8; 32];
+    let second_ix = SetDepositorAllowlistFixture::build_with_escrow(&mut ctx, escrow_pda, admin, rotated_root, 0);
+    second_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_depositor_allowlist_extension(&ctx, &extensions_pda, &rotated_root, 0);
+}
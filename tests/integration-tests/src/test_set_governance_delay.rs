@@ -0,0 +1,211 @@
+use crate::{
+    fixtures::{
+        AddTimelockFixture, CreateEscrowFixture, RemoveExtensionFixture, SetGovernanceDelayFixture,
+        SetImmutableFixture, SetRolesFixture, DEFAULT_GOVERNANCE_DELAY,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_GOVERNANCE_DELAY,
+    utils::{
+        assert_escrow_error, assert_extensions_header, assert_governance_delay_extension, assert_instruction_error,
+        find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable,
+        test_truncated_data, test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_governance_delay_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetGovernanceDelayFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_governance_delay_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetGovernanceDelayFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_governance_delay_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetGovernanceDelayFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_governance_delay_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetGovernanceDelayFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_governance_delay_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetGovernanceDelayFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_governance_delay_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetGovernanceDelayFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_governance_delay_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetGovernanceDelayFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_governance_delay_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetGovernanceDelayFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_governance_delay_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error =
+        SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_GOVERNANCE_DELAY)
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_governance_delay_extension_manager_cannot_set() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error =
+        SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, extension_manager, DEFAULT_GOVERNANCE_DELAY)
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_governance_delay_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    let error = SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_GOVERNANCE_DELAY)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_set_governance_delay_rejects_delay_above_i64_max() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, admin, i64::MAX as u64 + 1)
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_governance_delay_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetGovernanceDelayFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_set_governance_delay_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_GOVERNANCE_DELAY)
+        .send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_governance_delay_extension(&ctx, &extensions_pda, DEFAULT_GOVERNANCE_DELAY);
+}
+
+// ============================================================================
+// Direct Setter Gating Tests
+// ============================================================================
+
+#[test]
+fn test_governance_delay_blocks_direct_extension_setters() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = AddTimelockFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), 3_600)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayActive);
+
+    let first = Pubkey::new_unique();
+    let error = SetRolesFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), first, first, first)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayActive);
+
+    let error = RemoveExtensionFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        EXTENSION_TYPE_GOVERNANCE_DELAY,
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayActive);
+
+    // The delay itself can only be changed through a queued change
+    let error =
+        SetGovernanceDelayFixture::build_with_escrow(&mut ctx, escrow_pda, admin, 0).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayActive);
+}
@@ -1,18 +1,31 @@
 use crate::{
     fixtures::{
         AddBlockTokenExtensionsFixture, AddTimelockFixture, CreateEscrowFixture, SetHookFixture, SetImmutableFixture,
-        UnblockTokenExtensionFixture,
+        UnblockTokenExtensionFixture, WithdrawSetup,
+    },
+    utils::extensions_utils::HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS,
+    utils::hook_config::{
+        create_hook_config, create_hook_log, read_hook_log, HookConfig, HOOK_POINT_POST_WITHDRAW,
+        HOOK_POINT_PRE_WITHDRAW,
     },
     utils::{
         assert_block_token_extensions_extension, assert_escrow_error, assert_extensions_header, assert_hook_extension,
-        assert_instruction_error, assert_timelock_extension, find_escrow_pda, find_extensions_pda, test_empty_data,
-        test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account, test_wrong_current_program,
-        test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+        assert_hook_extension_with_flags, assert_instruction_error, assert_timelock_extension, find_escrow_pda,
+        find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable, test_truncated_data,
+        test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture,
+        TestContext, ESCROW_PROGRAM_ID, RANDOM_PUBKEY, TEST_HOOK_ALLOW_ID, TEST_HOOK_CONFIGURABLE_ID,
     },
 };
 use escrow_program_client::instructions::SetHookBuilder;
 use solana_address::Address;
-use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+use solana_system_interface::program::ID as SYSTEM_PROGRAM_ID;
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
 
 // ============================================================================
 // Error Tests - Using Generic Test Helpers
@@ -58,6 +71,36 @@ fn test_set_hook_invalid_extensions_bump() {
     assert_instruction_error(error, InstructionError::InvalidSeeds);
 }
 
+#[test]
+fn test_set_hook_unknown_flags() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetHookFixture::build_valid(&mut ctx);
+    let error = test_ix.with_data_byte_at(34, 0b100).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_hook_escrow_program_as_hook() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetHookFixture::build_valid(&mut ctx);
+    let mut data = test_ix.instruction.data.clone();
+    data[2..34].copy_from_slice(&ESCROW_PROGRAM_ID.to_bytes());
+    let error = test_ix.with_data(data).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_hook_token_and_system_programs_as_hook() {
+    for program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, SYSTEM_PROGRAM_ID] {
+        let mut ctx = TestContext::new();
+        let test_ix = SetHookFixture::build_valid(&mut ctx);
+        let mut data = test_ix.instruction.data.clone();
+        data[2..34].copy_from_slice(&program.to_bytes());
+        let error = test_ix.with_data(data).send_expect_error(&mut ctx);
+        assert_instruction_error(error, InstructionError::InvalidInstructionData);
+    }
+}
+
 #[test]
 fn test_set_hook_empty_data() {
     let mut ctx = TestContext::new();
@@ -164,6 +207,39 @@ fn test_set_hook_success() {
     assert_hook_extension(&ctx, &extensions_pda, &hook_program);
 }
 
+#[test]
+fn test_set_hook_with_writable_hook_accounts_flag() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+    let hook_program = Pubkey::new_unique();
+
+    let hook_ix = SetHookFixture::build_with_flags(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        hook_program,
+        HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS,
+    );
+    hook_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_hook_extension_with_flags(&ctx, &extensions_pda, &hook_program, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS);
+
+    // Clearing the flag updates the entry in place
+    let hook_ix = SetHookFixture::build_with_escrow(&mut ctx, escrow_pda, admin, hook_program);
+    hook_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_hook_extension_with_flags(&ctx, &extensions_pda, &hook_program, 0);
+}
+
 #[test]
 fn test_set_hook_hook_program_zero_address() {
     let mut ctx = TestContext::new();
@@ -185,6 +261,7 @@ fn test_set_hook_hook_program_zero_address() {
         .extensions(extensions_pda)
         .extensions_bump(extensions_bump)
         .hook_program(Address::from(hook_program.to_bytes()))
+        .flags(0)
         .instruction();
 
     let test_ix = crate::utils::TestInstruction { instruction, signers: vec![admin], name: "SetHook" };
@@ -310,3 +387,72 @@ fn test_multiple_extensions_extension_count() {
     hook_ix.send_expect_success(&mut ctx);
     assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 2);
 }
+
+// ============================================================================
+// Hook Invocation Tests
+// ============================================================================
+
+#[test]
+fn test_set_hook_replacement_receives_read_only_context() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.set_hook(&mut ctx, TEST_HOOK_CONFIGURABLE_ID);
+
+    // The newly set hook asserts it sees the withdraw core accounts in order, read-only and unsigned
+    let core_accounts = [setup.escrow_pda, setup.depositor.pubkey(), setup.mint.pubkey(), setup.receipt_pda];
+    let config = create_hook_config(
+        &mut ctx,
+        &HookConfig::new().expect(&[HOOK_POINT_PRE_WITHDRAW, HOOK_POINT_POST_WITHDRAW], &core_accounts),
+    );
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_set_hook_without_writable_flag_hook_cannot_log() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.set_hook(&mut ctx, TEST_HOOK_CONFIGURABLE_ID);
+
+    let config = create_hook_config(&mut ctx, &HookConfig::new().log());
+    let log = create_hook_log(&mut ctx, &config);
+
+    // The log is downgraded to read-only, so the hook fails trying to record and the
+    // withdraw aborts with the hook's own error
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_set_hook_with_writable_flag_hook_logs_invocations() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    SetHookFixture::build_with_flags(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        TEST_HOOK_CONFIGURABLE_ID,
+        HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS,
+    )
+    .send_expect_success(&mut ctx);
+    setup.hook_program = Some(TEST_HOOK_CONFIGURABLE_ID);
+
+    let config = create_hook_config(&mut ctx, &HookConfig::new().log());
+    let log = create_hook_log(&mut ctx, &config);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let (count, entries) = read_hook_log(&ctx, &log);
+    assert_eq!(count, 2);
+    assert_eq!(entries[0].hook_point, HOOK_POINT_PRE_WITHDRAW);
+    assert_eq!(entries[1].hook_point, HOOK_POINT_POST_WITHDRAW);
+    assert!(entries.iter().all(|entry| entry.writable_count == 1), "Only the log should be writable");
+}
@@ -0,0 +1,326 @@
+use crate::{
+    fixtures::{
+        CreateEscrowFixture, LockExtensionFixture, RemoveExtensionFixture, SetGovernanceDelayFixture,
+        SetImmutableFixture, SetMetadataFixture, SetRolesFixture, DEFAULT_GOVERNANCE_DELAY,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE, DEFAULT_METADATA_NAME, DEFAULT_METADATA_URI,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_METADATA,
+    utils::{
+        assert_escrow_error, assert_extension_missing, assert_extensions_header, assert_instruction_error,
+        assert_metadata_extension, find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer,
+        test_not_writable, test_truncated_data, test_wrong_account, test_wrong_current_program,
+        test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+fn set_default_metadata(ctx: &mut TestContext, escrow_pda: Pubkey, admin: Keypair) {
+    SetMetadataFixture::build_with_escrow(
+        ctx,
+        escrow_pda,
+        admin,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    )
+    .send_expect_success(ctx);
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_metadata_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetMetadataFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_metadata_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetMetadataFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_metadata_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetMetadataFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_metadata_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetMetadataFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_metadata_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetMetadataFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_metadata_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetMetadataFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_metadata_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetMetadataFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_metadata_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetMetadataFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_metadata_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let error = SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, "name", "", &[])
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_metadata_name_too_long() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let long_name = "a".repeat(65);
+    let error = SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, &long_name, "", &[])
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_metadata_uri_too_long() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let long_uri = "a".repeat(201);
+    let error = SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, "", &long_uri, &[])
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_metadata_external_reference_too_long() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+
+    let error = SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, "", "", &[7u8; 65])
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_metadata_invalid_utf8_name() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetMetadataFixture::build_valid(&mut ctx);
+
+    // discriminator (1) + extensions_bump (1) + name length (4)
+    let error = test_ix.with_data_byte_at(6, 0xFF).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_metadata_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetMetadataFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_metadata_fails_when_locked() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    set_default_metadata(&mut ctx, escrow_pda, admin.insecure_clone());
+    LockExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), EXTENSION_TYPE_METADATA)
+        .send_expect_success(&mut ctx);
+
+    let error = SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, "renamed", "", &[])
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+
+    assert_metadata_extension(
+        &ctx,
+        &extensions_pda,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    );
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_set_metadata_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    set_default_metadata(&mut ctx, escrow_pda, admin);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_metadata_extension(
+        &ctx,
+        &extensions_pda,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    );
+}
+
+#[test]
+fn test_set_metadata_max_length_fields() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    let name = "n".repeat(64);
+    let uri = "u".repeat(200);
+    let external_reference = [9u8; 64];
+    SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, &name, &uri, &external_reference)
+        .send_expect_success(&mut ctx);
+
+    assert_metadata_extension(&ctx, &extensions_pda, &name, &uri, &external_reference);
+}
+
+#[test]
+fn test_set_metadata_replaces_existing_value() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    set_default_metadata(&mut ctx, escrow_pda, admin.insecure_clone());
+
+    ctx.warp_to_slot(2);
+
+    SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, "Short", "", b"deal-43")
+        .send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_metadata_extension(&ctx, &extensions_pda, "Short", "", b"deal-43");
+}
+
+#[test]
+fn test_set_metadata_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+    set_default_metadata(&mut ctx, escrow_pda, admin);
+
+    assert_metadata_extension(
+        &ctx,
+        &extensions_pda,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    );
+}
+
+#[test]
+fn test_set_metadata_succeeds_with_governance_delay() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+    set_default_metadata(&mut ctx, escrow_pda, admin);
+
+    assert_metadata_extension(
+        &ctx,
+        &extensions_pda,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    );
+}
+
+#[test]
+fn test_extension_manager_can_set_metadata_and_admin_cannot() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    let extension_manager = ctx.create_funded_keypair();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error =
+        SetMetadataFixture::build_with_escrow(&mut ctx, escrow_pda, admin, "name", "", &[]).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    set_default_metadata(&mut ctx, escrow_pda, extension_manager);
+    assert_metadata_extension(
+        &ctx,
+        &extensions_pda,
+        DEFAULT_METADATA_NAME,
+        DEFAULT_METADATA_URI,
+        DEFAULT_METADATA_EXTERNAL_REFERENCE,
+    );
+}
+
+#[test]
+fn test_set_metadata_can_be_removed() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    set_default_metadata(&mut ctx, escrow_pda, admin.insecure_clone());
+    RemoveExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_METADATA)
+        .send_expect_success(&mut ctx);
+
+    assert_extension_missing(&ctx, &extensions_pda, EXTENSION_TYPE_METADATA);
+}
@@ -0,0 +1,170 @@
+use crate::{
+    fixtures::{SetMintLimitsFixture, SetMintLimitsSetup},
+    utils::{
+        assert_allowed_mint_limits, assert_escrow_error, assert_instruction_error, find_extensions_pda,
+        test_empty_data, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_current_program,
+        EscrowError, InstructionTestFixture, TestContext, TestInstruction, RANDOM_PUBKEY,
+    },
+};
+use escrow_program_client::instructions::{SetImmutableBuilder, SetMintLimitsBuilder};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_mint_limits_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetMintLimitsFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_set_mint_limits_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetMintLimitsFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_set_mint_limits_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetMintLimitsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_limits_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintLimitsFixture::build_valid(&mut ctx).with_account_at(5, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidEventAuthority);
+}
+
+#[test]
+fn test_set_mint_limits_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetMintLimitsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_limits_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetMintLimitsFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_limits_wrong_escrow() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintLimitsFixture::build_valid(&mut ctx).with_account_at(1, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_mint_limits_wrong_extensions() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintLimitsFixture::build_valid(&mut ctx).with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_mint_limits_wrong_allowed_mint() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintLimitsFixture::build_valid(&mut ctx).with_account_at(4, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_mint_limits_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+
+    let instruction = SetMintLimitsBuilder::new()
+        .admin(wrong_admin.pubkey())
+        .escrow(setup.escrow_pda)
+        .extensions(find_extensions_pda(&setup.escrow_pda).0)
+        .mint(setup.mint_pubkey)
+        .allowed_mint(setup.allowed_mint_pda)
+        .max_total_deposits(1_000)
+        .min_deposit(1)
+        .max_deposit(100)
+        .instruction();
+
+    let test_ix = TestInstruction { instruction, signers: vec![wrong_admin], name: "SetMintLimits" };
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_mint_limits_min_above_max_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    let error = setup.build_instruction(&ctx, 0, 500, 100).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_mint_limits_legacy_allowed_mint_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+
+    let error = setup.build_instruction(&ctx, 1_000_000, 10, 100_000).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::LegacyAllowedMint);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_set_mint_limits_success() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 0, 0, 0);
+
+    setup.build_instruction(&ctx, 1_000_000, 10, 100_000).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 1_000_000, 10, 100_000);
+}
+
+#[test]
+fn test_set_mint_limits_min_without_max() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx, 0, 500, 0).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 0, 500, 0);
+}
+
+#[test]
+fn test_set_mint_limits_overwrites_previous_limits() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx, 1_000_000, 10, 100_000).send_expect_success(&mut ctx);
+    setup.build_instruction(&ctx, 0, 0, 0).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 0, 0, 0);
+}
+
+#[test]
+fn test_set_mint_limits_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let setup = SetMintLimitsSetup::new(&mut ctx);
+
+    let set_immutable_ix =
+        SetImmutableBuilder::new().admin(setup.admin.pubkey()).escrow(setup.escrow_pda).instruction();
+    ctx.send_transaction(set_immutable_ix, &[&setup.admin]).unwrap();
+
+    setup.build_instruction(&ctx, 5_000, 0, 0).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 5_000, 0, 0);
+}
@@ -0,0 +1,460 @@
+use crate::{
+    fixtures::{
+        AllowMintSetup, ExecuteQueuedChangeFixture, LockExtensionFixture, QueueChangeFixture,
+        SetGovernanceDelayFixture, SetImmutableFixture, SetMintOverrideFixture, SetRolesFixture, CHANGE_ACTION_REMOVE,
+        CHANGE_ACTION_SET, DEFAULT_CHANGE_ID, DEFAULT_GOVERNANCE_DELAY, DEFAULT_OVERRIDE_LOCK_DURATION,
+    },
+    utils::extensions_utils::{
+        EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_DEPOSITOR_ALLOWLIST, EXTENSION_TYPE_GOVERNANCE_DELAY,
+        EXTENSION_TYPE_HOOK, EXTENSION_TYPE_TIMELOCK,
+    },
+    utils::{
+        assert_allowed_mint_limits, assert_escrow_error, assert_instruction_error, assert_mint_override,
+        test_empty_data, test_missing_signer, test_not_writable, test_truncated_data, test_wrong_account,
+        test_wrong_current_program, test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext,
+        RANDOM_PUBKEY, TEST_HOOK_ALLOW_ID,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Creates an escrow with an allowed mint
+fn setup_allowed_mint(ctx: &mut TestContext) -> AllowMintSetup {
+    let setup = AllowMintSetup::new(ctx);
+    setup.build_instruction(ctx).send_expect_success(ctx);
+    setup
+}
+
+fn set_timelock_override(ctx: &mut TestContext, setup: &AllowMintSetup, admin: Keypair, lock_duration: u64) {
+    SetMintOverrideFixture::build_with_mint(
+        ctx,
+        setup.escrow_pda,
+        admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        lock_duration.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(ctx);
+}
+
+fn hook_value(hook_program: Pubkey) -> Vec<u8> {
+    let mut value = hook_program.to_bytes().to_vec();
+    value.push(0); // flags
+    value
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_mint_override_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetMintOverrideFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_mint_override_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetMintOverrideFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_set_mint_override_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetMintOverrideFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_override_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetMintOverrideFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_override_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetMintOverrideFixture>(&mut ctx, 7, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_mint_override_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetMintOverrideFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_override_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetMintOverrideFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_override_wrong_allowed_mint() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintOverrideFixture::build_valid(&mut ctx).with_account_at(5, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_mint_override_wrong_mint() {
+    let mut ctx = TestContext::new();
+    let error =
+        SetMintOverrideFixture::build_valid(&mut ctx).with_account_at(4, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_mint_override_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+    let wrong_admin = ctx.create_funded_keypair();
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        wrong_admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_mint_override_rejects_non_overridable_extension() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_DEPOSITOR_ALLOWLIST,
+        vec![],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_mint_override_rejects_invalid_value() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![0u8; 7],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_mint_override_remove_missing_override_fails() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![],
+    )
+    .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::UninitializedAccount);
+}
+
+#[test]
+fn test_set_mint_override_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, setup.escrow_pda, setup.admin.insecure_clone())
+        .send_expect_success(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_set_mint_override_fails_with_governance_delay() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::GovernanceDelayActive);
+}
+
+#[test]
+fn test_set_mint_override_fails_when_extension_locked() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    LockExtensionFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        EXTENSION_TYPE_TIMELOCK,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::ExtensionLocked);
+
+    // Other extensions can still be overridden
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value(TEST_HOOK_ALLOW_ID),
+    )
+    .send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_set_mint_override_arbiter_requires_signer() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+    let arbiter = ctx.create_funded_keypair();
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_ARBITER,
+        arbiter.pubkey().to_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_set_mint_override_success() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    assert_mint_override(&ctx, &setup.allowed_mint_pda, EXTENSION_TYPE_TIMELOCK, None);
+
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), DEFAULT_OVERRIDE_LOCK_DURATION);
+
+    assert_mint_override(
+        &ctx,
+        &setup.allowed_mint_pda,
+        EXTENSION_TYPE_TIMELOCK,
+        Some(&DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes()),
+    );
+    assert_allowed_mint_limits(&ctx, &setup.allowed_mint_pda, 0, 0, 0);
+}
+
+#[test]
+fn test_set_mint_override_after_queued_governance_delay_removal() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    SetGovernanceDelayFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        DEFAULT_GOVERNANCE_DELAY,
+    )
+    .send_expect_success(&mut ctx);
+
+    // Overrides cannot be queued; they stay frozen until the delay is removed through the queue
+    QueueChangeFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_GOVERNANCE_DELAY,
+        vec![],
+    )
+    .send_expect_success(&mut ctx);
+    let now = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(now + DEFAULT_GOVERNANCE_DELAY as i64);
+    ExecuteQueuedChangeFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        DEFAULT_CHANGE_ID,
+    )
+    .send_expect_success(&mut ctx);
+
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), DEFAULT_OVERRIDE_LOCK_DURATION);
+
+    assert_mint_override(
+        &ctx,
+        &setup.allowed_mint_pda,
+        EXTENSION_TYPE_TIMELOCK,
+        Some(&DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes()),
+    );
+}
+
+#[test]
+fn test_set_mint_override_replaces_existing_value() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), DEFAULT_OVERRIDE_LOCK_DURATION);
+    ctx.warp_to_slot(2);
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), 60);
+
+    assert_mint_override(&ctx, &setup.allowed_mint_pda, EXTENSION_TYPE_TIMELOCK, Some(&60u64.to_le_bytes()));
+}
+
+#[test]
+fn test_set_mint_override_multiple_extensions() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+    let arbiter = ctx.create_funded_keypair();
+
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), DEFAULT_OVERRIDE_LOCK_DURATION);
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value(TEST_HOOK_ALLOW_ID),
+    )
+    .send_expect_success(&mut ctx);
+    SetMintOverrideFixture::build_set_arbiter(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        arbiter.insecure_clone(),
+    )
+    .send_expect_success(&mut ctx);
+
+    assert_mint_override(
+        &ctx,
+        &setup.allowed_mint_pda,
+        EXTENSION_TYPE_TIMELOCK,
+        Some(&DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes()),
+    );
+    assert_mint_override(&ctx, &setup.allowed_mint_pda, EXTENSION_TYPE_HOOK, Some(&hook_value(TEST_HOOK_ALLOW_ID)));
+    assert_mint_override(&ctx, &setup.allowed_mint_pda, EXTENSION_TYPE_ARBITER, Some(&arbiter.pubkey().to_bytes()));
+}
+
+#[test]
+fn test_set_mint_override_remove() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+    let initial_len = ctx.get_account(&setup.allowed_mint_pda).unwrap().data.len();
+
+    set_timelock_override(&mut ctx, &setup, setup.admin.insecure_clone(), DEFAULT_OVERRIDE_LOCK_DURATION);
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin,
+        setup.mint_pubkey,
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![],
+    )
+    .send_expect_success(&mut ctx);
+
+    assert_mint_override(&ctx, &setup.allowed_mint_pda, EXTENSION_TYPE_TIMELOCK, None);
+    assert_eq!(ctx.get_account(&setup.allowed_mint_pda).unwrap().data.len(), initial_len);
+}
+
+#[test]
+fn test_extension_manager_can_set_mint_override_and_admin_cannot() {
+    let mut ctx = TestContext::new();
+    let setup = setup_allowed_mint(&mut ctx);
+    let extension_manager = ctx.create_funded_keypair();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint_pubkey,
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes().to_vec(),
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    set_timelock_override(&mut ctx, &setup, extension_manager, DEFAULT_OVERRIDE_LOCK_DURATION);
+    assert_mint_override(
+        &ctx,
+        &setup.allowed_mint_pda,
+        EXTENSION_TYPE_TIMELOCK,
+        Some(&DEFAULT_OVERRIDE_LOCK_DURATION.to_le_bytes()),
+    );
+}
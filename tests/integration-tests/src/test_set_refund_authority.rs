@@ -0,0 +1,181 @@
+use crate::{
+    fixtures::{CreateEscrowFixture, SetImmutableFixture, SetRefundAuthorityFixture, DEFAULT_REFUND_AUTHORITY_FLAGS},
+    utils::{
+        assert_escrow_error, assert_extensions_header, assert_instruction_error, assert_refund_authority_extension,
+        extensions_utils::{REFUND_AUTHORITY_FLAG_ADMIN, REFUND_AUTHORITY_FLAG_ARBITER},
+        find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer, test_not_writable,
+        test_truncated_data, test_wrong_account, test_wrong_current_program, test_wrong_system_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_refund_authority_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetRefundAuthorityFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_refund_authority_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetRefundAuthorityFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_refund_authority_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetRefundAuthorityFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_refund_authority_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetRefundAuthorityFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_refund_authority_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetRefundAuthorityFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_refund_authority_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRefundAuthorityFixture::build_valid(&mut ctx);
+    let correct_bump = test_ix.instruction.data[1];
+    let invalid_bump = correct_bump.wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_refund_authority_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetRefundAuthorityFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_refund_authority_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetRefundAuthorityFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_refund_authority_wrong_admin() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let wrong_admin = ctx.create_funded_keypair();
+    let test_ix =
+        SetRefundAuthorityFixture::build_with_escrow(&mut ctx, escrow_pda, wrong_admin, DEFAULT_REFUND_AUTHORITY_FLAGS);
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_refund_authority_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRefundAuthorityFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+#[test]
+fn test_set_refund_authority_fails_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let set_immutable_ix = SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone());
+    set_immutable_ix.send_expect_success(&mut ctx);
+
+    let test_ix =
+        SetRefundAuthorityFixture::build_with_escrow(&mut ctx, escrow_pda, admin, DEFAULT_REFUND_AUTHORITY_FLAGS);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::EscrowImmutable);
+}
+
+#[test]
+fn test_set_refund_authority_no_flags() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRefundAuthorityFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_data_byte_at(2, 0).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_set_refund_authority_unknown_flags() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRefundAuthorityFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_data_byte_at(2, 1 << 7).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_set_refund_authority_success() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRefundAuthorityFixture::build_valid(&mut ctx);
+
+    let extensions_pda = test_ix.instruction.accounts[3].pubkey;
+    let extensions_bump = test_ix.instruction.data[1];
+
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_refund_authority_extension(&ctx, &extensions_pda, DEFAULT_REFUND_AUTHORITY_FLAGS);
+}
+
+#[test]
+fn test_set_refund_authority_updates_existing_extension() {
+    let mut ctx = TestContext::new();
+
+    let escrow_ix = CreateEscrowFixture::build_valid(&mut ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(&mut ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    let first_ix = SetRefundAuthorityFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        REFUND_AUTHORITY_FLAG_ADMIN,
+    );
+    first_ix.send_expect_success(&mut ctx);
+    assert_refund_authority_extension(&ctx, &extensions_pda, REFUND_AUTHORITY_FLAG_ADMIN);
+
+    let second_ix =
+        SetRefundAuthorityFixture::build_with_escrow(&mut ctx, escrow_pda, admin, REFUND_AUTHORITY_FLAG_ARBITER);
+    second_ix.send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_refund_authority_extension(&ctx, &extensions_pda, REFUND_AUTHORITY_FLAG_ARBITER);
+}
@@ -0,0 +1,359 @@
+use crate::{
+    fixtures::{
+        AddTimelockFixture, AllowMintFixture, BlockMintSetup, CreateEscrowFixture, RemoveExtensionFixture,
+        SetImmutableFixture, SetRolesFixture, UpdateAdminFixture,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_ROLES,
+    utils::{
+        assert_account_exists, assert_account_not_exists, assert_escrow_error, assert_extension_missing,
+        assert_extensions_header, assert_instruction_error, assert_roles_extension, assert_timelock_extension,
+        find_allowed_mint_pda, find_escrow_pda, find_extensions_pda, test_empty_data, test_missing_signer,
+        test_not_writable, test_truncated_data, test_wrong_account, test_wrong_current_program,
+        test_wrong_system_program, EscrowError, InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use spl_token_interface::ID as TOKEN_PROGRAM_ID;
+
+/// Creates an escrow and returns its PDA and admin
+fn setup_escrow(ctx: &mut TestContext) -> (Pubkey, Keypair) {
+    let escrow_ix = CreateEscrowFixture::build_valid(ctx);
+    let admin = escrow_ix.signers[0].insecure_clone();
+    let escrow_seed = escrow_ix.signers[1].pubkey();
+    escrow_ix.send_expect_success(ctx);
+
+    let (escrow_pda, _) = find_escrow_pda(&escrow_seed);
+    (escrow_pda, admin)
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_set_roles_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SetRolesFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_set_roles_extensions_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SetRolesFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_set_roles_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<SetRolesFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_roles_wrong_escrow_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SetRolesFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_roles_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<SetRolesFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_set_roles_invalid_extensions_bump() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRolesFixture::build_valid(&mut ctx);
+    let invalid_bump = test_ix.instruction.data[1].wrapping_add(1);
+    let error = test_ix.with_data_byte_at(1, invalid_bump).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_set_roles_empty_data() {
+    let mut ctx = TestContext::new();
+    test_empty_data::<SetRolesFixture>(&mut ctx);
+}
+
+#[test]
+fn test_set_roles_truncated_data() {
+    let mut ctx = TestContext::new();
+    test_truncated_data::<SetRolesFixture>(&mut ctx);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_set_roles_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, _) = setup_escrow(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+    let test_ix = SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        wrong_admin,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_roles_role_holder_cannot_reassign_roles() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let extension_manager_pubkey = extension_manager.pubkey();
+    let error = SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        extension_manager,
+        extension_manager_pubkey,
+        extension_manager_pubkey,
+        extension_manager_pubkey,
+    )
+    .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_set_roles_escrow_not_owned_by_program() {
+    let mut ctx = TestContext::new();
+    let test_ix = SetRolesFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(2, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountOwner);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_set_roles_success() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    let mint_manager = Pubkey::new_unique();
+    let extension_manager = Pubkey::new_unique();
+    let admin_transfer_authority = Pubkey::new_unique();
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin,
+        mint_manager,
+        extension_manager,
+        admin_transfer_authority,
+    )
+    .send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_roles_extension(&ctx, &extensions_pda, &mint_manager, &extension_manager, &admin_transfer_authority);
+}
+
+#[test]
+fn test_set_roles_updates_existing_extension() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, extensions_bump) = find_extensions_pda(&escrow_pda);
+
+    let first = Pubkey::new_unique();
+    SetRolesFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone(), first, first, first)
+        .send_expect_success(&mut ctx);
+
+    // Unassigning a role writes the default address back in place
+    let second = Pubkey::new_unique();
+    SetRolesFixture::build_with_escrow(&mut ctx, escrow_pda, admin, second, Pubkey::default(), second)
+        .send_expect_success(&mut ctx);
+
+    assert_extensions_header(&ctx, &extensions_pda, extensions_bump, 1);
+    assert_roles_extension(&ctx, &extensions_pda, &second, &Pubkey::default(), &second);
+}
+
+#[test]
+fn test_set_roles_succeeds_when_escrow_is_immutable() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetImmutableFixture::build_with_escrow(&mut ctx, escrow_pda, admin.insecure_clone()).send_expect_success(&mut ctx);
+
+    let mint_manager = Pubkey::new_unique();
+    SetRolesFixture::build_with_escrow(&mut ctx, escrow_pda, admin, mint_manager, Pubkey::default(), Pubkey::default())
+        .send_expect_success(&mut ctx);
+
+    assert_roles_extension(&ctx, &extensions_pda, &mint_manager, &Pubkey::default(), &Pubkey::default());
+}
+
+// ============================================================================
+// Role Enforcement Tests
+// ============================================================================
+
+#[test]
+fn test_mint_manager_can_allow_mint_and_admin_cannot() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let mint_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        mint_manager.pubkey(),
+        Pubkey::default(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let mint = Keypair::new();
+    ctx.create_mint(&mint, &ctx.payer.pubkey(), 6);
+
+    let error =
+        AllowMintFixture::build_with_escrow_and_mint(&mut ctx, escrow_pda, admin, mint.pubkey(), TOKEN_PROGRAM_ID)
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    AllowMintFixture::build_with_escrow_and_mint(&mut ctx, escrow_pda, mint_manager, mint.pubkey(), TOKEN_PROGRAM_ID)
+        .send_expect_success(&mut ctx);
+
+    let (allowed_mint_pda, _) = find_allowed_mint_pda(&escrow_pda, &mint.pubkey());
+    assert_account_exists(&ctx, &allowed_mint_pda);
+}
+
+#[test]
+fn test_mint_manager_can_block_mint() {
+    let mut ctx = TestContext::new();
+    let mut setup = BlockMintSetup::new(&mut ctx);
+    let mint_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        mint_manager.pubkey(),
+        Pubkey::default(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    setup.admin = mint_manager;
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_account_not_exists(&ctx, &setup.allowed_mint_pda);
+}
+
+#[test]
+fn test_extension_manager_can_add_timelock_and_admin_cannot() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = AddTimelockFixture::build_with_escrow(&mut ctx, escrow_pda, admin, 3600).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    AddTimelockFixture::build_with_escrow(&mut ctx, escrow_pda, extension_manager, 3600).send_expect_success(&mut ctx);
+    assert_timelock_extension(&ctx, &extensions_pda, 3600);
+}
+
+#[test]
+fn test_unassigned_role_falls_back_to_admin() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::new_unique(),
+        Pubkey::default(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    AddTimelockFixture::build_with_escrow(&mut ctx, escrow_pda, admin, 3600).send_expect_success(&mut ctx);
+    assert_timelock_extension(&ctx, &extensions_pda, 3600);
+}
+
+#[test]
+fn test_only_admin_can_remove_roles_extension() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let (extensions_pda, _) = find_extensions_pda(&escrow_pda);
+    let extension_manager = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::default(),
+        extension_manager.pubkey(),
+        Pubkey::default(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error =
+        RemoveExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, extension_manager, EXTENSION_TYPE_ROLES)
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+
+    RemoveExtensionFixture::build_with_escrow(&mut ctx, escrow_pda, admin, EXTENSION_TYPE_ROLES)
+        .send_expect_success(&mut ctx);
+    assert_extension_missing(&ctx, &extensions_pda, EXTENSION_TYPE_ROLES);
+}
+
+#[test]
+fn test_admin_transfer_authority_can_update_admin_and_admin_cannot() {
+    let mut ctx = TestContext::new();
+    let (escrow_pda, admin) = setup_escrow(&mut ctx);
+    let admin_transfer_authority = Keypair::new();
+
+    SetRolesFixture::build_with_escrow(
+        &mut ctx,
+        escrow_pda,
+        admin.insecure_clone(),
+        Pubkey::default(),
+        Pubkey::default(),
+        admin_transfer_authority.pubkey(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error =
+        UpdateAdminFixture::build_with_escrow(&mut ctx, escrow_pda, admin, Keypair::new()).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRoleAuthority);
+
+    UpdateAdminFixture::build_with_escrow(&mut ctx, escrow_pda, admin_transfer_authority, Keypair::new())
+        .send_expect_success(&mut ctx);
+}
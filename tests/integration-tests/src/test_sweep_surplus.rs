@@ -0,0 +1,250 @@
+use crate::{
+    fixtures::{
+        AllowMintFixture, DepositSetup, SweepSurplusFixture, SweepSurplusSetup, WithdrawSetup, DEFAULT_DEPOSIT_AMOUNT,
+        DEFAULT_SURPLUS_AMOUNT,
+    },
+    utils::{
+        assert_allowed_mint_opening_balance, assert_escrow_error, assert_instruction_error, find_extensions_pda,
+        test_missing_signer, test_not_writable, test_wrong_current_program, test_wrong_owner, test_wrong_token_program,
+        EscrowError, InstructionTestFixture, TestContext, TestInstruction, RANDOM_PUBKEY,
+    },
+};
+use escrow_program_client::instructions::{BlockMintBuilder, SweepSurplusBuilder};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, signature::Signer};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_sweep_surplus_missing_admin_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<SweepSurplusFixture>(&mut ctx, 0, 0);
+}
+
+#[test]
+fn test_sweep_surplus_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SweepSurplusFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_sweep_surplus_destination_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<SweepSurplusFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_sweep_surplus_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<SweepSurplusFixture>(&mut ctx);
+}
+
+#[test]
+fn test_sweep_surplus_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    let error =
+        SweepSurplusFixture::build_valid(&mut ctx).with_account_at(7, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidEventAuthority);
+}
+
+#[test]
+fn test_sweep_surplus_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<SweepSurplusFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_sweep_surplus_wrong_allowed_mint_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<SweepSurplusFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_sweep_surplus_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<SweepSurplusFixture>(&mut ctx, 6);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_sweep_surplus_wrong_admin() {
+    let mut ctx = TestContext::new();
+    let setup = SweepSurplusSetup::new(&mut ctx);
+
+    let wrong_admin = ctx.create_funded_keypair();
+
+    let instruction = SweepSurplusBuilder::new()
+        .admin(wrong_admin.pubkey())
+        .escrow(setup.escrow_pda)
+        .mint(setup.mint_pubkey)
+        .allowed_mint(setup.allowed_mint_pda)
+        .vault(setup.vault)
+        .destination(setup.destination)
+        .token_program(setup.token_program)
+        .instruction();
+
+    let test_ix = TestInstruction { instruction, signers: vec![wrong_admin], name: "SweepSurplus" };
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidAdmin);
+}
+
+#[test]
+fn test_sweep_surplus_wrong_vault_ata() {
+    let mut ctx = TestContext::new();
+    let mut setup = SweepSurplusSetup::new(&mut ctx);
+
+    let wrong_vault = Pubkey::new_unique();
+    ctx.create_token_account_at_address(&wrong_vault, &setup.escrow_pda, &setup.mint_pubkey, DEFAULT_SURPLUS_AMOUNT);
+    setup.vault = wrong_vault;
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_sweep_surplus_no_surplus_fails() {
+    let mut ctx = TestContext::new();
+    let deposit_setup = DepositSetup::new(&mut ctx);
+    deposit_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let setup = SweepSurplusSetup::from_deposit_setup(&mut ctx, &deposit_setup);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::NoSurplus);
+}
+
+#[test]
+fn test_sweep_surplus_insolvent_vault_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SweepSurplusSetup::new(&mut ctx);
+    ctx.set_token_balance(&setup.vault, DEFAULT_DEPOSIT_AMOUNT - 1);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::NoSurplus);
+}
+
+#[test]
+fn test_sweep_surplus_twice_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SweepSurplusSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.svm.expire_blockhash();
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::NoSurplus);
+}
+
+#[test]
+fn test_sweep_surplus_legacy_allowed_mint_fails() {
+    let mut ctx = TestContext::new();
+    let setup = SweepSurplusSetup::new(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::NoSurplus);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_sweep_surplus_success() {
+    let mut ctx = TestContext::new();
+    let setup = SweepSurplusSetup::new(&mut ctx);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.vault), DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.destination), DEFAULT_SURPLUS_AMOUNT);
+}
+
+#[test]
+fn test_sweep_surplus_receipt_still_withdrawable() {
+    let mut ctx = TestContext::new();
+    let withdraw_setup = WithdrawSetup::new(&mut ctx);
+    ctx.set_token_balance(&withdraw_setup.vault, DEFAULT_DEPOSIT_AMOUNT + DEFAULT_SURPLUS_AMOUNT);
+
+    let destination = ctx.create_token_account(&withdraw_setup.admin.pubkey(), &withdraw_setup.mint.pubkey());
+    let setup = SweepSurplusSetup {
+        escrow_pda: withdraw_setup.escrow_pda,
+        admin: withdraw_setup.admin.insecure_clone(),
+        mint_pubkey: withdraw_setup.mint.pubkey(),
+        allowed_mint_pda: withdraw_setup.allowed_mint_pda,
+        vault: withdraw_setup.vault,
+        destination,
+        token_program: withdraw_setup.token_program,
+    };
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let initial_withdrawer_balance = ctx.get_token_balance(&withdraw_setup.depositor_token_account);
+    withdraw_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&withdraw_setup.vault), 0);
+    assert_eq!(
+        ctx.get_token_balance(&withdraw_setup.depositor_token_account),
+        initial_withdrawer_balance + DEFAULT_DEPOSIT_AMOUNT
+    );
+}
+
+#[test]
+fn test_sweep_surplus_keeps_opening_balance_after_reallow() {
+    let mut ctx = TestContext::new();
+    let mut withdraw_setup = WithdrawSetup::new(&mut ctx);
+    withdraw_setup.replace_with_legacy_receipt(&mut ctx);
+    ctx.write_legacy_allowed_mint(&withdraw_setup.allowed_mint_pda);
+
+    // Block and re-allow the mint while a receipt from before the running totals is still
+    // open: no AllowedMint counts it, so the vault balance at re-allow time must be held back
+    let block_mint_ix = BlockMintBuilder::new()
+        .admin(withdraw_setup.admin.pubkey())
+        .rent_recipient(ctx.payer.pubkey())
+        .escrow(withdraw_setup.escrow_pda)
+        .extensions(find_extensions_pda(&withdraw_setup.escrow_pda).0)
+        .mint(withdraw_setup.mint.pubkey())
+        .allowed_mint(withdraw_setup.allowed_mint_pda)
+        .token_program(withdraw_setup.token_program)
+        .instruction();
+    ctx.send_transaction(block_mint_ix, &[&withdraw_setup.admin]).unwrap();
+
+    AllowMintFixture::build_with_escrow_and_mint(
+        &mut ctx,
+        withdraw_setup.escrow_pda,
+        withdraw_setup.admin.insecure_clone(),
+        withdraw_setup.mint.pubkey(),
+        withdraw_setup.token_program,
+    )
+    .send_expect_success(&mut ctx);
+    assert_allowed_mint_opening_balance(&ctx, &withdraw_setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+
+    let destination = ctx.create_token_account(&withdraw_setup.admin.pubkey(), &withdraw_setup.mint.pubkey());
+    let setup = SweepSurplusSetup {
+        escrow_pda: withdraw_setup.escrow_pda,
+        admin: withdraw_setup.admin.insecure_clone(),
+        mint_pubkey: withdraw_setup.mint.pubkey(),
+        allowed_mint_pda: withdraw_setup.allowed_mint_pda,
+        vault: withdraw_setup.vault,
+        destination,
+        token_program: withdraw_setup.token_program,
+    };
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::NoSurplus);
+
+    // Only tokens beyond the opening balance can be swept
+    ctx.set_token_balance(&withdraw_setup.vault, DEFAULT_DEPOSIT_AMOUNT + DEFAULT_SURPLUS_AMOUNT);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(ctx.get_token_balance(&destination), DEFAULT_SURPLUS_AMOUNT);
+
+    let initial_withdrawer_balance = ctx.get_token_balance(&withdraw_setup.depositor_token_account);
+    withdraw_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert_eq!(
+        ctx.get_token_balance(&withdraw_setup.depositor_token_account),
+        initial_withdrawer_balance + DEFAULT_DEPOSIT_AMOUNT
+    );
+}
@@ -2,8 +2,8 @@ use crate::{
     fixtures::{CreateEscrowFixture, UpdateAdminFixture},
     utils::{
         assert_escrow_account, assert_escrow_mutability, assert_instruction_error, find_escrow_pda,
-        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, InstructionTestFixture,
-        TestContext, TestInstruction, RANDOM_PUBKEY,
+        find_extensions_pda, test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program,
+        InstructionTestFixture, TestContext, TestInstruction, RANDOM_PUBKEY,
     },
 };
 use escrow_program_client::instructions::{SetImmutableBuilder, UpdateAdminBuilder};
@@ -44,7 +44,7 @@ fn test_update_admin_wrong_escrow_program() {
 #[test]
 fn test_update_admin_invalid_event_authority() {
     let mut ctx = TestContext::new();
-    test_wrong_account::<UpdateAdminFixture>(&mut ctx, 3, InstructionError::Custom(2));
+    test_wrong_account::<UpdateAdminFixture>(&mut ctx, 4, InstructionError::Custom(2));
 }
 
 // ============================================================================
@@ -69,6 +69,15 @@ fn test_update_admin_wrong_admin() {
     assert_instruction_error(error, InstructionError::Custom(1)); // InvalidAdmin
 }
 
+#[test]
+fn test_update_admin_wrong_extensions() {
+    let mut ctx = TestContext::new();
+    let test_ix = UpdateAdminFixture::build_valid(&mut ctx);
+
+    let error = test_ix.with_account_at(3, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
 #[test]
 fn test_update_admin_escrow_not_owned_by_program() {
     let mut ctx = TestContext::new();
@@ -201,8 +210,12 @@ fn test_update_admin_idempotent() {
 
     // Update admin to the same admin (idempotent operation)
     let same_admin = admin.insecure_clone();
-    let instruction =
-        UpdateAdminBuilder::new().admin(admin.pubkey()).new_admin(same_admin.pubkey()).escrow(escrow_pda).instruction();
+    let instruction = UpdateAdminBuilder::new()
+        .admin(admin.pubkey())
+        .new_admin(same_admin.pubkey())
+        .escrow(escrow_pda)
+        .extensions(find_extensions_pda(&escrow_pda).0)
+        .instruction();
 
     let test_ix =
         TestInstruction { instruction, signers: vec![admin.insecure_clone(), same_admin], name: "UpdateAdmin" };
@@ -0,0 +1,158 @@
+use crate::{
+    fixtures::{DepositSetup, VerifySolvencyFixture, VerifySolvencySetup, DEFAULT_DEPOSIT_AMOUNT},
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_allowed_mint_total_deposited, assert_escrow_error,
+        assert_instruction_error, test_wrong_current_program, test_wrong_owner, test_wrong_token_program, EscrowError,
+        InstructionTestFixture, TestContext, RANDOM_PUBKEY,
+    },
+};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey};
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_verify_solvency_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<VerifySolvencyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_verify_solvency_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    let error =
+        VerifySolvencyFixture::build_valid(&mut ctx).with_account_at(5, RANDOM_PUBKEY).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidEventAuthority);
+}
+
+#[test]
+fn test_verify_solvency_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<VerifySolvencyFixture>(&mut ctx, 0);
+}
+
+#[test]
+fn test_verify_solvency_wrong_allowed_mint_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<VerifySolvencyFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_verify_solvency_wrong_vault_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<VerifySolvencyFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_verify_solvency_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<VerifySolvencyFixture>(&mut ctx, 4);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_verify_solvency_wrong_vault_ata() {
+    let mut ctx = TestContext::new();
+    let mut setup = VerifySolvencySetup::new(&mut ctx);
+
+    // A token account holding enough funds at an address other than the escrow's ATA
+    let wrong_vault = Pubkey::new_unique();
+    ctx.create_token_account_at_address(&wrong_vault, &setup.escrow_pda, &setup.mint_pubkey, DEFAULT_DEPOSIT_AMOUNT);
+    setup.vault = wrong_vault;
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+#[test]
+fn test_verify_solvency_allowed_mint_for_other_mint() {
+    let mut ctx = TestContext::new();
+    let mut setup = VerifySolvencySetup::new(&mut ctx);
+    let other = VerifySolvencySetup::new(&mut ctx);
+
+    setup.allowed_mint_pda = other.allowed_mint_pda;
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_verify_solvency_vault_short_fails() {
+    let mut ctx = TestContext::new();
+    let setup = VerifySolvencySetup::new(&mut ctx);
+
+    ctx.set_token_balance(&setup.vault, DEFAULT_DEPOSIT_AMOUNT - 1);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::VaultInsolvent);
+}
+
+// ============================================================================
+// Happy Path Tests
+// ============================================================================
+
+#[test]
+fn test_verify_solvency_success() {
+    let mut ctx = TestContext::new();
+    let setup = VerifySolvencySetup::new(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_verify_solvency_token_2022() {
+    let mut ctx = TestContext::new();
+    let deposit_setup = DepositSetup::new_token_2022(&mut ctx);
+    deposit_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    let setup = VerifySolvencySetup::from_deposit_setup(&deposit_setup);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_verify_solvency_no_deposits() {
+    let mut ctx = TestContext::new();
+    let deposit_setup = DepositSetup::new(&mut ctx);
+    let setup = VerifySolvencySetup::from_deposit_setup(&deposit_setup);
+
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_verify_solvency_surplus_succeeds() {
+    let mut ctx = TestContext::new();
+    let setup = VerifySolvencySetup::new(&mut ctx);
+
+    // Tokens sent straight to the vault are surplus, not a liability
+    ctx.set_token_balance(&setup.vault, DEFAULT_DEPOSIT_AMOUNT * 2);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_verify_solvency_tracks_multiple_receipts() {
+    let mut ctx = TestContext::new();
+    let mut deposit_setup = DepositSetup::new(&mut ctx);
+    deposit_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    deposit_setup.advance_receipt();
+    deposit_setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    let setup = VerifySolvencySetup::from_deposit_setup(&deposit_setup);
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT * 2);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 2);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    ctx.set_token_balance(&setup.vault, DEFAULT_DEPOSIT_AMOUNT * 2 - 1);
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::VaultInsolvent);
+}
@@ -1,19 +1,33 @@
 use crate::{
-    fixtures::{AllowMintSetup, WithdrawFixture, WithdrawSetup, DEFAULT_DEPOSIT_AMOUNT},
+    fixtures::{
+        AllowMintSetup, SetMintOverrideFixture, WithdrawFixture, WithdrawSetup, CHANGE_ACTION_REMOVE,
+        CHANGE_ACTION_SET, DEFAULT_COOLDOWN_PERIOD, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::{EXTENSION_TYPE_TIMELOCK, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS},
+    utils::hook_config::{
+        create_hook_config, create_hook_log, read_hook_log, HookConfig, HookLogEntry, HOOK_POINT_POST_DEPOSIT,
+        HOOK_POINT_POST_WITHDRAW, HOOK_POINT_PRE_DEPOSIT, HOOK_POINT_PRE_WITHDRAW, TEST_HOOK_ASSERT_FAILED_CODE,
+    },
     utils::{
-        assert_custom_error, assert_escrow_error, assert_instruction_error, test_missing_signer, test_not_writable,
-        test_wrong_account, test_wrong_current_program, test_wrong_owner, test_wrong_system_program,
-        test_wrong_token_program, EscrowError, TestContext, TestInstruction, TEST_HOOK_ALLOW_ID, TEST_HOOK_DENY_ERROR,
-        TEST_HOOK_DENY_ID,
+        assert_allowed_mint_outstanding_receipts, assert_allowed_mint_total_deposited, assert_custom_error,
+        assert_escrow_error, assert_hook_error, assert_instruction_error, find_allowed_mint_pda, find_extensions_pda,
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, test_wrong_token_program, EscrowError, InstructionTestFixture, TestContext,
+        TestInstruction, TEST_HOOK_ALLOW_ID, TEST_HOOK_CONFIGURABLE_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+        TEST_HOOK_REENTER_ID, TEST_HOOK_REJECT_CODE, TEST_HOOK_REJECT_ID, TEST_HOOK_RELAY_ID,
     },
 };
-use escrow_program_client::instructions::WithdrawBuilder;
+use escrow_program_client::{
+    instructions::{BlockMintBuilder, WithdrawBuilder},
+    ESCROW_PROGRAM_ID,
+};
 use solana_sdk::{
     account::Account,
     instruction::{AccountMeta, InstructionError},
     pubkey::Pubkey,
     signature::{Keypair, Signer},
 };
+use spl_associated_token_account::{get_associated_token_address, get_associated_token_address_with_program_id};
 use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
 
 // ============================================================================
@@ -39,7 +53,7 @@ fn test_withdraw_vault_not_writable() {
 }
 
 #[test]
-fn test_withdraw_withdrawer_token_account_not_writable() {
+fn test_withdraw_destination_token_account_not_writable() {
     let mut ctx = TestContext::new();
     test_not_writable::<WithdrawFixture>(&mut ctx, 6);
 }
@@ -117,14 +131,17 @@ fn test_withdraw_wrong_extensions_account() {
     let wrong_extensions = Pubkey::new_unique();
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(wrong_extensions) // Wrong extensions PDA
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -145,14 +162,17 @@ fn test_withdraw_wrong_vault_ata() {
     ctx.create_token_account_at_address(&wrong_vault, &setup.escrow_pda, &setup.mint.pubkey(), DEFAULT_DEPOSIT_AMOUNT);
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(wrong_vault) // Wrong vault address
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -173,14 +193,17 @@ fn test_withdraw_wrong_withdrawer_ata() {
     ctx.create_token_account_at_address(&wrong_token_account, &wrong_owner, &setup.mint.pubkey(), 0);
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(wrong_token_account) // Wrong withdrawer token account
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(wrong_token_account) // Wrong withdrawer token account
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -203,14 +226,17 @@ fn test_withdraw_wrong_withdrawer() {
     let wrong_withdrawer_token_account = ctx.create_token_account(&wrong_withdrawer.pubkey(), &setup.mint.pubkey());
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(wrong_withdrawer.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(wrong_withdrawer_token_account)
+        .destination(wrong_withdrawer.pubkey())
+        .destination_token_account(wrong_withdrawer_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -218,6 +244,15 @@ fn test_withdraw_wrong_withdrawer() {
     assert_escrow_error(error, EscrowError::InvalidWithdrawer);
 }
 
+#[test]
+fn test_withdraw_blocked_by_cooldown() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).cooldown(DEFAULT_COOLDOWN_PERIOD).build();
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::CooldownActive);
+}
+
 // ============================================================================
 // Timelock Tests
 // ============================================================================
@@ -346,17 +381,16 @@ fn test_withdraw_returns_rent_to_payer() {
 }
 
 #[test]
-fn test_withdraw_returns_rent_to_custom_recipient() {
+fn test_withdraw_returns_rent_to_designated_recipient() {
     let mut ctx = TestContext::new();
-    let setup = WithdrawSetup::new(&mut ctx);
-
     let custom_recipient = Pubkey::new_unique();
     ctx.svm.set_account(custom_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+    let setup = WithdrawSetup::builder(&mut ctx).rent_recipient(custom_recipient).build();
 
     let recipient_balance_before = ctx.get_account(&custom_recipient).unwrap().lamports;
     let receipt_rent = ctx.get_account(&setup.receipt_pda).unwrap().lamports;
 
-    let test_ix = setup.build_instruction_with_rent_recipient(&ctx, custom_recipient);
+    let test_ix = setup.build_instruction(&ctx);
     test_ix.send_expect_success(&mut ctx);
 
     let recipient_balance_after = ctx.get_account(&custom_recipient).unwrap().lamports;
@@ -364,8 +398,272 @@ fn test_withdraw_returns_rent_to_custom_recipient() {
     assert_eq!(
         recipient_balance_after,
         recipient_balance_before + receipt_rent,
-        "Custom rent recipient should receive exact receipt rent"
+        "Designated rent recipient should receive exact receipt rent"
+    );
+}
+
+#[test]
+fn test_withdraw_wrong_rent_recipient_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let custom_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(custom_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+
+    let test_ix = setup.build_instruction_with_rent_recipient(&ctx, custom_recipient);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRentRecipient);
+}
+
+#[test]
+fn test_withdraw_legacy_receipt() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+    let legacy_receipt_pda = setup.replace_with_legacy_receipt(&mut ctx);
+
+    let initial_withdrawer_balance = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&legacy_receipt_pda).is_none(), "Legacy receipt should be closed");
+    assert_eq!(
+        ctx.get_token_balance(&setup.depositor_token_account),
+        initial_withdrawer_balance + DEFAULT_DEPOSIT_AMOUNT
+    );
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+}
+
+#[test]
+fn test_withdraw_legacy_receipt_with_legacy_allowed_mint() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+    setup.replace_with_legacy_receipt(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Legacy receipt should be closed");
+    let allowed_mint_account = ctx.get_account(&setup.allowed_mint_pda).unwrap();
+    assert_eq!(allowed_mint_account.data, vec![allowed_mint_account.data[0], 1, allowed_mint_account.data[2]]);
+}
+
+#[test]
+fn test_withdraw_legacy_receipt_returns_rent_to_any_recipient() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+
+    // Version 1 receipts predate the rent recipient
+    setup.replace_with_legacy_receipt(&mut ctx);
+
+    let custom_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(custom_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+    let receipt_rent = ctx.get_account(&setup.receipt_pda).unwrap().lamports;
+
+    let test_ix = setup.build_instruction_with_rent_recipient(&ctx, custom_recipient);
+    test_ix.send_expect_success(&mut ctx);
+
+    let recipient_balance_after = ctx.get_account(&custom_recipient).unwrap().lamports;
+    assert_eq!(recipient_balance_after, 1_000_000 + receipt_rent);
+}
+
+// ============================================================================
+// Destination Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_to_other_destination_creates_ata() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let destination = Pubkey::new_unique();
+    let destination_token_account = get_associated_token_address(&destination, &setup.mint.pubkey());
+    assert!(ctx.get_account(&destination_token_account).is_none());
+
+    let test_ix = setup.build_instruction_with_destination(&ctx, ctx.payer.pubkey(), destination);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&destination_token_account), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_to_other_destination_token_2022_creates_ata() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_token_2022(&mut ctx);
+
+    let destination = Pubkey::new_unique();
+    let destination_token_account =
+        get_associated_token_address_with_program_id(&destination, &setup.mint.pubkey(), &TOKEN_2022_PROGRAM_ID);
+
+    let test_ix = setup.build_instruction_with_destination(&ctx, ctx.payer.pubkey(), destination);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&destination_token_account), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_to_existing_destination_ata() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let destination = Pubkey::new_unique();
+    let destination_token_account = ctx.create_token_account(&destination, &setup.mint.pubkey());
+    let initial_withdrawer_balance = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let test_ix = setup.build_instruction_with_destination(&ctx, ctx.payer.pubkey(), destination);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&destination_token_account), DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), initial_withdrawer_balance);
+}
+
+#[test]
+fn test_withdraw_recreates_closed_withdrawer_ata() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    ctx.svm.set_account(setup.depositor_token_account, Account::default()).unwrap();
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_destination_token_account_not_ata_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let destination = Pubkey::new_unique();
+    let test_ix = setup
+        .build_instruction_with_destination(&ctx, ctx.payer.pubkey(), destination)
+        .with_account_at(6, setup.depositor_token_account);
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidAccountData);
+}
+
+// ============================================================================
+// Withdraw Delegate Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_by_delegate_pays_depositor() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    let withdraw_delegate_pda = setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    let initial_depositor_balance = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let test_ix = setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey());
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(
+        ctx.get_token_balance(&setup.depositor_token_account),
+        initial_depositor_balance + DEFAULT_DEPOSIT_AMOUNT
     );
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&withdraw_delegate_pda).is_none(), "Withdraw delegate should be closed");
+}
+
+#[test]
+fn test_withdraw_by_delegate_with_arbiter_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_with_arbiter(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    let initial_depositor_balance = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let test_ix = setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey());
+    test_ix.send_expect_success(&mut ctx);
+
+    assert_eq!(
+        ctx.get_token_balance(&setup.depositor_token_account),
+        initial_depositor_balance + DEFAULT_DEPOSIT_AMOUNT
+    );
+}
+
+#[test]
+fn test_withdraw_by_delegate_before_expiry_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    let expires_at = ctx.get_current_timestamp() + 3600;
+    setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), expires_at);
+
+    let test_ix = setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey());
+    test_ix.send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_withdraw_by_delegate_to_other_destination_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    let test_ix = setup.build_delegated_instruction(&ctx, &delegate, delegate.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawDestination);
+}
+
+#[test]
+fn test_withdraw_by_expired_delegate_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    let expires_at = ctx.get_current_timestamp() + 3600;
+    setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), expires_at);
+
+    ctx.warp_to_timestamp(expires_at);
+
+    let test_ix = setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::WithdrawDelegateExpired);
+}
+
+#[test]
+fn test_withdraw_by_unapproved_signer_with_delegate_account_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    setup.approve_withdraw_delegate(&mut ctx, Pubkey::new_unique(), 0);
+
+    let impostor = ctx.create_funded_keypair();
+    let test_ix = setup.build_delegated_instruction(&ctx, &impostor, setup.depositor.pubkey());
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_withdraw_by_delegate_of_other_receipt_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let other_setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    let other_withdraw_delegate_pda = other_setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    let test_ix = setup
+        .build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey())
+        .with_account_at(16, other_withdraw_delegate_pda);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawDelegate);
+}
+
+#[test]
+fn test_withdraw_by_delegate_account_not_writable_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let delegate = ctx.create_funded_keypair();
+    setup.approve_withdraw_delegate(&mut ctx, delegate.pubkey(), 0);
+
+    let mut test_ix = setup.build_delegated_instruction(&ctx, &delegate, setup.depositor.pubkey());
+    test_ix.instruction.accounts[16].is_writable = false;
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::Immutable);
 }
 
 // ============================================================================
@@ -440,6 +738,94 @@ fn test_withdraw_with_hook_rejected() {
     assert_eq!(final_vault_balance, initial_vault_balance, "Vault balance should be unchanged");
 }
 
+#[test]
+fn test_withdraw_with_hook_rejected_with_code() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_REJECT_ID);
+
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_hook_error(error, TEST_HOOK_REJECT_CODE);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist after rejected withdraw");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+
+#[test]
+fn test_withdraw_with_reentrant_hook_rejected() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_REENTER_ID);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    // The malicious hook replays Withdraw with the receipt it was handed
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist after reentry attempt");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+
+    // The failed reentry leaves the receipt withdrawable
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+}
+
+#[test]
+fn test_withdraw_with_reentrant_hook_through_relay_rejected() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_REENTER_ID);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    // The hook forwards to the relay program, which replays Withdraw into the escrow
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(TEST_HOOK_RELAY_ID, false));
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist after reentry attempt");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+#[test]
+fn test_withdraw_with_stored_escrow_program_hook_rejected() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new_with_hook(&mut ctx, TEST_HOOK_ALLOW_ID);
+    // A hook entry written before the escrow program was rejected as a hook
+    setup.replace_hook_program(&mut ctx, ESCROW_PROGRAM_ID);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+
+    assert_escrow_error(error, EscrowError::HookRejected);
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist after rejected withdraw");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+
+#[test]
+fn test_withdraw_hook_writes_owned_account_when_enabled() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS)
+        .build();
+    let hook_state = ctx.create_program_owned_account(&TEST_HOOK_ALLOW_ID, 8);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new(hook_state, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    // Pre-withdraw and post-withdraw hooks each bump the counter
+    let hook_state_account = ctx.get_account(&hook_state).expect("Hook state should exist");
+    assert_eq!(hook_state_account.data[0], 2);
+}
+
 #[test]
 fn test_withdraw_with_hook_extra_signer_is_downgraded() {
     let mut ctx = TestContext::new();
@@ -454,6 +840,159 @@ fn test_withdraw_with_hook_extra_signer_is_downgraded() {
     assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed after successful withdraw");
 }
 
+// ============================================================================
+// Hook Bypass Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_with_hook_bypass_after_grace_period_success() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_DENY_ID);
+
+    // Hook blocks the regular withdraw path
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+
+    let hook_bypass_request_pda = setup.request_hook_bypass(&mut ctx);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+
+    let initial_withdrawer_balance = ctx.get_token_balance(&setup.depositor_token_account);
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    let final_withdrawer_balance = ctx.get_token_balance(&setup.depositor_token_account);
+    assert_eq!(final_withdrawer_balance, initial_withdrawer_balance + DEFAULT_DEPOSIT_AMOUNT);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_none(), "Hook bypass request should be closed");
+}
+
+#[test]
+fn test_withdraw_with_hook_bypass_before_grace_period_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_DENY_ID);
+    setup.request_hook_bypass(&mut ctx);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64 - 1);
+    ctx.warp_to_slot(2);
+
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::HookBypassGracePeriodActive);
+}
+
+#[test]
+fn test_withdraw_with_hook_bypass_still_requires_arbiter() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .arbiter()
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_DENY_ID);
+    let hook_bypass_request_pda = setup.request_hook_bypass(&mut ctx);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+
+    // Without the arbiter, the bypass request sits where the arbiter is expected
+    let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .rent_recipient(ctx.payer.pubkey())
+        .withdrawer(setup.depositor.pubkey())
+        .escrow(setup.escrow_pda)
+        .extensions(setup.extensions_pda)
+        .receipt(setup.receipt_pda)
+        .vault(setup.vault)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
+        .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
+        .token_program(setup.token_program)
+        .add_remaining_account(AccountMeta::new(hook_bypass_request_pda, false))
+        .instruction();
+    let test_ix = TestInstruction { instruction, signers: vec![setup.depositor.insecure_clone()], name: "Withdraw" };
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+
+    // With the arbiter, the bypass succeeds
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_with_hook_bypass_request_for_other_receipt_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let mut other_setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let other_request_pda = other_setup.request_hook_bypass(&mut ctx);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+
+    setup.hook_bypass_request = Some(other_request_pda);
+    let test_ix = setup.build_instruction(&ctx);
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidHookBypassRequest);
+}
+
+#[test]
+fn test_withdraw_with_hook_bypass_request_not_writable_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    setup.request_hook_bypass(&mut ctx);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    let last = test_ix.instruction.accounts.len() - 1;
+    test_ix.instruction.accounts[last].is_writable = false;
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::Immutable);
+}
+
+#[test]
+fn test_withdraw_with_hook_and_bypass_extension_uses_hook_by_default() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+
+    let test_ix = setup.build_instruction(&ctx);
+    test_ix.send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
 // ============================================================================
 // Cross-Escrow Protection Tests
 // ============================================================================
@@ -471,14 +1010,17 @@ fn test_withdraw_receipt_for_different_escrow_fails() {
     let vault_b = ctx.create_token_account(&setup_b.escrow_pda, &setup_a.mint.pubkey());
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup_a.depositor.pubkey())
         .escrow(setup_b.escrow_pda)
         .extensions(setup_b.escrow_extensions_pda)
         .receipt(setup_a.receipt_pda)
         .vault(vault_b)
-        .withdrawer_token_account(setup_a.depositor_token_account)
+        .destination(setup_a.depositor.pubkey())
+        .destination_token_account(setup_a.depositor_token_account)
         .mint(setup_a.mint.pubkey())
+        .allowed_mint(setup_b.allowed_mint_pda)
         .token_program(setup_a.token_program)
         .instruction();
 
@@ -499,14 +1041,17 @@ fn test_withdraw_receipt_mint_mismatch_fails() {
     let second_withdrawer_token_account = ctx.create_token_account(&setup.depositor.pubkey(), &second_mint.pubkey());
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(second_vault)
-        .withdrawer_token_account(second_withdrawer_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(second_withdrawer_token_account)
         .mint(second_mint.pubkey())
+        .allowed_mint(find_allowed_mint_pda(&setup.escrow_pda, &second_mint.pubkey()).0)
         .token_program(setup.token_program)
         .instruction();
 
@@ -532,14 +1077,17 @@ fn test_withdraw_double_withdraw_fails() {
     ctx.warp_to_slot(2);
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -576,14 +1124,17 @@ fn test_withdraw_rejects_reactivated_account_wrong_owner() {
     ctx.warp_to_slot(2);
 
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -598,6 +1149,189 @@ fn test_withdraw_rejects_reactivated_account_wrong_owner() {
     );
 }
 
+// ============================================================================
+// Mint Limit Tests
+// ============================================================================
+
+fn block_mint(ctx: &mut TestContext, setup: &WithdrawSetup) {
+    let instruction = BlockMintBuilder::new()
+        .admin(setup.admin.pubkey())
+        .rent_recipient(ctx.payer.pubkey())
+        .escrow(setup.escrow_pda)
+        .extensions(find_extensions_pda(&setup.escrow_pda).0)
+        .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
+        .token_program(setup.token_program)
+        .instruction();
+    ctx.send_transaction(instruction, &[&setup.admin]).unwrap();
+}
+
+#[test]
+fn test_withdraw_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawFixture>(&mut ctx, 12);
+}
+
+#[test]
+fn test_withdraw_releases_total_deposited() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert_allowed_mint_total_deposited(&ctx, &setup.allowed_mint_pda, 0);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+}
+
+#[test]
+fn test_withdraw_after_mint_blocked_succeeds() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+
+    // Only receipts from before the running totals can outlive their AllowedMint
+    setup.replace_with_legacy_receipt(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+    block_mint(&mut ctx, &setup);
+    assert!(ctx.get_account(&setup.allowed_mint_pda).is_none(), "AllowedMint should be closed");
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_after_mint_blocked_wrong_allowed_mint_fails() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+
+    setup.replace_with_legacy_receipt(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+    block_mint(&mut ctx, &setup);
+
+    let error = setup.build_instruction(&ctx).with_account_at(12, Pubkey::new_unique()).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+#[test]
+fn test_withdraw_wrong_allowed_mint_address() {
+    let mut ctx = TestContext::new();
+    let error =
+        WithdrawFixture::build_valid(&mut ctx).with_account_at(12, Pubkey::new_unique()).send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidSeeds);
+}
+
+// ============================================================================
+// Configurable Hook Tests
+// ============================================================================
+
+/// Builds a withdraw setup whose deposit ran through the allow hook, then swaps in the configurable hook
+fn configurable_hook_setup(ctx: &mut TestContext) -> WithdrawSetup {
+    let mut setup = WithdrawSetup::builder(ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS)
+        .build();
+    setup.replace_hook_program(ctx, TEST_HOOK_CONFIGURABLE_ID);
+    setup
+}
+
+#[test]
+fn test_withdraw_hook_receives_expected_context() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let core_accounts = [setup.escrow_pda, setup.depositor.pubkey(), setup.mint.pubkey(), setup.receipt_pda];
+    let config = create_hook_config(
+        &mut ctx,
+        &HookConfig::new().expect(&[HOOK_POINT_PRE_WITHDRAW, HOOK_POINT_POST_WITHDRAW], &core_accounts).log(),
+    );
+    let log = create_hook_log(&mut ctx, &config);
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let (count, entries) = read_hook_log(&ctx, &log);
+    assert_eq!(count, 2);
+    for (entry, hook_point) in entries.iter().zip([HOOK_POINT_PRE_WITHDRAW, HOOK_POINT_POST_WITHDRAW]) {
+        assert_eq!(
+            *entry,
+            HookLogEntry {
+                hook_point,
+                account_count: 6,
+                writable_count: 1,
+                signer_count: 0,
+                core_accounts: core_accounts.to_vec(),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_withdraw_hook_unexpected_hook_point_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let core_accounts = [setup.escrow_pda, setup.depositor.pubkey(), setup.mint.pubkey(), setup.receipt_pda];
+
+    // Only deposit hook points are expected, so the pre-withdraw invocation fails the assertion
+    let config = create_hook_config(
+        &mut ctx,
+        &HookConfig::new().expect(&[HOOK_POINT_PRE_DEPOSIT, HOOK_POINT_POST_DEPOSIT], &core_accounts),
+    );
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_hook_error(error, TEST_HOOK_ASSERT_FAILED_CODE);
+}
+
+#[test]
+fn test_withdraw_hook_rejects_pre_withdraw() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let initial_vault_balance = ctx.get_token_balance(&setup.vault);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().reject_at(HOOK_POINT_PRE_WITHDRAW, 12));
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_hook_error(error, 12);
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist");
+    assert_eq!(ctx.get_token_balance(&setup.vault), initial_vault_balance, "Vault balance should be unchanged");
+}
+
+#[test]
+fn test_withdraw_hook_untagged_return_data_ignored() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().untagged_return_data(12));
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_configurable_hook_reentry_rejected() {
+    let mut ctx = TestContext::new();
+    let setup = configurable_hook_setup(&mut ctx);
+    let config = create_hook_config(&mut ctx, &HookConfig::new().reenter());
+
+    let mut test_ix = setup.build_instruction(&ctx);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(ESCROW_PROGRAM_ID, false));
+    let error = test_ix.send_expect_error(&mut ctx);
+
+    assert_instruction_error(error, InstructionError::ReentrancyNotAllowed);
+    assert!(ctx.get_account(&setup.receipt_pda).is_some(), "Receipt should still exist after reentry attempt");
+}
+
 // ============================================================================
 // Additional Hook Tests
 // ============================================================================
@@ -613,14 +1347,17 @@ fn test_withdraw_with_hook_wrong_program() {
     let wrong_hook = Pubkey::new_unique();
 
     let mut instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -667,14 +1404,17 @@ fn test_withdraw_with_arbiter_missing_signer() {
     // Build instruction manually without arbiter as signer
     let mut builder = WithdrawBuilder::new();
     builder
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program);
 
     // Add arbiter as non-signer (should fail)
@@ -697,14 +1437,17 @@ fn test_withdraw_with_arbiter_wrong_address() {
 
     let mut builder = WithdrawBuilder::new();
     builder
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program);
 
     builder.add_remaining_account(AccountMeta::new_readonly(wrong_arbiter.pubkey(), true));
@@ -727,14 +1470,17 @@ fn test_withdraw_with_arbiter_no_remaining_accounts() {
 
     // Build instruction without any remaining accounts (arbiter required but missing)
     let instruction = WithdrawBuilder::new()
+        .payer(ctx.payer.pubkey())
         .rent_recipient(ctx.payer.pubkey())
         .withdrawer(setup.depositor.pubkey())
         .escrow(setup.escrow_pda)
         .extensions(setup.extensions_pda)
         .receipt(setup.receipt_pda)
         .vault(setup.vault)
-        .withdrawer_token_account(setup.depositor_token_account)
+        .destination(setup.depositor.pubkey())
+        .destination_token_account(setup.depositor_token_account)
         .mint(setup.mint.pubkey())
+        .allowed_mint(setup.allowed_mint_pda)
         .token_program(setup.token_program)
         .instruction();
 
@@ -818,6 +1564,122 @@ fn test_withdraw_without_arbiter_extension_no_signer_needed() {
     assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
 }
 
+// ============================================================================
+// Per-Mint Override Tests
+// ============================================================================
+
+fn set_timelock_override(ctx: &mut TestContext, setup: &WithdrawSetup, lock_duration: u64) {
+    SetMintOverrideFixture::build_with_mint(
+        ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_TIMELOCK,
+        lock_duration.to_le_bytes().to_vec(),
+    )
+    .send_expect_success(ctx);
+}
+
+#[test]
+fn test_withdraw_mint_timelock_override_applies_without_escrow_timelock() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    set_timelock_override(&mut ctx, &setup, lock_duration);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::TimelockNotExpired);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + lock_duration as i64 + 1);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_mint_timelock_override_takes_precedence() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_with_timelock(&mut ctx, 3600);
+
+    set_timelock_override(&mut ctx, &setup, 0);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_falls_back_to_escrow_timelock_after_override_removed() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_with_timelock(&mut ctx, 3600);
+
+    set_timelock_override(&mut ctx, &setup, 0);
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![],
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::TimelockNotExpired);
+}
+
+#[test]
+fn test_withdraw_mint_arbiter_override_requires_signer() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+    let arbiter = ctx.create_funded_keypair();
+
+    SetMintOverrideFixture::build_set_arbiter(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        arbiter.insecure_clone(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = setup.build_instruction(&ctx).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+
+    setup.arbiter = Some(arbiter);
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_blocked_mint_after_overrides_removed() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::new(&mut ctx);
+
+    setup.replace_with_legacy_receipt(&mut ctx);
+    ctx.write_legacy_allowed_mint(&setup.allowed_mint_pda);
+    set_timelock_override(&mut ctx, &setup, 3600);
+
+    // The override has to be removed explicitly before the mint can be blocked
+    SetMintOverrideFixture::build_with_mint(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mint.pubkey(),
+        CHANGE_ACTION_REMOVE,
+        EXTENSION_TYPE_TIMELOCK,
+        vec![],
+    )
+    .send_expect_success(&mut ctx);
+    block_mint(&mut ctx, &setup);
+
+    setup.build_instruction(&ctx).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&setup.receipt_pda).is_none(), "Receipt should be closed");
+}
+
 // ============================================================================
 // Edge Case Tests
 // ============================================================================
@@ -0,0 +1,346 @@
+use crate::{
+    fixtures::{
+        BasketSetup, RequestHookBypassFixture, SetCooldownFixture, SetHookFixture, SetMintOverrideFixture,
+        WithdrawBasketFixture, CHANGE_ACTION_SET, DEFAULT_COOLDOWN_PERIOD, DEFAULT_DEPOSIT_AMOUNT,
+        DEFAULT_HOOK_BYPASS_GRACE_PERIOD,
+    },
+    utils::extensions_utils::EXTENSION_TYPE_HOOK,
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_allowed_mint_total_deposited, assert_custom_error,
+        assert_escrow_error, find_hook_bypass_request_pda, test_missing_signer, test_not_writable, test_wrong_account,
+        test_wrong_current_program, test_wrong_owner, EscrowError, TestContext, TestInstruction, RANDOM_PUBKEY,
+        TEST_HOOK_ALLOW_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+    },
+};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+fn set_hook_override(ctx: &mut TestContext, setup: &BasketSetup, leg: usize, hook_program: Pubkey) {
+    let mut hook_value = hook_program.to_bytes().to_vec();
+    hook_value.push(0); // flags
+    SetMintOverrideFixture::build_with_mint(
+        ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mints[leg].mint.pubkey(),
+        CHANGE_ACTION_SET,
+        EXTENSION_TYPE_HOOK,
+        hook_value,
+    )
+    .send_expect_success(ctx);
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_withdraw_basket_missing_withdrawer_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<WithdrawBasketFixture>(&mut ctx, 1, 0);
+}
+
+#[test]
+fn test_withdraw_basket_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawBasketFixture>(&mut ctx, 0);
+}
+
+#[test]
+fn test_withdraw_basket_basket_receipt_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawBasketFixture>(&mut ctx, 4);
+}
+
+#[test]
+fn test_withdraw_basket_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawBasketFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_withdraw_basket_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawBasketFixture>(&mut ctx, 10);
+}
+
+#[test]
+fn test_withdraw_basket_depositor_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawBasketFixture>(&mut ctx, 11);
+}
+
+#[test]
+fn test_withdraw_basket_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<WithdrawBasketFixture>(&mut ctx);
+}
+
+#[test]
+fn test_withdraw_basket_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<WithdrawBasketFixture>(&mut ctx, 5, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_withdraw_basket_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<WithdrawBasketFixture>(&mut ctx, 2);
+}
+
+#[test]
+fn test_withdraw_basket_wrong_basket_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<WithdrawBasketFixture>(&mut ctx, 4);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_basket_wrong_withdrawer() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let wrong_withdrawer = ctx.create_funded_keypair();
+    let mut test_ix = WithdrawBasketFixture::build_with_setup(&setup, basket_receipt);
+    test_ix.instruction.accounts[1].pubkey = wrong_withdrawer.pubkey();
+    let test_ix = TestInstruction { signers: vec![wrong_withdrawer], ..test_ix };
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_withdraw_basket_wrong_rent_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let wrong_recipient = Pubkey::new_unique();
+    ctx.svm.set_account(wrong_recipient, Account { lamports: 1_000_000, ..Account::default() }).unwrap();
+
+    let error = WithdrawBasketFixture::build_with_setup(&setup, basket_receipt)
+        .with_account_at(0, wrong_recipient)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidRentRecipient);
+}
+
+#[test]
+fn test_withdraw_basket_timelock_not_expired() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let setup = BasketSetup::builder(&mut ctx).timelock(lock_duration).build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let error = WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::TimelockNotExpired);
+}
+
+#[test]
+fn test_withdraw_basket_arbiter_missing_signer() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).arbiter().build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    // Drop every leg's arbiter from remaining accounts and signers
+    let arbiter = setup.arbiter.as_ref().unwrap().pubkey();
+    let mut test_ix = WithdrawBasketFixture::build_with_setup(&setup, basket_receipt);
+    test_ix.instruction.accounts.retain(|meta| meta.pubkey != arbiter);
+    test_ix.signers.pop();
+
+    let error = test_ix.send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidArbiter);
+}
+
+#[test]
+fn test_withdraw_basket_cooldown_active() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    SetCooldownFixture::build_with_escrow(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        DEFAULT_COOLDOWN_PERIOD,
+    )
+    .send_expect_success(&mut ctx);
+
+    let error = WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::CooldownActive);
+}
+
+#[test]
+fn test_withdraw_basket_leg_hook_changed_after_deposit_runs_new_hook() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    set_hook_override(&mut ctx, &setup, 1, TEST_HOOK_DENY_ID);
+
+    let leg_auth_accounts = [vec![], vec![AccountMeta::new_readonly(TEST_HOOK_DENY_ID, false)]];
+    let error =
+        WithdrawBasketFixture::build_with_leg_accounts(&setup, basket_receipt, &leg_auth_accounts, vec![], vec![])
+            .send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_basket_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+    for basket_mint in &setup.mints {
+        assert_eq!(ctx.get_token_balance(&basket_mint.vault), 0);
+        assert_eq!(ctx.get_token_balance(&basket_mint.depositor_token_account), DEFAULT_DEPOSIT_AMOUNT * 10);
+        assert_allowed_mint_total_deposited(&ctx, &basket_mint.allowed_mint_pda, 0);
+        assert_allowed_mint_outstanding_receipts(&ctx, &basket_mint.allowed_mint_pda, 0);
+    }
+}
+
+#[test]
+fn test_withdraw_basket_leaves_other_baskets_open() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let first = setup.deposit(&mut ctx, 0);
+    let second = setup.deposit(&mut ctx, 1);
+
+    WithdrawBasketFixture::build_with_setup(&setup, first).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&first).is_none(), "Withdrawn basket should be closed");
+    assert!(ctx.get_account(&second).is_some(), "Other basket should remain open");
+    assert_eq!(ctx.get_token_balance(&setup.mints[0].vault), DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_basket_refunds_rent_recipient() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let basket_lamports = ctx.get_account(&basket_receipt).unwrap().lamports;
+    let recipient_before = ctx.get_account(&setup.rent_recipient).unwrap().lamports;
+
+    // The payer is also the fee payer, so allow for the transaction fee
+    WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_success(&mut ctx);
+
+    let recipient_after = ctx.get_account(&setup.rent_recipient).unwrap().lamports;
+    assert!(recipient_after + 10_000 >= recipient_before + basket_lamports);
+}
+
+#[test]
+fn test_withdraw_basket_timelock_expired_success() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let setup = BasketSetup::builder(&mut ctx).timelock(lock_duration).build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + lock_duration as i64 + 1);
+
+    WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_basket_with_arbiter_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).arbiter().build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_basket_with_allow_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx).hook_program(TEST_HOOK_ALLOW_ID).build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    WithdrawBasketFixture::build_with_setup(&setup, basket_receipt).send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_basket_legs_with_different_overrides_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::new(&mut ctx);
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    // After the deposit, the first leg gets a hook and the second an arbiter
+    set_hook_override(&mut ctx, &setup, 0, TEST_HOOK_ALLOW_ID);
+    let arbiter = ctx.create_funded_keypair();
+    SetMintOverrideFixture::build_set_arbiter(
+        &mut ctx,
+        setup.escrow_pda,
+        setup.admin.insecure_clone(),
+        setup.mints[1].mint.pubkey(),
+        arbiter.insecure_clone(),
+    )
+    .send_expect_success(&mut ctx);
+
+    let leg_auth_accounts = [
+        vec![AccountMeta::new_readonly(TEST_HOOK_ALLOW_ID, false), AccountMeta::new_readonly(RANDOM_PUBKEY, false)],
+        vec![AccountMeta::new_readonly(arbiter.pubkey(), true)],
+    ];
+    WithdrawBasketFixture::build_with_leg_accounts(
+        &setup,
+        basket_receipt,
+        &leg_auth_accounts,
+        vec![1, 0],
+        vec![arbiter],
+    )
+    .send_expect_success(&mut ctx);
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+}
+
+#[test]
+fn test_withdraw_basket_with_hook_bypass_success() {
+    let mut ctx = TestContext::new();
+    let setup = BasketSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let basket_receipt = setup.deposit(&mut ctx, 0);
+
+    // Hook blocks the regular withdraw path
+    SetHookFixture::build_with_escrow(&mut ctx, setup.escrow_pda, setup.admin.insecure_clone(), TEST_HOOK_DENY_ID)
+        .send_expect_success(&mut ctx);
+    let deny_accounts = vec![vec![AccountMeta::new_readonly(TEST_HOOK_DENY_ID, false)]; setup.mints.len()];
+    let error = WithdrawBasketFixture::build_with_leg_accounts(&setup, basket_receipt, &deny_accounts, vec![], vec![])
+        .send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+
+    RequestHookBypassFixture::build_for_basket(&ctx, &setup, basket_receipt, 0).send_expect_success(&mut ctx);
+    let (hook_bypass_request_pda, _) = find_hook_bypass_request_pda(&basket_receipt);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+
+    // The request stands in for every leg's hook program
+    let bypass_accounts = vec![vec![AccountMeta::new(hook_bypass_request_pda, false)]; setup.mints.len()];
+    WithdrawBasketFixture::build_with_leg_accounts(&setup, basket_receipt, &bypass_accounts, vec![], vec![])
+        .send_expect_success(&mut ctx);
+
+    for basket_mint in &setup.mints {
+        assert_eq!(ctx.get_token_balance(&basket_mint.depositor_token_account), DEFAULT_DEPOSIT_AMOUNT * 10);
+    }
+    assert!(ctx.get_account(&basket_receipt).is_none(), "Basket receipt should be closed");
+    assert!(ctx.get_account(&hook_bypass_request_pda).is_none(), "Hook bypass request should be closed");
+}
@@ -0,0 +1,436 @@
+use crate::{
+    fixtures::{WithdrawManyFixture, WithdrawSetup, DEFAULT_DEPOSIT_AMOUNT, DEFAULT_HOOK_BYPASS_GRACE_PERIOD},
+    utils::hook_config::{
+        create_hook_config, create_hook_log, read_hook_log, HookConfig, HookLogEntry, HOOK_POINT_POST_WITHDRAW_BATCH,
+        HOOK_POINT_PRE_WITHDRAW_BATCH,
+    },
+    utils::{
+        assert_allowed_mint_outstanding_receipts, assert_custom_error, assert_escrow_error, assert_instruction_error,
+        extensions_utils::{HOOK_FLAG_BATCH_WITHDRAW_HOOK, HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS},
+        test_missing_signer, test_not_writable, test_wrong_account, test_wrong_current_program, test_wrong_owner,
+        test_wrong_system_program, test_wrong_token_program, EscrowError, TestContext, TEST_HOOK_ALLOW_ID,
+        TEST_HOOK_CONFIGURABLE_ID, TEST_HOOK_DENY_ERROR, TEST_HOOK_DENY_ID,
+    },
+};
+use solana_sdk::{
+    instruction::{AccountMeta, InstructionError},
+    pubkey::Pubkey,
+    signature::Signer,
+};
+
+/// Escrow with an allowing hook and the hook bypass extension, `count` receipts deposited through the
+/// hook, then the hook swapped for `hook_program`
+fn hook_bypass_setup(
+    ctx: &mut TestContext,
+    hook_flags: u8,
+    count: u64,
+    hook_program: Pubkey,
+) -> (WithdrawSetup, Vec<Pubkey>) {
+    let mut setup = WithdrawSetup::builder(ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(hook_flags)
+        .hook_bypass(DEFAULT_HOOK_BYPASS_GRACE_PERIOD)
+        .build();
+    let receipts = WithdrawManyFixture::deposit_receipts(ctx, &setup, count);
+    setup.replace_hook_program(ctx, hook_program);
+    (setup, receipts)
+}
+
+fn warp_past_hook_bypass_grace_period(ctx: &mut TestContext) {
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + DEFAULT_HOOK_BYPASS_GRACE_PERIOD as i64);
+    ctx.warp_to_slot(2);
+}
+
+// ============================================================================
+// Error Tests - Using Generic Test Helpers
+// ============================================================================
+
+#[test]
+fn test_withdraw_many_missing_withdrawer_signer() {
+    let mut ctx = TestContext::new();
+    test_missing_signer::<WithdrawManyFixture>(&mut ctx, 2, 0);
+}
+
+#[test]
+fn test_withdraw_many_rent_recipient_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawManyFixture>(&mut ctx, 1);
+}
+
+#[test]
+fn test_withdraw_many_vault_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawManyFixture>(&mut ctx, 5);
+}
+
+#[test]
+fn test_withdraw_many_destination_token_account_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawManyFixture>(&mut ctx, 7);
+}
+
+#[test]
+fn test_withdraw_many_allowed_mint_not_writable() {
+    let mut ctx = TestContext::new();
+    test_not_writable::<WithdrawManyFixture>(&mut ctx, 14);
+}
+
+#[test]
+fn test_withdraw_many_wrong_system_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_system_program::<WithdrawManyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_withdraw_many_wrong_current_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_current_program::<WithdrawManyFixture>(&mut ctx);
+}
+
+#[test]
+fn test_withdraw_many_invalid_event_authority() {
+    let mut ctx = TestContext::new();
+    test_wrong_account::<WithdrawManyFixture>(&mut ctx, 12, InstructionError::Custom(2));
+}
+
+#[test]
+fn test_withdraw_many_wrong_token_program() {
+    let mut ctx = TestContext::new();
+    test_wrong_token_program::<WithdrawManyFixture>(&mut ctx, 9);
+}
+
+#[test]
+fn test_withdraw_many_wrong_escrow_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<WithdrawManyFixture>(&mut ctx, 3);
+}
+
+#[test]
+fn test_withdraw_many_wrong_receipt_owner() {
+    let mut ctx = TestContext::new();
+    test_wrong_owner::<WithdrawManyFixture>(&mut ctx, 15);
+}
+
+// ============================================================================
+// Custom Error Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_many_zero_receipt_count() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let error = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts)
+        .with_data_byte_at(1, 0)
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::InvalidInstructionData);
+}
+
+#[test]
+fn test_withdraw_many_receipt_count_exceeds_accounts() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let error = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts)
+        .with_data_byte_at(1, 3)
+        .send_expect_error(&mut ctx);
+    assert_instruction_error(error, InstructionError::NotEnoughAccountKeys);
+}
+
+#[test]
+fn test_withdraw_many_duplicate_receipt() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+
+    let receipts = [setup.receipt_pda, setup.receipt_pda];
+    let error = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::DuplicateReceipt);
+}
+
+#[test]
+fn test_withdraw_many_wrong_withdrawer() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let other = ctx.create_funded_keypair();
+    let error = WithdrawManyFixture::build_with_withdrawer(&ctx, &setup, &other, &receipts).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidWithdrawer);
+}
+
+#[test]
+fn test_withdraw_many_timelock_not_expired() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let setup = WithdrawSetup::new_with_timelock(&mut ctx, lock_duration);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let error = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::TimelockNotExpired);
+}
+
+#[test]
+fn test_withdraw_many_with_deny_hook_fails() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).hook_program(TEST_HOOK_DENY_ID).build();
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let error = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+#[test]
+fn test_withdraw_many_hook_bypass_for_some_receipts_still_runs_hook() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(&mut ctx, 0, 2, TEST_HOOK_DENY_ID);
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts[..1]);
+    warp_past_hook_bypass_grace_period(&mut ctx);
+
+    // The second receipt has no request, so the hook still runs for it
+    let error = WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts, &requests)
+        .send_expect_error(&mut ctx);
+    assert_custom_error(error, TEST_HOOK_DENY_ERROR);
+}
+
+#[test]
+fn test_withdraw_many_hook_bypass_grace_period_active() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(&mut ctx, 0, 2, TEST_HOOK_DENY_ID);
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts);
+
+    let error = WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts, &requests)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::HookBypassGracePeriodActive);
+}
+
+#[test]
+fn test_withdraw_many_hook_bypass_request_for_receipt_outside_batch() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(&mut ctx, 0, 3, TEST_HOOK_ALLOW_ID);
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts[2..]);
+    warp_past_hook_bypass_grace_period(&mut ctx);
+
+    let error = WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts[..2], &requests)
+        .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidHookBypassRequest);
+}
+
+#[test]
+fn test_withdraw_many_duplicate_hook_bypass_request() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(&mut ctx, 0, 2, TEST_HOOK_ALLOW_ID);
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts[..1]);
+    warp_past_hook_bypass_grace_period(&mut ctx);
+
+    let error =
+        WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts, &[requests[0], requests[0]])
+            .send_expect_error(&mut ctx);
+    assert_escrow_error(error, EscrowError::InvalidHookBypassRequest);
+}
+
+// ============================================================================
+// Success Tests
+// ============================================================================
+
+#[test]
+fn test_withdraw_many_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 3);
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    for receipt in &receipts {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+    }
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + 3 * DEFAULT_DEPOSIT_AMOUNT);
+    assert_eq!(ctx.get_token_balance(&setup.vault), 0);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 0);
+}
+
+#[test]
+fn test_withdraw_many_subset_leaves_other_receipts_open() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 3);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts[..2]).send_expect_success(&mut ctx);
+
+    assert!(ctx.get_account(&receipts[0]).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&receipts[1]).is_none(), "Receipt should be closed");
+    assert!(ctx.get_account(&receipts[2]).is_some(), "Receipt should remain open");
+    assert_eq!(ctx.get_token_balance(&setup.vault), DEFAULT_DEPOSIT_AMOUNT);
+    assert_allowed_mint_outstanding_receipts(&ctx, &setup.allowed_mint_pda, 1);
+}
+
+#[test]
+fn test_withdraw_many_token_2022_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).token_2022().build();
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + 2 * DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_many_timelock_expired_success() {
+    let mut ctx = TestContext::new();
+    let lock_duration = 3600;
+    let setup = WithdrawSetup::new_with_timelock(&mut ctx, lock_duration);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    let current_time = ctx.get_current_timestamp();
+    ctx.warp_to_timestamp(current_time + lock_duration as i64 + 1);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    for receipt in &receipts {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+    }
+}
+
+#[test]
+fn test_withdraw_many_with_arbiter_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::new_with_arbiter(&mut ctx);
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    for receipt in &receipts {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+    }
+}
+
+#[test]
+fn test_withdraw_many_with_per_receipt_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx).hook_program(TEST_HOOK_ALLOW_ID).build();
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    for receipt in &receipts {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+    }
+}
+
+#[test]
+fn test_withdraw_many_with_batch_hook_success() {
+    let mut ctx = TestContext::new();
+    let setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_BATCH_WITHDRAW_HOOK)
+        .build();
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 3);
+
+    WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts).send_expect_success(&mut ctx);
+
+    for receipt in &receipts {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+    }
+}
+
+#[test]
+fn test_withdraw_many_batch_hook_receives_batch_hook_points() {
+    let mut ctx = TestContext::new();
+    let mut setup = WithdrawSetup::builder(&mut ctx)
+        .hook_program(TEST_HOOK_ALLOW_ID)
+        .hook_flags(HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS | HOOK_FLAG_BATCH_WITHDRAW_HOOK)
+        .build();
+    let receipts = WithdrawManyFixture::deposit_receipts(&mut ctx, &setup, 2);
+    setup.replace_hook_program(&mut ctx, TEST_HOOK_CONFIGURABLE_ID);
+
+    // The hook finds its config after escrow, withdrawer, mint and both receipts
+    let core_accounts = [setup.escrow_pda, setup.depositor.pubkey(), setup.mint.pubkey(), receipts[0], receipts[1]];
+    let config = create_hook_config(&mut ctx, &HookConfig::new().log());
+    let log = create_hook_log(&mut ctx, &config);
+
+    let mut test_ix = WithdrawManyFixture::build_with_setup(&ctx, &setup, &receipts);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let (count, entries) = read_hook_log(&ctx, &log);
+    assert_eq!(count, 2);
+    for (entry, hook_point) in entries.iter().zip([HOOK_POINT_PRE_WITHDRAW_BATCH, HOOK_POINT_POST_WITHDRAW_BATCH]) {
+        assert_eq!(
+            *entry,
+            HookLogEntry {
+                hook_point,
+                account_count: core_accounts.len() as u8 + 2,
+                writable_count: 1,
+                signer_count: 0,
+                core_accounts: core_accounts[..4].to_vec(),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_withdraw_many_with_hook_bypass_success() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(&mut ctx, 0, 2, TEST_HOOK_DENY_ID);
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts);
+    warp_past_hook_bypass_grace_period(&mut ctx);
+
+    let balance_before = ctx.get_token_balance(&setup.depositor_token_account);
+
+    // Every receipt is bypassed, so the denying hook is never invoked
+    WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts, &requests)
+        .send_expect_success(&mut ctx);
+
+    for (receipt, request) in receipts.iter().zip(&requests) {
+        assert!(ctx.get_account(receipt).is_none(), "Receipt should be closed");
+        assert!(ctx.get_account(request).is_none(), "Hook bypass request should be closed");
+    }
+    assert_eq!(ctx.get_token_balance(&setup.depositor_token_account), balance_before + 2 * DEFAULT_DEPOSIT_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_many_batch_hook_skips_bypassed_receipts() {
+    let mut ctx = TestContext::new();
+    let (setup, receipts) = hook_bypass_setup(
+        &mut ctx,
+        HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS | HOOK_FLAG_BATCH_WITHDRAW_HOOK,
+        2,
+        TEST_HOOK_CONFIGURABLE_ID,
+    );
+    let requests = WithdrawManyFixture::request_hook_bypasses(&mut ctx, &setup, &receipts[..1]);
+    warp_past_hook_bypass_grace_period(&mut ctx);
+
+    // The batch hook only sees the receipt without a bypass
+    let core_accounts = [setup.escrow_pda, setup.depositor.pubkey(), setup.mint.pubkey(), receipts[1]];
+    let config = create_hook_config(&mut ctx, &HookConfig::new().log());
+    let log = create_hook_log(&mut ctx, &config);
+
+    let mut test_ix = WithdrawManyFixture::build_with_hook_bypass_requests(&ctx, &setup, &receipts, &requests);
+    test_ix.instruction.accounts.push(AccountMeta::new_readonly(config, false));
+    test_ix.instruction.accounts.push(AccountMeta::new(log, false));
+    test_ix.send_expect_success(&mut ctx);
+
+    let (count, entries) = read_hook_log(&ctx, &log);
+    assert_eq!(count, 2);
+    for (entry, hook_point) in entries.iter().zip([HOOK_POINT_PRE_WITHDRAW_BATCH, HOOK_POINT_POST_WITHDRAW_BATCH]) {
+        assert_eq!(
+            *entry,
+            HookLogEntry {
+                hook_point,
+                account_count: core_accounts.len() as u8 + 2,
+                writable_count: 1,
+                signer_count: 0,
+                core_accounts: core_accounts.to_vec(),
+            }
+        );
+    }
+    assert!(ctx.get_account(&requests[0]).is_none(), "Hook bypass request should be closed");
+}
@@ -1,11 +1,17 @@
 use crate::utils::extensions_utils::{
-    block_token_extensions_byte_len, find_extension, ARBITER_DATA_LEN, ESCROW_EXTENSIONS_DISCRIMINATOR,
-    EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, EXTENSION_TYPE_HOOK, EXTENSION_TYPE_TIMELOCK,
-    HOOK_DATA_LEN, TIMELOCK_DATA_LEN,
+    block_token_extensions_byte_len, find_extension, find_mint_override, metadata_byte_len, ARBITER_DATA_LEN,
+    AUTO_RELEASE_DATA_LEN, COOLDOWN_DATA_LEN, DEPOSITOR_ALLOWLIST_DATA_LEN, ESCROW_EXTENSIONS_DISCRIMINATOR,
+    EXTENSION_LOCKS_DATA_LEN, EXTENSION_TYPE_ARBITER, EXTENSION_TYPE_AUTO_RELEASE,
+    EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS, EXTENSION_TYPE_COOLDOWN, EXTENSION_TYPE_DEPOSITOR_ALLOWLIST,
+    EXTENSION_TYPE_EXTENSION_LOCKS, EXTENSION_TYPE_GOVERNANCE_DELAY, EXTENSION_TYPE_HOOK, EXTENSION_TYPE_HOOK_BYPASS,
+    EXTENSION_TYPE_METADATA, EXTENSION_TYPE_REFUND_AUTHORITY, EXTENSION_TYPE_ROLES, EXTENSION_TYPE_TIMELOCK,
+    GOVERNANCE_DELAY_DATA_LEN, HOOK_BYPASS_DATA_LEN, HOOK_DATA_LEN, REFUND_AUTHORITY_DATA_LEN, ROLES_DATA_LEN,
+    TIMELOCK_DATA_LEN,
 };
 use crate::utils::TestContext;
 use escrow_program_client::{
-    accounts::{AllowedMint, Escrow},
+    accounts::{AllowedMint, DepositorAllowance, Escrow, PendingChange, Receipt, ReceiptCounter},
+    hook_errors::{decode_hook_error, hook_error_code},
     ESCROW_PROGRAM_ID,
 };
 use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, transaction::TransactionError};
@@ -15,6 +21,9 @@ pub use escrow_program_client::errors::EscrowProgramError as EscrowError;
 // Since the error when hook program rejects is the error from the CPI call that's just returned directly.
 pub const TEST_HOOK_DENY_ERROR: u32 = 1;
 
+// Reason code the reject hook sets as return data; surfaced in the reserved hook error range.
+pub const TEST_HOOK_REJECT_CODE: u16 = 7;
+
 pub fn assert_escrow_error(tx_error: TransactionError, expected: EscrowError) {
     assert_instruction_error(tx_error, InstructionError::Custom(expected as u32));
 }
@@ -43,6 +52,17 @@ pub fn assert_custom_error(tx_error: TransactionError, expected_code: u32) {
     assert_instruction_error(tx_error, InstructionError::Custom(expected_code));
 }
 
+/// Assert that a transaction error is a hook rejection with the given reason code
+pub fn assert_hook_error(tx_error: TransactionError, expected_code: u16) {
+    match tx_error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(decode_hook_error(code), Some(expected_code), "Expected hook code {expected_code}, got {code}");
+            assert_eq!(code, hook_error_code(expected_code));
+        }
+        other => panic!("Expected hook error, got {other:?}"),
+    }
+}
+
 pub fn assert_escrow_account(
     context: &TestContext,
     escrow_pda: &Pubkey,
@@ -96,6 +116,15 @@ pub fn assert_timelock_extension(ctx: &TestContext, extensions_pda: &Pubkey, exp
 }
 
 pub fn assert_hook_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_hook_program: &Pubkey) {
+    assert_hook_extension_with_flags(ctx, extensions_pda, expected_hook_program, 0);
+}
+
+pub fn assert_hook_extension_with_flags(
+    ctx: &TestContext,
+    extensions_pda: &Pubkey,
+    expected_hook_program: &Pubkey,
+    expected_flags: u8,
+) {
     let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
     let data = &account.data;
 
@@ -104,6 +133,36 @@ pub fn assert_hook_extension(ctx: &TestContext, extensions_pda: &Pubkey, expecte
 
     let hook_program = Pubkey::new_from_array(tlv_data[0..32].try_into().unwrap());
     assert_eq!(hook_program, *expected_hook_program, "Wrong hook program");
+    assert_eq!(tlv_data[32], expected_flags, "Wrong hook flags");
+}
+
+pub fn assert_hook_bypass_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_grace_period: u64) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_HOOK_BYPASS).expect("Hook bypass extension not found");
+    assert_eq!(tlv_data.len(), HOOK_BYPASS_DATA_LEN, "Wrong hook bypass data length");
+
+    let grace_period = u64::from_le_bytes(tlv_data[0..8].try_into().unwrap());
+    assert_eq!(grace_period, expected_grace_period, "Wrong grace period");
+}
+
+pub fn assert_depositor_allowlist_extension(
+    ctx: &TestContext,
+    extensions_pda: &Pubkey,
+    expected_merkle_root: &[u8; 32],
+    expected_per_address_cap: u64,
+) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data =
+        find_extension(data, EXTENSION_TYPE_DEPOSITOR_ALLOWLIST).expect("Depositor allowlist extension not found");
+    assert_eq!(tlv_data.len(), DEPOSITOR_ALLOWLIST_DATA_LEN, "Wrong depositor allowlist data length");
+
+    assert_eq!(&tlv_data[0..32], expected_merkle_root, "Wrong merkle root");
+    let per_address_cap = u64::from_le_bytes(tlv_data[32..40].try_into().unwrap());
+    assert_eq!(per_address_cap, expected_per_address_cap, "Wrong per-address cap");
 }
 
 pub fn assert_block_token_extensions_extension(
@@ -141,12 +200,132 @@ pub fn assert_arbiter_extension(ctx: &TestContext, extensions_pda: &Pubkey, expe
     assert_eq!(arbiter, *expected_arbiter, "Wrong arbiter");
 }
 
+pub fn assert_roles_extension(
+    ctx: &TestContext,
+    extensions_pda: &Pubkey,
+    expected_mint_manager: &Pubkey,
+    expected_extension_manager: &Pubkey,
+    expected_admin_transfer_authority: &Pubkey,
+) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_ROLES).expect("Roles extension not found");
+    assert_eq!(tlv_data.len(), ROLES_DATA_LEN, "Wrong roles data length");
+
+    let mint_manager = Pubkey::new_from_array(tlv_data[0..32].try_into().unwrap());
+    let extension_manager = Pubkey::new_from_array(tlv_data[32..64].try_into().unwrap());
+    let admin_transfer_authority = Pubkey::new_from_array(tlv_data[64..96].try_into().unwrap());
+    assert_eq!(mint_manager, *expected_mint_manager, "Wrong mint manager");
+    assert_eq!(extension_manager, *expected_extension_manager, "Wrong extension manager");
+    assert_eq!(admin_transfer_authority, *expected_admin_transfer_authority, "Wrong admin transfer authority");
+}
+
+pub fn assert_governance_delay_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_delay: u64) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_GOVERNANCE_DELAY).expect("Governance delay extension not found");
+    assert_eq!(tlv_data.len(), GOVERNANCE_DELAY_DATA_LEN, "Wrong governance delay data length");
+
+    let delay = u64::from_le_bytes(tlv_data[0..8].try_into().unwrap());
+    assert_eq!(delay, expected_delay, "Wrong governance delay");
+}
+
+pub fn assert_auto_release_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_release_delay: u64) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_AUTO_RELEASE).expect("Auto-release extension not found");
+    assert_eq!(tlv_data.len(), AUTO_RELEASE_DATA_LEN, "Wrong auto-release data length");
+
+    let release_delay = u64::from_le_bytes(tlv_data[0..8].try_into().unwrap());
+    assert_eq!(release_delay, expected_release_delay, "Wrong release delay");
+}
+
+pub fn assert_refund_authority_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_flags: u8) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_REFUND_AUTHORITY).expect("Refund authority extension not found");
+    assert_eq!(tlv_data.len(), REFUND_AUTHORITY_DATA_LEN, "Wrong refund authority data length");
+    assert_eq!(tlv_data[0], expected_flags, "Wrong refund authority flags");
+}
+
+pub fn assert_cooldown_extension(ctx: &TestContext, extensions_pda: &Pubkey, expected_cooldown_period: u64) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_COOLDOWN).expect("Cooldown extension not found");
+    assert_eq!(tlv_data.len(), COOLDOWN_DATA_LEN, "Wrong cooldown data length");
+
+    let cooldown_period = u64::from_le_bytes(tlv_data[0..8].try_into().unwrap());
+    assert_eq!(cooldown_period, expected_cooldown_period, "Wrong cooldown period");
+}
+
+pub fn assert_metadata_extension(
+    ctx: &TestContext,
+    extensions_pda: &Pubkey,
+    expected_name: &str,
+    expected_uri: &str,
+    expected_external_reference: &[u8],
+) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_METADATA).expect("Metadata extension not found");
+    assert_eq!(
+        tlv_data.len(),
+        metadata_byte_len(expected_name, expected_uri, expected_external_reference),
+        "Wrong metadata data length"
+    );
+
+    let mut offset = 0;
+    let mut fields = Vec::with_capacity(3);
+    for _ in 0..3 {
+        let len = u32::from_le_bytes(tlv_data[offset..offset + 4].try_into().unwrap()) as usize;
+        fields.push(&tlv_data[offset + 4..offset + 4 + len]);
+        offset += 4 + len;
+    }
+    assert_eq!(fields[0], expected_name.as_bytes(), "Wrong metadata name");
+    assert_eq!(fields[1], expected_uri.as_bytes(), "Wrong metadata uri");
+    assert_eq!(fields[2], expected_external_reference, "Wrong metadata external reference");
+}
+
+pub fn assert_extension_locks(
+    ctx: &TestContext,
+    extensions_pda: &Pubkey,
+    expected_locked_types: &[u16],
+    expected_mint_list_locked: bool,
+) {
+    let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
+    let data = &account.data;
+
+    let tlv_data = find_extension(data, EXTENSION_TYPE_EXTENSION_LOCKS).expect("Extension locks not found");
+    assert_eq!(tlv_data.len(), EXTENSION_LOCKS_DATA_LEN, "Wrong extension locks data length");
+
+    let expected_locked_extensions = expected_locked_types.iter().fold(0u32, |bits, ext_type| bits | 1 << ext_type);
+    let locked_extensions = u32::from_le_bytes(tlv_data[0..4].try_into().unwrap());
+    assert_eq!(locked_extensions, expected_locked_extensions, "Wrong locked extensions");
+    assert_eq!(tlv_data[4] == 1, expected_mint_list_locked, "Wrong mint list lock");
+}
+
 pub fn assert_extension_missing(ctx: &TestContext, extensions_pda: &Pubkey, extension_type: u16) {
     let account = ctx.get_account(extensions_pda).expect("Extensions account should exist");
     let data = &account.data;
     assert!(find_extension(data, extension_type).is_none(), "Extension type {extension_type} should be absent");
 }
 
+pub fn assert_mint_override(
+    ctx: &TestContext,
+    allowed_mint_pda: &Pubkey,
+    extension_type: u16,
+    expected_value: Option<&[u8]>,
+) {
+    let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
+    assert_eq!(find_mint_override(&account.data, extension_type), expected_value);
+}
+
 pub fn assert_allowed_mint_account(ctx: &TestContext, allowed_mint_pda: &Pubkey, expected_bump: u8) {
     let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
 
@@ -156,3 +335,95 @@ pub fn assert_allowed_mint_account(ctx: &TestContext, allowed_mint_pda: &Pubkey,
 
     assert_eq!(allowed_mint.bump, expected_bump);
 }
+
+pub fn assert_allowed_mint_limits(
+    ctx: &TestContext,
+    allowed_mint_pda: &Pubkey,
+    expected_max_total_deposits: u64,
+    expected_min_deposit: u64,
+    expected_max_deposit: u64,
+) {
+    let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
+    let allowed_mint = AllowedMint::from_bytes(&account.data).expect("Should deserialize AllowedMint account");
+
+    assert_eq!(allowed_mint.max_total_deposits, expected_max_total_deposits);
+    assert_eq!(allowed_mint.min_deposit, expected_min_deposit);
+    assert_eq!(allowed_mint.max_deposit, expected_max_deposit);
+}
+
+pub fn assert_allowed_mint_total_deposited(ctx: &TestContext, allowed_mint_pda: &Pubkey, expected_total: u64) {
+    let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
+    let allowed_mint = AllowedMint::from_bytes(&account.data).expect("Should deserialize AllowedMint account");
+
+    assert_eq!(allowed_mint.total_deposited, expected_total);
+}
+
+pub fn assert_allowed_mint_outstanding_receipts(ctx: &TestContext, allowed_mint_pda: &Pubkey, expected_count: u64) {
+    let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
+    let allowed_mint = AllowedMint::from_bytes(&account.data).expect("Should deserialize AllowedMint account");
+
+    assert_eq!(allowed_mint.outstanding_receipts, expected_count);
+}
+
+pub fn assert_allowed_mint_opening_balance(ctx: &TestContext, allowed_mint_pda: &Pubkey, expected_balance: u64) {
+    let account = ctx.get_account(allowed_mint_pda).expect("AllowedMint account should exist");
+    let allowed_mint = AllowedMint::from_bytes(&account.data).expect("Should deserialize AllowedMint account");
+
+    assert_eq!(allowed_mint.opening_balance, expected_balance);
+}
+
+pub fn assert_depositor_allowance(
+    ctx: &TestContext,
+    allowance_pda: &Pubkey,
+    expected_escrow: &Pubkey,
+    expected_depositor: &Pubkey,
+    expected_mint: &Pubkey,
+    expected_deposited: u64,
+) {
+    let account = ctx.get_account(allowance_pda).expect("DepositorAllowance account should exist");
+    assert_eq!(account.owner, ESCROW_PROGRAM_ID);
+
+    let allowance =
+        DepositorAllowance::from_bytes(&account.data).expect("Should deserialize DepositorAllowance account");
+
+    assert_eq!(allowance.escrow.as_ref(), expected_escrow.as_ref());
+    assert_eq!(allowance.depositor.as_ref(), expected_depositor.as_ref());
+    assert_eq!(allowance.mint.as_ref(), expected_mint.as_ref());
+    assert_eq!(allowance.deposited, expected_deposited);
+}
+
+pub fn assert_receipt_counter(ctx: &TestContext, receipt_counter_pda: &Pubkey, expected_next_nonce: u64) {
+    let account = ctx.get_account(receipt_counter_pda).expect("ReceiptCounter account should exist");
+    assert_eq!(account.owner, ESCROW_PROGRAM_ID);
+
+    let counter = ReceiptCounter::from_bytes(&account.data).expect("Should deserialize ReceiptCounter account");
+    assert_eq!(counter.next_nonce, expected_next_nonce);
+}
+
+pub fn assert_receipt_nonce(ctx: &TestContext, receipt_pda: &Pubkey, expected_nonce: u64) {
+    let account = ctx.get_account(receipt_pda).expect("Receipt account should exist");
+    let receipt = Receipt::from_bytes(&account.data).expect("Should deserialize Receipt account");
+    assert_eq!(receipt.nonce, expected_nonce);
+}
+
+pub fn assert_receipt_rent_recipient(ctx: &TestContext, receipt_pda: &Pubkey, expected_rent_recipient: &Pubkey) {
+    let account = ctx.get_account(receipt_pda).expect("Receipt account should exist");
+    let receipt = Receipt::from_bytes(&account.data).expect("Should deserialize Receipt account");
+    assert_eq!(receipt.rent_recipient, *expected_rent_recipient);
+}
+
+pub fn assert_pending_change(
+    ctx: &TestContext,
+    pending_change_pda: &Pubkey,
+    expected_escrow: &Pubkey,
+    expected_change_id: u64,
+    expected_queued_at: i64,
+) {
+    let account = ctx.get_account(pending_change_pda).expect("PendingChange account should exist");
+    assert_eq!(account.owner, ESCROW_PROGRAM_ID);
+
+    let pending_change = PendingChange::from_bytes(&account.data).expect("Should deserialize PendingChange account");
+    assert_eq!(pending_change.escrow, *expected_escrow);
+    assert_eq!(pending_change.change_id, expected_change_id);
+    assert_eq!(pending_change.queued_at, expected_queued_at);
+}
@@ -4,22 +4,65 @@ pub const EXTENSION_TYPE_TIMELOCK: u16 = 0;
 pub const EXTENSION_TYPE_HOOK: u16 = 1;
 pub const EXTENSION_TYPE_BLOCK_TOKEN_EXTENSIONS: u16 = 2;
 pub const EXTENSION_TYPE_ARBITER: u16 = 3;
+pub const EXTENSION_TYPE_HOOK_BYPASS: u16 = 4;
+pub const EXTENSION_TYPE_DEPOSITOR_ALLOWLIST: u16 = 5;
+pub const EXTENSION_TYPE_ROLES: u16 = 6;
+pub const EXTENSION_TYPE_GOVERNANCE_DELAY: u16 = 7;
+pub const EXTENSION_TYPE_EXTENSION_LOCKS: u16 = 8;
+pub const EXTENSION_TYPE_METADATA: u16 = 9;
+pub const EXTENSION_TYPE_AUTO_RELEASE: u16 = 10;
+pub const EXTENSION_TYPE_REFUND_AUTHORITY: u16 = 11;
+pub const EXTENSION_TYPE_COOLDOWN: u16 = 12;
 
 pub const ESCROW_EXTENSIONS_DISCRIMINATOR: u8 = 2;
 pub const ESCROW_EXTENSIONS_HEADER_LEN: usize = 4; // discriminator + bump + version + extension_count
+pub const ALLOWED_MINT_LEN: usize = 58; // discriminator + version + bump + padding + six u64 fields
 
 pub const TIMELOCK_DATA_LEN: usize = 8;
-pub const HOOK_DATA_LEN: usize = 32;
+pub const HOOK_DATA_LEN: usize = 33;
+pub const HOOK_FLAG_WRITABLE_HOOK_ACCOUNTS: u8 = 1;
+pub const HOOK_FLAG_BATCH_WITHDRAW_HOOK: u8 = 1 << 1;
 pub const ARBITER_DATA_LEN: usize = 32;
+pub const HOOK_BYPASS_DATA_LEN: usize = 8;
+pub const DEPOSITOR_ALLOWLIST_DATA_LEN: usize = 40;
+pub const ROLES_DATA_LEN: usize = 96;
+pub const GOVERNANCE_DELAY_DATA_LEN: usize = 8;
+pub const EXTENSION_LOCKS_DATA_LEN: usize = 5;
+pub const AUTO_RELEASE_DATA_LEN: usize = 8;
+pub const REFUND_AUTHORITY_DATA_LEN: usize = 1;
+pub const REFUND_AUTHORITY_FLAG_ADMIN: u8 = 1;
+pub const REFUND_AUTHORITY_FLAG_ARBITER: u8 = 1 << 1;
+pub const COOLDOWN_DATA_LEN: usize = 8;
 
 /// Calculate the expected byte length for block token extensions data
 pub fn block_token_extensions_byte_len(count: usize) -> usize {
     1 + (count * 2) // count (1) + blocked_extensions (count * 2)
 }
 
+/// Calculate the expected byte length for metadata data
+pub fn metadata_byte_len(name: &str, uri: &str, external_reference: &[u8]) -> usize {
+    4 + name.len() + 4 + uri.len() + 4 + external_reference.len() // three u32 length prefixes + field bytes
+}
+
+/// Encode a single TLV entry in the extensions PDA layout
+pub fn tlv_entry(ext_type: u16, value: &[u8]) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(TLV_HEADER_SIZE + value.len());
+    entry.extend_from_slice(&ext_type.to_le_bytes());
+    entry.extend_from_slice(&(value.len() as u16).to_le_bytes());
+    entry.extend_from_slice(value);
+    entry
+}
+
 pub fn find_extension(account_data: &[u8], ext_type: u16) -> Option<&[u8]> {
-    let mut offset = ESCROW_EXTENSIONS_HEADER_LEN;
+    find_tlv_entry(account_data, ESCROW_EXTENSIONS_HEADER_LEN, ext_type)
+}
 
+/// Per-mint override data stored after the fixed AllowedMint fields
+pub fn find_mint_override(account_data: &[u8], ext_type: u16) -> Option<&[u8]> {
+    find_tlv_entry(account_data, ALLOWED_MINT_LEN, ext_type)
+}
+
+fn find_tlv_entry(account_data: &[u8], mut offset: usize, ext_type: u16) -> Option<&[u8]> {
     while offset + TLV_HEADER_SIZE <= account_data.len() {
         let type_bytes = u16::from_le_bytes(account_data[offset..offset + 2].try_into().unwrap());
         let length = u16::from_le_bytes(account_data[offset + 2..offset + 4].try_into().unwrap()) as usize;
@@ -36,3 +79,9 @@ pub fn find_extension(account_data: &[u8], ext_type: u16) -> Option<&[u8]> {
     }
     None
 }
+
+/// Byte offset of an extension's data within the account, for tests that patch TLV data in place
+pub fn find_extension_offset(account_data: &[u8], ext_type: u16) -> Option<usize> {
+    let data = find_extension(account_data, ext_type)?;
+    Some(data.as_ptr() as usize - account_data.as_ptr() as usize)
+}
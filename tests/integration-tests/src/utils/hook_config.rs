@@ -0,0 +1,158 @@
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use crate::utils::{TestContext, TEST_HOOK_CONFIGURABLE_ID};
+
+pub const HOOK_POINT_PRE_DEPOSIT: u8 = 0;
+pub const HOOK_POINT_POST_DEPOSIT: u8 = 1;
+pub const HOOK_POINT_PRE_WITHDRAW: u8 = 2;
+pub const HOOK_POINT_POST_WITHDRAW: u8 = 3;
+pub const HOOK_POINT_PRE_WITHDRAW_BATCH: u8 = 4;
+pub const HOOK_POINT_POST_WITHDRAW_BATCH: u8 = 5;
+
+pub const HOOK_CONFIG_FLAG_ASSERT: u8 = 1 << 0;
+pub const HOOK_CONFIG_FLAG_LOG: u8 = 1 << 1;
+pub const HOOK_CONFIG_FLAG_REENTER: u8 = 1 << 2;
+pub const HOOK_CONFIG_FLAG_UNTAGGED_RETURN_DATA: u8 = 1 << 3;
+
+const MAX_CORE_ACCOUNTS: usize = 4;
+pub const HOOK_CONFIG_LEN: usize = 10 + MAX_CORE_ACCOUNTS * 32;
+const HOOK_LOG_ENTRY_LEN: usize = 4 + MAX_CORE_ACCOUNTS * 32;
+pub const HOOK_LOG_CAPACITY: usize = 4;
+pub const HOOK_LOG_LEN: usize = 4 + HOOK_LOG_CAPACITY * HOOK_LOG_ENTRY_LEN;
+pub const HOOK_LOG_SEED: &[u8] = b"hook_log";
+
+// Reason code the configurable hook reports when a configured assertion fails.
+pub const TEST_HOOK_ASSERT_FAILED_CODE: u16 = 0xA55E;
+
+/// Behaviour of the configurable test hook, serialized into its config account
+#[derive(Clone, Debug, Default)]
+pub struct HookConfig {
+    pub reject_points: u8,
+    pub reject_code: u16,
+    pub flags: u8,
+    pub burn_iterations: u32,
+    pub expected_points: u8,
+    pub expected_accounts: Vec<Pubkey>,
+}
+
+impl HookConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reject the given hook point with a reason code
+    pub fn reject_at(mut self, hook_point: u8, code: u16) -> Self {
+        self.reject_points |= 1 << hook_point;
+        self.reject_code = code;
+        self
+    }
+
+    /// Assert the hook is only invoked at `hook_points` with exactly `core_accounts`, in order
+    pub fn expect(mut self, hook_points: &[u8], core_accounts: &[Pubkey]) -> Self {
+        assert!(core_accounts.len() <= MAX_CORE_ACCOUNTS, "At most {MAX_CORE_ACCOUNTS} core accounts");
+        self.flags |= HOOK_CONFIG_FLAG_ASSERT;
+        self.expected_points = hook_points.iter().fold(0, |mask, point| mask | (1 << point));
+        self.expected_accounts = core_accounts.to_vec();
+        self
+    }
+
+    /// Record each invocation into the log account following the config
+    pub fn log(mut self) -> Self {
+        self.flags |= HOOK_CONFIG_FLAG_LOG;
+        self
+    }
+
+    /// Burn compute with a busy loop on every invocation
+    pub fn burn(mut self, iterations: u32) -> Self {
+        self.burn_iterations = iterations;
+        self
+    }
+
+    /// Call back into the escrow program (which must be passed as an extra account)
+    pub fn reenter(mut self) -> Self {
+        self.flags |= HOOK_CONFIG_FLAG_REENTER;
+        self
+    }
+
+    /// Set `code` as return data without the rejection magic, then succeed
+    pub fn untagged_return_data(mut self, code: u16) -> Self {
+        self.flags |= HOOK_CONFIG_FLAG_UNTAGGED_RETURN_DATA;
+        self.reject_code = code;
+        self
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = vec![0u8; HOOK_CONFIG_LEN];
+        data[0] = self.reject_points;
+        data[1..3].copy_from_slice(&self.reject_code.to_le_bytes());
+        data[3] = self.flags;
+        data[4..8].copy_from_slice(&self.burn_iterations.to_le_bytes());
+        data[8] = self.expected_points;
+        data[9] = self.expected_accounts.len() as u8;
+        for (i, account) in self.expected_accounts.iter().enumerate() {
+            data[10 + i * 32..10 + (i + 1) * 32].copy_from_slice(account.as_ref());
+        }
+        data
+    }
+}
+
+/// A single invocation recorded by the configurable hook
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookLogEntry {
+    pub hook_point: u8,
+    pub account_count: u8,
+    pub writable_count: u8,
+    pub signer_count: u8,
+    pub core_accounts: Vec<Pubkey>,
+}
+
+pub fn find_hook_log_pda(config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[HOOK_LOG_SEED, config.as_ref()], &TEST_HOOK_CONFIGURABLE_ID)
+}
+
+/// Creates a hook-owned config account holding `config`
+pub fn create_hook_config(ctx: &mut TestContext, config: &HookConfig) -> Pubkey {
+    let pubkey = ctx.create_program_owned_account(&TEST_HOOK_CONFIGURABLE_ID, HOOK_CONFIG_LEN);
+    let mut account = ctx.get_account(&pubkey).expect("Hook config should exist");
+    account.data = config.to_bytes();
+    ctx.svm.set_account(pubkey, account).unwrap();
+    pubkey
+}
+
+/// Creates the empty hook-owned log PDA for a config account
+pub fn create_hook_log(ctx: &mut TestContext, config: &Pubkey) -> Pubkey {
+    let (log_pda, _) = find_hook_log_pda(config);
+    let lamports = ctx.svm.minimum_balance_for_rent_exemption(HOOK_LOG_LEN);
+    ctx.svm
+        .set_account(
+            log_pda,
+            Account { lamports, data: vec![0u8; HOOK_LOG_LEN], owner: TEST_HOOK_CONFIGURABLE_ID, ..Account::default() },
+        )
+        .unwrap();
+    log_pda
+}
+
+/// Reads the total invocation count and the recorded entries from a hook log
+pub fn read_hook_log(ctx: &TestContext, log: &Pubkey) -> (u32, Vec<HookLogEntry>) {
+    let data = ctx.get_account(log).expect("Hook log should exist").data;
+    let count = u32::from_le_bytes(data[0..4].try_into().unwrap());
+
+    let entries = (0..(count as usize).min(HOOK_LOG_CAPACITY))
+        .map(|i| {
+            let entry = &data[4 + i * HOOK_LOG_ENTRY_LEN..4 + (i + 1) * HOOK_LOG_ENTRY_LEN];
+            // Batch hook points record only the first MAX_CORE_ACCOUNTS core accounts
+            let core_len = if entry[0] <= HOOK_POINT_POST_DEPOSIT { 3 } else { MAX_CORE_ACCOUNTS };
+            HookLogEntry {
+                hook_point: entry[0],
+                account_count: entry[1],
+                writable_count: entry[2],
+                signer_count: entry[3],
+                core_accounts: (0..core_len)
+                    .map(|j| Pubkey::try_from(&entry[4 + j * 32..4 + (j + 1) * 32]).unwrap())
+                    .collect(),
+            }
+        })
+        .collect();
+
+    (count, entries)
+}
@@ -0,0 +1,59 @@
+use solana_program::hash::hashv;
+use solana_sdk::pubkey::Pubkey;
+
+const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+pub fn merkle_leaf(depositor: &Pubkey) -> [u8; 32] {
+    hashv(&[MERKLE_LEAF_PREFIX, depositor.as_ref()]).to_bytes()
+}
+
+pub fn merkle_parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[MERKLE_NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Depositor allowlist tree matching the program's sorted-pair hashing
+///
+/// An odd node at the end of a layer is carried up unchanged.
+pub struct DepositorMerkleTree {
+    depositors: Vec<Pubkey>,
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl DepositorMerkleTree {
+    pub fn new(depositors: &[Pubkey]) -> Self {
+        assert!(!depositors.is_empty(), "Allowlist must contain at least one depositor");
+
+        let mut layers = vec![depositors.iter().map(merkle_leaf).collect::<Vec<_>>()];
+        while layers.last().unwrap().len() > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| if pair.len() == 2 { merkle_parent(&pair[0], &pair[1]) } else { pair[0] })
+                .collect();
+            layers.push(next);
+        }
+
+        Self { depositors: depositors.to_vec(), layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers.last().unwrap()[0]
+    }
+
+    pub fn proof(&self, depositor: &Pubkey) -> Vec<[u8; 32]> {
+        let mut index =
+            self.depositors.iter().position(|d| d == depositor).expect("Depositor should be in the allowlist");
+
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
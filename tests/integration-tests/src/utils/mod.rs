@@ -1,6 +1,8 @@
 pub mod assertions;
 pub mod cu_utils;
 pub mod extensions_utils;
+pub mod hook_config;
+pub mod merkle_utils;
 pub mod pda_utils;
 pub mod setup;
 pub mod test_helpers;
@@ -1,10 +1,26 @@
-use escrow_program_client::accounts::{AllowedMint, Escrow, EscrowExtensionsHeader, EventAuthority, Receipt};
+use escrow_program_client::{
+    accounts::{
+        AllowedMint, BasketReceipt, DepositorAllowance, Escrow, EscrowExtensionsHeader, EventAuthority,
+        HookBypassRequest, PendingChange, Receipt, ReceiptCounter, WithdrawDelegate, WithdrawRequest,
+    },
+    ESCROW_PROGRAM_ID,
+};
+use solana_program::hash::hashv;
 use solana_sdk::pubkey::Pubkey;
 
 pub fn find_escrow_pda(escrow_seed: &Pubkey) -> (Pubkey, u8) {
     Escrow::find_pda(escrow_seed)
 }
 
+/// Escrow seed used by `CreateEscrowWithSeed`: `sha256("admin_bound_escrow_seed" || admin || seed)`
+pub fn admin_bound_escrow_seed(admin: &Pubkey, seed: &[u8; 32]) -> Pubkey {
+    Pubkey::new_from_array(hashv(&[b"admin_bound_escrow_seed", admin.as_ref(), seed]).to_bytes())
+}
+
+pub fn find_admin_bound_escrow_pda(admin: &Pubkey, seed: &[u8; 32]) -> (Pubkey, u8) {
+    find_escrow_pda(&admin_bound_escrow_seed(admin, seed))
+}
+
 pub fn find_extensions_pda(escrow: &Pubkey) -> (Pubkey, u8) {
     EscrowExtensionsHeader::find_pda(escrow)
 }
@@ -13,14 +29,55 @@ pub fn find_event_authority_pda() -> (Pubkey, u8) {
     EventAuthority::find_pda()
 }
 
-pub fn find_receipt_pda(escrow: &Pubkey, depositor: &Pubkey, mint: &Pubkey, receipt_seed: &Pubkey) -> (Pubkey, u8) {
-    Receipt::find_pda(escrow, depositor, mint, receipt_seed)
+pub fn find_receipt_pda(escrow: &Pubkey, depositor: &Pubkey, mint: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Receipt::find_pda(escrow, depositor, mint, nonce)
+}
+
+/// Address of a version 1 receipt, which was derived from a `receipt_seed` instead of a nonce
+pub fn find_legacy_receipt_pda(
+    escrow: &Pubkey,
+    depositor: &Pubkey,
+    mint: &Pubkey,
+    receipt_seed: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"receipt", escrow.as_ref(), depositor.as_ref(), mint.as_ref(), receipt_seed.as_ref()],
+        &ESCROW_PROGRAM_ID,
+    )
+}
+
+pub fn find_receipt_counter_pda(escrow: &Pubkey, depositor: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    ReceiptCounter::find_pda(escrow, depositor, mint)
 }
 
 pub fn find_allowed_mint_pda(escrow: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
     AllowedMint::find_pda(escrow, mint)
 }
 
+pub fn find_hook_bypass_request_pda(receipt: &Pubkey) -> (Pubkey, u8) {
+    HookBypassRequest::find_pda(receipt)
+}
+
+pub fn find_withdraw_delegate_pda(receipt: &Pubkey) -> (Pubkey, u8) {
+    WithdrawDelegate::find_pda(receipt)
+}
+
+pub fn find_withdraw_request_pda(receipt: &Pubkey) -> (Pubkey, u8) {
+    WithdrawRequest::find_pda(receipt)
+}
+
+pub fn find_depositor_allowance_pda(escrow: &Pubkey, depositor: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    DepositorAllowance::find_pda(escrow, depositor, mint)
+}
+
+pub fn find_pending_change_pda(escrow: &Pubkey, change_id: u64) -> (Pubkey, u8) {
+    PendingChange::find_pda(escrow, change_id)
+}
+
+pub fn find_basket_receipt_pda(escrow: &Pubkey, depositor: &Pubkey, basket_id: u64) -> (Pubkey, u8) {
+    BasketReceipt::find_pda(escrow, depositor, basket_id)
+}
+
 pub fn find_noncanonical_program_address(seeds: &[&[u8]], program_id: &Pubkey) -> Option<(Pubkey, u8)> {
     let (_, canonical_bump) = Pubkey::find_program_address(seeds, program_id);
 
@@ -14,6 +14,11 @@ use escrow_program_client::ESCROW_PROGRAM_ID;
 
 pub const TEST_HOOK_ALLOW_ID: Pubkey = pubkey!("HookA11ow1111111111111111111111111111111111");
 pub const TEST_HOOK_DENY_ID: Pubkey = pubkey!("HookDeny11111111111111111111111111111111111");
+pub const TEST_HOOK_REJECT_ID: Pubkey = pubkey!("HookReject111111111111111111111111111111111");
+pub const TEST_HOOK_REENTER_ID: Pubkey = pubkey!("HookReenter11111111111111111111111111111111");
+/// Second deployment of the reenter hook, used as a relay between a hook and the escrow
+pub const TEST_HOOK_RELAY_ID: Pubkey = pubkey!("HookRe1ay1111111111111111111111111111111111");
+pub const TEST_HOOK_CONFIGURABLE_ID: Pubkey = pubkey!("HookConfig111111111111111111111111111111111");
 
 const MIN_LAMPORTS: u64 = 500_000_000;
 const CU_TRACKING_ENV_VAR: &str = "CU_TRACKING";
@@ -47,6 +52,16 @@ impl TestContext {
         let hook_deny_data = include_bytes!("../../../../target/deploy/test_hook_deny.so");
         let _ = svm.add_program(TEST_HOOK_DENY_ID, hook_deny_data);
 
+        let hook_reject_data = include_bytes!("../../../../target/deploy/test_hook_reject.so");
+        let _ = svm.add_program(TEST_HOOK_REJECT_ID, hook_reject_data);
+
+        let hook_reenter_data = include_bytes!("../../../../target/deploy/test_hook_reenter.so");
+        let _ = svm.add_program(TEST_HOOK_REENTER_ID, hook_reenter_data);
+        let _ = svm.add_program(TEST_HOOK_RELAY_ID, hook_reenter_data);
+
+        let hook_configurable_data = include_bytes!("../../../../target/deploy/test_hook_configurable.so");
+        let _ = svm.add_program(TEST_HOOK_CONFIGURABLE_ID, hook_configurable_data);
+
         let payer = Keypair::new();
         svm.airdrop(&payer.pubkey(), MIN_LAMPORTS).unwrap();
 
@@ -117,6 +132,24 @@ impl TestContext {
         kp
     }
 
+    /// Creates a rent-exempt, zeroed account owned by `owner` (e.g. hook program state)
+    pub fn create_program_owned_account(&mut self, owner: &Pubkey, data_len: usize) -> Pubkey {
+        let pubkey = Pubkey::new_unique();
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data_len);
+        self.svm
+            .set_account(pubkey, Account { lamports, data: vec![0u8; data_len], owner: *owner, ..Account::default() })
+            .unwrap();
+        pubkey
+    }
+
+    /// Rewrites an AllowedMint account in the version 1 layout, which only held the bump
+    pub fn write_legacy_allowed_mint(&mut self, allowed_mint: &Pubkey) {
+        let account = self.get_account(allowed_mint).expect("AllowedMint account should exist");
+        let data = vec![account.data[0], 1, account.data[2]]; // discriminator, version, bump
+        let lamports = self.svm.minimum_balance_for_rent_exemption(data.len());
+        self.svm.set_account(*allowed_mint, Account { lamports, data, ..account }).unwrap();
+    }
+
     pub fn warp_to_timestamp(&mut self, unix_timestamp: i64) {
         self.svm.set_sysvar(&Clock {
             slot: 1,
@@ -1,8 +1,42 @@
 //! Test Hook Program for LiteSVM Integration Tests
 //!
-//! Two variants via feature flags:
-//! - allow: Accepts all operations
-//! - deny: Rejects all operations
+//! Five variants via feature flags:
+//! - allow: Accepts all operations, bumping a counter in any writable account it owns
+//! - deny: Rejects all operations by failing the CPI
+//! - reject: Rejects all operations with a tagged reason code set as return data
+//! - reenter: Maliciously calls back into the escrow program with a Withdraw. Forwards to the first
+//!   other executable account it is passed, so a second deployment can relay the call through a third program
+//! - configurable: Behaviour driven by a config account passed as the first extra account
+//!
+//! # Configurable Variant
+//!
+//! The config account must be owned by the hook program and placed directly after the
+//! core accounts (3 for deposit hook points, 4 for withdraw hook points, 3 plus the receipt
+//! count for batch withdraw hook points). Layout:
+//!
+//! | Offset | Size | Field                                                            |
+//! |--------|------|------------------------------------------------------------------|
+//! | 0      | 1    | reject_points: bitmask of hook points to reject (1 << hook_point) |
+//! | 1      | 2    | reject_code: u16 reason code set as return data on rejection      |
+//! | 3      | 1    | flags: CONFIG_FLAG_*                                               |
+//! | 4      | 4    | burn_iterations: u32 busy-loop iterations to consume compute      |
+//! | 8      | 1    | expected_points: bitmask of hook points allowed when asserting    |
+//! | 9      | 1    | expected_len: number of expected core account addresses (max 4)   |
+//! | 10     | 128  | expected_accounts: expected core account addresses, in order      |
+//!
+//! With `CONFIG_FLAG_LOG` set, the account after the config is a writable, hook-owned log
+//! that records each invocation (requires the escrow hook's writable-accounts flag):
+//!
+//! | Offset          | Size | Field                                            |
+//! |-----------------|------|--------------------------------------------------|
+//! | 0               | 4    | invocation count (u32, keeps counting past capacity) |
+//! | 4 + i * 132     | 1    | hook_point                                       |
+//! | 5 + i * 132     | 1    | account_count                                    |
+//! | 6 + i * 132     | 1    | writable_count                                   |
+//! | 7 + i * 132     | 1    | signer_count                                     |
+//! | 8 + i * 132     | 128  | first 4 core account addresses, zero padded      |
+//!
+//! Failed assertions are reported as a rejection with `ASSERT_FAILED_CODE`.
 
 #![no_std]
 
@@ -15,24 +49,21 @@ pinocchio::default_allocator!();
 pinocchio::nostd_panic_handler!();
 
 #[cfg(feature = "allow")]
-pub fn process_instruction(_program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+pub fn process_instruction(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
     use pinocchio::error::ProgramError;
 
     // Validate core context shape so integration tests catch missing account context.
-    // hook_point: 0=PreDeposit, 1=PostDeposit, 2=PreWithdraw, 3=PostWithdraw
-    let hook_point = *instruction_data.first().ok_or(ProgramError::InvalidInstructionData)?;
-    match hook_point {
-        0..=1 => {
-            if accounts.len() < 3 {
-                return Err(ProgramError::Custom(42));
-            }
-        }
-        2..=3 => {
-            if accounts.len() < 4 {
-                return Err(ProgramError::Custom(42));
-            }
+    let core_len = core_account_count(instruction_data).ok_or(ProgramError::InvalidInstructionData)?;
+    if accounts.len() < core_len {
+        return Err(ProgramError::Custom(42));
+    }
+
+    // Per-user state: byte 0 of each writable hook-owned account counts invocations.
+    for account in accounts.iter().filter(|account| account.is_writable() && account.owned_by(program_id)) {
+        let mut data = account.try_borrow_mut()?;
+        if let Some(counter) = data.first_mut() {
+            *counter = counter.wrapping_add(1);
         }
-        _ => return Err(ProgramError::InvalidInstructionData),
     }
 
     Ok(())
@@ -48,7 +79,205 @@ pub fn process_instruction(
     Err(ProgramError::Custom(1))
 }
 
-#[cfg(not(any(feature = "allow", feature = "deny")))]
+/// Reason code reported by the `reject` variant
+pub const REJECT_CODE: u16 = 7;
+
+/// Prefix the escrow requires on return data for it to count as a rejection
+pub const REJECTION_MAGIC: [u8; 4] = *b"EHRJ";
+
+/// Return data that rejects the escrow operation with `code`
+pub fn rejection(code: u16) -> [u8; 6] {
+    let mut data = [0u8; 6];
+    data[..4].copy_from_slice(&REJECTION_MAGIC);
+    data[4..].copy_from_slice(&code.to_le_bytes());
+    data
+}
+
+#[cfg(feature = "reject")]
+pub fn process_instruction(
+    _program_id: &Address,
+    _accounts: &[AccountView],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    pinocchio::cpi::set_return_data(&rejection(REJECT_CODE));
+    Ok(())
+}
+
+/// Escrow instruction the `reenter` variant replays (Withdraw)
+pub const REENTER_DISCRIMINATOR: u8 = 5;
+
+/// Config bit: validate hook point and core account order against the config
+pub const CONFIG_FLAG_ASSERT: u8 = 1 << 0;
+/// Config bit: record each invocation into the log account following the config
+pub const CONFIG_FLAG_LOG: u8 = 1 << 1;
+/// Config bit: call back into the escrow program with a Withdraw
+pub const CONFIG_FLAG_REENTER: u8 = 1 << 2;
+/// Config bit: set the reject code as untagged return data and succeed
+pub const CONFIG_FLAG_UNTAGGED_RETURN_DATA: u8 = 1 << 3;
+
+/// Max core accounts the escrow passes to a hook (withdraw hook points)
+pub const MAX_CORE_ACCOUNTS: usize = 4;
+/// Config account length
+pub const CONFIG_LEN: usize = 10 + MAX_CORE_ACCOUNTS * 32;
+/// Log entry length: hook_point, account_count, writable_count, signer_count, core addresses
+pub const LOG_ENTRY_LEN: usize = 4 + MAX_CORE_ACCOUNTS * 32;
+/// Number of entries a log account holds
+pub const LOG_CAPACITY: usize = 4;
+/// Log account length
+pub const LOG_LEN: usize = 4 + LOG_CAPACITY * LOG_ENTRY_LEN;
+/// Reason code reported when a configured assertion fails
+pub const ASSERT_FAILED_CODE: u16 = 0xA55E;
+
+/// Number of core accounts the escrow passes for a hook invocation
+///
+/// hook_point: 0=PreDeposit, 1=PostDeposit, 2=PreWithdraw, 3=PostWithdraw,
+/// 4=PreWithdrawBatch, 5=PostWithdrawBatch (followed by the receipt count)
+pub fn core_account_count(instruction_data: &[u8]) -> Option<usize> {
+    match instruction_data {
+        [0..=1] => Some(3),
+        [2..=3] => Some(4),
+        [4..=5, receipt_count] => Some(3 + *receipt_count as usize),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "configurable")]
+pub fn process_instruction(program_id: &Address, accounts: &[AccountView], instruction_data: &[u8]) -> ProgramResult {
+    use pinocchio::{cpi::set_return_data, error::ProgramError};
+
+    let hook_point = *instruction_data.first().ok_or(ProgramError::InvalidInstructionData)?;
+    let core_len = core_account_count(instruction_data).ok_or(ProgramError::InvalidInstructionData)?;
+
+    let config_account = accounts.get(core_len).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if !config_account.owned_by(program_id) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    let config_data = config_account.try_borrow()?;
+    let config: &[u8; CONFIG_LEN] =
+        config_data.get(..CONFIG_LEN).and_then(|d| d.try_into().ok()).ok_or(ProgramError::InvalidAccountData)?;
+    let flags = config[3];
+    let point_bit = 1u8 << hook_point;
+
+    if flags & CONFIG_FLAG_ASSERT != 0 && !assert_context(config, point_bit, &accounts[..core_len]) {
+        set_return_data(&rejection(ASSERT_FAILED_CODE));
+        return Ok(());
+    }
+
+    if flags & CONFIG_FLAG_LOG != 0 {
+        let log_account = accounts.get(core_len + 1).ok_or(ProgramError::NotEnoughAccountKeys)?;
+        if !log_account.owned_by(program_id) || !log_account.is_writable() {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        record_invocation(&mut log_account.try_borrow_mut()?, hook_point, accounts, core_len)?;
+    }
+
+    let burn_iterations = u32::from_le_bytes([config[4], config[5], config[6], config[7]]);
+    let mut accumulator = 0u64;
+    for i in 0..burn_iterations {
+        accumulator = core::hint::black_box(accumulator.wrapping_mul(31).wrapping_add(i as u64));
+    }
+
+    let reject_code = u16::from_le_bytes([config[1], config[2]]);
+    if config[0] & point_bit != 0 {
+        set_return_data(&rejection(reject_code));
+        return Ok(());
+    }
+
+    if flags & CONFIG_FLAG_UNTAGGED_RETURN_DATA != 0 {
+        set_return_data(&reject_code.to_le_bytes());
+    }
+
+    if flags & CONFIG_FLAG_REENTER != 0 {
+        drop(config_data);
+        return reenter(program_id, accounts);
+    }
+
+    Ok(())
+}
+
+/// Checks the hook point and core account order against the config
+///
+/// The payload shape is already checked by `core_account_count`.
+#[cfg(feature = "configurable")]
+fn assert_context(config: &[u8; CONFIG_LEN], point_bit: u8, core_accounts: &[AccountView]) -> bool {
+    let expected_len = config[9] as usize;
+    if config[8] & point_bit == 0 || expected_len != core_accounts.len() {
+        return false;
+    }
+
+    core_accounts.iter().enumerate().all(|(i, account)| {
+        let offset = 10 + i * 32;
+        !account.is_signer() && !account.is_writable() && account.address().as_ref() == &config[offset..offset + 32]
+    })
+}
+
+/// Appends an invocation entry to the log, dropping entries past capacity
+#[cfg(feature = "configurable")]
+fn record_invocation(log: &mut [u8], hook_point: u8, accounts: &[AccountView], core_len: usize) -> ProgramResult {
+    use pinocchio::error::ProgramError;
+
+    if log.len() < LOG_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    let count = u32::from_le_bytes([log[0], log[1], log[2], log[3]]);
+    log[..4].copy_from_slice(&count.wrapping_add(1).to_le_bytes());
+
+    let index = count as usize;
+    if index >= LOG_CAPACITY {
+        return Ok(());
+    }
+
+    let entry = &mut log[4 + index * LOG_ENTRY_LEN..4 + (index + 1) * LOG_ENTRY_LEN];
+    entry[0] = hook_point;
+    entry[1] = accounts.len() as u8;
+    entry[2] = accounts.iter().filter(|account| account.is_writable()).count() as u8;
+    entry[3] = accounts.iter().filter(|account| account.is_signer()).count() as u8;
+    for (i, account) in accounts.iter().take(core_len.min(MAX_CORE_ACCOUNTS)).enumerate() {
+        entry[4 + i * 32..4 + (i + 1) * 32].copy_from_slice(account.address().as_ref());
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "reenter")]
+pub fn process_instruction(program_id: &Address, accounts: &[AccountView], _instruction_data: &[u8]) -> ProgramResult {
+    reenter(program_id, accounts)
+}
+
+/// Calls back into the escrow program, which must be passed as an extra account
+#[cfg(any(feature = "reenter", feature = "configurable"))]
+fn reenter(program_id: &Address, accounts: &[AccountView]) -> ProgramResult {
+    use alloc::vec::Vec;
+    use pinocchio::{
+        cpi::invoke_with_slice,
+        error::ProgramError,
+        instruction::{InstructionAccount, InstructionView},
+    };
+
+    let escrow_program = accounts
+        .iter()
+        .find(|account| account.executable() && account.address() != program_id)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    let account_views: Vec<&AccountView> = accounts.iter().collect();
+    let instruction_accounts: Vec<InstructionAccount> = accounts.iter().map(InstructionAccount::from).collect();
+    let instruction = InstructionView {
+        program_id: escrow_program.address(),
+        accounts: &instruction_accounts,
+        data: &[REENTER_DISCRIMINATOR],
+    };
+
+    invoke_with_slice(&instruction, &account_views)
+}
+
+#[cfg(not(any(
+    feature = "allow",
+    feature = "deny",
+    feature = "reject",
+    feature = "reenter",
+    feature = "configurable"
+)))]
 pub fn process_instruction(
     _program_id: &Address,
     _accounts: &[AccountView],